@@ -17,6 +17,9 @@ pub struct CreationContext<'s> {
     ///
     /// You can use this to customize the look of egui, e.g to call [`egui::Context::set_fonts`],
     /// [`egui::Context::set_visuals`] etc.
+    ///
+    /// The persisted egui memory has already been loaded into it.
+    /// Call [`egui::Memory::migrate`] here if what you persist has changed between versions of your app.
     pub egui_ctx: egui::Context,
 
     /// Information about the surrounding environment.
@@ -288,6 +291,13 @@ pub struct NativeOptions {
     /// When `true`, [`winit::platform::run_return::EventLoopExtRunReturn::run_return`] is used.
     /// When `false`, [`winit::event_loop::EventLoop::run`] is used.
     pub run_and_return: bool,
+
+    /// Where to persist the app state and the egui memory.
+    ///
+    /// If `None` (default), a file in the data directory of the app is used,
+    /// if the `persistence` feature is enabled.
+    /// Set this to persist to e.g. a database or a server instead. See also [`CachedStorage`].
+    pub storage_creator: Option<std::sync::Arc<StorageCreator>>,
 }
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -315,6 +325,7 @@ impl Default for NativeOptions {
             follow_system_theme: cfg!(target_os = "macos") || cfg!(target_os = "windows"),
             default_theme: Theme::Dark,
             run_and_return: true,
+            storage_creator: None,
         }
     }
 }
@@ -364,6 +375,12 @@ pub struct WebOptions {
     ///
     /// Default: [`WebGlContextOption::BestFirst`].
     pub webgl_context_option: WebGlContextOption,
+
+    /// Where to persist the app state and the egui memory.
+    ///
+    /// If `None` (default), the local storage of the browser is used.
+    /// Set this to persist to e.g. IndexedDB or a server instead. See also [`CachedStorage`].
+    pub storage_creator: Option<std::sync::Arc<StorageCreator>>,
 }
 
 #[cfg(target_arch = "wasm32")]
@@ -373,6 +390,7 @@ impl Default for WebOptions {
             follow_system_theme: true,
             default_theme: Theme::Dark,
             webgl_context_option: WebGlContextOption::BestFirst,
+            storage_creator: None,
         }
     }
 }
@@ -755,6 +773,72 @@ pub trait Storage {
     fn flush(&mut self);
 }
 
+/// Creates the [`Storage`] of an app, given the name of the app (or the canvas id on the web).
+///
+/// See [`NativeOptions::storage_creator`] and [`WebOptions::storage_creator`].
+pub type StorageCreator = dyn Fn(&str) -> Option<Box<dyn Storage>> + Send + Sync;
+
+/// A [`Storage`] that keeps all values in memory,
+/// and hands the changed values to a callback on [`Storage::flush`].
+///
+/// This is useful for asynchronous backends, like IndexedDB or a server:
+/// load all values before starting the app, and write the changes asynchronously from the callback.
+///
+/// ```
+/// let storage = eframe::CachedStorage::new(
+///     vec![("key".to_owned(), "value".to_owned())],
+///     |changes| {
+///         for (key, value) in changes {
+///             // send to the server…
+///         }
+///     },
+/// );
+/// ```
+pub struct CachedStorage {
+    values: std::collections::BTreeMap<String, String>,
+    changed: std::collections::BTreeSet<String>,
+    on_flush: Box<dyn FnMut(Vec<(String, String)>)>,
+}
+
+impl CachedStorage {
+    pub fn new(
+        values: impl IntoIterator<Item = (String, String)>,
+        on_flush: impl FnMut(Vec<(String, String)>) + 'static,
+    ) -> Self {
+        Self {
+            values: values.into_iter().collect(),
+            changed: Default::default(),
+            on_flush: Box::new(on_flush),
+        }
+    }
+}
+
+impl Storage for CachedStorage {
+    fn get_string(&self, key: &str) -> Option<String> {
+        self.values.get(key).cloned()
+    }
+
+    fn set_string(&mut self, key: &str, value: String) {
+        if self.values.get(key) != Some(&value) {
+            self.values.insert(key.to_owned(), value);
+            self.changed.insert(key.to_owned());
+        }
+    }
+
+    fn flush(&mut self) {
+        if !self.changed.is_empty() {
+            let changes = std::mem::take(&mut self.changed)
+                .into_iter()
+                .filter_map(|key| {
+                    let value = self.values.get(&key)?.clone();
+                    Some((key, value))
+                })
+                .collect();
+            (self.on_flush)(changes);
+        }
+    }
+}
+
 /// Stores nothing.
 #[derive(Clone, Default)]
 pub(crate) struct DummyStorage {}
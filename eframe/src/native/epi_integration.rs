@@ -165,10 +165,17 @@ pub fn handle_app_output(
 
 // ----------------------------------------------------------------------------
 
-/// For loading/saving app state and/or egui memory to disk.
-pub fn create_storage(_app_name: &str) -> Option<Box<dyn epi::Storage>> {
+/// For loading/saving app state and/or egui memory to disk,
+/// or wherever [`epi::NativeOptions::storage_creator`] says.
+pub fn create_storage(
+    app_name: &str,
+    storage_creator: Option<&epi::StorageCreator>,
+) -> Option<Box<dyn epi::Storage>> {
+    if let Some(storage_creator) = storage_creator {
+        return storage_creator(app_name);
+    }
     #[cfg(feature = "persistence")]
-    if let Some(storage) = super::file_storage::FileStorage::from_app_name(_app_name) {
+    if let Some(storage) = super::file_storage::FileStorage::from_app_name(app_name) {
         return Some(Box::new(storage));
     }
     None
@@ -367,10 +374,21 @@ pub fn load_window_settings(_storage: Option<&dyn epi::Storage>) -> Option<Windo
     None
 }
 
+/// Use [`egui::Memory::migrate`] to handle memory persisted by older versions of your app.
 pub fn load_egui_memory(_storage: Option<&dyn epi::Storage>) -> Option<egui::Memory> {
     #[cfg(feature = "persistence")]
     {
-        epi::get_value(_storage?, STORAGE_EGUI_MEMORY_KEY)
+        let ron = _storage?.get_string(STORAGE_EGUI_MEMORY_KEY)?;
+        match ron::from_str(&ron) {
+            Ok(memory) => Some(memory),
+            Err(err) => {
+                tracing::warn!(
+                    "Failed to parse egui memory, starting from scratch: {}",
+                    err
+                );
+                None
+            }
+        }
     }
     #[cfg(not(feature = "persistence"))]
     None
@@ -246,7 +246,10 @@ mod glow_integration {
             native_options: &epi::NativeOptions,
             app_creator: epi::AppCreator,
         ) -> Self {
-            let storage = epi_integration::create_storage(app_name);
+            let storage = epi_integration::create_storage(
+                app_name,
+                native_options.storage_creator.as_deref(),
+            );
             let window_settings = epi_integration::load_window_settings(storage.as_deref());
 
             let window_builder = epi_integration::window_builder(native_options, &window_settings)
@@ -365,6 +368,7 @@ mod glow_integration {
                 &clipped_primitives,
                 &textures_delta,
             );
+            integration.egui_ctx.recycle_primitives(clipped_primitives);
 
             integration.post_rendering(app.as_mut(), window);
 
@@ -490,7 +494,10 @@ mod wgpu_integration {
             native_options: &epi::NativeOptions,
             app_creator: epi::AppCreator,
         ) -> Self {
-            let storage = epi_integration::create_storage(app_name);
+            let storage = epi_integration::create_storage(
+                app_name,
+                native_options.storage_creator.as_deref(),
+            );
             let window_settings = epi_integration::load_window_settings(storage.as_deref());
 
             let window = epi_integration::window_builder(native_options, &window_settings)
@@ -532,6 +539,8 @@ mod wgpu_integration {
             );
             let theme = system_theme.unwrap_or(native_options.default_theme);
             integration.egui_ctx.set_visuals(theme.egui_visuals());
+            // egui-wgpu can paint `Shape::Instanced` with GPU instancing:
+            integration.egui_ctx.tessellation_options().instancing = true;
 
             {
                 let event_loop_proxy = egui::mutex::Mutex::new(event_loop.create_proxy());
@@ -621,6 +630,7 @@ mod wgpu_integration {
                 &clipped_primitives,
                 &textures_delta,
             );
+            integration.egui_ctx.recycle_primitives(clipped_primitives);
 
             integration.post_rendering(app.as_mut(), window);
 
@@ -204,10 +204,14 @@ impl AppRunner {
             cpu_usage: None,
             native_pixels_per_point: Some(native_pixels_per_point()),
         };
-        let storage = LocalStorage::default();
+        let storage: Box<dyn epi::Storage> = web_options
+            .storage_creator
+            .as_ref()
+            .and_then(|storage_creator| storage_creator(canvas_id))
+            .unwrap_or_else(|| Box::new(LocalStorage::default()));
 
         let egui_ctx = egui::Context::default();
-        load_memory(&egui_ctx);
+        load_memory(&egui_ctx, storage.as_ref());
 
         let theme = system_theme.unwrap_or(web_options.default_theme);
         egui_ctx.set_visuals(theme.egui_visuals());
@@ -215,7 +219,7 @@ impl AppRunner {
         let app = app_creator(&epi::CreationContext {
             egui_ctx: egui_ctx.clone(),
             integration_info: info.clone(),
-            storage: Some(&storage),
+            storage: Some(storage.as_ref()),
             #[cfg(feature = "glow")]
             gl: Some(painter.painter.gl().clone()),
             #[cfg(feature = "wgpu")]
@@ -225,7 +229,7 @@ impl AppRunner {
         let frame = epi::Frame {
             info,
             output: Default::default(),
-            storage: Some(Box::new(storage)),
+            storage: Some(storage),
             #[cfg(feature = "glow")]
             gl: Some(painter.gl().clone()),
             #[cfg(feature = "wgpu")]
@@ -270,11 +274,12 @@ impl AppRunner {
         let time_since_last_save = now - self.last_save_time;
 
         if time_since_last_save > self.app.auto_save_interval().as_secs_f64() {
-            if self.app.persist_egui_memory() {
-                save_memory(&self.egui_ctx);
-            }
             if let Some(storage) = self.frame.storage_mut() {
+                if self.app.persist_egui_memory() {
+                    save_memory(&self.egui_ctx, storage);
+                }
                 self.app.save(storage);
+                storage.flush();
             }
             self.last_save_time = now;
         }
@@ -374,7 +379,9 @@ impl AppRunner {
             cursor_icon,
             open_url,
             copied_text,
-            events: _, // already handled
+            copied_image: _, // not supported yet
+            copied_html: _,  // not supported yet, but we still copy the plain-text `copied_text`
+            events: _,       // already handled
             mutable_text_under_cursor,
             text_cursor_pos,
         } = platform_output;
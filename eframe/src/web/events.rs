@@ -16,6 +16,9 @@ pub fn paint_and_schedule(
             runner_lock.clear_color_buffer();
             let (repaint_after, clipped_primitives) = runner_lock.logic()?;
             runner_lock.paint(&clipped_primitives)?;
+            runner_lock
+                .egui_ctx()
+                .recycle_primitives(clipped_primitives);
             runner_lock
                 .needs_repaint
                 .repaint_after(repaint_after.as_secs_f64());
@@ -429,6 +432,15 @@ pub fn install_canvas_events(runner_container: &mut AppRunnerContainer) -> Resul
         "dragover",
         |event: web_sys::DragEvent, mut runner_lock| {
             if let Some(data_transfer) = event.data_transfer() {
+                // No mouse events are sent while dragging, so this is how egui learns
+                // which widget the files are hovering:
+                let pos = pos_from_mouse_event(runner_lock.canvas_id(), &event);
+                runner_lock
+                    .input
+                    .raw
+                    .events
+                    .push(egui::Event::PointerMoved(pos));
+
                 runner_lock.input.raw.hovered_files.clear();
                 for i in 0..data_transfer.items().length() {
                     if let Some(item) = data_transfer.items().get(i) {
@@ -11,8 +11,8 @@ pub fn local_storage_set(key: &str, value: &str) {
 }
 
 #[cfg(feature = "persistence")]
-pub fn load_memory(ctx: &egui::Context) {
-    if let Some(memory_string) = local_storage_get("egui_memory_ron") {
+pub fn load_memory(ctx: &egui::Context, storage: &dyn crate::Storage) {
+    if let Some(memory_string) = storage.get_string("egui_memory_ron") {
         match ron::from_str(&memory_string) {
             Ok(memory) => {
                 *ctx.memory() = memory;
@@ -25,13 +25,13 @@ pub fn load_memory(ctx: &egui::Context) {
 }
 
 #[cfg(not(feature = "persistence"))]
-pub fn load_memory(_: &egui::Context) {}
+pub fn load_memory(_: &egui::Context, _: &dyn crate::Storage) {}
 
 #[cfg(feature = "persistence")]
-pub fn save_memory(ctx: &egui::Context) {
+pub fn save_memory(ctx: &egui::Context, storage: &mut dyn crate::Storage) {
     match ron::to_string(&*ctx.memory()) {
         Ok(ron) => {
-            local_storage_set("egui_memory_ron", &ron);
+            storage.set_string("egui_memory_ron", ron);
         }
         Err(err) => {
             tracing::error!("Failed to serialize memory as RON: {}", err);
@@ -40,4 +40,4 @@ pub fn save_memory(ctx: &egui::Context) {
 }
 
 #[cfg(not(feature = "persistence"))]
-pub fn save_memory(_: &egui::Context) {}
+pub fn save_memory(_: &egui::Context, _: &mut dyn crate::Storage) {}
@@ -69,6 +69,49 @@ fn vs_conv_main(
     return out;
 }
 
+// [`egui::epaint::Instance`]: the template vertex is scaled, moved and multiplied in color.
+fn instanced_position(a_pos: vec2<f32>, i_offset: vec2<f32>, i_scale: f32) -> vec4<f32> {
+    return position_from_screen(i_scale * a_pos + i_offset);
+}
+
+fn instanced_color(a_color: u32, i_color: u32) -> vec4<f32> {
+    return unpack_color(a_color) * unpack_color(i_color) / 255.0;
+}
+
+@vertex
+fn vs_instanced_main(
+    @location(0) a_pos: vec2<f32>,
+    @location(1) a_tex_coord: vec2<f32>,
+    @location(2) a_color: u32,
+    @location(3) i_offset: vec2<f32>,
+    @location(4) i_scale: f32,
+    @location(5) i_color: u32,
+) -> VertexOutput {
+    var out: VertexOutput;
+    out.tex_coord = a_tex_coord;
+    let color = instanced_color(a_color, i_color);
+    out.color = vec4<f32>(linear_from_srgb(color.rgb), color.a / 255.0);
+    out.position = instanced_position(a_pos, i_offset, i_scale);
+    return out;
+}
+
+@vertex
+fn vs_instanced_conv_main(
+    @location(0) a_pos: vec2<f32>,
+    @location(1) a_tex_coord: vec2<f32>,
+    @location(2) a_color: u32,
+    @location(3) i_offset: vec2<f32>,
+    @location(4) i_scale: f32,
+    @location(5) i_color: u32,
+) -> VertexOutput {
+    var out: VertexOutput;
+    out.tex_coord = a_tex_coord;
+    let color = instanced_color(a_color, i_color);
+    out.color = vec4<f32>(color.rgba / 255.0);
+    out.position = instanced_position(a_pos, i_offset, i_scale);
+    return out;
+}
+
 // Fragment shader bindings
 
 @group(1) @binding(0) var r_tex_color: texture_2d<f32>;
@@ -79,6 +79,7 @@ enum BufferType {
     Uniform,
     Index,
     Vertex,
+    Instance,
 }
 
 /// Information about the screen used for rendering.
@@ -121,8 +122,12 @@ struct SizedBuffer {
 /// Render pass to render a egui based GUI.
 pub struct RenderPass {
     render_pipeline: wgpu::RenderPipeline,
+    /// For [`Primitive::Instanced`].
+    instanced_pipeline: wgpu::RenderPipeline,
     index_buffers: Vec<SizedBuffer>,
     vertex_buffers: Vec<SizedBuffer>,
+    /// One for each [`Primitive::Instanced`], which also uses an index and a vertex buffer for its template.
+    instance_buffers: Vec<SizedBuffer>,
     uniform_buffer: SizedBuffer,
     uniform_bind_group: wgpu::BindGroup,
     texture_bind_group_layout: wgpu::BindGroupLayout,
@@ -221,68 +226,93 @@ impl RenderPass {
             push_constant_ranges: &[],
         });
 
-        let render_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
-            label: Some("egui_pipeline"),
-            layout: Some(&pipeline_layout),
-            vertex: wgpu::VertexState {
-                entry_point: if output_format.describe().srgb {
-                    "vs_main"
-                } else {
-                    "vs_conv_main"
+        let vertex_buffer_layout = wgpu::VertexBufferLayout {
+            array_stride: 5 * 4,
+            step_mode: wgpu::VertexStepMode::Vertex,
+            // 0: vec2 position
+            // 1: vec2 texture coordinates
+            // 2: uint color
+            attributes: &wgpu::vertex_attr_array![0 => Float32x2, 1 => Float32x2, 2 => Uint32],
+        };
+        let instance_buffer_layout = wgpu::VertexBufferLayout {
+            array_stride: 4 * 4,
+            step_mode: wgpu::VertexStepMode::Instance,
+            // 3: vec2 offset
+            // 4: float scale
+            // 5: uint color
+            attributes: &wgpu::vertex_attr_array![3 => Float32x2, 4 => Float32, 5 => Uint32],
+        };
+        let srgb = output_format.describe().srgb;
+
+        let create_pipeline = |label, entry_point, buffers: &[wgpu::VertexBufferLayout<'_>]| {
+            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                label: Some(label),
+                layout: Some(&pipeline_layout),
+                vertex: wgpu::VertexState {
+                    entry_point,
+                    module: &module,
+                    buffers,
+                },
+                primitive: wgpu::PrimitiveState {
+                    topology: wgpu::PrimitiveTopology::TriangleList,
+                    unclipped_depth: false,
+                    conservative: false,
+                    cull_mode: None,
+                    front_face: wgpu::FrontFace::default(),
+                    polygon_mode: wgpu::PolygonMode::default(),
+                    strip_index_format: None,
+                },
+                depth_stencil: None,
+                multisample: wgpu::MultisampleState {
+                    alpha_to_coverage_enabled: false,
+                    count: msaa_samples,
+                    mask: !0,
                 },
-                module: &module,
-                buffers: &[wgpu::VertexBufferLayout {
-                    array_stride: 5 * 4,
-                    step_mode: wgpu::VertexStepMode::Vertex,
-                    // 0: vec2 position
-                    // 1: vec2 texture coordinates
-                    // 2: uint color
-                    attributes: &wgpu::vertex_attr_array![0 => Float32x2, 1 => Float32x2, 2 => Uint32],
-                }],
-            },
-            primitive: wgpu::PrimitiveState {
-                topology: wgpu::PrimitiveTopology::TriangleList,
-                unclipped_depth: false,
-                conservative: false,
-                cull_mode: None,
-                front_face: wgpu::FrontFace::default(),
-                polygon_mode: wgpu::PolygonMode::default(),
-                strip_index_format: None,
-            },
-            depth_stencil: None,
-            multisample: wgpu::MultisampleState {
-                alpha_to_coverage_enabled: false,
-                count: msaa_samples,
-                mask: !0,
-            },
 
-            fragment: Some(wgpu::FragmentState {
-                module: &module,
-                entry_point: "fs_main",
-                targets: &[Some(wgpu::ColorTargetState {
-                    format: output_format,
-                    blend: Some(wgpu::BlendState {
-                        color: wgpu::BlendComponent {
-                            src_factor: wgpu::BlendFactor::One,
-                            dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
-                            operation: wgpu::BlendOperation::Add,
-                        },
-                        alpha: wgpu::BlendComponent {
-                            src_factor: wgpu::BlendFactor::OneMinusDstAlpha,
-                            dst_factor: wgpu::BlendFactor::One,
-                            operation: wgpu::BlendOperation::Add,
-                        },
-                    }),
-                    write_mask: wgpu::ColorWrites::ALL,
-                })],
-            }),
-            multiview: None,
-        });
+                fragment: Some(wgpu::FragmentState {
+                    module: &module,
+                    entry_point: "fs_main",
+                    targets: &[Some(wgpu::ColorTargetState {
+                        format: output_format,
+                        blend: Some(wgpu::BlendState {
+                            color: wgpu::BlendComponent {
+                                src_factor: wgpu::BlendFactor::One,
+                                dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
+                                operation: wgpu::BlendOperation::Add,
+                            },
+                            alpha: wgpu::BlendComponent {
+                                src_factor: wgpu::BlendFactor::OneMinusDstAlpha,
+                                dst_factor: wgpu::BlendFactor::One,
+                                operation: wgpu::BlendOperation::Add,
+                            },
+                        }),
+                        write_mask: wgpu::ColorWrites::ALL,
+                    })],
+                }),
+                multiview: None,
+            })
+        };
+        let render_pipeline = create_pipeline(
+            "egui_pipeline",
+            if srgb { "vs_main" } else { "vs_conv_main" },
+            &[vertex_buffer_layout.clone()],
+        );
+        let instanced_pipeline = create_pipeline(
+            "egui_instanced_pipeline",
+            if srgb {
+                "vs_instanced_main"
+            } else {
+                "vs_instanced_conv_main"
+            },
+            &[vertex_buffer_layout, instance_buffer_layout],
+        );
 
         Self {
             render_pipeline,
+            instanced_pipeline,
             vertex_buffers: Vec::with_capacity(64),
             index_buffers: Vec::with_capacity(64),
+            instance_buffers: Vec::new(),
             uniform_buffer,
             uniform_bind_group,
             texture_bind_group_layout,
@@ -342,6 +372,7 @@ impl RenderPass {
 
         let mut index_buffers = self.index_buffers.iter();
         let mut vertex_buffers = self.vertex_buffers.iter();
+        let mut instance_buffers = self.instance_buffers.iter();
 
         for egui::ClippedPrimitive {
             clip_rect,
@@ -367,10 +398,18 @@ impl RenderPass {
 
                 if rect.width == 0 || rect.height == 0 {
                     // Skip rendering with zero-sized clip areas.
-                    if let Primitive::Mesh(_) = primitive {
-                        // If this is a mesh, we need to advance the index and vertex buffer iterators
-                        index_buffers.next().unwrap();
-                        vertex_buffers.next().unwrap();
+                    match primitive {
+                        Primitive::Mesh(_) => {
+                            // If this is a mesh, we need to advance the index and vertex buffer iterators
+                            index_buffers.next().unwrap();
+                            vertex_buffers.next().unwrap();
+                        }
+                        Primitive::Instanced(_) => {
+                            index_buffers.next().unwrap();
+                            vertex_buffers.next().unwrap();
+                            instance_buffers.next().unwrap();
+                        }
+                        Primitive::Callback(_) => {}
                     }
                     continue;
                 }
@@ -395,6 +434,31 @@ impl RenderPass {
                         tracing::warn!("Missing texture: {:?}", mesh.texture_id);
                     }
                 }
+                Primitive::Instanced(instanced) => {
+                    let index_buffer = index_buffers.next().unwrap();
+                    let vertex_buffer = vertex_buffers.next().unwrap();
+                    let instance_buffer = instance_buffers.next().unwrap();
+
+                    let texture_id = instanced.texture_id();
+                    if let Some((_texture, bind_group)) = self.textures.get(&texture_id) {
+                        rpass.set_pipeline(&self.instanced_pipeline);
+                        rpass.set_bind_group(1, bind_group, &[]);
+                        rpass.set_index_buffer(
+                            index_buffer.buffer.slice(..),
+                            wgpu::IndexFormat::Uint32,
+                        );
+                        rpass.set_vertex_buffer(0, vertex_buffer.buffer.slice(..));
+                        rpass.set_vertex_buffer(1, instance_buffer.buffer.slice(..));
+                        rpass.draw_indexed(
+                            0..instanced.template.indices.len() as u32,
+                            0,
+                            0..instanced.instances.len() as u32,
+                        );
+                        rpass.set_pipeline(&self.render_pipeline);
+                    } else {
+                        tracing::warn!("Missing texture: {:?}", texture_id);
+                    }
+                }
                 Primitive::Callback(callback) => {
                     let cbfn = if let Some(c) = callback.callback.downcast_ref::<CallbackFn>() {
                         c
@@ -473,6 +537,15 @@ impl RenderPass {
                 );
                 Cow::Borrowed(&image.pixels)
             }
+            egui::ImageData::Hdr(image) => {
+                assert_eq!(
+                    width as usize * height as usize,
+                    image.pixels.len(),
+                    "Mismatch between texture size and texel count"
+                );
+                // We only render standard dynamic range, so clamp the colors:
+                Cow::Owned(image.srgba_pixels().collect::<Vec<_>>())
+            }
             egui::ImageData::Font(image) => {
                 assert_eq!(
                     width as usize * height as usize,
@@ -678,41 +751,38 @@ impl RenderPass {
         );
 
         let mut mesh_idx = 0;
+        let mut instanced_idx = 0;
         for egui::ClippedPrimitive { primitive, .. } in paint_jobs.iter() {
             match primitive {
                 Primitive::Mesh(mesh) => {
-                    let data: &[u8] = bytemuck::cast_slice(&mesh.indices);
-                    if mesh_idx < self.index_buffers.len() {
-                        self.update_buffer(device, queue, &BufferType::Index, mesh_idx, data);
-                    } else {
-                        let buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
-                            label: Some("egui_index_buffer"),
-                            contents: data,
-                            usage: wgpu::BufferUsages::INDEX | wgpu::BufferUsages::COPY_DST,
-                        });
-                        self.index_buffers.push(SizedBuffer {
-                            buffer,
-                            size: data.len(),
-                        });
-                    }
+                    self.upload_mesh(device, queue, mesh_idx, mesh);
+                    mesh_idx += 1;
+                }
+                Primitive::Instanced(instanced) => {
+                    self.upload_mesh(device, queue, mesh_idx, &instanced.template);
+                    mesh_idx += 1;
 
-                    let data: &[u8] = bytemuck::cast_slice(&mesh.vertices);
-                    if mesh_idx < self.vertex_buffers.len() {
-                        self.update_buffer(device, queue, &BufferType::Vertex, mesh_idx, data);
+                    let data: &[u8] = bytemuck::cast_slice(&instanced.instances);
+                    if instanced_idx < self.instance_buffers.len() {
+                        self.update_buffer(
+                            device,
+                            queue,
+                            &BufferType::Instance,
+                            instanced_idx,
+                            data,
+                        );
                     } else {
                         let buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
-                            label: Some("egui_vertex_buffer"),
+                            label: Some("egui_instance_buffer"),
                             contents: data,
                             usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
                         });
-
-                        self.vertex_buffers.push(SizedBuffer {
+                        self.instance_buffers.push(SizedBuffer {
                             buffer,
                             size: data.len(),
                         });
                     }
-
-                    mesh_idx += 1;
+                    instanced_idx += 1;
                 }
                 Primitive::Callback(callback) => {
                     let cbfn = if let Some(c) = callback.callback.downcast_ref::<CallbackFn>() {
@@ -728,6 +798,46 @@ impl RenderPass {
         }
     }
 
+    /// Upload the indices and vertices of a mesh into the index and vertex buffers at `index`.
+    fn upload_mesh(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        index: usize,
+        mesh: &egui::epaint::Mesh,
+    ) {
+        let data: &[u8] = bytemuck::cast_slice(&mesh.indices);
+        if index < self.index_buffers.len() {
+            self.update_buffer(device, queue, &BufferType::Index, index, data);
+        } else {
+            let buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("egui_index_buffer"),
+                contents: data,
+                usage: wgpu::BufferUsages::INDEX | wgpu::BufferUsages::COPY_DST,
+            });
+            self.index_buffers.push(SizedBuffer {
+                buffer,
+                size: data.len(),
+            });
+        }
+
+        let data: &[u8] = bytemuck::cast_slice(&mesh.vertices);
+        if index < self.vertex_buffers.len() {
+            self.update_buffer(device, queue, &BufferType::Vertex, index, data);
+        } else {
+            let buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("egui_vertex_buffer"),
+                contents: data,
+                usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
+            });
+
+            self.vertex_buffers.push(SizedBuffer {
+                buffer,
+                size: data.len(),
+            });
+        }
+    }
+
     /// Updates the buffers used by egui. Will properly re-size the buffers if needed.
     fn update_buffer(
         &mut self,
@@ -748,6 +858,11 @@ impl RenderPass {
                 wgpu::BufferUsages::VERTEX,
                 "egui_vertex_buffer",
             ),
+            BufferType::Instance => (
+                &mut self.instance_buffers[index],
+                wgpu::BufferUsages::VERTEX,
+                "egui_instance_buffer",
+            ),
             BufferType::Uniform => (
                 &mut self.uniform_buffer,
                 wgpu::BufferUsages::UNIFORM,
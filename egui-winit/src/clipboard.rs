@@ -22,6 +22,12 @@ pub struct Clipboard {
 
     /// Fallback manual clipboard.
     clipboard: String,
+
+    /// We don't support images in the OS clipboard yet,
+    /// so they can only be copied and pasted within the same app.
+    ///
+    /// Along with the text that was in the clipboard when the image was copied.
+    image: Option<(egui::ColorImage, Option<String>)>,
 }
 
 impl Clipboard {
@@ -42,6 +48,7 @@ impl Clipboard {
             ))]
             smithay: init_smithay_clipboard(wayland_display),
             clipboard: Default::default(),
+            image: None,
         }
     }
 
@@ -81,6 +88,9 @@ impl Clipboard {
     }
 
     pub fn set(&mut self, text: String) {
+        // Whatever image was copied before is no longer what is in the clipboard:
+        self.image = None;
+
         #[cfg(all(
             any(
                 target_os = "linux",
@@ -108,6 +118,25 @@ impl Clipboard {
     }
 }
 
+impl Clipboard {
+    /// The latest image copied within this app, as long as nothing else has been copied since.
+    pub fn get_image(&mut self) -> Option<egui::ColorImage> {
+        self.image.as_ref()?;
+        let text = self.get();
+        match &self.image {
+            Some((image, text_when_copied)) if *text_when_copied == text => Some(image.clone()),
+            _ => None,
+        }
+    }
+
+    pub fn set_image(&mut self, image: egui::ColorImage) {
+        // We can't tell when something else is copied in another app,
+        // except by the text in the clipboard changing:
+        let text = self.get();
+        self.image = Some((image, text));
+    }
+}
+
 #[cfg(all(feature = "arboard", not(target_os = "android")))]
 fn init_arboard() -> Option<arboard::Clipboard> {
     match arboard::Clipboard::new() {
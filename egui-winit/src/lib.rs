@@ -48,6 +48,10 @@ pub struct State {
     current_cursor_icon: egui::CursorIcon,
     /// What egui uses.
     current_pixels_per_point: f32,
+    /// What the window system reports.
+    native_pixels_per_point: f32,
+    /// Used instead of [`Self::native_pixels_per_point`], if set.
+    pixels_per_point_override: Option<f32>,
 
     clipboard: clipboard::Clipboard,
     screen_reader: screen_reader::ScreenReader,
@@ -82,6 +86,8 @@ impl State {
             any_pointer_button_down: false,
             current_cursor_icon: egui::CursorIcon::Default,
             current_pixels_per_point: 1.0,
+            native_pixels_per_point: 1.0,
+            pixels_per_point_override: None,
 
             clipboard: clipboard::Clipboard::new(wayland_display),
             screen_reader: screen_reader::ScreenReader::default(),
@@ -105,7 +111,26 @@ impl State {
     ///
     /// Once this has been initialized for a new window then this state will be maintained by handling
     /// [`winit::event::WindowEvent::ScaleFactorChanged`] events.
+    ///
+    /// If an override is set with [`Self::set_pixels_per_point_override`], that is used instead.
     pub fn set_pixels_per_point(&mut self, pixels_per_point: f32) {
+        self.native_pixels_per_point = pixels_per_point;
+        self.apply_pixels_per_point();
+    }
+
+    /// Force a specific scale factor for this window, ignoring the native one and any changes to it.
+    ///
+    /// This is useful when e.g. a window on a projector or a screenshot window needs a specific scale.
+    /// Pass `None` to go back to the native scale factor.
+    pub fn set_pixels_per_point_override(&mut self, pixels_per_point: Option<f32>) {
+        self.pixels_per_point_override = pixels_per_point;
+        self.apply_pixels_per_point();
+    }
+
+    fn apply_pixels_per_point(&mut self) {
+        let pixels_per_point = self
+            .pixels_per_point_override
+            .unwrap_or(self.native_pixels_per_point);
         self.egui_input.pixels_per_point = Some(pixels_per_point);
         self.current_pixels_per_point = pixels_per_point;
     }
@@ -167,9 +192,7 @@ impl State {
         use winit::event::WindowEvent;
         match event {
             WindowEvent::ScaleFactorChanged { scale_factor, .. } => {
-                let pixels_per_point = *scale_factor as f32;
-                self.egui_input.pixels_per_point = Some(pixels_per_point);
-                self.current_pixels_per_point = pixels_per_point;
+                self.set_pixels_per_point(*scale_factor as f32);
                 false
             }
             WindowEvent::MouseInput { state, button, .. } => {
@@ -443,6 +466,9 @@ impl State {
                             self.egui_input.events.push(egui::Event::Paste(contents));
                         }
                     }
+                    if let Some(image) = self.clipboard.get_image() {
+                        self.egui_input.events.push(egui::Event::PasteImage(image));
+                    }
                 }
             }
 
@@ -479,7 +505,9 @@ impl State {
             cursor_icon,
             open_url,
             copied_text,
-            events: _,                    // handled above
+            copied_image,
+            copied_html: _, // not supported yet, but we still copy the plain-text `copied_text`
+            events: _,      // handled above
             mutable_text_under_cursor: _, // only used in eframe web
             text_cursor_pos,
         } = platform_output;
@@ -495,6 +523,10 @@ impl State {
             self.clipboard.set(copied_text);
         }
 
+        if let Some(copied_image) = copied_image {
+            self.clipboard.set_image(copied_image);
+        }
+
         if let Some(egui::Pos2 { x, y }) = text_cursor_pos {
             window.set_ime_position(winit::dpi::LogicalPosition { x, y });
         }
@@ -600,6 +632,11 @@ fn translate_virtual_key_code(key: winit::event::VirtualKeyCode) -> Option<egui:
         VirtualKeyCode::PageUp => Key::PageUp,
         VirtualKeyCode::PageDown => Key::PageDown,
 
+        VirtualKeyCode::Minus | VirtualKeyCode::NumpadSubtract => Key::Minus,
+        VirtualKeyCode::Equals | VirtualKeyCode::Plus | VirtualKeyCode::NumpadAdd => {
+            Key::PlusEquals
+        }
+
         VirtualKeyCode::Key0 | VirtualKeyCode::Numpad0 => Key::Num0,
         VirtualKeyCode::Key1 | VirtualKeyCode::Numpad1 => Key::Num1,
         VirtualKeyCode::Key2 | VirtualKeyCode::Numpad2 => Key::Num2,
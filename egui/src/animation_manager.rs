@@ -1,9 +1,17 @@
-use crate::{emath::remap_clamp, Id, IdMap, InputState};
+use crate::{
+    emath::remap_clamp,
+    tween::{Easing, Tween, Tweenable},
+    util::IdTypeMap,
+    Id, IdMap, InputState,
+};
 
 #[derive(Clone, Default)]
 pub(crate) struct AnimationManager {
     bools: IdMap<BoolAnim>,
     values: IdMap<ValueAnim>,
+    /// [`TargetAnim`] of different types.
+    targets: IdTypeMap,
+    tweens: IdMap<TweenState>,
 }
 
 #[derive(Clone, Debug)]
@@ -21,6 +29,29 @@ struct ValueAnim {
     toggle_time: f64,
 }
 
+#[derive(Clone, Debug)]
+struct TargetAnim<T> {
+    from: T,
+    to: T,
+    /// when did `to` last change?
+    start_time: f64,
+}
+
+#[derive(Clone, Debug)]
+struct TweenState {
+    start_time: f64,
+    /// Has [`Tween::on_complete`] been called?
+    completed: bool,
+}
+
+/// Returned by [`AnimationManager::play_tween`].
+pub(crate) struct TweenProgress<T> {
+    pub value: T,
+    pub in_progress: bool,
+    /// The tween finished this frame.
+    pub just_completed: bool,
+}
+
 impl AnimationManager {
     /// See `Context::animate_bool` for documentation
     pub fn animate_bool(
@@ -108,4 +139,70 @@ impl AnimationManager {
             }
         }
     }
+
+    /// See `Context::animate_to` for documentation.
+    ///
+    /// Returns the current value, and whether it is still animating.
+    pub fn animate_to<T: Tweenable>(
+        &mut self,
+        input: &InputState,
+        id: Id,
+        target: T,
+        duration: f32,
+        easing: Easing,
+    ) -> (T, bool) {
+        let anim = self.targets.get_temp_mut_or_insert_with(id, || TargetAnim {
+            from: target,
+            to: target,
+            start_time: -f64::INFINITY, // long time ago
+        });
+
+        let value_at = |anim: &TargetAnim<T>| {
+            // Extrapolate forwards, like in `animate_value`:
+            let elapsed = (input.time - anim.start_time) as f32 + input.predicted_dt;
+            if duration <= 0.0 || elapsed >= duration {
+                anim.to
+            } else {
+                T::tween(anim.from, anim.to, easing.apply(elapsed / duration))
+            }
+        };
+
+        if anim.to != target {
+            anim.from = value_at(anim); // start the new animation from where we are now
+            anim.to = target;
+            anim.start_time = input.time;
+        }
+        let value = value_at(anim);
+        (value, value != target)
+    }
+
+    /// See `Context::play_tween` for documentation.
+    pub fn play_tween<T: Tweenable>(
+        &mut self,
+        input: &InputState,
+        id: Id,
+        tween: &Tween<T>,
+    ) -> TweenProgress<T> {
+        let state = self.tweens.entry(id).or_insert_with(|| TweenState {
+            start_time: input.time,
+            completed: false,
+        });
+        let elapsed = (input.time - state.start_time) as f32;
+        let finished = elapsed >= tween.duration();
+        let just_completed = finished && !state.completed;
+        state.completed |= finished;
+        TweenProgress {
+            value: tween.value_at(elapsed),
+            in_progress: !finished,
+            just_completed,
+        }
+    }
+
+    pub fn restart_tween(&mut self, id: Id) {
+        self.tweens.remove(&id);
+    }
+
+    pub fn is_tween_finished(&self, id: Id) -> bool {
+        self.tweens.get(&id).map_or(false, |state| state.completed)
+    }
 }
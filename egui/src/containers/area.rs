@@ -53,6 +53,7 @@ pub struct Area {
     anchor: Option<(Align2, Vec2)>,
     new_pos: Option<Pos2>,
     drag_bounds: Option<Rect>,
+    opacity: f32,
 }
 
 impl Area {
@@ -67,6 +68,7 @@ impl Area {
             new_pos: None,
             anchor: None,
             drag_bounds: None,
+            opacity: 1.0,
         }
     }
 
@@ -157,6 +159,15 @@ impl Area {
         self
     }
 
+    /// Multiply the colors of everything in the area by this, in `[0, 1]`,
+    /// e.g. to fade it in or out. See [`Context::set_layer_opacity`].
+    ///
+    /// Default: `1.0`.
+    pub fn opacity(mut self, opacity: f32) -> Self {
+        self.opacity = opacity;
+        self
+    }
+
     pub(crate) fn get_pivot(&self) -> Align2 {
         if let Some((pivot, _)) = self.anchor {
             pivot
@@ -204,9 +215,13 @@ impl Area {
             new_pos,
             anchor,
             drag_bounds,
+            opacity,
         } = self;
 
         let layer_id = LayerId::new(order, id);
+        if opacity < 1.0 {
+            ctx.set_layer_opacity(layer_id, opacity);
+        }
 
         let state = ctx.memory().areas.get(id).cloned();
         let is_new = state.is_none();
@@ -0,0 +1,213 @@
+//! A framed container with a title bar, see [`Card`].
+
+use crate::*;
+
+use super::collapsing_header::{paint_default_icon, CollapsingState};
+
+/// A framed container with a title bar.
+///
+/// The title bar can have a collapse button, a close button,
+/// a menu and any other action buttons you like.
+/// The card is framed with [`Frame::card`], so that all cards in an app look the same.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut open = true;
+/// egui::Card::new("Statistics")
+///     .collapsible(true)
+///     .open(&mut open)
+///     .actions(|ui| {
+///         if ui.small_button("⟳").on_hover_text("Refresh").clicked() {
+///             /* … */
+///         }
+///     })
+///     .menu(|ui| {
+///         if ui.button("Export…").clicked() {
+///             ui.close_menu();
+///         }
+///     })
+///     .show(ui, |ui| {
+///         ui.label("Card contents");
+///     });
+/// # });
+/// ```
+#[must_use = "You should call .show()"]
+pub struct Card<'a> {
+    title: WidgetText,
+    id_source: Option<Id>,
+    frame: Option<Frame>,
+    collapsible: bool,
+    default_open: bool,
+    open: Option<&'a mut bool>,
+    actions: Option<Box<dyn FnOnce(&mut Ui) + 'a>>,
+    menu: Option<Box<dyn FnOnce(&mut Ui) + 'a>>,
+}
+
+impl<'a> Card<'a> {
+    /// The title is also used as the id source, unless [`Self::id_source`] is set.
+    pub fn new(title: impl Into<WidgetText>) -> Self {
+        Self {
+            title: title.into(),
+            id_source: None,
+            frame: None,
+            collapsible: false,
+            default_open: true,
+            open: None,
+            actions: None,
+            menu: None,
+        }
+    }
+
+    /// Explicitly set the source of the [`Id`] of this card, instead of using the title.
+    pub fn id_source(mut self, id_source: impl std::hash::Hash) -> Self {
+        self.id_source = Some(Id::new(id_source));
+        self
+    }
+
+    /// Change the frame. Default: [`Frame::card`].
+    pub fn frame(mut self, frame: Frame) -> Self {
+        self.frame = Some(frame);
+        self
+    }
+
+    /// Show a button for collapsing the body. Default: `false`.
+    pub fn collapsible(mut self, collapsible: bool) -> Self {
+        self.collapsible = collapsible;
+        self
+    }
+
+    /// If [`Self::collapsible`], should the card start out open? Default: `true`.
+    pub fn default_open(mut self, default_open: bool) -> Self {
+        self.default_open = default_open;
+        self
+    }
+
+    /// Show a close button, which sets `open` to `false`.
+    ///
+    /// Nothing is shown if `open` is `false`.
+    pub fn open(mut self, open: &'a mut bool) -> Self {
+        self.open = Some(open);
+        self
+    }
+
+    /// Add buttons (or anything else) to the right side of the title bar.
+    ///
+    /// They are laid out right-to-left.
+    pub fn actions(mut self, add_actions: impl FnOnce(&mut Ui) + 'a) -> Self {
+        self.actions = Some(Box::new(add_actions));
+        self
+    }
+
+    /// Add a menu button to the right side of the title bar, with these contents.
+    pub fn menu(mut self, add_menu: impl FnOnce(&mut Ui) + 'a) -> Self {
+        self.menu = Some(Box::new(add_menu));
+        self
+    }
+
+    /// Returns `None` if closed.
+    pub fn show<R>(
+        self,
+        ui: &mut Ui,
+        add_body: impl FnOnce(&mut Ui) -> R,
+    ) -> Option<CardResponse<R>> {
+        let Self {
+            title,
+            id_source,
+            frame,
+            collapsible,
+            default_open,
+            open,
+            actions,
+            menu,
+        } = self;
+
+        if matches!(open.as_deref(), Some(false)) {
+            return None;
+        }
+
+        let id = ui.make_persistent_id(id_source.unwrap_or_else(|| Id::new(title.text())));
+        let frame = frame.unwrap_or_else(|| Frame::card(ui.style()));
+        let mut state = CollapsingState::load_with_default_open(ui.ctx(), id, default_open);
+        let mut close_clicked = false;
+
+        let InnerResponse {
+            inner: (header_response, body_returned),
+            response,
+        } = frame.show(ui, |ui| {
+            let header_response = ui
+                .horizontal(|ui| {
+                    if collapsible {
+                        ui.spacing_mut().item_spacing.x = 0.0; // the toggler button uses the full indent width
+                        state.show_toggle_button(ui, paint_default_icon);
+                        ui.spacing_mut().item_spacing.x = ui.spacing().icon_spacing;
+                    }
+
+                    let title_response = ui.add(Label::new(title.strong()).sense(if collapsible {
+                        Sense::click()
+                    } else {
+                        Sense::hover()
+                    }));
+                    if title_response.clicked() {
+                        state.toggle(ui);
+                    }
+
+                    ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                        if open.is_some() {
+                            let (rect, _) = ui.allocate_exact_size(
+                                Vec2::splat(ui.spacing().icon_width),
+                                Sense::hover(),
+                            );
+                            close_clicked = super::window::close_button(ui, rect)
+                                .on_hover_text(ui.ctx().translate("egui.close", "Close"))
+                                .clicked();
+                        }
+                        if let Some(menu) = menu {
+                            ui.menu_button("⏷", menu);
+                        }
+                        if let Some(actions) = actions {
+                            actions(ui);
+                        }
+                    });
+                })
+                .response;
+
+            let body_returned = if collapsible {
+                state
+                    .show_body_unindented(ui, |ui| {
+                        ui.separator();
+                        add_body(ui)
+                    })
+                    .map(|inner| inner.inner)
+            } else {
+                ui.separator();
+                Some(add_body(ui))
+            };
+
+            (header_response, body_returned)
+        });
+
+        if close_clicked {
+            if let Some(open) = open {
+                *open = false;
+            }
+        }
+
+        Some(CardResponse {
+            response,
+            header_response,
+            body_returned,
+        })
+    }
+}
+
+/// The response from showing a [`Card`].
+pub struct CardResponse<R> {
+    /// The whole card, including the frame.
+    pub response: Response,
+
+    /// The title bar.
+    pub header_response: Response,
+
+    /// `None` iff collapsed.
+    pub body_returned: Option<R>,
+}
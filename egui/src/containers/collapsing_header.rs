@@ -207,12 +207,23 @@ impl CollapsingState {
                 clip_rect.max.y = clip_rect.max.y.min(child_ui.max_rect().top() + max_height);
                 child_ui.set_clip_rect(clip_rect);
 
+                let layer_id = child_ui.layer_id();
+                let start = child_ui.ctx().graphics().list(layer_id).next_idx();
+
                 let ret = add_body(child_ui);
 
                 let mut min_rect = child_ui.min_rect();
                 self.state.open_height = Some(min_rect.height());
                 self.store(child_ui.ctx()); // remember the height
 
+                child_ui.style().transitions.collapsing.apply(
+                    child_ui.ctx(),
+                    layer_id,
+                    Some(start),
+                    min_rect,
+                    openness,
+                );
+
                 // Pretend children took up at most `max_height` space:
                 min_rect.max.y = min_rect.max.y.at_most(min_rect.top() + max_height);
                 child_ui.force_set_min_rect(min_rect);
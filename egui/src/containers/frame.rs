@@ -42,6 +42,22 @@ impl Frame {
         }
     }
 
+    /// A raised panel, as used by [`Card`].
+    pub fn card(style: &Style) -> Self {
+        Self {
+            inner_margin: Margin::same(8.0),
+            rounding: style.visuals.window_rounding,
+            shadow: if style.visuals.dark_mode {
+                Shadow::small_dark()
+            } else {
+                Shadow::small_light()
+            },
+            fill: style.visuals.window_fill(),
+            stroke: style.visuals.widgets.noninteractive.bg_stroke,
+            ..Default::default()
+        }
+    }
+
     pub(crate) fn side_top_panel(style: &Style) -> Self {
         Self {
             inner_margin: Margin::symmetric(8.0, 2.0),
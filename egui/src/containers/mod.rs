@@ -1,8 +1,9 @@
-//! Containers are pieces of the UI which wraps other pieces of UI. Examples: [`Window`], [`ScrollArea`], [`Resize`], [`SidePanel`], etc.
+//! Containers are pieces of the UI which wraps other pieces of UI. Examples: [`Window`], [`ScrollArea`], [`Resize`], [`SidePanel`], [`Splitter`], etc.
 //!
 //! For instance, a [`Frame`] adds a frame and background to some contained UI.
 
 pub(crate) mod area;
+pub(crate) mod card;
 pub mod collapsing_header;
 mod combo_box;
 pub(crate) mod frame;
@@ -10,10 +11,12 @@ pub mod panel;
 pub mod popup;
 pub(crate) mod resize;
 pub(crate) mod scroll_area;
+pub mod splitter;
 pub(crate) mod window;
 
 pub use {
     area::Area,
+    card::{Card, CardResponse},
     collapsing_header::{CollapsingHeader, CollapsingResponse},
     combo_box::*,
     frame::Frame,
@@ -21,5 +24,6 @@ pub use {
     popup::*,
     resize::Resize,
     scroll_area::ScrollArea,
+    splitter::{Splitter, SplitterMode},
     window::Window,
 };
@@ -9,6 +9,13 @@ use crate::*;
 pub(crate) struct MonoState {
     last_id: Option<Id>,
     last_size: Vec<Vec2>,
+
+    /// The tooltip of the hovered widget, since when it has been hovered,
+    /// and when and in what frame it was last hovered.
+    hovered: Option<(Id, f64, f64, u64)>,
+
+    /// The tooltip that was last shown, since when, in what frame, and where.
+    shown: Option<(Id, f64, u64, Rect)>,
 }
 
 impl MonoState {
@@ -47,6 +54,62 @@ impl MonoState {
     }
 }
 
+/// Should the tooltip of a widget be shown this frame?
+///
+/// Applies [`style::Interaction::tooltip_delay`] and [`style::Interaction::tooltip_grace_time`].
+pub(crate) fn should_show_tooltip(ctx: &Context, tooltip_id: Id, widget_hovered: bool) -> bool {
+    let interaction = ctx.style().interaction.clone();
+    let now = ctx.input().time;
+    let frame_nr = ctx.frame_nr();
+    let mut state = MonoState::load(ctx).unwrap_or_default();
+
+    let show = if widget_hovered {
+        let since = state
+            .hovered
+            .filter(|&(id, _, _, last_frame_nr)| id == tooltip_id && last_frame_nr + 1 >= frame_nr)
+            .map_or(now, |(_, since, _, _)| since);
+        state.hovered = Some((tooltip_id, since, now, frame_nr));
+
+        let remaining = interaction.tooltip_delay as f64 - (now - since);
+        if remaining > 0.0 {
+            ctx.request_repaint_after(std::time::Duration::from_secs_f64(remaining));
+            false
+        } else {
+            true
+        }
+    } else if let Some((id, _, last_hovered, _)) = state.hovered {
+        let grace_time = interaction.tooltip_grace_time as f64;
+        if id != tooltip_id || grace_time <= 0.0 {
+            false
+        } else {
+            let pointer_over_tooltip = match (state.shown, ctx.input().pointer.hover_pos()) {
+                (Some((shown_id, _, _, rect)), Some(pointer_pos)) => {
+                    shown_id == tooltip_id && rect.contains(pointer_pos)
+                }
+                _ => false,
+            };
+            if pointer_over_tooltip {
+                // Restart the grace period, so we don't close when the pointer leaves the tooltip:
+                state.hovered = Some((tooltip_id, now, now, frame_nr));
+                true
+            } else {
+                let remaining = grace_time - (now - last_hovered);
+                if remaining > 0.0 {
+                    ctx.request_repaint_after(std::time::Duration::from_secs_f64(remaining));
+                    true
+                } else {
+                    false
+                }
+            }
+        }
+    } else {
+        false
+    };
+
+    state.store(ctx);
+    show
+}
+
 // ----------------------------------------------------------------------------
 
 /// Show a tooltip at the current pointer position (if any).
@@ -157,6 +220,7 @@ fn show_tooltip_at_avoid_dyn<'c, R>(
     mut avoid_rect: Rect,
     add_contents: Box<dyn FnOnce(&mut Ui) -> R + 'c>,
 ) -> Option<R> {
+    let tooltip_id = id;
     let mut tooltip_rect = Rect::NOTHING;
     let mut count = 0;
 
@@ -209,9 +273,35 @@ fn show_tooltip_at_avoid_dyn<'c, R>(
 
     let position = position.at_least(ctx.input().screen_rect().min);
 
-    let InnerResponse { inner, response } = show_tooltip_area_dyn(ctx, id, position, add_contents);
+    let now = ctx.input().time;
+    let frame_nr = ctx.frame_nr();
+    let shown_since = state
+        .shown
+        .filter(|&(id, _, last_frame_nr, _)| id == tooltip_id && last_frame_nr + 1 >= frame_nr)
+        .map_or(now, |(_, since, _, _)| since);
+
+    let fade_in_time = ctx.style().interaction.tooltip_fade_in_time;
+    let opacity = if fade_in_time > 0.0 {
+        let opacity = ((now - shown_since) as f32 / fade_in_time).clamp(0.0, 1.0);
+        if opacity < 1.0 {
+            ctx.request_repaint();
+        }
+        opacity
+    } else {
+        1.0
+    };
+
+    let openness =
+        ctx.style()
+            .transitions
+            .tooltip
+            .animate(ctx, tooltip_id.with("transition"), true);
+
+    let InnerResponse { inner, response } =
+        show_tooltip_area_dyn(ctx, id, position, opacity, openness, add_contents);
 
     state.set_tooltip_size(id, count, response.rect.size());
+    state.shown = Some((tooltip_id, shown_since, frame_nr, response.rect));
     state.store(ctx);
 
     ctx.frame_state().tooltip_rect = Some(crate::frame_state::TooltipRect {
@@ -244,26 +334,44 @@ pub fn show_tooltip_text(ctx: &Context, id: Id, text: impl Into<WidgetText>) ->
 }
 
 /// Show a pop-over window.
+///
+/// With a [`style::Interaction::tooltip_grace_time`] the user can move the pointer into the tooltip,
+/// so then its area is interactable.
 fn show_tooltip_area_dyn<'c, R>(
     ctx: &Context,
     id: Id,
     window_pos: Pos2,
+    opacity: f32,
+    openness: f32,
     add_contents: Box<dyn FnOnce(&mut Ui) -> R + 'c>,
 ) -> InnerResponse<R> {
     use containers::*;
-    Area::new(id)
+    let interactable = ctx.style().interaction.tooltip_grace_time > 0.0;
+    let area = Area::new(id)
         .order(Order::Tooltip)
         .fixed_pos(window_pos)
-        .interactable(false)
-        .drag_bounds(Rect::EVERYTHING) // disable clip rect
-        .show(ctx, |ui| {
-            Frame::popup(&ctx.style())
-                .show(ui, |ui| {
-                    ui.set_max_width(ui.spacing().tooltip_width);
-                    add_contents(ui)
-                })
-                .inner
-        })
+        .interactable(interactable)
+        .drag_bounds(Rect::EVERYTHING); // disable clip rect
+
+    // Several tooltips can share the same area, so only transform this one:
+    let start = ctx.graphics().list(area.layer()).next_idx();
+    let inner_response = area.show(ctx, |ui| {
+        ui.set_opacity(opacity);
+        Frame::popup(&ctx.style())
+            .show(ui, |ui| {
+                ui.set_max_width(ui.spacing().tooltip_width);
+                add_contents(ui)
+            })
+            .inner
+    });
+    ctx.style().transitions.tooltip.apply(
+        ctx,
+        area.layer(),
+        Some(start),
+        inner_response.response.rect,
+        openness,
+    );
+    inner_response
 }
 
 /// Shows a popup below another widget.
@@ -294,27 +402,34 @@ pub fn popup_below_widget<R>(
     widget_response: &Response,
     add_contents: impl FnOnce(&mut Ui) -> R,
 ) -> Option<R> {
-    if ui.memory().is_popup_open(popup_id) {
-        let inner = Area::new(popup_id)
+    let is_open = ui.memory().is_popup_open(popup_id);
+    let transition = ui.style().transitions.popup;
+    let openness = transition.animate(ui.ctx(), popup_id.with("transition"), is_open);
+    if openness > 0.0 {
+        let area = Area::new(popup_id)
             .order(Order::Foreground)
             .fixed_pos(widget_response.rect.left_bottom())
-            .show(ui.ctx(), |ui| {
-                // Note: we use a separate clip-rect for this area, so the popup can be outside the parent.
-                // See https://github.com/emilk/egui/issues/825
-                let frame = Frame::popup(ui.style());
-                let frame_margin = frame.inner_margin + frame.outer_margin;
-                frame
-                    .show(ui, |ui| {
-                        ui.with_layout(Layout::top_down_justified(Align::LEFT), |ui| {
-                            ui.set_width(widget_response.rect.width() - frame_margin.sum().x);
-                            add_contents(ui)
-                        })
-                        .inner
+            .interactable(is_open); // not while the exit transition plays
+        let InnerResponse { inner, response } = area.show(ui.ctx(), |ui| {
+            // Note: we use a separate clip-rect for this area, so the popup can be outside the parent.
+            // See https://github.com/emilk/egui/issues/825
+            let frame = Frame::popup(ui.style());
+            let frame_margin = frame.inner_margin + frame.outer_margin;
+            frame
+                .show(ui, |ui| {
+                    ui.with_layout(Layout::top_down_justified(Align::LEFT), |ui| {
+                        ui.set_width(widget_response.rect.width() - frame_margin.sum().x);
+                        add_contents(ui)
                     })
                     .inner
-            })
-            .inner;
+                })
+                .inner
+        });
+        transition.apply(ui.ctx(), area.layer(), None, response.rect, openness);
 
+        if !is_open {
+            return None;
+        }
         if ui.input().key_pressed(Key::Escape) || widget_response.clicked_elsewhere() {
             ui.memory().close_popup();
         }
@@ -0,0 +1,263 @@
+//! Split a region of a [`Ui`] in two, with a draggable divider in between.
+
+use crate::*;
+
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+struct SplitterState {
+    /// The size of the first pane, as a fraction of the space shared by both panes.
+    fraction: f32,
+
+    /// The size of the first pane, in points.
+    first_size: f32,
+}
+
+impl SplitterState {
+    fn load(ctx: &Context, id: Id) -> Option<Self> {
+        ctx.data().get_persisted(id)
+    }
+
+    fn store(self, ctx: &Context, id: Id) {
+        ctx.data().insert_persisted(id, self);
+    }
+}
+
+/// How a [`Splitter`] divides its space when it changes size.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SplitterMode {
+    /// The first pane keeps the same fraction of the space.
+    Proportional,
+
+    /// The first pane keeps the same size, and the second pane gets what is left.
+    Fixed,
+}
+
+/// Split the available space of a [`Ui`] into two panes, with a draggable divider in between.
+///
+/// Unlike [`SidePanel`] this can be used anywhere, including inside of other splitters.
+/// The splitter fills all the available space along the split direction,
+/// so it should be given a finite size (i.e. not be put directly in a [`ScrollArea`]).
+///
+/// The position of the divider is remembered between frames (and between runs, if persistence is on).
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// egui::Splitter::horizontal("my_splitter")
+///     .min_size(50.0)
+///     .show(ui, |left_ui, right_ui| {
+///         left_ui.label("Left");
+///         right_ui.label("Right");
+///     });
+/// # });
+/// ```
+#[must_use = "You should call .show()"]
+pub struct Splitter {
+    id_source: Id,
+    /// Side-by-side if `true`, otherwise one above the other.
+    horizontal: bool,
+    mode: SplitterMode,
+    default_fraction: f32,
+    default_first_size: Option<f32>,
+    min_sizes: [f32; 2],
+}
+
+impl Splitter {
+    /// Two panes side by side, with a vertical divider.
+    pub fn horizontal(id_source: impl std::hash::Hash) -> Self {
+        Self::new(id_source, true)
+    }
+
+    /// Two panes on top of each other, with a horizontal divider.
+    pub fn vertical(id_source: impl std::hash::Hash) -> Self {
+        Self::new(id_source, false)
+    }
+
+    fn new(id_source: impl std::hash::Hash, horizontal: bool) -> Self {
+        Self {
+            id_source: Id::new(id_source),
+            horizontal,
+            mode: SplitterMode::Proportional,
+            default_fraction: 0.5,
+            default_first_size: None,
+            min_sizes: [0.0; 2],
+        }
+    }
+
+    /// Default: [`SplitterMode::Proportional`].
+    pub fn mode(mut self, mode: SplitterMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// The initial size of the first pane, as a fraction of the space. Default: `0.5`.
+    pub fn default_fraction(mut self, default_fraction: f32) -> Self {
+        self.default_fraction = default_fraction.clamp(0.0, 1.0);
+        self
+    }
+
+    /// The initial size of the first pane, in points. Overrides [`Self::default_fraction`].
+    pub fn default_first_size(mut self, default_first_size: f32) -> Self {
+        self.default_first_size = Some(default_first_size);
+        self
+    }
+
+    /// The minimum size of both panes. Default: `0.0`.
+    pub fn min_size(mut self, min_size: f32) -> Self {
+        self.min_sizes = [min_size; 2];
+        self
+    }
+
+    /// The minimum size of the first and second pane respectively. Default: `0.0`.
+    pub fn min_sizes(mut self, first: f32, second: f32) -> Self {
+        self.min_sizes = [first, second];
+        self
+    }
+
+    /// Show the splitter, calling `add_contents` with the [`Ui`] of the first and second pane.
+    ///
+    /// The [`Response`] covers the whole splitter, and is [`Response::changed`] when the divider was moved.
+    pub fn show<R>(
+        self,
+        ui: &mut Ui,
+        add_contents: impl FnOnce(&mut Ui, &mut Ui) -> R,
+    ) -> InnerResponse<R> {
+        let Self {
+            id_source,
+            horizontal,
+            mode,
+            default_fraction,
+            default_first_size,
+            min_sizes,
+        } = self;
+
+        let id = ui.make_persistent_id(id_source);
+        let rect = ui.available_rect_before_wrap();
+        let thickness = if horizontal {
+            ui.spacing().item_spacing.x
+        } else {
+            ui.spacing().item_spacing.y
+        };
+        let total_size = if horizontal {
+            rect.width()
+        } else {
+            rect.height()
+        };
+        let shared_size = (total_size - thickness).at_least(0.0);
+        let clamp_first_size = |first_size: f32| {
+            first_size
+                .at_most(shared_size - min_sizes[1])
+                .at_least(min_sizes[0])
+                .at_most(shared_size)
+        };
+
+        let state = SplitterState::load(ui.ctx(), id);
+        let mut first_size = match (state, mode) {
+            (Some(state), SplitterMode::Proportional) => state.fraction * shared_size,
+            (Some(state), SplitterMode::Fixed) => state.first_size,
+            (None, _) => default_first_size.unwrap_or(default_fraction * shared_size),
+        };
+        first_size = clamp_first_size(first_size);
+
+        let divider_rect = |first_size: f32| {
+            let start = if horizontal { rect.left() } else { rect.top() } + first_size;
+            if horizontal {
+                Rect::from_x_y_ranges(start..=start + thickness, rect.y_range())
+            } else {
+                Rect::from_x_y_ranges(rect.x_range(), start..=start + thickness)
+            }
+        };
+
+        let grab_radius = ui.style().interaction.resize_grab_radius_side;
+        let grab_rect = if horizontal {
+            divider_rect(first_size).expand2(vec2(grab_radius, 0.0))
+        } else {
+            divider_rect(first_size).expand2(vec2(0.0, grab_radius))
+        };
+        let divider_response = ui.interact(grab_rect, id.with("divider"), Sense::drag());
+
+        let mut moved = false;
+        if divider_response.dragged() {
+            if let Some(pointer) = ui.ctx().pointer_interact_pos() {
+                let new_first_size = if horizontal {
+                    pointer.x - rect.left()
+                } else {
+                    pointer.y - rect.top()
+                } - 0.5 * thickness;
+                let new_first_size = clamp_first_size(new_first_size);
+                moved = new_first_size != first_size;
+                first_size = new_first_size;
+            }
+        }
+        if divider_response.hovered() || divider_response.dragged() {
+            ui.output().cursor_icon = if horizontal {
+                CursorIcon::ResizeHorizontal
+            } else {
+                CursorIcon::ResizeVertical
+            };
+        }
+
+        let divider = divider_rect(first_size);
+        let (first_rect, second_rect) = if horizontal {
+            (
+                Rect::from_min_max(rect.min, pos2(divider.left(), rect.bottom())),
+                Rect::from_min_max(pos2(divider.right(), rect.top()), rect.max),
+            )
+        } else {
+            (
+                Rect::from_min_max(rect.min, pos2(rect.right(), divider.top())),
+                Rect::from_min_max(pos2(rect.left(), divider.bottom()), rect.max),
+            )
+        };
+
+        let mut first_ui = ui.child_ui_with_id_source(first_rect, *ui.layout(), id.with("first"));
+        first_ui.set_clip_rect(first_rect.intersect(ui.clip_rect()));
+        let mut second_ui =
+            ui.child_ui_with_id_source(second_rect, *ui.layout(), id.with("second"));
+        second_ui.set_clip_rect(second_rect.intersect(ui.clip_rect()));
+
+        let inner = add_contents(&mut first_ui, &mut second_ui);
+
+        // Fill the split direction, but only take up as much as needed across it:
+        let content_max = first_ui.min_rect().max.max(second_ui.min_rect().max);
+        let used_rect = if horizontal {
+            Rect::from_min_max(rect.min, pos2(rect.right(), content_max.y))
+        } else {
+            Rect::from_min_max(rect.min, pos2(content_max.x, rect.bottom()))
+        };
+        let mut response = ui.allocate_rect(used_rect, Sense::hover());
+
+        let stroke = if divider_response.dragged() {
+            ui.visuals().widgets.active.bg_stroke
+        } else if divider_response.hovered() {
+            ui.visuals().widgets.hovered.bg_stroke
+        } else {
+            ui.visuals().widgets.noninteractive.bg_stroke
+        };
+        let divider = divider.intersect(used_rect);
+        if horizontal {
+            ui.painter()
+                .vline(divider.center().x, divider.y_range(), stroke);
+        } else {
+            ui.painter()
+                .hline(divider.x_range(), divider.center().y, stroke);
+        }
+
+        // Only store when moved, so that temporarily shrinking the splitter doesn't move the divider:
+        if moved || state.is_none() {
+            SplitterState {
+                fraction: if shared_size > 0.0 {
+                    first_size / shared_size
+                } else {
+                    default_fraction
+                },
+                first_size,
+            }
+            .store(ui.ctx(), id);
+        }
+
+        if moved {
+            response.mark_changed();
+        }
+        InnerResponse::new(inner, response)
+    }
+}
@@ -230,6 +230,12 @@ impl<'open> Window<'open> {
         self.area = self.area.drag_bounds(bounds);
         self
     }
+
+    /// Fade the whole window, e.g. to `0.5`. See [`Area::opacity`].
+    pub fn opacity(mut self, opacity: f32) -> Self {
+        self.area = self.area.opacity(opacity);
+        self
+    }
 }
 
 impl<'open> Window<'open> {
@@ -263,11 +269,21 @@ impl<'open> Window<'open> {
         let frame = frame.unwrap_or_else(|| Frame::window(&ctx.style()));
 
         let is_open = !matches!(open, Some(false)) || ctx.memory().everything_is_visible();
-        area.show_open_close_animation(ctx, &frame, is_open);
+        let transition = ctx.style().transitions.window;
+        let openness = transition.animate(ctx, area.id.with("transition"), is_open);
+        if transition.is_none() {
+            area.show_open_close_animation(ctx, &frame, is_open);
+        }
 
-        if !is_open {
+        if openness <= 0.0 {
             return None;
         }
+        // While the exit transition plays, the window can no longer be interacted with:
+        let area = if is_open {
+            area
+        } else {
+            area.interactable(false)
+        };
 
         let area_id = area.id;
         let area_layer_id = area.layer();
@@ -403,6 +419,11 @@ impl<'open> Window<'open> {
             .min;
 
         let full_response = area.end(ctx, area_content_ui);
+        transition.apply(ctx, area_layer_id, None, full_response.rect, openness);
+
+        if !is_open {
+            return None;
+        }
 
         let inner_response = InnerResponse {
             inner: content_inner,
@@ -918,7 +939,7 @@ impl TitleBar {
 /// - `rect`: The rectangular area to fit the button in
 ///
 /// Returns the result of a click on a button if it was pressed
-fn close_button(ui: &mut Ui, rect: Rect) -> Response {
+pub(crate) fn close_button(ui: &mut Ui, rect: Rect) -> Response {
     let close_id = ui.auto_id_with("window_close_button");
     let response = ui.interact(rect, close_id, Sense::click());
     ui.expand_to_include_rect(response.rect);
@@ -1,9 +1,18 @@
 // #![warn(missing_docs)]
-use std::sync::Arc;
+use std::{any::Any, sync::Arc};
 
 use crate::{
-    animation_manager::AnimationManager, data::output::PlatformOutput, frame_state::FrameState,
-    input_state::*, layers::GraphicLayers, memory::Options, output::FullOutput, TextureHandle, *,
+    animation_manager::AnimationManager,
+    data::output::PlatformOutput,
+    drag_and_drop::DragAndDrop,
+    frame_profiler::{FrameProfiler, Stopwatch},
+    frame_state::FrameState,
+    i18n::{LocaleFormat, Localization, Translations},
+    input_state::*,
+    layers::GraphicLayers,
+    memory::Options,
+    output::{FullOutput, OpenUrl},
+    TextureHandle, *,
 };
 use epaint::{mutex::*, stats::*, text::Fonts, textures::TextureFilter, TessellationOptions, *};
 
@@ -28,6 +37,9 @@ impl Default for WrappedTextureManager {
 }
 
 // ----------------------------------------------------------------------------
+/// See [`Context::set_open_url_handler`].
+type OpenUrlHandler = dyn Fn(&Context, OpenUrl) -> Option<OpenUrl> + Send + Sync;
+
 #[derive(Default)]
 struct ContextImpl {
     /// `None` until the start of the first frame.
@@ -49,18 +61,62 @@ struct ContextImpl {
     /// the duration backend will poll for new events, before forcing another egui update
     /// even if there's no new events.
     repaint_after: std::time::Duration,
+    /// Repaint deadlines of individual layers, see [`Context::request_layer_repaint_after`].
+    /// Only the ones of layers that are still visible at the end of the frame are honored.
+    layer_repaint_after: epaint::ahash::AHashMap<LayerId, std::time::Duration>,
+    /// Opacity of individual layers this frame, see [`Context::set_layer_opacity`].
+    layer_opacity: epaint::ahash::AHashMap<LayerId, f32>,
     /// While positive, keep requesting repaints. Decrement at the end of each frame.
     repaint_requests: u32,
     request_repaint_callback: Option<Box<dyn Fn() + Send + Sync>>,
+    open_url_handler: Option<Arc<OpenUrlHandler>>,
     requested_repaint_last_frame: bool,
+
+    profiler: FrameProfiler,
+
+    drag_and_drop: DragAndDrop,
+
+    localization: Localization,
+
+    /// Events injected with [`Context::inject_event`], to be handled at the start of the next frame.
+    injected_events: Vec<Event>,
+
+    /// Buffers reused from one frame to the next, see [`Context::recycle_primitives`].
+    buffer_pool: BufferPool,
+
+    /// The [`Options::zoom_factor`] that [`InputState::pixels_per_point`] currently includes.
+    /// `None` means 1.0.
+    applied_zoom_factor: Option<f32>,
+
+    /// Incremented at the start of each frame.
+    frame_nr: u64,
 }
 
 impl ContextImpl {
-    fn begin_frame_mut(&mut self, new_raw_input: RawInput) {
+    fn begin_frame_mut(&mut self, mut new_raw_input: RawInput) {
+        self.profiler.begin_input();
+        self.frame_nr += 1;
+
+        if !self.injected_events.is_empty() {
+            // They happened between the frames, so before whatever the integration collected.
+            let injected = std::mem::take(&mut self.injected_events);
+            new_raw_input.events.splice(0..0, injected);
+        }
+
+        self.begin_pass_mut(new_raw_input);
+
+        self.profiler.end_input();
+    }
+
+    /// Everything [`Self::begin_frame_mut`] does except counting the frame,
+    /// also used for the extra pass of [`Context::run_low_latency`].
+    fn begin_pass_mut(&mut self, new_raw_input: RawInput) {
         self.memory.begin_frame(&self.input, &new_raw_input);
 
+        let native_pixels_per_point = new_raw_input.pixels_per_point;
         self.input = std::mem::take(&mut self.input)
             .begin_frame(new_raw_input, self.requested_repaint_last_frame);
+        self.update_zoom_mut(native_pixels_per_point);
 
         if let Some(new_pixels_per_point) = self.memory.new_pixels_per_point.take() {
             self.input.pixels_per_point = new_pixels_per_point;
@@ -68,6 +124,12 @@ impl ContextImpl {
 
         self.frame_state.begin_frame(&self.input);
 
+        if let Some((modifiers, key)) = self.memory.options.profiler_shortcut {
+            if self.input.consume_key(modifiers, key) {
+                self.profiler.visible = !self.profiler.visible;
+            }
+        }
+
         self.update_fonts_mut();
 
         // Ensure we register the background area so panels and background ui can catch clicks:
@@ -82,6 +144,43 @@ impl ContextImpl {
         );
     }
 
+    /// Handle the zoom shortcuts and apply [`Options::zoom_factor`] to [`InputState::pixels_per_point`].
+    fn update_zoom_mut(&mut self, native_pixels_per_point: Option<f32>) {
+        const ZOOM_STEP: f32 = 1.1;
+
+        let options = &mut self.memory.options;
+        let mut zoom_factor = options.zoom_factor;
+        if options.zoom_with_keyboard {
+            let input = &mut self.input;
+            if input.consume_key(Modifiers::COMMAND, Key::PlusEquals)
+                || input.consume_key(Modifiers::COMMAND | Modifiers::SHIFT, Key::PlusEquals)
+            {
+                zoom_factor *= ZOOM_STEP;
+            }
+            if input.consume_key(Modifiers::COMMAND, Key::Minus) {
+                zoom_factor /= ZOOM_STEP;
+            }
+            if input.consume_key(Modifiers::COMMAND, Key::Num0) {
+                zoom_factor = 1.0;
+            }
+        }
+        if options.zoom_with_pinch {
+            zoom_factor *= self.input.zoom_delta();
+        }
+        let zoom_factor = zoom_factor.clamp(0.2, 5.0);
+        options.zoom_factor = zoom_factor;
+
+        if let Some(native_pixels_per_point) = native_pixels_per_point {
+            self.input.pixels_per_point = native_pixels_per_point * zoom_factor;
+        } else {
+            let applied_zoom_factor = self.applied_zoom_factor.unwrap_or(1.0);
+            if zoom_factor != applied_zoom_factor {
+                self.input.pixels_per_point *= zoom_factor / applied_zoom_factor;
+            }
+        }
+        self.applied_zoom_factor = Some(zoom_factor);
+    }
+
     /// Load fonts unless already loaded.
     fn update_fonts_mut(&mut self) {
         let pixels_per_point = self.input.pixels_per_point();
@@ -128,7 +227,7 @@ impl ContextImpl {
 ///
 /// ``` no_run
 /// # fn handle_platform_output(_: egui::PlatformOutput) {}
-/// # fn paint(textures_detla: egui::TexturesDelta, _: Vec<egui::ClippedPrimitive>) {}
+/// # fn paint(textures_detla: egui::TexturesDelta, _: &[egui::ClippedPrimitive]) {}
 /// let mut ctx = egui::Context::default();
 ///
 /// // Game loop:
@@ -144,7 +243,8 @@ impl ContextImpl {
 ///     });
 ///     handle_platform_output(full_output.platform_output);
 ///     let clipped_primitives = ctx.tessellate(full_output.shapes); // create triangles to paint
-///     paint(full_output.textures_delta, clipped_primitives);
+///     paint(full_output.textures_delta, &clipped_primitives);
+///     ctx.recycle_primitives(clipped_primitives); // optional: reuse the memory next frame
 /// }
 /// ```
 #[derive(Clone)]
@@ -205,6 +305,57 @@ impl Context {
         self.end_frame()
     }
 
+    /// Like [`Self::run`], but runs `run_ui` a second time in frames where
+    /// a pointer button was pressed or released over one of the egui layers.
+    ///
+    /// The first pass reacts to the press or release, and the second pass shows the result,
+    /// so that e.g. a button that opens a window shows the window the same frame it is clicked.
+    /// This makes clicks and drags feel snappier at low refresh rates,
+    /// at the cost of running the ui twice in those frames.
+    ///
+    /// The second pass sees no new input events and no time passing,
+    /// and both passes are the same frame, see [`Self::frame_nr`].
+    #[must_use]
+    pub fn run_low_latency(
+        &self,
+        new_input: RawInput,
+        mut run_ui: impl FnMut(&Context),
+    ) -> FullOutput {
+        let extra_pass_input = RawInput {
+            events: vec![],
+            dropped_files: vec![],
+            predicted_dt: 0.0, // don't advance animations in the extra pass
+            ..new_input.clone()
+        };
+
+        self.begin_frame(new_input);
+        run_ui(self);
+        if !self.pointer_pressed_or_released_on_layer() {
+            return self.end_frame();
+        }
+
+        let mut full_output = self.end_pass();
+        let shapes = std::mem::take(&mut full_output.shapes);
+        self.write().buffer_pool.recycle_shapes(shapes);
+
+        self.write().begin_pass_mut(extra_pass_input);
+        run_ui(self);
+        full_output.append(self.end_frame());
+        full_output
+    }
+
+    /// Did a pointer button go down or up over one of our layers this frame?
+    fn pointer_pressed_or_released_on_layer(&self) -> bool {
+        let pos = {
+            let input = self.input();
+            if !input.pointer.any_pressed() && !input.pointer.any_released() {
+                return false;
+            }
+            input.pointer.interact_pos()
+        };
+        pos.map_or(false, |pos| self.layer_id_at(pos).is_some())
+    }
+
     /// An alternative to calling [`Self::run`].
     ///
     /// ```
@@ -237,8 +388,24 @@ impl Context {
     /// The given [`Rect`] should be approximately where the widget will be.
     /// The most important thing is that [`Rect::min`] is approximately correct,
     /// because that's where the warning will be painted. If you don't know what size to pick, just pick [`Vec2::ZERO`].
-    pub fn check_for_id_clash(&self, id: Id, new_rect: Rect, what: &str) {
-        let prev_rect = self.frame_state().used_ids.insert(id, new_rect);
+    ///
+    /// In debug builds the source location of the caller is recorded too,
+    /// and reported in the clash, see [`Self::id_clashes`].
+    #[track_caller]
+    pub fn check_for_id_clash(&self, id: Id, new_rect: Rect, what: &'static str) {
+        let location = std::panic::Location::caller();
+
+        let (prev_rect, prev_use) = {
+            let frame_state = &mut *self.frame_state();
+            let prev_rect = frame_state.used_ids.insert(id, new_rect);
+            let prev_use = if cfg!(debug_assertions) {
+                frame_state.id_uses.insert(id, (what, location))
+            } else {
+                None
+            };
+            (prev_rect, prev_use)
+        };
+
         if let Some(prev_rect) = prev_rect {
             // it is ok to reuse the same ID for e.g. a frame around a widget,
             // or to check for interaction with the same widget twice:
@@ -248,6 +415,24 @@ impl Context {
                 return;
             }
 
+            let (prev_what, prev_location) = match prev_use {
+                Some((prev_what, prev_location)) => (prev_what, Some(prev_location)),
+                None => ("", None),
+            };
+            let clash = IdClash {
+                id,
+                first: IdUse {
+                    what: prev_what,
+                    rect: prev_rect,
+                    location: prev_location,
+                },
+                second: IdUse {
+                    what,
+                    rect: new_rect,
+                    location: cfg!(debug_assertions).then(|| location),
+                },
+            };
+
             let show_error = |pos: Pos2, text: String| {
                 let painter = self.debug_painter();
                 let rect = painter.error(pos, text);
@@ -263,30 +448,50 @@ impl Context {
                 }
             };
 
-            let id_str = id.short_debug_format();
+            let describe = |ordinal: &str, id_use: &IdUse| {
+                let what = if id_use.what.is_empty() {
+                    what
+                } else {
+                    id_use.what
+                };
+                format!("{} use of {} ID {}", ordinal, what, id.short_debug_format())
+            };
 
             if prev_rect.min.distance(new_rect.min) < 4.0 {
-                show_error(
-                    new_rect.min,
-                    format!("Double use of {} ID {}", what, id_str),
-                );
+                show_error(new_rect.min, describe("Double", &clash.second));
             } else {
-                show_error(
-                    prev_rect.min,
-                    format!("First use of {} ID {}", what, id_str),
-                );
-                show_error(
-                    new_rect.min,
-                    format!("Second use of {} ID {}", what, id_str),
-                );
+                show_error(prev_rect.min, describe("First", &clash.first));
+                show_error(new_rect.min, describe("Second", &clash.second));
             }
+
+            self.frame_state().id_clashes.push(clash);
         }
     }
 
+    /// Tooltips only allow interaction if their area is interactable,
+    /// see [`style::Interaction::tooltip_grace_time`].
+    fn layer_allows_interaction(&self, layer_id: LayerId) -> bool {
+        layer_id.allow_interaction()
+            || self
+                .memory()
+                .areas
+                .get(layer_id.id)
+                .map_or(false, |state| state.interactable)
+    }
+
+    /// All [`Id`] clashes detected so far this frame, see [`Self::check_for_id_clash`].
+    ///
+    /// Call this after your ui code (or after [`Self::run`]) to get everything of a frame.
+    /// This is useful for asserting that your app has no clashes in tests.
+    pub fn id_clashes(&self) -> Vec<IdClash> {
+        self.frame_state().id_clashes.clone()
+    }
+
     // ---------------------------------------------------------------------
 
     /// Use `ui.interact` instead
     #[allow(clippy::too_many_arguments)]
+    #[track_caller]
     pub(crate) fn interact(
         &self,
         clip_rect: Rect,
@@ -310,6 +515,7 @@ impl Context {
     }
 
     /// You specify if a thing is hovered, and the function gives a [`Response`].
+    #[track_caller]
     pub(crate) fn interact_with_hovered(
         &self,
         layer_id: LayerId,
@@ -329,17 +535,14 @@ impl Context {
             sense,
             enabled,
             hovered,
-            clicked: Default::default(),
-            double_clicked: Default::default(),
-            triple_clicked: Default::default(),
+            clicked: 0,
             dragged: false,
             drag_released: false,
             is_pointer_button_down_on: false,
-            interact_pointer_pos: None,
             changed: false, // must be set by the widget itself
         };
 
-        if !enabled || !sense.focusable || !layer_id.allow_interaction() {
+        if !enabled || !sense.focusable || !self.layer_allows_interaction(layer_id) {
             // Not interested or allowed input:
             self.memory().surrender_focus(id);
             return response;
@@ -365,7 +568,7 @@ impl Context {
             && (input.key_pressed(Key::Space) || input.key_pressed(Key::Enter))
         {
             // Space/enter works like a primary click for e.g. selected buttons
-            response.clicked[PointerButton::Primary as usize] = true;
+            response.set_clicked_by(PointerButton::Primary, true);
         }
 
         if sense.click || sense.drag {
@@ -411,12 +614,8 @@ impl Context {
 
                         if hovered && response.is_pointer_button_down_on {
                             if let Some(click) = click {
-                                let clicked = hovered && response.is_pointer_button_down_on;
-                                response.clicked[click.button as usize] = clicked;
-                                response.double_clicked[click.button as usize] =
-                                    clicked && click.is_double();
-                                response.triple_clicked[click.button as usize] =
-                                    clicked && click.is_triple();
+                                // Double- and triple-clicks are looked up lazily by `Response`.
+                                response.set_clicked_by(click.button, true);
                             }
                         }
                     }
@@ -424,10 +623,6 @@ impl Context {
             }
         }
 
-        if response.is_pointer_button_down_on {
-            response.interact_pointer_pos = input.pointer.interact_pos();
-        }
-
         if input.pointer.any_down() {
             response.hovered &= response.is_pointer_button_down_on; // we don't hover widgets while interacting with *other* widgets
         }
@@ -540,6 +735,19 @@ impl Context {
         })
     }
 
+    /// A shared copy of some text that is shown every frame, see [`Fonts::intern`].
+    ///
+    /// Passing it to a widget doesn't allocate, and its layout is cached like any other text.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// ui.label(ui.ctx().intern("A label that doesn't allocate"));
+    /// # });
+    /// ```
+    pub fn intern(&self, text: &str) -> Arc<str> {
+        self.fonts().intern(text)
+    }
+
     #[inline]
     fn fonts_mut(&self) -> RwLockWriteGuard<'_, Option<Fonts>> {
         RwLockWriteGuard::map(self.write(), |c| &mut c.fonts)
@@ -558,6 +766,13 @@ impl Context {
 }
 
 impl Context {
+    /// The number of frames started so far, i.e. the number of the current frame.
+    ///
+    /// This is `1` during the first frame.
+    pub fn frame_nr(&self) -> u64 {
+        self.read().frame_nr
+    }
+
     /// Call this if there is need to repaint the UI, i.e. if you are showing an animation.
     ///
     /// If this is called at least once in a frame, then there will be another frame right after this.
@@ -608,6 +823,32 @@ impl Context {
         ctx.repaint_after = ctx.repaint_after.min(duration);
     }
 
+    /// Request a repaint at the given [`InputState::time`], in the case of no new input events being received.
+    ///
+    /// This is a convenience for widgets that know when they next change, like a countdown or a clock:
+    /// they can declare the exact time of their next change instead of computing a duration.
+    /// Times in the past result in an immediate repaint.
+    ///
+    /// Like [`Self::request_repaint_after`], this only affects the current frame,
+    /// so call it every frame the widget is shown.
+    pub fn request_repaint_at(&self, time: f64) {
+        let now = self.input().time;
+        let duration = std::time::Duration::from_secs_f64((time - now).max(0.0));
+        self.request_repaint_after(duration);
+    }
+
+    /// Like [`Self::request_repaint_after`], but only honored if the given layer
+    /// is still visible at the end of the frame.
+    ///
+    /// Use this (or [`Ui::request_repaint_after`]) for things that only need
+    /// refreshing while they are on screen, so that e.g. a countdown in a closed
+    /// window doesn't keep waking up an otherwise idle app.
+    pub fn request_layer_repaint_after(&self, layer_id: LayerId, duration: std::time::Duration) {
+        let mut ctx = self.write();
+        let layer_repaint_after = ctx.layer_repaint_after.entry(layer_id).or_insert(duration);
+        *layer_repaint_after = (*layer_repaint_after).min(duration);
+    }
+
     /// For integrations: this callback will be called when an egui user calls [`Self::request_repaint`].
     ///
     /// This lets you wake up a sleeping UI thread.
@@ -618,6 +859,54 @@ impl Context {
         self.write().request_repaint_callback = Some(callback);
     }
 
+    /// Ask the integration to open a URL, after passing it through
+    /// the handler set with [`Self::set_open_url_handler`] (if any).
+    ///
+    /// This is what [`Hyperlink`] uses.
+    /// Set `ctx.output().open_url` directly to bypass the handler.
+    pub fn open_url(&self, open_url: OpenUrl) {
+        let handler = self.read().open_url_handler.clone();
+        let open_url = if let Some(handler) = handler {
+            handler(self, open_url)
+        } else {
+            Some(open_url)
+        };
+        if let Some(open_url) = open_url {
+            self.output().open_url = Some(open_url);
+        }
+    }
+
+    /// Intercept the URLs opened with [`Self::open_url`], e.g. by a [`Hyperlink`].
+    ///
+    /// Return the url (possibly modified) to have the integration open it,
+    /// or `None` if you took care of it yourself, e.g. by routing it within your app,
+    /// logging it, or asking the user for confirmation first.
+    /// To open the url once the user has confirmed, set `ctx.output().open_url`.
+    ///
+    /// ```
+    /// # let ctx = egui::Context::default();
+    /// ctx.set_open_url_handler(|_ctx, open_url| {
+    ///     if open_url.url.starts_with("https://") {
+    ///         Some(open_url)
+    ///     } else {
+    ///         None // only allow secure links
+    ///     }
+    /// });
+    /// ```
+    ///
+    /// Note that only one handler can be set. Any new call overrides the previous handler.
+    pub fn set_open_url_handler(
+        &self,
+        handler: impl Fn(&Context, OpenUrl) -> Option<OpenUrl> + Send + Sync + 'static,
+    ) {
+        self.write().open_url_handler = Some(Arc::new(handler));
+    }
+
+    /// Remove the handler set with [`Self::set_open_url_handler`].
+    pub fn clear_open_url_handler(&self) {
+        self.write().open_url_handler = None;
+    }
+
     /// Tell `egui` which fonts to use.
     ///
     /// The default `egui` fonts only support latin and cyrillic alphabets,
@@ -687,6 +976,30 @@ impl Context {
         self.memory().new_pixels_per_point = Some(pixels_per_point);
     }
 
+    /// How much the user has zoomed the ui, on top of the native scale factor.
+    ///
+    /// [`Self::pixels_per_point`] is the native pixels-per-point times this.
+    pub fn zoom_factor(&self) -> f32 {
+        self.options().zoom_factor
+    }
+
+    /// Scale all layout and text, e.g. for letting the user resize the whole ui.
+    /// Will become active at the start of the next frame.
+    ///
+    /// Unlike [`Self::set_pixels_per_point`] this is relative to the native scale factor,
+    /// so it survives moving the window to a monitor with a different DPI.
+    /// By default the user can also zoom with Ctrl/Cmd together with `+`, `-` and `0`,
+    /// see `zoom_with_keyboard` in [`Self::options`].
+    ///
+    /// Like [`Self::set_pixels_per_point`] this requires an integration that respects
+    /// the egui pixels-per-point, which `eframe` on web does not.
+    pub fn set_zoom_factor(&self, zoom_factor: f32) {
+        if zoom_factor != self.zoom_factor() {
+            self.request_repaint();
+        }
+        self.options().zoom_factor = zoom_factor;
+    }
+
     /// Useful for pixel-perfect rendering
     pub(crate) fn round_to_pixel(&self, point: f32) -> f32 {
         let pixels_per_point = self.pixels_per_point();
@@ -816,6 +1129,21 @@ impl Context {
     /// Call at the end of each frame.
     #[must_use]
     pub fn end_frame(&self) -> FullOutput {
+        {
+            let ctx_impl = &mut *self.write();
+            ctx_impl.profiler.end_layout(ctx_impl.input.time);
+        }
+
+        self.end_pass()
+    }
+
+    /// Everything [`Self::end_frame`] does except timing the frame,
+    /// also used for the first pass of [`Self::run_low_latency`].
+    fn end_pass(&self) -> FullOutput {
+        if self.profiler_visible() {
+            self.profiler_window();
+        }
+
         if self.input().wants_repaint() {
             self.request_repaint();
         }
@@ -826,6 +1154,7 @@ impl Context {
             ctx_impl
                 .memory
                 .end_frame(&ctx_impl.input, &ctx_impl.frame_state.used_ids);
+            ctx_impl.drag_and_drop.end_frame(&ctx_impl.input);
 
             let font_image_delta = ctx_impl.fonts.as_ref().unwrap().font_image_delta();
             if let Some(font_image_delta) = font_image_delta {
@@ -841,6 +1170,20 @@ impl Context {
 
         let platform_output: PlatformOutput = std::mem::take(&mut self.output());
 
+        {
+            let ctx_impl = &mut *self.write();
+            let areas = &ctx_impl.memory.areas;
+            let layer_repaint_after = ctx_impl
+                .layer_repaint_after
+                .drain()
+                .filter(|(layer_id, _)| areas.is_visible(layer_id))
+                .map(|(_, duration)| duration)
+                .min();
+            if let Some(layer_repaint_after) = layer_repaint_after {
+                ctx_impl.repaint_after = ctx_impl.repaint_after.min(layer_repaint_after);
+            }
+        }
+
         // if repaint_requests is greater than zero. just set the duration to zero for immediate
         // repaint. if there's no repaint requests, then we can use the actual repaint_after instead.
         let repaint_after = if self.read().repaint_requests > 0 {
@@ -868,10 +1211,12 @@ impl Context {
 
     fn drain_paint_lists(&self) -> Vec<ClippedShape> {
         let ctx_impl = &mut *self.write();
+        let layer_opacity = std::mem::take(&mut ctx_impl.layer_opacity);
+        let mut shapes = ctx_impl.buffer_pool.take_shapes();
         ctx_impl
             .graphics
-            .drain(ctx_impl.memory.areas.order())
-            .collect()
+            .drain(ctx_impl.memory.areas.order(), &layer_opacity, &mut shapes);
+        shapes
     }
 
     /// Tessellate the given shapes into triangle meshes.
@@ -880,6 +1225,8 @@ impl Context {
         // shapes are the same, but just comparing the shapes takes about 50% of the time
         // it takes to tessellate them, so it is not a worth optimization.
 
+        let stopwatch = Stopwatch::started();
+
         let pixels_per_point = self.pixels_per_point();
         let tessellation_options = *self.tessellation_options();
         let texture_atlas = self.fonts().texture_atlas();
@@ -887,17 +1234,32 @@ impl Context {
         let prepared_discs = texture_atlas.lock().prepared_discs();
 
         let paint_stats = PaintStats::from_shapes(&shapes);
-        let clipped_primitives = tessellator::tessellate_shapes(
+        let mut buffer_pool = std::mem::take(&mut self.write().buffer_pool);
+        let clipped_primitives = tessellator::tessellate_shapes_pooled(
             pixels_per_point,
             tessellation_options,
             font_tex_size,
             prepared_discs,
             shapes,
+            &mut buffer_pool,
         );
-        self.write().paint_stats = paint_stats.with_clipped_primitives(&clipped_primitives);
+        let ctx_impl = &mut *self.write();
+        ctx_impl.buffer_pool = buffer_pool;
+        ctx_impl.paint_stats = paint_stats.with_clipped_primitives(&clipped_primitives);
+        ctx_impl.profiler.record_tessellation(stopwatch.elapsed());
         clipped_primitives
     }
 
+    /// Give back what [`Self::tessellate`] returned once you have painted it,
+    /// so that the next frame can reuse the memory of the meshes instead of allocating new ones.
+    ///
+    /// This is optional, but saves a lot of allocations in UIs that paint many shapes.
+    pub fn recycle_primitives(&self, clipped_primitives: Vec<ClippedPrimitive>) {
+        self.write()
+            .buffer_pool
+            .recycle_primitives(clipped_primitives);
+    }
+
     // ---------------------------------------------------------------------
 
     /// How much space is used by panels and windows.
@@ -996,6 +1358,39 @@ impl Context {
         }
     }
 
+    /// Multiply the colors of everything painted to this layer this frame by `opacity`, in `[0, 1]`.
+    ///
+    /// This is applied once at the end of the frame, so it is cheap even for big layers.
+    /// It has to be set again each frame. It doesn't affect interaction, only how the layer looks.
+    ///
+    /// Use it to fade whole areas (see also [`Area::opacity`]),
+    /// or to dim everything behind a modal window:
+    ///
+    /// ```
+    /// # let ctx = egui::Context::default();
+    /// # let modal_layer = egui::LayerId::new(egui::Order::Foreground, egui::Id::new("modal"));
+    /// let layer_ids: Vec<_> = ctx.memory().layer_ids().collect();
+    /// for layer_id in layer_ids {
+    ///     if layer_id != modal_layer {
+    ///         ctx.set_layer_opacity(layer_id, 0.3);
+    ///     }
+    /// }
+    /// ```
+    pub fn set_layer_opacity(&self, layer_id: LayerId, opacity: f32) {
+        self.write()
+            .layer_opacity
+            .insert(layer_id, opacity.clamp(0.0, 1.0));
+    }
+
+    /// The opacity set with [`Self::set_layer_opacity`] this frame, or `1.0`.
+    pub fn layer_opacity(&self, layer_id: LayerId) -> f32 {
+        self.read()
+            .layer_opacity
+            .get(&layer_id)
+            .copied()
+            .unwrap_or(1.0)
+    }
+
     /// Top-most layer at the given position.
     pub fn layer_id_at(&self, pos: Pos2) -> Option<LayerId> {
         let resize_grab_radius_side = self.style().interaction.resize_grab_radius_side;
@@ -1042,9 +1437,17 @@ impl Context {
     ///
     /// The function will call [`Self::request_repaint()`] when appropriate.
     ///
-    /// The animation time is taken from [`Style::animation_time`].
+    /// The animation time is taken from [`Style::animation_time`],
+    /// and is zero if [`Style::reduce_motion`] is set.
     pub fn animate_bool(&self, id: Id, value: bool) -> f32 {
-        let animation_time = self.style().animation_time;
+        let animation_time = {
+            let style = self.style();
+            if style.reduce_motion {
+                0.0
+            } else {
+                style.animation_time
+            }
+        };
         self.animate_bool_with_time(id, value, animation_time)
     }
 
@@ -1081,6 +1484,67 @@ impl Context {
         animated_value
     }
 
+    /// Smoothly animate any [`Tweenable`] value (e.g. `f32`, [`Vec2`] or [`Color32`]) towards `target`.
+    ///
+    /// At the first call the value is written to memory and returned as is.
+    /// When `target` changes, the returned value eases from its current value to the new `target`,
+    /// taking `duration` seconds.
+    ///
+    /// The function will call [`Self::request_repaint()`] while the animation is in progress.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let hovered = false;
+    /// let target = if hovered { egui::Color32::WHITE } else { egui::Color32::GRAY };
+    /// let color = ui.ctx().animate_to(ui.id(), target, 0.2, egui::Easing::CubicOut);
+    /// # });
+    /// ```
+    pub fn animate_to<T: Tweenable>(&self, id: Id, target: T, duration: f32, easing: Easing) -> T {
+        let (value, animation_in_progress) = {
+            let ctx_impl = &mut *self.write();
+            ctx_impl
+                .animation_manager
+                .animate_to(&ctx_impl.input, id, target, duration, easing)
+        };
+        if animation_in_progress {
+            self.request_repaint();
+        }
+        value
+    }
+
+    /// Play a [`Tween`], returning its current value.
+    ///
+    /// The tween starts the first time this is called with `id`,
+    /// and then stays at its end value, until [`Self::restart_tween`] is called.
+    /// [`Tween::on_complete`] is called on the first frame after the tween has finished.
+    ///
+    /// The function will call [`Self::request_repaint()`] until the tween has finished.
+    pub fn play_tween<T: Tweenable>(&self, id: Id, tween: &Tween<T>) -> T {
+        let progress = {
+            let ctx_impl = &mut *self.write();
+            ctx_impl
+                .animation_manager
+                .play_tween(&ctx_impl.input, id, tween)
+        };
+        if progress.in_progress {
+            self.request_repaint();
+        }
+        if progress.just_completed {
+            tween.call_on_complete();
+        }
+        progress.value
+    }
+
+    /// Play the tween with this id from the start the next time [`Self::play_tween`] is called.
+    pub fn restart_tween(&self, id: Id) {
+        self.write().animation_manager.restart_tween(id);
+    }
+
+    /// Has the tween with this id played to the end?
+    pub fn is_tween_finished(&self, id: Id) -> bool {
+        self.read().animation_manager.is_tween_finished(id)
+    }
+
     /// Clear memory of any animations.
     pub fn clear_animations(&self) {
         self.write().animation_manager = Default::default();
@@ -1276,16 +1740,25 @@ impl Context {
                         continue;
                     }
                     let text = format!("{} - {:?}", layer_id.short_debug_format(), area.rect(),);
-                    // TODO(emilk): `Sense::hover_highlight()`
-                    if ui
-                        .add(Label::new(RichText::new(text).monospace()).sense(Sense::click()))
-                        .hovered
-                        && is_visible
-                    {
-                        ui.ctx()
-                            .debug_painter()
-                            .debug_rect(area.rect(), Color32::RED, "");
-                    }
+                    ui.horizontal(|ui| {
+                        if ui
+                            .small_button("🗑")
+                            .on_hover_text("Forget the position and size of this area")
+                            .clicked()
+                        {
+                            self.memory().areas.remove(layer_id);
+                        }
+                        // TODO(emilk): `Sense::hover_highlight()`
+                        if ui
+                            .add(Label::new(RichText::new(text).monospace()).sense(Sense::click()))
+                            .hovered
+                            && is_visible
+                        {
+                            ui.ctx()
+                                .debug_painter()
+                                .debug_rect(area.rect(), Color32::RED, "");
+                        }
+                    });
                 }
             }
         });
@@ -1332,6 +1805,10 @@ impl Context {
             }
         });
 
+        ui.collapsing("Stored data by type", |ui| {
+            self.data_ui(ui);
+        });
+
         ui.shrink_width_to_current(); // don't let the text below grow this window wider
         ui.label("NOTE: the position of this window cannot be reset from within itself.");
 
@@ -1342,6 +1819,283 @@ impl Context {
     }
 }
 
+/// ## Profiling
+impl Context {
+    /// Is the built-in frame-time profiler window shown?
+    ///
+    /// The user can toggle it with `profiler_shortcut` in [`Self::options`].
+    pub fn profiler_visible(&self) -> bool {
+        self.read().profiler.visible
+    }
+
+    /// Show or hide the built-in frame-time profiler window.
+    pub fn set_profiler_visible(&self, visible: bool) {
+        self.write().profiler.visible = visible;
+    }
+
+    /// How long the most recent frames took, oldest first.
+    ///
+    /// The tessellation time of the current frame is only known after [`Self::tessellate`].
+    pub fn frame_timings(&self) -> Vec<FrameTimings> {
+        self.read().profiler.frames().1
+    }
+
+    /// Percentile stats over [`Self::frame_timings`].
+    pub fn frame_time_stats(&self) -> Option<FrameTimeStats> {
+        FrameTimeStats::from_timings(&self.frame_timings())
+    }
+
+    /// Show the frame-time stats and chart of the built-in profiler.
+    pub fn profiler_ui(&self, ui: &mut Ui) {
+        let (first_frame_nr, frames) = self.read().profiler.frames();
+        crate::frame_profiler::profiler_ui(ui, first_frame_nr, &frames);
+    }
+
+    fn profiler_window(&self) {
+        let mut open = true;
+        Window::new("⏱ Frame profiler")
+            .open(&mut open)
+            .default_width(360.0)
+            .show(self, |ui| self.profiler_ui(ui));
+        if !open {
+            self.set_profiler_visible(false);
+        }
+    }
+}
+
+/// ## Drag and drop
+impl Context {
+    /// Attach a payload to the drag that is currently in progress.
+    ///
+    /// Usually you want [`Response::dnd_drag_source`] or [`Ui::dnd_drag_source`] instead.
+    /// The payload is forgotten at the end of the frame in which the pointer is released.
+    pub fn set_dragged_payload<Payload: Any + Send + Sync>(&self, payload: Payload) {
+        self.write().drag_and_drop.set_payload(payload);
+    }
+
+    /// The payload of the drag in progress, if it is of type `Payload`.
+    pub fn dragged_payload<Payload: Any + Send + Sync>(&self) -> Option<Arc<Payload>> {
+        self.read().drag_and_drop.payload()
+    }
+
+    /// Like [`Self::dragged_payload`], but no one else will get the payload after this.
+    pub fn take_dragged_payload<Payload: Any + Send + Sync>(&self) -> Option<Arc<Payload>> {
+        self.write().drag_and_drop.take_payload()
+    }
+
+    /// Is something with a payload (of any type) being dragged?
+    pub fn has_dragged_payload(&self) -> bool {
+        self.read().drag_and_drop.has_payload()
+    }
+
+    /// Cancel the drag in progress and forget its payload.
+    pub fn stop_dragging(&self) {
+        let ctx_impl = &mut *self.write();
+        ctx_impl.drag_and_drop.clear();
+        ctx_impl.memory.interaction.drag_id = None;
+    }
+}
+
+/// ## Synthetic input
+/// For UI automation, demos, scripted tutorials etc.
+impl Context {
+    /// Handle this event at the start of the next frame, as if it came from the integration.
+    ///
+    /// Injected events come before the events of the next [`RawInput`], in the order they were injected.
+    /// This requests a repaint, so that the next frame happens.
+    ///
+    /// Note that [`InputState::modifiers`] comes from [`RawInput::modifiers`],
+    /// so injecting a key event with modifiers won't change it.
+    pub fn inject_event(&self, event: Event) {
+        self.write().injected_events.push(event);
+        self.request_repaint();
+    }
+
+    /// Inject events that move the pointer to `pos` (in points).
+    pub fn inject_pointer_move(&self, pos: Pos2) {
+        self.inject_event(Event::PointerMoved(pos));
+    }
+
+    /// Inject events that move the pointer to `pos` and click it there.
+    ///
+    /// The press and release are handled in the same frame,
+    /// which is enough for [`Response::clicked`] to be `true` on the widget at `pos`.
+    pub fn inject_click(&self, pos: Pos2, button: PointerButton) {
+        let modifiers = self.input().modifiers;
+        self.write().injected_events.extend([
+            Event::PointerMoved(pos),
+            Event::PointerButton {
+                pos,
+                button,
+                pressed: true,
+                modifiers,
+            },
+            Event::PointerButton {
+                pos,
+                button,
+                pressed: false,
+                modifiers,
+            },
+        ]);
+        self.request_repaint();
+    }
+
+    /// Inject the press and release of a key.
+    pub fn inject_key(&self, key: Key, modifiers: Modifiers) {
+        self.write().injected_events.extend([
+            Event::Key {
+                key,
+                pressed: true,
+                modifiers,
+            },
+            Event::Key {
+                key,
+                pressed: false,
+                modifiers,
+            },
+        ]);
+        self.request_repaint();
+    }
+
+    /// Inject typed text, which goes to the widget with keyboard focus (e.g. a [`TextEdit`]).
+    pub fn inject_text(&self, text: impl Into<String>) {
+        self.inject_event(Event::Text(text.into()));
+    }
+
+    /// Are there injected events that have not been handled yet?
+    pub fn has_injected_events(&self) -> bool {
+        !self.read().injected_events.is_empty()
+    }
+}
+
+/// ## Localization
+impl Context {
+    /// Set the locale used for the built-in strings, e.g. `"en"`, `"sv"` or `"pt-BR"`.
+    ///
+    /// Default: `"en"`. See [`crate::i18n`].
+    pub fn set_locale(&self, locale: impl Into<String>) {
+        self.write().localization.locale = locale.into();
+    }
+
+    /// The locale set with [`Self::set_locale`].
+    pub fn locale(&self) -> String {
+        self.read().localization.locale.clone()
+    }
+
+    /// Supply the translations of the built-in strings. See [`crate::i18n`].
+    pub fn set_translations(&self, translations: impl Translations + 'static) {
+        self.write().localization.translations = Some(Arc::new(translations));
+    }
+
+    /// Go back to showing all built-in strings in English.
+    pub fn clear_translations(&self) {
+        self.write().localization.translations = None;
+    }
+
+    /// Set how numbers and dates are written, e.g. `ctx.set_locale_format(LocaleFormat::for_locale("de"))`.
+    ///
+    /// This is independent of [`Self::set_locale`].
+    pub fn set_locale_format(&self, format: LocaleFormat) {
+        self.write().localization.format = Arc::new(format);
+    }
+
+    /// How numbers and dates are written, see [`Self::set_locale_format`].
+    pub fn locale_format(&self) -> Arc<LocaleFormat> {
+        self.read().localization.format.clone()
+    }
+
+    /// Translate a built-in string into the current locale.
+    ///
+    /// Returns `english` if there is no translation.
+    pub fn translate<'a>(&self, key: &str, english: &'a str) -> std::borrow::Cow<'a, str> {
+        self.read().localization.translate(key, english)
+    }
+}
+
+impl Context {
+    /// How much state egui is currently holding on to.
+    ///
+    /// Show the details with [`Self::memory_ui`].
+    pub fn memory_usage(&self) -> MemoryUsage {
+        let (num_textures, texture_bytes) = {
+            let tex_mngr = self.tex_manager();
+            let tex_mngr = tex_mngr.read();
+            let texture_bytes = tex_mngr.allocated().map(|(_, tex)| tex.bytes_used()).sum();
+            (tex_mngr.num_allocated(), texture_bytes)
+        };
+
+        let memory = self.memory();
+        let entries = memory.data.entries();
+        MemoryUsage {
+            num_data: entries.len(),
+            num_serialized: entries.iter().filter(|entry| entry.serialized).count(),
+            data_bytes: entries.iter().map(|entry| entry.size).sum(),
+            num_areas: memory.areas.count(),
+            num_textures,
+            texture_bytes,
+        }
+    }
+
+    /// Show everything in [`Memory::data`], grouped by type, with buttons for removing entries.
+    fn data_ui(&self, ui: &mut Ui) {
+        let mut by_type: Vec<(util::id_type_map::TypeId, Vec<util::IdTypeMapEntry>)> = vec![];
+        let all_entries = self.memory().data.entries();
+        for entry in all_entries {
+            if let Some((_, entries)) = by_type.iter_mut().find(|(t, _)| *t == entry.type_id) {
+                entries.push(entry);
+            } else {
+                by_type.push((entry.type_id, vec![entry]));
+            }
+        }
+        // Biggest first, since that is what you are most likely looking for:
+        by_type.sort_by_key(|(_, entries)| {
+            std::cmp::Reverse(entries.iter().map(|entry| entry.size).sum::<usize>())
+        });
+
+        if by_type.is_empty() {
+            ui.label("Nothing stored.");
+        }
+
+        for (type_id, mut entries) in by_type {
+            let type_name = entries
+                .iter()
+                .find_map(|entry| entry.type_name)
+                .unwrap_or("<not yet deserialized>");
+            let bytes: usize = entries.iter().map(|entry| entry.size).sum();
+
+            ui.horizontal(|ui| {
+                if ui
+                    .small_button("🗑")
+                    .on_hover_text("Remove all values of this type")
+                    .clicked()
+                {
+                    self.data().remove_by_type_id(type_id);
+                }
+                CollapsingHeader::new(format!("{} × {} ({} B)", entries.len(), type_name, bytes))
+                    .id_source(type_id)
+                    .show(ui, |ui| {
+                        entries.sort_by_key(|entry| entry.key);
+                        for entry in &entries {
+                            ui.horizontal(|ui| {
+                                if ui.small_button("🗑").clicked() {
+                                    self.data().remove_entry(entry.key);
+                                }
+                                let mut text = format!("{:016X}: {} B", entry.key, entry.size);
+                                if entry.persisted {
+                                    text += ", persisted";
+                                }
+                                if entry.serialized {
+                                    text += ", serialized";
+                                }
+                                ui.monospace(text);
+                            });
+                        }
+                    });
+            });
+        }
+    }
+}
+
 impl Context {
     pub fn style_ui(&self, ui: &mut Ui) {
         let mut style: Style = (*self.style()).clone();
@@ -1355,3 +2109,71 @@ fn context_impl_send_sync() {
     fn assert_send_sync<T: Send + Sync>() {}
     assert_send_sync::<Context>();
 }
+
+#[test]
+fn test_id_clashes() {
+    let ctx = Context::default();
+    let _ = ctx.run(Default::default(), |ctx| {
+        CentralPanel::default().show(ctx, |ui| {
+            let id = Id::new("same");
+            let rect = Rect::from_min_size(pos2(0.0, 0.0), vec2(10.0, 10.0));
+            ui.interact(rect, id, Sense::click());
+            ui.interact(rect.translate(vec2(50.0, 0.0)), id, Sense::click());
+        });
+    });
+
+    let clashes = ctx.id_clashes();
+    assert_eq!(clashes.len(), 1);
+    assert_eq!(clashes[0].id, Id::new("same"));
+    if cfg!(debug_assertions) {
+        let location = clashes[0].first.location.unwrap();
+        assert!(location.file().ends_with("context.rs"));
+    }
+}
+
+#[test]
+fn test_inject_click() {
+    fn run(ctx: &Context) -> (Rect, bool) {
+        let mut button = (Rect::NOTHING, false);
+        let _ = ctx.run(Default::default(), |ctx| {
+            CentralPanel::default().show(ctx, |ui| {
+                let response = ui.button("Click me");
+                button = (response.rect, response.clicked());
+            });
+        });
+        button
+    }
+
+    let ctx = Context::default();
+    let (button_rect, clicked) = run(&ctx);
+    assert!(!clicked);
+    ctx.inject_click(button_rect.center(), PointerButton::Primary);
+    assert!(ctx.has_injected_events());
+    let (_, clicked) = run(&ctx);
+    assert!(clicked);
+    assert!(!ctx.has_injected_events());
+}
+
+#[test]
+fn test_low_latency_extra_pass() {
+    fn run(ctx: &Context) -> (Rect, usize) {
+        let mut button_rect = Rect::NOTHING;
+        let mut passes = 0;
+        let _ = ctx.run_low_latency(Default::default(), |ctx| {
+            passes += 1;
+            CentralPanel::default().show(ctx, |ui| {
+                button_rect = ui.button("Click me").rect;
+            });
+        });
+        (button_rect, passes)
+    }
+
+    let ctx = Context::default();
+    let (button_rect, passes) = run(&ctx);
+    assert_eq!(passes, 1);
+    ctx.inject_click(button_rect.center(), PointerButton::Primary);
+    let frame_nr = ctx.frame_nr();
+    let (_, passes) = run(&ctx);
+    assert_eq!(passes, 2);
+    assert_eq!(ctx.frame_nr(), frame_nr + 1);
+}
@@ -175,6 +175,18 @@ pub enum Event {
     /// The integration detected a "paste" event (e.g. Cmd+V).
     Paste(String),
 
+    /// The integration detected a "paste" event, and the clipboard contains an image.
+    ///
+    /// Not all integrations support this.
+    PasteImage(crate::ColorImage),
+
+    /// The integration detected a "paste" event, and the clipboard contains HTML.
+    ///
+    /// The integration should send a [`Self::Paste`] with the plain text too,
+    /// for widgets that don't understand HTML.
+    /// Not all integrations support this.
+    PasteHtml(String),
+
     /// Text input, e.g. via keyboard.
     ///
     /// When the user presses enter/return, do not send a [`Text`](Event::Text) (just [`Key::Enter`]).
@@ -492,6 +504,12 @@ pub enum Key {
     PageUp,
     PageDown,
 
+    /// The `-` key, either from the main row or from the numpad.
+    Minus,
+
+    /// The `=`/`+` key, either from the main row or from the numpad.
+    PlusEquals,
+
     /// Either from the main row or from the numpad.
     Num0,
     /// Either from the main row or from the numpad.
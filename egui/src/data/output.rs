@@ -5,7 +5,12 @@ use crate::WidgetType;
 /// What egui emits each frame from [`crate::Context::run`].
 ///
 /// The backend should use this.
+///
+/// With the `serde` feature this can be serialized, so that the egui logic can run in one process
+/// (e.g. a headless server) and send what to paint to a thin client that renders it.
+/// Shapes with a [`epaint::PaintCallback`] can't be serialized, so leave those out.
 #[derive(Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct FullOutput {
     /// Non-rendering related output.
     pub platform_output: PlatformOutput,
@@ -76,6 +81,17 @@ pub struct PlatformOutput {
     /// ```
     pub copied_text: String,
 
+    /// If set, put this image in the system clipboard.
+    ///
+    /// Not all integrations support this. See also [`Self::copy_image`].
+    pub copied_image: Option<crate::ColorImage>,
+
+    /// If set, put this HTML in the system clipboard, with [`Self::copied_text`] as the plain-text alternative.
+    /// Ignore if empty.
+    ///
+    /// Not all integrations support this. See also [`Self::copy_html`] and [`Self::copy_table`].
+    pub copied_html: String,
+
     /// Events that may be useful to e.g. a screen reader.
     pub events: Vec<OutputEvent>,
 
@@ -94,6 +110,55 @@ impl PlatformOutput {
         self.open_url = Some(OpenUrl::same_tab(url));
     }
 
+    /// Put the given image in the system clipboard.
+    pub fn copy_image(&mut self, image: crate::ColorImage) {
+        self.copied_image = Some(image);
+    }
+
+    /// Put the given HTML in the system clipboard,
+    /// with the given text for applications that don't understand HTML.
+    pub fn copy_html(&mut self, html: impl ToString, text: impl ToString) {
+        self.copied_html = html.to_string();
+        self.copied_text = text.to_string();
+    }
+
+    /// Put a table in the system clipboard, as an HTML table and as tab-separated text.
+    ///
+    /// This makes it paste nicely into both spreadsheets and text editors.
+    ///
+    /// ```
+    /// # let mut output = egui::PlatformOutput::default();
+    /// output.copy_table(&[vec!["x", "y"], vec!["1", "2"]]);
+    /// assert_eq!(output.copied_text, "x\ty\n1\t2");
+    /// ```
+    pub fn copy_table<Row, Cell>(&mut self, rows: &[Row])
+    where
+        Row: AsRef<[Cell]>,
+        Cell: AsRef<str>,
+    {
+        let mut html = "<table>".to_owned();
+        let mut text = String::new();
+        for (row_nr, row) in rows.iter().enumerate() {
+            if row_nr > 0 {
+                text += "\n";
+            }
+            html += "<tr>";
+            for (col_nr, cell) in row.as_ref().iter().enumerate() {
+                if col_nr > 0 {
+                    text += "\t";
+                }
+                let cell = cell.as_ref();
+                html += "<td>";
+                html += &html_escape(cell);
+                html += "</td>";
+                text += &cell.replace(&['\t', '\n'][..], " ");
+            }
+            html += "</tr>";
+        }
+        html += "</table>";
+        self.copy_html(html, text);
+    }
+
     /// This can be used by a text-to-speech system to describe the events (if any).
     pub fn events_description(&self) -> String {
         // only describe last event:
@@ -118,6 +183,8 @@ impl PlatformOutput {
             cursor_icon,
             open_url,
             copied_text,
+            copied_image,
+            copied_html,
             mut events,
             mutable_text_under_cursor,
             text_cursor_pos,
@@ -130,6 +197,12 @@ impl PlatformOutput {
         if !copied_text.is_empty() {
             self.copied_text = copied_text;
         }
+        if copied_image.is_some() {
+            self.copied_image = copied_image;
+        }
+        if !copied_html.is_empty() {
+            self.copied_html = copied_html;
+        }
         self.events.append(&mut events);
         self.mutable_text_under_cursor = mutable_text_under_cursor;
         self.text_cursor_pos = text_cursor_pos.or(self.text_cursor_pos);
@@ -143,6 +216,20 @@ impl PlatformOutput {
     }
 }
 
+fn html_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped += "&amp;",
+            '<' => escaped += "&lt;",
+            '>' => escaped += "&gt;",
+            '"' => escaped += "&quot;",
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
 /// What URL to open, and how.
 #[derive(Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
@@ -0,0 +1,98 @@
+//! Drag-and-drop with typed payloads.
+//!
+//! See [`Response::dnd_drag_source`], [`Ui::dnd_drag_source`] and [`Ui::dnd_drop_zone`].
+
+use std::{any::Any, sync::Arc};
+
+use crate::*;
+
+/// The payload of the drag that is currently in progress, if any.
+///
+/// The payload is forgotten at the end of the frame in which the pointer is released,
+/// so drop targets have exactly one frame to pick it up.
+#[derive(Clone, Default)]
+pub(crate) struct DragAndDrop {
+    payload: Option<Arc<dyn Any + Send + Sync>>,
+}
+
+impl DragAndDrop {
+    pub fn set_payload<Payload: Any + Send + Sync>(&mut self, payload: Payload) {
+        self.payload = Some(Arc::new(payload));
+    }
+
+    /// `None` if nothing is being dragged, or if the payload is of another type.
+    pub fn payload<Payload: Any + Send + Sync>(&self) -> Option<Arc<Payload>> {
+        self.payload.clone()?.downcast().ok()
+    }
+
+    /// Like [`Self::payload`], but also clears it if it was of the correct type.
+    pub fn take_payload<Payload: Any + Send + Sync>(&mut self) -> Option<Arc<Payload>> {
+        let payload = self.payload::<Payload>()?;
+        self.payload = None;
+        Some(payload)
+    }
+
+    pub fn has_payload(&self) -> bool {
+        self.payload.is_some()
+    }
+
+    pub fn clear(&mut self) {
+        self.payload = None;
+    }
+
+    pub fn end_frame(&mut self, input: &InputState) {
+        if input.pointer.any_released() || !input.pointer.any_down() {
+            self.payload = None;
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Paint `add_contents` and a hover highlight behind it.
+///
+/// The highlight shows whether the drop zone can accept what is currently being dragged.
+pub(crate) fn drop_zone_ui<R>(
+    ui: &mut Ui,
+    is_anything_being_dragged: bool,
+    can_accept_what_is_being_dragged: bool,
+    is_hovered: impl FnOnce(&Response) -> bool,
+    add_contents: impl FnOnce(&mut Ui) -> R,
+) -> InnerResponse<R> {
+    let margin = Vec2::splat(4.0);
+
+    let outer_rect_bounds = ui.available_rect_before_wrap();
+    let inner_rect = outer_rect_bounds.shrink2(margin);
+    let where_to_put_background = ui.painter().add(Shape::Noop);
+    let mut content_ui = ui.child_ui(inner_rect, *ui.layout());
+    let ret = add_contents(&mut content_ui);
+    let outer_rect = Rect::from_min_max(outer_rect_bounds.min, content_ui.min_rect().max + margin);
+    let (rect, response) = ui.allocate_at_least(outer_rect.size(), Sense::hover());
+
+    let style =
+        if is_anything_being_dragged && can_accept_what_is_being_dragged && is_hovered(&response) {
+            ui.visuals().widgets.active
+        } else {
+            ui.visuals().widgets.inactive
+        };
+
+    let mut fill = style.bg_fill;
+    let mut stroke = style.bg_stroke;
+    if is_anything_being_dragged && !can_accept_what_is_being_dragged {
+        // gray out:
+        fill = color::tint_color_towards(fill, ui.visuals().window_fill());
+        stroke.color = color::tint_color_towards(stroke.color, ui.visuals().window_fill());
+    }
+
+    ui.painter().set(
+        where_to_put_background,
+        epaint::RectShape {
+            rounding: style.rounding,
+            fill,
+            stroke,
+            rect,
+        },
+    );
+
+    InnerResponse::new(ret, response)
+}
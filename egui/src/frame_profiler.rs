@@ -0,0 +1,278 @@
+//! A built-in frame-time profiler, see [`Context::set_profiler_visible`].
+
+use crate::{
+    plot::{Candle, CandleElem, ChartPlot, Legend, Line, Plot, PlotPoints},
+    util::History,
+    *,
+};
+use epaint::util::FloatOrd;
+
+/// How many frames are aggregated into each candle of the profiler chart.
+const FRAMES_PER_CANDLE: u64 = 10;
+
+/// How egui spent its time during one frame, in seconds.
+///
+/// egui has no monotonic clock on the web, so there all timings are zero.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct FrameTimings {
+    /// Time spent in [`Context::begin_frame`], processing the [`RawInput`].
+    pub input: f32,
+
+    /// Time spent running your ui code, i.e. between [`Context::begin_frame`] and [`Context::end_frame`].
+    pub layout: f32,
+
+    /// Time spent in [`Context::tessellate`].
+    pub tessellation: f32,
+}
+
+impl FrameTimings {
+    /// The sum of all phases.
+    pub fn total(&self) -> f32 {
+        self.input + self.layout + self.tessellation
+    }
+}
+
+/// Statistics over the [`FrameTimings::total`] of the most recent frames, in seconds.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FrameTimeStats {
+    pub mean: f32,
+    /// The median.
+    pub p50: f32,
+    pub p95: f32,
+    pub p99: f32,
+    pub max: f32,
+}
+
+impl FrameTimeStats {
+    /// `None` if there are no frames.
+    pub fn from_timings(frames: &[FrameTimings]) -> Option<Self> {
+        if frames.is_empty() {
+            return None;
+        }
+
+        let mut totals: Vec<f32> = frames.iter().map(FrameTimings::total).collect();
+        totals.sort_by_key(|total| total.ord());
+
+        let percentile = |p: f32| {
+            let index = ((totals.len() - 1) as f32 * p).round() as usize;
+            totals[index]
+        };
+
+        Some(Self {
+            mean: totals.iter().sum::<f32>() / totals.len() as f32,
+            p50: percentile(0.50),
+            p95: percentile(0.95),
+            p99: percentile(0.99),
+            max: totals[totals.len() - 1],
+        })
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Measures wall-clock time on native.
+///
+/// egui has no clock on the web, so there it always reads zero.
+#[derive(Clone, Copy, Default)]
+pub(crate) struct Stopwatch {
+    #[cfg(not(target_arch = "wasm32"))]
+    start: Option<std::time::Instant>,
+}
+
+impl Stopwatch {
+    pub fn started() -> Self {
+        let mut stopwatch = Self::default();
+        stopwatch.restart();
+        stopwatch
+    }
+
+    pub fn restart(&mut self) {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            self.start = Some(std::time::Instant::now());
+        }
+    }
+
+    /// Seconds since the last [`Self::restart`].
+    pub fn elapsed(&self) -> f32 {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            self.start
+                .map_or(0.0, |start| start.elapsed().as_secs_f32())
+        }
+        #[cfg(target_arch = "wasm32")]
+        {
+            0.0
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Records the [`FrameTimings`] of the most recent frames.
+pub(crate) struct FrameProfiler {
+    /// Show the profiler window?
+    pub visible: bool,
+    history: History<FrameTimings>,
+    current: FrameTimings,
+    stopwatch: Stopwatch,
+}
+
+impl Default for FrameProfiler {
+    fn default() -> Self {
+        Self {
+            visible: false,
+            history: History::new(0..300, 10.0),
+            current: Default::default(),
+            stopwatch: Default::default(),
+        }
+    }
+}
+
+impl FrameProfiler {
+    /// Call at the very start of [`Context::begin_frame`].
+    pub fn begin_input(&mut self) {
+        self.current = Default::default();
+        self.stopwatch.restart();
+    }
+
+    /// Call at the very end of [`Context::begin_frame`].
+    pub fn end_input(&mut self) {
+        self.current.input = self.stopwatch.elapsed();
+        self.stopwatch.restart();
+    }
+
+    /// Call at the very start of [`Context::end_frame`].
+    pub fn end_layout(&mut self, now: f64) {
+        self.current.layout = self.stopwatch.elapsed();
+        self.history.add(now, self.current);
+    }
+
+    /// Tessellation happens after [`Context::end_frame`], so we amend the latest frame.
+    pub fn record_tessellation(&mut self, seconds: f32) {
+        if let Some(latest) = self.history.latest_mut() {
+            latest.tessellation = seconds;
+        }
+    }
+
+    /// Oldest first, together with the frame number of the first one.
+    pub fn frames(&self) -> (u64, Vec<FrameTimings>) {
+        let first_frame_nr = self.history.total_count() - self.history.len() as u64;
+        (first_frame_nr, self.history.values().collect())
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+fn ms(seconds: f32) -> f64 {
+    1e3 * seconds as f64
+}
+
+/// One candle per [`FRAMES_PER_CANDLE`] frames.
+///
+/// The buckets are aligned to the frame number so that the candles don't change
+/// as old frames scroll out of the history.
+fn frame_candles(first_frame_nr: u64, frames: &[FrameTimings]) -> Vec<CandleElem> {
+    let mut candles = vec![];
+    let mut start = 0;
+    while start < frames.len() {
+        let bucket = (first_frame_nr + start as u64) / FRAMES_PER_CANDLE;
+        let end = ((bucket + 1) * FRAMES_PER_CANDLE - first_frame_nr) as usize;
+        let end = end.min(frames.len());
+        let totals: Vec<f64> = frames[start..end].iter().map(|t| ms(t.total())).collect();
+
+        let open = totals[0];
+        let close = totals[totals.len() - 1];
+        let high = totals.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        let low = totals.iter().copied().fold(f64::INFINITY, f64::min);
+
+        let width = FRAMES_PER_CANDLE as f64;
+        let mut candle = CandleElem::new(Candle::new(open, high, low, close, totals.len() as f64))
+            .candle_width(0.8 * width)
+            .whisker_width(0.4 * width);
+        candle.x = (bucket as f64 + 0.5) * width;
+        candles.push(candle);
+
+        start = end;
+    }
+    candles
+}
+
+fn phase_line(
+    first_frame_nr: u64,
+    frames: &[FrameTimings],
+    name: &str,
+    phase: impl Fn(&FrameTimings) -> f32,
+) -> Line {
+    let points: PlotPoints = frames
+        .iter()
+        .enumerate()
+        .map(|(i, timings)| [(first_frame_nr + i as u64) as f64, ms(phase(timings))])
+        .collect();
+    Line::new(points).name(name)
+}
+
+/// Show percentile stats and a scrolling chart of the given frames.
+pub(crate) fn profiler_ui(ui: &mut Ui, first_frame_nr: u64, frames: &[FrameTimings]) {
+    let stats = if let Some(stats) = FrameTimeStats::from_timings(frames) {
+        stats
+    } else {
+        ui.label("No frames recorded yet.");
+        return;
+    };
+
+    if cfg!(target_arch = "wasm32") {
+        ui.label("Frame timings are not measured on the web.");
+    }
+
+    ui.label(format!(
+        "Mean: {:.2} ms   p50: {:.2} ms   p95: {:.2} ms   p99: {:.2} ms   max: {:.2} ms",
+        ms(stats.mean),
+        ms(stats.p50),
+        ms(stats.p95),
+        ms(stats.p99),
+        ms(stats.max),
+    ))
+    .on_hover_text(format!("Over the last {} frames", frames.len()));
+
+    let candles = frame_candles(first_frame_nr, frames);
+
+    // No interaction, so that the plot keeps following the latest frames:
+    Plot::new("egui_frame_profiler")
+        .height(160.0)
+        .allow_drag(false)
+        .allow_zoom(false)
+        .allow_scroll(false)
+        .allow_boxed_zoom(false)
+        .include_y(0.0)
+        .legend(Legend::default())
+        .show(ui, |plot_ui| {
+            plot_ui.chart_plot(
+                ChartPlot::new(candles)
+                    .name("Total")
+                    .color(Color32::from_rgb(100, 150, 250)),
+            );
+            plot_ui.line(phase_line(first_frame_nr, frames, "Input", |t| t.input));
+            plot_ui.line(phase_line(first_frame_nr, frames, "Layout", |t| t.layout));
+            plot_ui.line(phase_line(first_frame_nr, frames, "Tessellation", |t| {
+                t.tessellation
+            }));
+        });
+}
+
+#[test]
+fn test_frame_time_stats() {
+    let frames: Vec<FrameTimings> = (1..=100)
+        .map(|i| FrameTimings {
+            layout: i as f32,
+            ..Default::default()
+        })
+        .collect();
+    let stats = FrameTimeStats::from_timings(&frames).unwrap();
+    assert_eq!(stats.p50, 51.0);
+    assert_eq!(stats.p95, 95.0);
+    assert_eq!(stats.p99, 99.0);
+    assert_eq!(stats.max, 100.0);
+    assert_eq!(stats.mean, 50.5);
+    assert_eq!(FrameTimeStats::from_timings(&[]), None);
+}
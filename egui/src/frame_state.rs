@@ -9,6 +9,35 @@ pub(crate) struct TooltipRect {
     pub count: usize,
 }
 
+/// One use of an [`Id`], see [`IdClash`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct IdUse {
+    /// What used the [`Id`], e.g. `"widget"`, `"Grid"` or `"Plot"`.
+    ///
+    /// In release builds this is only known for the second use of a clash, and empty for the first.
+    pub what: &'static str,
+
+    /// Approximately where the user of the [`Id`] was on screen.
+    pub rect: Rect,
+
+    /// The source code that registered the [`Id`], in debug builds.
+    ///
+    /// This is found using `#[track_caller]`, which propagates through [`Ui::interact`] and
+    /// the [`Ui::allocate_response`] family, so for the builtin widgets this usually points
+    /// to the widget implementation, and for custom widgets to your code.
+    pub location: Option<&'static std::panic::Location<'static>>,
+}
+
+/// Two different things used the same [`Id`] during the same frame, see [`Context::id_clashes`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct IdClash {
+    pub id: Id,
+    pub first: IdUse,
+    pub second: IdUse,
+}
+
+// ----------------------------------------------------------------------------
+
 /// State that is collected during a frame and then cleared.
 /// Short-term (single frame) memory.
 #[derive(Clone)]
@@ -17,6 +46,13 @@ pub(crate) struct FrameState {
     /// Used to debug [`Id`] clashes of widgets.
     pub(crate) used_ids: IdMap<Rect>,
 
+    /// What and where registered each of [`Self::used_ids`].
+    /// Only filled in debug builds.
+    pub(crate) id_uses: IdMap<(&'static str, &'static std::panic::Location<'static>)>,
+
+    /// All [`Id`] clashes detected so far this frame.
+    pub(crate) id_clashes: Vec<IdClash>,
+
     /// Starts off as the screen_rect, shrinks as panels are added.
     /// The [`CentralPanel`] does not change this.
     /// This is the area available to Window's.
@@ -47,6 +83,8 @@ impl Default for FrameState {
     fn default() -> Self {
         Self {
             used_ids: Default::default(),
+            id_uses: Default::default(),
+            id_clashes: Default::default(),
             available_rect: Rect::NAN,
             unused_rect: Rect::NAN,
             used_by_panels: Rect::NAN,
@@ -61,6 +99,8 @@ impl FrameState {
     pub(crate) fn begin_frame(&mut self, input: &InputState) {
         let Self {
             used_ids,
+            id_uses,
+            id_clashes,
             available_rect,
             unused_rect,
             used_by_panels,
@@ -70,6 +110,8 @@ impl FrameState {
         } = self;
 
         used_ids.clear();
+        id_uses.clear();
+        id_clashes.clear();
         *available_rect = input.screen_rect();
         *unused_rect = input.screen_rect();
         *used_by_panels = Rect::NOTHING;
@@ -0,0 +1,336 @@
+//! Localization of the strings that egui itself shows to the user,
+//! e.g. the value labels of plots and the tooltips of built-in buttons.
+//!
+//! Each such string has a key (e.g. `"egui.plot.candle.open"`) and an English default.
+//! Set the locale with [`Context::set_locale`] and supply the translations with
+//! [`Context::set_translations`]. Any string that has no translation is shown in English.
+//!
+//! | Key | English |
+//! | --- | ------- |
+//! | `egui.close` | Close |
+//! | `egui.pagination.first` | First page |
+//! | `egui.pagination.previous` | Previous page |
+//! | `egui.pagination.next` | Next page |
+//! | `egui.pagination.last` | Last page |
+//! | `egui.pagination.page` | Page |
+//! | `egui.pagination.of` | of |
+//! | `egui.pagination.per_page` | / page |
+//! | `egui.promise.abandoned` | Task was abandoned |
+//! | `egui.color.copy` | Click to copy |
+//! | `egui.color.rgb` | Red Green Blue |
+//! | `egui.color.rgba` | Red Green Blue with premultiplied Alpha |
+//! | `egui.color.selected` | Selected color |
+//! | `egui.color.blending` | Blending: |
+//! | `egui.color.normal` | Normal |
+//! | `egui.color.additive` | Additive |
+//! | `egui.color.alpha` | Alpha |
+//! | `egui.color.hue` | Hue |
+//! | `egui.color.edit` | Click to edit color |
+//! | `egui.plot.candle.open` | Open |
+//! | `egui.plot.candle.high` | High |
+//! | `egui.plot.candle.low` | Low |
+//! | `egui.plot.candle.close` | Close |
+//! | `egui.plot.candle.volume` | Volume |
+//! | `egui.plot.box.max` | Max |
+//! | `egui.plot.box.quartile3` | Quartile 3 |
+//! | `egui.plot.box.median` | Median |
+//! | `egui.plot.box.quartile1` | Quartile 1 |
+//! | `egui.plot.box.min` | Min |
+//! | `egui.plot.bar.total` | Total |
+//! | `egui.plot.heatmap.value` | Value |
+//!
+//! Other crates, like `egui_extras`, use the same mechanism with their own keys.
+
+use std::{borrow::Cow, collections::HashMap, sync::Arc};
+
+/// Supplies translations for the built-in strings, see the [module docs](crate::i18n).
+pub trait Translations: Send + Sync {
+    /// Translate the string with the given key into the given locale (e.g. `"sv"` or `"pt-BR"`).
+    ///
+    /// Return `None` to show the English default.
+    fn translate(&self, locale: &str, key: &str) -> Option<String>;
+}
+
+impl<F> Translations for F
+where
+    F: Fn(&str, &str) -> Option<String> + Send + Sync,
+{
+    fn translate(&self, locale: &str, key: &str) -> Option<String> {
+        self(locale, key)
+    }
+}
+
+/// [`Translations`] stored in a lookup table.
+///
+/// ```
+/// let mut table = egui::i18n::TranslationTable::default();
+/// table.insert("sv", "egui.plot.candle.open", "Öppning");
+/// # let ctx = egui::Context::default();
+/// ctx.set_translations(table);
+/// ctx.set_locale("sv-SE");
+/// assert_eq!(ctx.translate("egui.plot.candle.open", "Open"), "Öppning");
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct TranslationTable {
+    /// locale -> key -> translation
+    locales: HashMap<String, HashMap<String, String>>,
+}
+
+impl TranslationTable {
+    pub fn insert(
+        &mut self,
+        locale: impl Into<String>,
+        key: impl Into<String>,
+        translation: impl Into<String>,
+    ) {
+        self.locales
+            .entry(locale.into())
+            .or_default()
+            .insert(key.into(), translation.into());
+    }
+}
+
+impl Translations for TranslationTable {
+    fn translate(&self, locale: &str, key: &str) -> Option<String> {
+        self.locales.get(locale)?.get(key).cloned()
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+pub(crate) struct Localization {
+    pub locale: String,
+    pub translations: Option<Arc<dyn Translations>>,
+    pub format: Arc<LocaleFormat>,
+}
+
+impl Default for Localization {
+    fn default() -> Self {
+        Self {
+            locale: "en".to_owned(),
+            translations: None,
+            format: Default::default(),
+        }
+    }
+}
+
+impl Localization {
+    /// Falls back from e.g. `"pt-BR"` to `"pt"`, and then to `english`.
+    pub fn translate<'a>(&self, key: &str, english: &'a str) -> Cow<'a, str> {
+        if let Some(translations) = &self.translations {
+            if let Some(translation) = translations.translate(&self.locale, key) {
+                return translation.into();
+            }
+            if let Some((language, _region)) = self.locale.split_once(&['-', '_'][..]) {
+                if let Some(translation) = translations.translate(language, key) {
+                    return translation.into();
+                }
+            }
+        }
+        english.into()
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// The order of year, month and day in a date.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum DateOrder {
+    /// 2022-12-31
+    YearMonthDay,
+    /// 31.12.2022
+    DayMonthYear,
+    /// 12/31/2022
+    MonthDayYear,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+/// How numbers and dates are written in some locale.
+///
+/// Set it with [`Context::set_locale_format`](crate::Context::set_locale_format).
+/// It is used by [`crate::DragValue`] and [`crate::Slider`], the tick labels of plots,
+/// and the date picker of `egui_extras`.
+///
+/// The default writes numbers the way Rust does (`1234567.89`) and dates as `2022-12-31`,
+/// with weeks starting on Monday.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct LocaleFormat {
+    pub decimal_separator: char,
+
+    /// Put between each group of three digits, e.g. `,` for `1,000,000`. `None` for no grouping.
+    pub digit_group_separator: Option<char>,
+
+    pub date_order: DateOrder,
+
+    /// E.g. `-`, `.` or `/`.
+    pub date_separator: char,
+
+    /// What day calendars start the week with.
+    pub first_weekday: Weekday,
+}
+
+impl Default for LocaleFormat {
+    fn default() -> Self {
+        Self {
+            decimal_separator: '.',
+            digit_group_separator: None,
+            date_order: DateOrder::YearMonthDay,
+            date_separator: '-',
+            first_weekday: Weekday::Monday,
+        }
+    }
+}
+
+impl LocaleFormat {
+    /// A best guess for a few common locales, e.g. `"en-US"`, `"de"` or `"sv-SE"`.
+    ///
+    /// Falls back to [`Self::default`] for locales it doesn't know.
+    pub fn for_locale(locale: &str) -> Self {
+        let language = locale
+            .split(&['-', '_'][..])
+            .next()
+            .unwrap_or_default()
+            .to_lowercase();
+        let region = locale
+            .split(&['-', '_'][..])
+            .nth(1)
+            .unwrap_or_default()
+            .to_uppercase();
+
+        let european = |decimal_separator, digit_group_separator, date_separator| Self {
+            decimal_separator,
+            digit_group_separator: Some(digit_group_separator),
+            date_order: DateOrder::DayMonthYear,
+            date_separator,
+            first_weekday: Weekday::Monday,
+        };
+
+        match (language.as_str(), region.as_str()) {
+            ("en", "US" | "") => Self {
+                decimal_separator: '.',
+                digit_group_separator: Some(','),
+                date_order: DateOrder::MonthDayYear,
+                date_separator: '/',
+                first_weekday: Weekday::Sunday,
+            },
+            ("en", _) => european('.', ',', '/'),
+            ("de" | "da" | "nb" | "no" | "fi" | "pl" | "ru" | "tr", _) => european(',', '.', '.'),
+            ("es" | "it" | "pt" | "nl", _) => european(',', '.', '/'),
+            ("fr", _) => european(',', '\u{202F}', '/'), // narrow no-break space
+            ("sv", _) => Self {
+                decimal_separator: ',',
+                digit_group_separator: Some('\u{A0}'), // no-break space
+                date_order: DateOrder::YearMonthDay,
+                date_separator: '-',
+                first_weekday: Weekday::Monday,
+            },
+            ("ja" | "zh" | "ko", _) => Self {
+                decimal_separator: '.',
+                digit_group_separator: Some(','),
+                date_order: DateOrder::YearMonthDay,
+                date_separator: '/',
+                first_weekday: Weekday::Sunday,
+            },
+            _ => Self::default(),
+        }
+    }
+
+    /// Localize a number that was formatted by Rust, e.g. `"-1234.5"` to `"-1,234.5"`.
+    ///
+    /// Text that isn't a plain number, like `"1e-7"` or `"NaN"`, only gets its decimal separator replaced.
+    pub fn format_number(&self, text: &str) -> String {
+        let (sign, unsigned) = match text.strip_prefix('-') {
+            Some(unsigned) => ("-", unsigned),
+            None => ("", text),
+        };
+        let (integer, fraction) = match unsigned.split_once('.') {
+            Some((integer, fraction)) => (integer, Some(fraction)),
+            None => (unsigned, None),
+        };
+
+        let mut out = String::with_capacity(text.len() + text.len() / 3);
+        out.push_str(sign);
+        match self.digit_group_separator {
+            Some(separator) if integer.bytes().all(|b| b.is_ascii_digit()) => {
+                for (i, digit) in integer.chars().enumerate() {
+                    if i > 0 && (integer.len() - i) % 3 == 0 {
+                        out.push(separator);
+                    }
+                    out.push(digit);
+                }
+            }
+            _ => out.push_str(integer),
+        }
+        if let Some(fraction) = fraction {
+            out.push(self.decimal_separator);
+            out.push_str(fraction);
+        }
+        out
+    }
+
+    /// Parse a number written by the user, ignoring digit group separators.
+    ///
+    /// Numbers written the Rust way (with a `.` decimal separator) are also accepted,
+    /// as long as `.` isn't the digit group separator.
+    pub fn parse_number(&self, text: &str) -> Option<f64> {
+        // Users can't be expected to type a no-break space:
+        let ignore_whitespace = self
+            .digit_group_separator
+            .map_or(false, char::is_whitespace);
+        let text: String = text
+            .trim()
+            .chars()
+            .filter(|&c| {
+                Some(c) != self.digit_group_separator && !(ignore_whitespace && c.is_whitespace())
+            })
+            .map(|c| if c == self.decimal_separator { '.' } else { c })
+            .collect();
+        text.parse().ok()
+    }
+
+    /// E.g. `2022-12-31`, `31.12.2022` or `12/31/2022`.
+    pub fn format_date(&self, year: i32, month: u32, day: u32) -> String {
+        let sep = self.date_separator;
+        match self.date_order {
+            DateOrder::YearMonthDay => format!("{}{}{:02}{}{:02}", year, sep, month, sep, day),
+            DateOrder::DayMonthYear => format!("{:02}{}{:02}{}{}", day, sep, month, sep, year),
+            DateOrder::MonthDayYear => format!("{:02}{}{:02}{}{}", month, sep, day, sep, year),
+        }
+    }
+}
+
+#[test]
+fn test_locale_format() {
+    let default = LocaleFormat::default();
+    assert_eq!(default.format_number("-1234567.25"), "-1234567.25");
+    assert_eq!(default.parse_number("1234.5"), Some(1234.5));
+
+    let us = LocaleFormat::for_locale("en-US");
+    assert_eq!(us.format_number("-1234567.25"), "-1,234,567.25");
+    assert_eq!(us.format_number("123"), "123");
+    assert_eq!(us.parse_number("1,234.5"), Some(1234.5));
+    assert_eq!(us.format_date(2022, 3, 7), "03/07/2022");
+
+    let german = LocaleFormat::for_locale("de-DE");
+    assert_eq!(german.format_number("1234.5"), "1.234,5");
+    assert_eq!(german.parse_number("1.234,5"), Some(1234.5));
+    assert_eq!(german.format_date(2022, 3, 7), "07.03.2022");
+
+    let swedish = LocaleFormat::for_locale("sv");
+    assert_eq!(swedish.format_number("1234.5"), "1\u{A0}234,5");
+    assert_eq!(swedish.parse_number("1 234,5"), Some(1234.5));
+    assert_eq!(swedish.format_date(2022, 3, 7), "2022-03-07");
+}
@@ -112,6 +112,13 @@ pub struct InputState {
 
     /// In-order events received this frame
     pub events: Vec<Event>,
+
+    /// Where the pointer was the last time files were dragged over egui.
+    ///
+    /// This is kept after the files are dropped, so that files which arrive a few frames late
+    /// (the web backend loads them asynchronously) still go to the widget they were dropped on.
+    /// See [`crate::Response::dnd_dropped_files`].
+    pub file_drag_pos: Option<Pos2>,
 }
 
 impl Default for InputState {
@@ -132,6 +139,7 @@ impl Default for InputState {
             modifiers: Default::default(),
             keys_down: Default::default(),
             events: Default::default(),
+            file_drag_pos: None,
         }
     }
 }
@@ -157,6 +165,12 @@ impl InputState {
         }
         let pointer = self.pointer.begin_frame(time, &new);
 
+        let file_drag_pos = if new.hovered_files.is_empty() {
+            self.file_drag_pos
+        } else {
+            pointer.latest_pos().or(self.file_drag_pos)
+        };
+
         let mut keys_down = self.keys_down;
         let mut scroll_delta = Vec2::ZERO;
         let mut zoom_factor_delta = 1.0;
@@ -193,6 +207,7 @@ impl InputState {
             modifiers: new.modifiers,
             keys_down,
             events: new.events.clone(), // TODO(emilk): remove clone() and use raw.events
+            file_drag_pos,
             raw: new,
         }
     }
@@ -849,6 +864,7 @@ impl InputState {
             modifiers,
             keys_down,
             events,
+            file_drag_pos,
         } = self;
 
         ui.style_mut()
@@ -891,6 +907,7 @@ impl InputState {
         ui.label(format!("stable_dt:    {:.1} ms", 1e3 * stable_dt));
         ui.label(format!("modifiers: {:#?}", modifiers));
         ui.label(format!("keys_down: {:?}", keys_down));
+        ui.label(format!("file_drag_pos: {:?}", file_drag_pos));
         ui.scope(|ui| {
             ui.set_min_height(150.0);
             ui.label(format!("events: {:#?}", events))
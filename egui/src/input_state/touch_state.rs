@@ -36,6 +36,12 @@ pub struct MultiTouchInfo {
     /// * `zoom > 1`: pinch spread
     pub zoom_delta_2d: Vec2,
 
+    /// Like [`Self::zoom_delta_2d`], except that a diagonal pinch with two fingers zooms each axis
+    /// by how much the fingers spread along it, instead of both by the same factor.
+    ///
+    /// An axis along which the fingers are only a few points apart doesn't zoom: its factor is `1`.
+    pub zoom_delta_axes: Vec2,
+
     /// Rotation in radians. Moving fingers around each other will change this value. This is a
     /// relative value, comparing the orientation of fingers in the current frame with the previous
     /// frame. If all fingers are resting, this value is `0.0`.
@@ -192,6 +198,29 @@ impl TouchState {
                 ),
                 PinchType::Proportional => Vec2::splat(zoom_delta),
             };
+            // A pinch that starts out diagonal has the fingers apart along both axes.
+            let zoom_delta_axes = match state.pinch_type {
+                PinchType::Proportional if self.active_touches.len() == 2 => {
+                    // Along an axis where the fingers have (almost) come together,
+                    // the ratio is unstable, or NaN.
+                    let axis_zoom = |current: f32, previous: f32| {
+                        if previous < MIN_AXIS_DISTANCE {
+                            1.0
+                        } else {
+                            current / previous
+                        }
+                    };
+                    let (current, previous) = (
+                        state.current.avg_abs_distance2,
+                        state_previous.avg_abs_distance2,
+                    );
+                    Vec2::new(
+                        axis_zoom(current.x, previous.x),
+                        axis_zoom(current.y, previous.y),
+                    )
+                }
+                _ => zoom_delta2,
+            };
 
             MultiTouchInfo {
                 start_time: state.start_time,
@@ -199,6 +228,7 @@ impl TouchState {
                 num_touches: self.active_touches.len(),
                 zoom_delta,
                 zoom_delta_2d: zoom_delta2,
+                zoom_delta_axes,
                 rotation_delta: normalized_angle(state.current.heading - state_previous.heading),
                 translation_delta: state.current.avg_pos - state_previous.avg_pos,
                 force: state.current.avg_force,
@@ -297,6 +327,10 @@ impl Debug for TouchState {
     }
 }
 
+/// How far from their middle (in points) two fingers must be along an axis to zoom it on their own,
+/// see [`MultiTouchInfo::zoom_delta_axes`].
+const MIN_AXIS_DISTANCE: f32 = 3.0;
+
 #[derive(Clone, Debug)]
 enum PinchType {
     Horizontal,
@@ -144,6 +144,9 @@ impl Widget for &mut epaint::TessellationOptions {
                 coarse_tessellation_culling,
                 prerasterized_discs,
                 round_text_to_pixels,
+                round_strokes_to_pixels,
+                instancing: _,    // depends on the backend
+                vertex_colors: _, // depends on the backend
                 debug_paint_clip_rects,
                 debug_paint_text_rects,
                 debug_ignore_clip_rects,
@@ -174,6 +177,8 @@ impl Widget for &mut epaint::TessellationOptions {
                 );
                 ui.checkbox(round_text_to_pixels, "Align text positions to pixel grid")
                     .on_hover_text("Most text already is, so don't expect to see a large change.");
+                ui.checkbox(round_strokes_to_pixels, "Align thin strokes to pixel grid")
+                    .on_hover_text("Keeps lines sharp at fractional scale factors.");
 
                 ui.checkbox(debug_ignore_clip_rects, "Ignore clip rectangles");
                 ui.checkbox(debug_paint_clip_rects, "Paint clip rectangles");
@@ -2,7 +2,7 @@
 //! are sometimes painted behind or in front of other things.
 
 use crate::{Id, *};
-use epaint::{ClippedShape, Shape};
+use epaint::{ahash::AHashMap, ClippedShape, Shape};
 
 /// Different layer categories
 #[derive(Clone, Copy, Debug, Hash, Eq, PartialEq, Ord, PartialOrd)]
@@ -109,7 +109,7 @@ impl LayerId {
 }
 
 /// A unique identifier of a specific [`Shape`] in a [`PaintList`].
-#[derive(Clone, Copy, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct ShapeIdx(usize);
 
 /// A list of [`Shape`]s paired with a clip rectangle.
@@ -147,6 +147,33 @@ impl PaintList {
         self.0[idx.0] = ClippedShape(clip_rect, shape);
     }
 
+    /// The index that the next added [`Shape`] will get.
+    #[inline(always)]
+    pub(crate) fn next_idx(&self) -> ShapeIdx {
+        ShapeIdx(self.0.len())
+    }
+
+    /// The shapes added since `start` (see [`Self::next_idx`]), or all of them.
+    pub(crate) fn shapes_since_mut(&mut self, start: Option<ShapeIdx>) -> &mut [ClippedShape] {
+        let start = start.map_or(0, |start| start.0.min(self.0.len()));
+        &mut self.0[start..]
+    }
+
+    /// The shapes added from `start` up to `end` (see [`Self::next_idx`]).
+    pub(crate) fn shapes_between(&self, start: ShapeIdx, end: ShapeIdx) -> &[ClippedShape] {
+        let end = end.0.min(self.0.len());
+        &self.0[start.0.min(end)..end]
+    }
+
+    /// Multiply the colors of each [`Shape`] by `opacity`, in-place.
+    pub fn multiply_opacity(&mut self, opacity: f32) {
+        for ClippedShape(_, shape) in &mut self.0 {
+            epaint::shape_transform::adjust_colors(shape, &|color| {
+                *color = color.linear_multiply(opacity);
+            });
+        }
+    }
+
     /// Translate each [`Shape`] and clip rectangle by this much, in-place
     pub fn translate(&mut self, delta: Vec2) {
         for ClippedShape(clip_rect, shape) in &mut self.0 {
@@ -166,8 +193,23 @@ impl GraphicLayers {
             .or_default()
     }
 
-    pub fn drain(&mut self, area_order: &[LayerId]) -> impl ExactSizeIterator<Item = ClippedShape> {
-        let mut all_shapes: Vec<_> = Default::default();
+    /// Move all shapes into `all_shapes`, keeping the memory of the lists for the next frame.
+    ///
+    /// `layer_opacity` is multiplied with the colors of the layers in it.
+    pub fn drain(
+        &mut self,
+        area_order: &[LayerId],
+        layer_opacity: &AHashMap<LayerId, f32>,
+        all_shapes: &mut Vec<ClippedShape>,
+    ) {
+        let mut append = |layer_id: LayerId, list: &mut PaintList| {
+            if let Some(&opacity) = layer_opacity.get(&layer_id) {
+                if opacity < 1.0 {
+                    list.multiply_opacity(opacity);
+                }
+            }
+            all_shapes.append(&mut list.0);
+        };
 
         for &order in &Order::ALL {
             let order_map = &mut self.0[order as usize];
@@ -181,17 +223,15 @@ impl GraphicLayers {
             for layer_id in area_order {
                 if layer_id.order == order {
                     if let Some(list) = order_map.get_mut(&layer_id.id) {
-                        all_shapes.append(&mut list.0);
+                        append(*layer_id, list);
                     }
                 }
             }
 
             // Also draw areas that are missing in `area_order`:
-            for shapes in order_map.values_mut() {
-                all_shapes.append(&mut shapes.0);
+            for (&id, list) in order_map.iter_mut() {
+                append(LayerId::new(order, id), list);
             }
         }
-
-        all_shapes.into_iter()
     }
 }
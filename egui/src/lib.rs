@@ -303,8 +303,11 @@ mod animation_manager;
 pub mod containers;
 mod context;
 mod data;
+mod drag_and_drop;
+mod frame_profiler;
 mod frame_state;
 pub(crate) mod grid;
+pub mod i18n;
 mod id;
 mod input_state;
 pub mod introspection;
@@ -314,9 +317,12 @@ mod memory;
 pub mod menu;
 mod painter;
 pub(crate) mod placer;
+mod promise;
 mod response;
 mod sense;
 pub mod style;
+mod transition;
+mod tween;
 mod ui;
 pub mod util;
 pub mod widget_text;
@@ -332,8 +338,8 @@ pub use epaint::{
     color, mutex,
     text::{FontData, FontDefinitions, FontFamily, FontId, FontTweak},
     textures::{TextureFilter, TexturesDelta},
-    ClippedPrimitive, Color32, ColorImage, FontImage, ImageData, Mesh, PaintCallback,
-    PaintCallbackInfo, Rgba, Rounding, Shape, Stroke, TextureHandle, TextureId,
+    ClippedPrimitive, Color32, ColorEncoding, ColorImage, FontImage, HdrImage, ImageData, Mesh,
+    PaintCallback, PaintCallbackInfo, Rgba, Rounding, Shape, Stroke, TextureHandle, TextureId,
 };
 
 pub mod text {
@@ -351,17 +357,21 @@ pub use {
         input::*,
         output::{self, CursorIcon, FullOutput, PlatformOutput, WidgetInfo},
     },
+    frame_profiler::{FrameTimeStats, FrameTimings},
+    frame_state::{IdClash, IdUse},
     grid::Grid,
     id::{Id, IdMap},
     input_state::{InputState, MultiTouchInfo, PointerState},
     layers::{LayerId, Order},
     layout::*,
-    memory::Memory,
-    painter::Painter,
+    memory::{Memory, MemoryUsage, MemoryVersion},
+    painter::{Painter, TextOnPath},
+    promise::{Promise, PromiseSender},
     response::{InnerResponse, Response},
     sense::Sense,
     style::{FontSelection, Style, TextStyle, Visuals},
     text::{Galley, TextFormat},
+    tween::{Easing, Tween, Tweenable},
     ui::Ui,
     widget_text::{RichText, WidgetText},
     widgets::*,
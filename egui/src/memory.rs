@@ -88,6 +88,57 @@ pub struct Memory {
 
     #[cfg_attr(feature = "persistence", serde(skip))]
     everything_is_visible: bool,
+
+    /// Missing for memory persisted before versioning was added.
+    #[cfg_attr(feature = "persistence", serde(default = "MemoryVersion::unversioned"))]
+    version: MemoryVersion,
+
+    /// Was this memory deserialized, rather than created fresh? Only then is there anything to migrate.
+    #[cfg_attr(feature = "persistence", serde(skip, default = "deserialized"))]
+    deserialized: bool,
+}
+
+#[cfg(feature = "persistence")]
+fn deserialized() -> bool {
+    true
+}
+
+// ----------------------------------------------------------------------------
+
+/// The schema versions that a [`Memory`] was persisted with, see [`Memory::migrate`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
+pub struct MemoryVersion {
+    /// The version of egui's own persisted state, see [`Self::EGUI`].
+    ///
+    /// Zero for memory persisted before versioning was added.
+    pub egui: u32,
+
+    /// The version of your own persisted state, as given to [`Memory::migrate`].
+    ///
+    /// Zero if you have never called [`Memory::migrate`].
+    pub app: u32,
+}
+
+impl MemoryVersion {
+    /// The current version of egui's own persisted state.
+    ///
+    /// This is bumped whenever egui changes what it persists in an incompatible way.
+    pub const EGUI: u32 = 1;
+
+    #[cfg(feature = "persistence")]
+    fn unversioned() -> Self {
+        Self { egui: 0, app: 0 }
+    }
+}
+
+impl Default for MemoryVersion {
+    fn default() -> Self {
+        Self {
+            egui: Self::EGUI,
+            app: 0,
+        }
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -116,6 +167,23 @@ pub struct Options {
     /// This can lead to fewer texture operations, but may use up the texture atlas quicker
     /// if you are changing [`Style::text_styles`], of have a lot of text styles.
     pub preload_font_glyphs: bool,
+
+    /// Pressing this toggles the built-in frame-time profiler window,
+    /// see [`crate::Context::set_profiler_visible`].
+    ///
+    /// `None` disables the shortcut.
+    pub profiler_shortcut: Option<(crate::Modifiers, crate::Key)>,
+
+    /// Scales the whole ui on top of the native scale factor, see [`crate::Context::set_zoom_factor`].
+    pub zoom_factor: f32,
+
+    /// If true, the user can change [`Self::zoom_factor`] with Ctrl/Cmd together with `+`, `-` and `0`.
+    pub zoom_with_keyboard: bool,
+
+    /// If true, the user can change [`Self::zoom_factor`] with pinch gestures and Ctrl-scroll.
+    ///
+    /// Off by default, since widgets that zoom their own content (like plots) would see the gesture too.
+    pub zoom_with_pinch: bool,
 }
 
 impl Default for Options {
@@ -125,12 +193,47 @@ impl Default for Options {
             tessellation_options: Default::default(),
             screen_reader: false,
             preload_font_glyphs: true,
+            profiler_shortcut: Some((
+                crate::Modifiers::COMMAND | crate::Modifiers::SHIFT,
+                crate::Key::P,
+            )),
+            zoom_factor: 1.0,
+            // eframe on web always uses the native pixels-per-point:
+            zoom_with_keyboard: !cfg!(target_arch = "wasm32"),
+            zoom_with_pinch: false,
         }
     }
 }
 
 // ----------------------------------------------------------------------------
 
+/// An overview of how much state egui is holding on to, see [`crate::Context::memory_usage`].
+///
+/// If these numbers keep growing in a long-running app, you are probably
+/// creating widgets with ever-changing [`Id`]s.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct MemoryUsage {
+    /// Number of values in [`Memory::data`].
+    pub num_data: usize,
+
+    /// How many of [`Self::num_data`] are still in serialized form.
+    pub num_serialized: usize,
+
+    /// Approximate size of [`Memory::data`], see [`crate::util::IdTypeMapEntry::size`].
+    pub data_bytes: usize,
+
+    /// Number of remembered areas (panels, windows, popups, …).
+    pub num_areas: usize,
+
+    /// Number of allocated textures, including the font texture.
+    pub num_textures: usize,
+
+    /// Approximate size of all allocated textures.
+    pub texture_bytes: usize,
+}
+
+// ----------------------------------------------------------------------------
+
 /// Say there is a button in a scroll area.
 /// If the user clicks the button, the button should click.
 /// If the user drags the button we should scroll the scroll area.
@@ -435,6 +538,56 @@ impl Memory {
     }
 }
 
+/// ## Versioning
+impl Memory {
+    /// The schema versions this memory was persisted with.
+    ///
+    /// For a fresh memory, or after [`Self::migrate`], these are the current versions.
+    pub fn version(&self) -> MemoryVersion {
+        self.version
+    }
+
+    /// Upgrade memory that was persisted by an older version of egui or of your app.
+    ///
+    /// Call this once after loading the memory, e.g. when creating your app.
+    /// If the persisted versions differ from the current ones, `migrate` is called with the
+    /// persisted versions, so that you can convert or discard the state that has changed.
+    /// Afterwards [`Self::version`] returns `app_version`, and that is what will be persisted.
+    ///
+    /// Fresh memory, that wasn't deserialized, starts at the current versions without calling `migrate`.
+    ///
+    /// Bump `app_version` whenever the types you store in [`Self::data`] change.
+    ///
+    /// ```
+    /// # let ctx = egui::Context::default();
+    /// # struct OldPanelState;
+    /// ctx.memory().migrate(2, |persisted, memory| {
+    ///     if persisted.app < 2 {
+    ///         // The layout of our panels changed in version 2:
+    ///         memory.data.remove_by_type::<OldPanelState>();
+    ///     }
+    ///     if persisted.egui < egui::MemoryVersion::EGUI {
+    ///         memory.reset_areas();
+    ///     }
+    /// });
+    /// ```
+    ///
+    /// Values in [`Self::data`] that can no longer be deserialized are skipped when read,
+    /// but remain in memory. To drop everything that wasn't read on the first frame:
+    /// `memory.data.retain(|entry| !entry.serialized)`.
+    pub fn migrate(&mut self, app_version: u32, migrate: impl FnOnce(MemoryVersion, &mut Self)) {
+        let current = MemoryVersion {
+            egui: MemoryVersion::EGUI,
+            app: app_version,
+        };
+        if self.deserialized && self.version != current {
+            let persisted = self.version;
+            migrate(persisted, self);
+        }
+        self.version = current;
+    }
+}
+
 /// ## Popups
 /// Popups are things like combo-boxes, color pickers, menus etc.
 /// Only one can be be open at a time.
@@ -515,6 +668,15 @@ impl Areas {
         &self.order
     }
 
+    /// Forget everything about this area, e.g. its position and size.
+    pub(crate) fn remove(&mut self, layer_id: LayerId) {
+        self.areas.remove(&layer_id.id);
+        self.order.retain(|x| *x != layer_id);
+        self.visible_last_frame.remove(&layer_id);
+        self.visible_current_frame.remove(&layer_id);
+        self.wants_to_be_on_top.remove(&layer_id);
+    }
+
     pub(crate) fn set_state(&mut self, layer_id: LayerId, state: area::State) {
         self.visible_current_frame.insert(layer_id);
         self.areas.insert(layer_id.id, state);
@@ -598,3 +760,16 @@ fn memory_impl_send_sync() {
     fn assert_send_sync<T: Send + Sync>() {}
     assert_send_sync::<Memory>();
 }
+
+#[test]
+fn test_migrate_fresh_memory() {
+    let mut memory = Memory::default();
+    memory.migrate(3, |_, _| panic!("fresh memory has nothing to migrate"));
+    assert_eq!(
+        memory.version(),
+        MemoryVersion {
+            egui: MemoryVersion::EGUI,
+            app: 3
+        }
+    );
+}
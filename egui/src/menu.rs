@@ -192,6 +192,23 @@ pub(crate) fn context_menu(
     inner_response
 }
 
+/// Like [`context_menu`], but only opens on a secondary click rather than on the press,
+/// so that dragging with the secondary button can still be used for something else.
+pub(crate) fn context_menu_on_click(
+    response: &Response,
+    add_contents: impl FnOnce(&mut Ui),
+) -> Option<InnerResponse<()>> {
+    let menu_id = Id::new("__egui::context_menu");
+    let mut bar_state = BarState::load(&response.ctx, menu_id);
+
+    let menu_response = MenuRoot::context_click_release_interaction(response, &mut bar_state);
+    MenuRoot::handle_menu_response(&mut bar_state, menu_response);
+    let inner_response = bar_state.show(response, add_contents);
+
+    bar_state.store(&response.ctx, menu_id);
+    inner_response
+}
+
 /// Stores the state for the context menu.
 #[derive(Clone, Default)]
 pub(crate) struct MenuRootManager {
@@ -336,6 +353,27 @@ impl MenuRoot {
         MenuResponse::Stay
     }
 
+    /// Interaction with a context menu that opens on secondary clicks, see [`context_menu_on_click`].
+    fn context_click_release_interaction(
+        response: &Response,
+        root: &mut Option<MenuRoot>,
+    ) -> MenuResponse {
+        let pointer = &response.ctx.input().pointer;
+        if response.clicked_by(PointerButton::Secondary) {
+            if let Some(pos) = pointer.interact_pos() {
+                return MenuResponse::Create(pos, response.id);
+            }
+        } else if pointer.any_pressed() {
+            if let (Some(pos), Some(root)) = (pointer.interact_pos(), root) {
+                let in_old_menu = root.menu_state.read().area_contains(pos);
+                if !in_old_menu && root.id == response.id {
+                    return MenuResponse::Close;
+                }
+            }
+        }
+        MenuResponse::Stay
+    }
+
     fn handle_menu_response(root: &mut MenuRootManager, menu_response: MenuResponse) {
         match menu_response {
             MenuResponse::Create(pos, id) => {
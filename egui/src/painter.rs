@@ -2,14 +2,14 @@ use std::ops::RangeInclusive;
 use std::sync::Arc;
 
 use crate::{
-    emath::{Align2, Pos2, Rect, Vec2},
+    emath::{pos2, Align, Align2, Pos2, Rect, Vec2},
     layers::{LayerId, PaintList, ShapeIdx},
     Color32, Context, FontId,
 };
 use epaint::{
     mutex::{RwLockReadGuard, RwLockWriteGuard},
     text::{Fonts, Galley},
-    CircleShape, RectShape, Rounding, Shape, Stroke,
+    CircleShape, Mesh, RectShape, Rounding, Shape, Stroke, Vertex,
 };
 
 /// Helper to paint shapes and text to a specific region on a specific layer.
@@ -30,6 +30,10 @@ pub struct Painter {
     /// If set, all shapes will have their colors modified to be closer to this.
     /// This is used to implement grayed out interfaces.
     fade_to_color: Option<Color32>,
+
+    /// All shapes will have their colors multiplied by this.
+    /// This is used to fade things in and out.
+    opacity: f32,
 }
 
 impl Painter {
@@ -40,6 +44,7 @@ impl Painter {
             layer_id,
             clip_rect,
             fade_to_color: None,
+            opacity: 1.0,
         }
     }
 
@@ -51,6 +56,7 @@ impl Painter {
             layer_id,
             clip_rect: self.clip_rect,
             fade_to_color: None,
+            opacity: self.opacity,
         }
     }
 
@@ -64,6 +70,7 @@ impl Painter {
             layer_id: self.layer_id,
             clip_rect: rect.intersect(self.clip_rect),
             fade_to_color: self.fade_to_color,
+            opacity: self.opacity,
         }
     }
 
@@ -77,6 +84,19 @@ impl Painter {
         self.fade_to_color = fade_to_color;
     }
 
+    /// Multiply the colors of everything painted from now on by this, in `[0, 1]`.
+    ///
+    /// `0.0` is fully transparent, `1.0` (the default) leaves the colors unchanged.
+    pub fn set_opacity(&mut self, opacity: f32) {
+        self.opacity = opacity.clamp(0.0, 1.0);
+    }
+
+    /// See [`Self::set_opacity`].
+    #[inline(always)]
+    pub fn opacity(&self) -> f32 {
+        self.opacity
+    }
+
     pub(crate) fn is_visible(&self) -> bool {
         self.fade_to_color != Some(Color32::TRANSPARENT)
     }
@@ -93,6 +113,7 @@ impl Painter {
             layer_id: self.layer_id,
             clip_rect: rect.intersect(self.clip_rect),
             fade_to_color: self.fade_to_color,
+            opacity: self.opacity,
         }
     }
 }
@@ -160,6 +181,9 @@ impl Painter {
         if let Some(fade_to_color) = self.fade_to_color {
             tint_shape_towards(shape, fade_to_color);
         }
+        if self.opacity < 1.0 {
+            multiply_opacity(shape, self.opacity);
+        }
     }
 
     /// It is up to the caller to make sure there is room for this.
@@ -183,7 +207,7 @@ impl Painter {
             return;
         }
         if !shapes.is_empty() {
-            if self.fade_to_color.is_some() {
+            if self.fade_to_color.is_some() || self.opacity < 1.0 {
                 for shape in &mut shapes {
                     self.transform_shape(shape);
                 }
@@ -431,8 +455,244 @@ impl Painter {
     }
 }
 
+/// How [`Painter::text_on_path`] and [`Painter::text_on_arc`] place the text along the path.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TextOnPath {
+    /// [`Align::Min`] starts the text at the start of the path,
+    /// [`Align::Center`] centers it on the path, and [`Align::Max`] ends it at the end of the path.
+    pub align: Align,
+
+    /// Move the text this many points further along the path.
+    pub offset: f32,
+
+    /// Extra space between each glyph, in points. Can be negative.
+    pub letter_spacing: f32,
+
+    /// By default the path goes through the vertical middle of the text.
+    /// Positive values move the text away from the path, to its left side
+    /// (up, for a path going from left to right).
+    pub baseline_offset: f32,
+}
+
+impl Default for TextOnPath {
+    fn default() -> Self {
+        Self {
+            align: Align::Min,
+            offset: 0.0,
+            letter_spacing: 0.0,
+            baseline_offset: 0.0,
+        }
+    }
+}
+
+/// ## Text on a path
+impl Painter {
+    /// Paint a single line of text along a path, with each glyph rotated to follow it.
+    ///
+    /// The text reads from the first point towards the last, and glyphs that don't fit on the path are not painted.
+    /// Newlines are replaced by spaces.
+    ///
+    /// Returns the bounding rectangle of the painted glyphs.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn text_on_path(
+        &self,
+        path: &[Pos2],
+        text: impl ToString,
+        font_id: FontId,
+        text_color: Color32,
+        layout: TextOnPath,
+    ) -> Rect {
+        let path = PathSampler::new(path);
+        let galley = self.layout_no_wrap(text.to_string().replace('\n', " "), font_id, text_color);
+        let row = match galley.rows.first() {
+            Some(row) if !row.glyphs.is_empty() && path.length > 0.0 => row,
+            _ => return Rect::NOTHING,
+        };
+
+        let text_length = galley.size().x + layout.letter_spacing * (row.glyphs.len() - 1) as f32;
+        let start = layout.offset
+            + match layout.align {
+                Align::Min => 0.0,
+                Align::Center => (path.length - text_length) / 2.0,
+                Align::Max => path.length - text_length,
+            };
+        let middle_y = row.rect.center().y;
+
+        let [tex_w, tex_h] = self.fonts().font_image_size();
+        let uv_normalizer = Vec2::new(1.0 / tex_w as f32, 1.0 / tex_h as f32);
+
+        let mut mesh = Mesh::default();
+        for (i, glyph) in row.glyphs.iter().enumerate() {
+            let uv_rect = glyph.uv_rect;
+            if uv_rect.is_nothing() {
+                continue;
+            }
+            let center_x = glyph.pos.x + glyph.size.x / 2.0;
+            let (anchor, dir) =
+                match path.sample(start + center_x + layout.letter_spacing * i as f32) {
+                    Some(sample) => sample,
+                    None => continue,
+                };
+            let down = Vec2::new(-dir.y, dir.x);
+
+            let color = galley.job.sections[glyph.section_index as usize]
+                .format
+                .color;
+            let left_top = glyph.pos + uv_rect.offset;
+            let rect = Rect::from_min_size(left_top, uv_rect.size);
+            let uv = Rect::from_min_max(
+                pos2(uv_rect.min[0] as f32, uv_rect.min[1] as f32),
+                pos2(uv_rect.max[0] as f32, uv_rect.max[1] as f32),
+            );
+
+            let idx = mesh.vertices.len() as u32;
+            mesh.add_triangle(idx, idx + 1, idx + 2);
+            mesh.add_triangle(idx + 2, idx + 1, idx + 3);
+            for (corner, uv) in [
+                (rect.left_top(), uv.left_top()),
+                (rect.right_top(), uv.right_top()),
+                (rect.left_bottom(), uv.left_bottom()),
+                (rect.right_bottom(), uv.right_bottom()),
+            ] {
+                let along = corner.x - center_x;
+                let across = corner.y - middle_y - layout.baseline_offset;
+                mesh.vertices.push(Vertex {
+                    pos: anchor + along * dir + across * down,
+                    uv: (uv.to_vec2() * uv_normalizer).to_pos2(),
+                    color,
+                });
+            }
+        }
+
+        if mesh.is_empty() {
+            return Rect::NOTHING;
+        }
+        let rect = mesh.calc_bounds();
+        self.add(mesh);
+        rect
+    }
+
+    /// Paint a single line of text along a circular arc, e.g. the labels of a gauge.
+    ///
+    /// The angles are in radians, with zero to the right and increasing clockwise.
+    /// With increasing angles the text reads clockwise and stands on the outside of the circle,
+    /// which is what you want at the top of a circle.
+    /// With decreasing angles it reads counter-clockwise and hangs on the inside,
+    /// which is what you want at the bottom.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let center = egui::pos2(100.0, 100.0);
+    /// use egui::{Align, TextOnPath};
+    /// use std::f32::consts::PI;
+    ///
+    /// let layout = TextOnPath {
+    ///     align: Align::Center,
+    ///     ..Default::default()
+    /// };
+    /// let font_id = egui::FontId::proportional(14.0);
+    /// let color = ui.visuals().text_color();
+    /// let painter = ui.painter();
+    /// painter.text_on_arc(center, 50.0, -PI..=0.0, "Speed", font_id.clone(), color, layout);
+    /// painter.text_on_arc(center, 50.0, PI..=0.0, "km/h", font_id, color, layout);
+    /// # });
+    /// ```
+    ///
+    /// Returns the bounding rectangle of the painted glyphs.
+    #[allow(clippy::too_many_arguments)]
+    pub fn text_on_arc(
+        &self,
+        center: Pos2,
+        radius: f32,
+        angles: RangeInclusive<f32>,
+        text: impl ToString,
+        font_id: FontId,
+        text_color: Color32,
+        layout: TextOnPath,
+    ) -> Rect {
+        let (start, end) = (*angles.start(), *angles.end());
+        let segments = ((end - start).abs() * radius / 4.0)
+            .ceil()
+            .clamp(1.0, 1024.0) as usize;
+        let path: Vec<Pos2> = (0..=segments)
+            .map(|i| {
+                let angle = crate::lerp(start..=end, i as f32 / segments as f32);
+                center + radius * Vec2::angled(angle)
+            })
+            .collect();
+        self.text_on_path(&path, text, font_id, text_color, layout)
+    }
+}
+
+/// Finds positions along a polyline by distance from its start.
+struct PathSampler<'a> {
+    points: &'a [Pos2],
+
+    /// Distance from the start to each point.
+    distances: Vec<f32>,
+
+    length: f32,
+}
+
+impl<'a> PathSampler<'a> {
+    fn new(points: &'a [Pos2]) -> Self {
+        let mut distances = Vec::with_capacity(points.len());
+        let mut length = 0.0;
+        for (i, point) in points.iter().enumerate() {
+            if i > 0 {
+                length += points[i - 1].distance(*point);
+            }
+            distances.push(length);
+        }
+        Self {
+            points,
+            distances,
+            length,
+        }
+    }
+
+    /// The position and direction `distance` points along the path,
+    /// or `None` if that is beyond either end.
+    fn sample(&self, distance: f32) -> Option<(Pos2, Vec2)> {
+        if !(0.0..=self.length).contains(&distance) {
+            return None;
+        }
+        let segment = self
+            .distances
+            .windows(2)
+            .position(|d| d[0] < d[1] && distance <= d[1])?;
+        let (a, b) = (self.points[segment], self.points[segment + 1]);
+        let (d0, d1) = (self.distances[segment], self.distances[segment + 1]);
+        let t = (distance - d0) / (d1 - d0);
+        Some((a + t * (b - a), (b - a).normalized()))
+    }
+}
+
 fn tint_shape_towards(shape: &mut Shape, target: Color32) {
     epaint::shape_transform::adjust_colors(shape, &|color| {
         *color = crate::color::tint_color_towards(*color, target);
     });
 }
+
+fn multiply_opacity(shape: &mut Shape, opacity: f32) {
+    epaint::shape_transform::adjust_colors(shape, &|color| {
+        *color = color.linear_multiply(opacity);
+    });
+}
+
+#[test]
+fn test_path_sampler() {
+    let path = [
+        pos2(0.0, 0.0),
+        pos2(10.0, 0.0),
+        pos2(10.0, 0.0),
+        pos2(10.0, 20.0),
+    ];
+    let sampler = PathSampler::new(&path);
+    assert_eq!(sampler.length, 30.0);
+    assert_eq!(sampler.sample(-1.0), None);
+    assert_eq!(sampler.sample(0.0), Some((pos2(0.0, 0.0), Vec2::X)));
+    assert_eq!(sampler.sample(5.0), Some((pos2(5.0, 0.0), Vec2::X)));
+    assert_eq!(sampler.sample(20.0), Some((pos2(10.0, 10.0), Vec2::Y)));
+    assert_eq!(sampler.sample(31.0), None);
+}
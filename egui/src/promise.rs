@@ -0,0 +1,173 @@
+//! Waiting for the results of background work, see [`Promise`].
+
+use std::sync::mpsc;
+
+use crate::Context;
+
+enum State<T> {
+    Pending(mpsc::Receiver<T>),
+    Ready(T),
+    /// The [`PromiseSender`] was dropped without sending anything.
+    Abandoned,
+}
+
+/// A value that will be ready some time in the future, e.g. the result of a download.
+///
+/// The value is sent with a [`PromiseSender`], which requests a repaint when it is done,
+/// so you don't need to keep repainting while waiting.
+/// Poll the promise each frame with [`Self::ready`], or show it with [`crate::Ui::show_promise`].
+///
+/// ```
+/// # let ctx = egui::Context::default();
+/// let (sender, mut promise) = egui::Promise::new(&ctx);
+/// let worker = std::thread::spawn(move || {
+///     sender.send(42);
+/// });
+/// # worker.join().unwrap();
+/// # assert_eq!(promise.ready(), Some(&42));
+/// // Every frame:
+/// if let Some(value) = promise.ready() {
+///     // Use the value
+/// } else {
+///     // Show a spinner
+/// }
+/// ```
+pub struct Promise<T> {
+    state: State<T>,
+}
+
+impl<T> std::fmt::Debug for Promise<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let state = match &self.state {
+            State::Pending(_) => "pending",
+            State::Ready(_) => "ready",
+            State::Abandoned => "abandoned",
+        };
+        f.debug_struct("Promise").field("state", &state).finish()
+    }
+}
+
+impl<T: Send + 'static> Promise<T> {
+    /// Create a promise and the sender that will fulfill it.
+    ///
+    /// Sending the value (or dropping the sender) will request a repaint of `ctx`.
+    pub fn new(ctx: &Context) -> (PromiseSender<T>, Self) {
+        let (sender, receiver) = mpsc::channel();
+        let sender = PromiseSender {
+            sender,
+            ctx: ctx.clone(),
+        };
+        let promise = Self {
+            state: State::Pending(receiver),
+        };
+        (sender, promise)
+    }
+
+    /// A promise that is already fulfilled.
+    pub fn from_ready(value: T) -> Self {
+        Self {
+            state: State::Ready(value),
+        }
+    }
+
+    /// Run `task` on a new thread, and fulfill the promise with what it returns.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn spawn_thread(
+        ctx: &Context,
+        thread_name: impl Into<String>,
+        task: impl FnOnce() -> T + Send + 'static,
+    ) -> Self {
+        let (sender, promise) = Self::new(ctx);
+        std::thread::Builder::new()
+            .name(thread_name.into())
+            .spawn(move || sender.send(task()))
+            .expect("Failed to spawn thread");
+        promise
+    }
+
+    /// Fulfill the promise with the output of a future.
+    ///
+    /// egui has no executor, so you get back a future that you must spawn yourself,
+    /// e.g. with `tokio::spawn` or `wasm_bindgen_futures::spawn_local`.
+    pub fn from_future(
+        ctx: &Context,
+        future: impl std::future::Future<Output = T> + 'static,
+    ) -> (Self, impl std::future::Future<Output = ()> + 'static) {
+        let (sender, promise) = Self::new(ctx);
+        let task = async move {
+            sender.send(future.await);
+        };
+        (promise, task)
+    }
+}
+
+impl<T> Promise<T> {
+    fn poll(&mut self) {
+        if let State::Pending(receiver) = &self.state {
+            match receiver.try_recv() {
+                Ok(value) => self.state = State::Ready(value),
+                Err(mpsc::TryRecvError::Empty) => {}
+                Err(mpsc::TryRecvError::Disconnected) => self.state = State::Abandoned,
+            }
+        }
+    }
+
+    /// The value, if it has arrived.
+    pub fn ready(&mut self) -> Option<&T> {
+        self.poll();
+        match &self.state {
+            State::Ready(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// The value, if it has arrived.
+    pub fn ready_mut(&mut self) -> Option<&mut T> {
+        self.poll();
+        match &mut self.state {
+            State::Ready(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Take the value if it has arrived, otherwise give back the promise.
+    pub fn try_take(mut self) -> Result<T, Self> {
+        self.poll();
+        match self.state {
+            State::Ready(value) => Ok(value),
+            state => Err(Self { state }),
+        }
+    }
+
+    /// Still waiting for the value?
+    pub fn is_pending(&mut self) -> bool {
+        self.poll();
+        matches!(self.state, State::Pending(_))
+    }
+
+    /// Was the [`PromiseSender`] dropped without sending a value, e.g. because the task panicked?
+    ///
+    /// Such a promise will never be ready.
+    pub fn is_abandoned(&mut self) -> bool {
+        self.poll();
+        matches!(self.state, State::Abandoned)
+    }
+}
+
+/// Fulfills a [`Promise`]. See [`Promise::new`].
+pub struct PromiseSender<T> {
+    sender: mpsc::Sender<T>,
+    ctx: Context,
+}
+
+impl<T> PromiseSender<T> {
+    pub fn send(self, value: T) {
+        self.sender.send(value).ok(); // ignore the error if the promise was dropped
+    }
+}
+
+impl<T> Drop for PromiseSender<T> {
+    fn drop(&mut self) {
+        self.ctx.request_repaint();
+    }
+}
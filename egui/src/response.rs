@@ -1,7 +1,9 @@
+use std::{any::Any, sync::Arc};
+
 use crate::{
     emath::{Align, Pos2, Rect, Vec2},
-    menu, Context, CursorIcon, Id, LayerId, PointerButton, Sense, Ui, WidgetText,
-    NUM_POINTER_BUTTONS,
+    menu, Context, CursorIcon, DroppedFile, HoveredFile, Id, LayerId, PointerButton, Sense, Ui,
+    WidgetText,
 };
 
 // ----------------------------------------------------------------------------
@@ -13,6 +15,10 @@ use crate::{
 ///
 /// Whenever something gets added to a [`Ui`], a [`Response`] object is returned.
 /// [`ui.add`] returns a [`Response`], as does [`ui.button`], and all similar shortcuts.
+///
+/// To keep it small, only the outcome of the interaction is stored.
+/// Details like double-clicks, drag deltas and the hover position are looked up
+/// in the [`crate::InputState`] when you ask for them.
 #[derive(Clone)]
 pub struct Response {
     // CONTEXT:
@@ -43,16 +49,11 @@ pub struct Response {
     pub hovered: bool,
 
     /// The pointer clicked this thing this frame.
-    #[doc(hidden)]
-    pub clicked: [bool; NUM_POINTER_BUTTONS],
-
+    ///
+    /// One bit per [`PointerButton`], see [`Self::clicked_by`].
     // TODO(emilk): `released` for sliders
-    /// The thing was double-clicked.
     #[doc(hidden)]
-    pub double_clicked: [bool; NUM_POINTER_BUTTONS],
-
-    /// The thing was triple-clicked.
-    pub(crate) triple_clicked: [bool; NUM_POINTER_BUTTONS],
+    pub clicked: u8,
 
     /// The widgets is being dragged
     #[doc(hidden)]
@@ -67,11 +68,6 @@ pub struct Response {
     #[doc(hidden)]
     pub is_pointer_button_down_on: bool,
 
-    /// Where the pointer (mouse/touch) were when when this widget was clicked or dragged.
-    /// `None` if the widget is not being interacted with.
-    #[doc(hidden)]
-    pub interact_pointer_pos: Option<Pos2>,
-
     /// What the underlying data changed?
     ///
     /// e.g. the slider was dragged, text was entered in a [`TextEdit`](crate::TextEdit) etc.
@@ -91,12 +87,9 @@ impl std::fmt::Debug for Response {
             enabled,
             hovered,
             clicked,
-            double_clicked,
-            triple_clicked,
             dragged,
             drag_released,
             is_pointer_button_down_on,
-            interact_pointer_pos,
             changed,
         } = self;
         f.debug_struct("Response")
@@ -106,13 +99,10 @@ impl std::fmt::Debug for Response {
             .field("sense", sense)
             .field("enabled", enabled)
             .field("hovered", hovered)
-            .field("clicked", clicked)
-            .field("double_clicked", double_clicked)
-            .field("triple_clicked", triple_clicked)
+            .field("clicked", &format_args!("{:#07b}", clicked))
             .field("dragged", dragged)
             .field("drag_released", drag_released)
             .field("is_pointer_button_down_on", is_pointer_button_down_on)
-            .field("interact_pointer_pos", interact_pointer_pos)
             .field("changed", changed)
             .finish()
     }
@@ -130,42 +120,57 @@ impl Response {
     /// You can use [`Self::interact`] to sense more things *after* adding a widget.
     #[inline(always)]
     pub fn clicked(&self) -> bool {
-        self.clicked[PointerButton::Primary as usize]
+        self.clicked_by(PointerButton::Primary)
     }
 
     /// Returns true if this widget was clicked this frame by the given button.
+    #[inline(always)]
     pub fn clicked_by(&self, button: PointerButton) -> bool {
-        self.clicked[button as usize]
+        self.clicked & (1 << button as u8) != 0
     }
 
     /// Returns true if this widget was clicked this frame by the secondary mouse button (e.g. the right mouse button).
     pub fn secondary_clicked(&self) -> bool {
-        self.clicked[PointerButton::Secondary as usize]
+        self.clicked_by(PointerButton::Secondary)
     }
 
     /// Returns true if this widget was clicked this frame by the middle mouse button.
     pub fn middle_clicked(&self) -> bool {
-        self.clicked[PointerButton::Middle as usize]
+        self.clicked_by(PointerButton::Middle)
     }
 
     /// Returns true if this widget was double-clicked this frame by the primary button.
     pub fn double_clicked(&self) -> bool {
-        self.double_clicked[PointerButton::Primary as usize]
+        self.double_clicked_by(PointerButton::Primary)
     }
 
     /// Returns true if this widget was triple-clicked this frame by the primary button.
     pub fn triple_clicked(&self) -> bool {
-        self.triple_clicked[PointerButton::Primary as usize]
+        self.triple_clicked_by(PointerButton::Primary)
     }
 
     /// Returns true if this widget was double-clicked this frame by the given button.
     pub fn double_clicked_by(&self, button: PointerButton) -> bool {
-        self.double_clicked[button as usize]
+        // Only look at the input if we were clicked at all, which is rare:
+        self.clicked_by(button) && self.ctx.input().pointer.button_double_clicked(button)
     }
 
     /// Returns true if this widget was triple-clicked this frame by the given button.
     pub fn triple_clicked_by(&self, button: PointerButton) -> bool {
-        self.triple_clicked[button as usize]
+        self.clicked_by(button) && self.ctx.input().pointer.button_triple_clicked(button)
+    }
+
+    /// Mark this widget as clicked (or not) by the given button.
+    ///
+    /// Useful for a widget that handles some clicks itself,
+    /// e.g. the remove button of a [`crate::Chip`].
+    #[inline]
+    pub fn set_clicked_by(&mut self, button: PointerButton, clicked: bool) {
+        if clicked {
+            self.clicked |= 1 << button as u8;
+        } else {
+            self.clicked &= !(1 << button as u8);
+        }
     }
 
     /// `true` if there was a click *outside* this widget this frame.
@@ -283,6 +288,77 @@ impl Response {
         self.drag_released
     }
 
+    /// Make this widget the source of a drag-and-drop carrying `payload`.
+    ///
+    /// While the widget is being dragged, drop targets can pick up the payload with
+    /// [`Self::dnd_hover_payload`] and [`Self::dnd_release_payload`].
+    /// The widget must sense drags, see [`Sense::drag`].
+    ///
+    /// This only sets the payload and the cursor icon.
+    /// To also have the widget follow the pointer, use [`Ui::dnd_drag_source`].
+    pub fn dnd_drag_source<Payload: Any + Send + Sync>(self, payload: Payload) -> Self {
+        if self.dragged() {
+            self.ctx.set_dragged_payload(payload);
+            self.ctx.output().cursor_icon = CursorIcon::Grabbing;
+        } else if self.hovered() && self.sense.drag {
+            self.ctx.output().cursor_icon = CursorIcon::Grab;
+        }
+        self
+    }
+
+    /// The payload of the drag in progress, if it is of type `Payload` and is hovering this widget.
+    ///
+    /// Use this to highlight drop targets.
+    pub fn dnd_hover_payload<Payload: Any + Send + Sync>(&self) -> Option<Arc<Payload>> {
+        if self.hovered() {
+            self.ctx.dragged_payload()
+        } else {
+            None
+        }
+    }
+
+    /// The payload of type `Payload` that was dropped onto this widget this frame, if any.
+    ///
+    /// This takes the payload, so only one drop target will ever get it.
+    pub fn dnd_release_payload<Payload: Any + Send + Sync>(&self) -> Option<Arc<Payload>> {
+        if self.hovered() && self.ctx.input().pointer.any_released() {
+            self.ctx.take_dragged_payload()
+        } else {
+            None
+        }
+    }
+
+    /// The files that are being dragged from outside the app over this widget.
+    ///
+    /// Empty if no files are being dragged, or if they are not over this widget.
+    /// Use this to highlight file drop targets.
+    pub fn dnd_hover_files(&self) -> Vec<HoveredFile> {
+        if self.contains_file_drag_pos() {
+            self.ctx.input().raw.hovered_files.clone()
+        } else {
+            vec![]
+        }
+    }
+
+    /// The files that were dropped onto this widget this frame, if any.
+    ///
+    /// This takes the files out of [`crate::RawInput::dropped_files`],
+    /// so any app-wide file drop handling won't see them.
+    pub fn dnd_dropped_files(&self) -> Vec<DroppedFile> {
+        if !self.ctx.input().raw.dropped_files.is_empty() && self.contains_file_drag_pos() {
+            std::mem::take(&mut self.ctx.input_mut().raw.dropped_files)
+        } else {
+            vec![]
+        }
+    }
+
+    fn contains_file_drag_pos(&self) -> bool {
+        let pos = self.ctx.input().file_drag_pos;
+        pos.map_or(false, |pos| {
+            self.rect.contains(pos) && self.ctx.layer_id_at(pos) == Some(self.layer_id)
+        })
+    }
+
     /// If dragged, how many points were we dragged and in what direction?
     pub fn drag_delta(&self) -> Vec2 {
         if self.dragged() {
@@ -295,7 +371,11 @@ impl Response {
     /// Where the pointer (mouse/touch) were when when this widget was clicked or dragged.
     /// `None` if the widget is not being interacted with.
     pub fn interact_pointer_pos(&self) -> Option<Pos2> {
-        self.interact_pointer_pos
+        if self.is_pointer_button_down_on {
+            self.ctx.input().pointer.interact_pos()
+        } else {
+            None
+        }
     }
 
     /// If it is a good idea to show a tooltip, where is pointer?
@@ -391,6 +471,15 @@ impl Response {
             return true;
         }
 
+        let widget_hovered = self.is_hovered_for_tooltip();
+        crate::containers::popup::should_show_tooltip(
+            &self.ctx,
+            self.id.with("__tooltip"),
+            widget_hovered,
+        )
+    }
+
+    fn is_hovered_for_tooltip(&self) -> bool {
         if !self.hovered || !self.ctx.input().pointer.has_pointer() {
             return false;
         }
@@ -464,6 +553,7 @@ impl Response {
     /// # });
     /// ```
     #[must_use]
+    #[track_caller]
     pub fn interact(&self, sense: Sense) -> Self {
         self.ctx.interact_with_hovered(
             self.layer_id,
@@ -561,32 +651,11 @@ impl Response {
             sense: self.sense.union(other.sense),
             enabled: self.enabled || other.enabled,
             hovered: self.hovered || other.hovered,
-            clicked: [
-                self.clicked[0] || other.clicked[0],
-                self.clicked[1] || other.clicked[1],
-                self.clicked[2] || other.clicked[2],
-                self.clicked[3] || other.clicked[3],
-                self.clicked[4] || other.clicked[4],
-            ],
-            double_clicked: [
-                self.double_clicked[0] || other.double_clicked[0],
-                self.double_clicked[1] || other.double_clicked[1],
-                self.double_clicked[2] || other.double_clicked[2],
-                self.double_clicked[3] || other.double_clicked[3],
-                self.double_clicked[4] || other.double_clicked[4],
-            ],
-            triple_clicked: [
-                self.triple_clicked[0] || other.triple_clicked[0],
-                self.triple_clicked[1] || other.triple_clicked[1],
-                self.triple_clicked[2] || other.triple_clicked[2],
-                self.triple_clicked[3] || other.triple_clicked[3],
-                self.triple_clicked[4] || other.triple_clicked[4],
-            ],
+            clicked: self.clicked | other.clicked,
             dragged: self.dragged || other.dragged,
             drag_released: self.drag_released || other.drag_released,
             is_pointer_button_down_on: self.is_pointer_button_down_on
                 || other.is_pointer_button_down_on,
-            interact_pointer_pos: self.interact_pointer_pos.or(other.interact_pointer_pos),
             changed: self.changed || other.changed,
         }
     }
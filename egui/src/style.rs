@@ -194,6 +194,15 @@ pub struct Style {
     /// How many seconds a typical animation should last.
     pub animation_time: f32,
 
+    /// How windows, popups, tooltips and collapsing content appear and disappear.
+    pub transitions: Transitions,
+
+    /// Skip animations, for users that are sensitive to motion.
+    ///
+    /// If true, [`Self::transitions`] are instant and [`crate::Context::animate_bool`]
+    /// (used by e.g. collapsing headers) jumps straight to its target.
+    pub reduce_motion: bool,
+
     /// Options to help debug why egui behaves strangely.
     pub debug: DebugOptions,
 
@@ -393,6 +402,121 @@ pub struct Interaction {
 
     /// If `false`, tooltips will show up anytime you hover anything, even is mouse is still moving
     pub show_tooltips_only_when_still: bool,
+
+    /// How many seconds a widget must be hovered before its tooltip is shown.
+    pub tooltip_delay: f32,
+
+    /// For how many seconds the tooltip keeps showing after the pointer has left the widget.
+    ///
+    /// If this is positive, the tooltip also stays open while the pointer is over it,
+    /// and the widgets in it (e.g. links and buttons) can be interacted with.
+    pub tooltip_grace_time: f32,
+
+    /// How many seconds it takes a tooltip to fade in.
+    pub tooltip_fade_in_time: f32,
+}
+
+/// How something appears (and, reversed, disappears), see [`Transitions`].
+///
+/// The parts can be combined, e.g. `Transition::fade().with_slide(vec2(0.0, -8.0))`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Transition {
+    /// Fade in from fully transparent.
+    pub fade: bool,
+
+    /// Slide in from this offset, in points.
+    pub slide: Vec2,
+
+    /// Grow from this scale, around the center. `1.0` means no scaling.
+    pub scale: f32,
+
+    /// In seconds. `None` means [`Style::animation_time`].
+    pub duration: Option<f32>,
+
+    pub easing: crate::Easing,
+}
+
+impl Default for Transition {
+    fn default() -> Self {
+        Self::NONE
+    }
+}
+
+impl Transition {
+    /// Appear and disappear at once.
+    pub const NONE: Self = Self {
+        fade: false,
+        slide: Vec2::ZERO,
+        scale: 1.0,
+        duration: None,
+        easing: crate::Easing::CubicOut,
+    };
+
+    pub fn fade() -> Self {
+        Self::NONE.with_fade()
+    }
+
+    pub fn slide(offset: Vec2) -> Self {
+        Self::NONE.with_slide(offset)
+    }
+
+    pub fn scale(from: f32) -> Self {
+        Self::NONE.with_scale(from)
+    }
+
+    pub fn with_fade(mut self) -> Self {
+        self.fade = true;
+        self
+    }
+
+    pub fn with_slide(mut self, offset: Vec2) -> Self {
+        self.slide = offset;
+        self
+    }
+
+    pub fn with_scale(mut self, from: f32) -> Self {
+        self.scale = from;
+        self
+    }
+
+    pub fn with_duration(mut self, seconds: f32) -> Self {
+        self.duration = Some(seconds);
+        self
+    }
+
+    pub fn with_easing(mut self, easing: crate::Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Does this transition do nothing?
+    pub fn is_none(&self) -> bool {
+        !self.fade && self.slide == Vec2::ZERO && self.scale == 1.0
+    }
+}
+
+/// The enter and exit transitions of the built-in containers.
+///
+/// All are [`Transition::NONE`] by default.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Transitions {
+    /// For [`crate::Window`]s that are opened and closed with [`crate::Window::open`].
+    pub window: Transition,
+
+    /// For popups, e.g. of combo boxes.
+    pub popup: Transition,
+
+    /// Tooltips only have an enter transition, since they are gone as soon as they are no longer shown.
+    pub tooltip: Transition,
+
+    /// For the body of collapsing headers, on top of their height animation.
+    ///
+    /// This plays along with the height animation, so [`Transition::duration`] is ignored.
+    pub collapsing: Transition,
 }
 
 /// Controls the visual style (colors etc) of egui.
@@ -631,6 +755,8 @@ impl Default for Style {
             interaction: Interaction::default(),
             visuals: Visuals::default(),
             animation_time: 1.0 / 12.0,
+            transitions: Transitions::default(),
+            reduce_motion: false,
             debug: Default::default(),
             explanation_tooltips: false,
         }
@@ -664,6 +790,9 @@ impl Default for Interaction {
             resize_grab_radius_side: 5.0,
             resize_grab_radius_corner: 10.0,
             show_tooltips_only_when_still: false,
+            tooltip_delay: 0.0,
+            tooltip_grace_time: 0.0,
+            tooltip_fade_in_time: 0.0,
         }
     }
 }
@@ -844,6 +973,8 @@ impl Style {
             interaction,
             visuals,
             animation_time,
+            transitions,
+            reduce_motion,
             debug,
             explanation_tooltips,
         } = self;
@@ -887,9 +1018,15 @@ impl Style {
                     .suffix(" s"),
             );
             ui.end_row();
+
+            ui.label("Reduce motion:");
+            ui.checkbox(reduce_motion, "")
+                .on_hover_text("Skip animations and transitions");
+            ui.end_row();
         });
 
         ui.collapsing("🔠 Text Styles", |ui| text_styles_ui(ui, text_styles));
+        ui.collapsing("🎬 Transitions", |ui| transitions.ui(ui));
         ui.collapsing("📏 Spacing", |ui| spacing.ui(ui));
         ui.collapsing("☝ Interaction", |ui| interaction.ui(ui));
         ui.collapsing("🎨 Visuals", |ui| visuals.ui(ui));
@@ -1033,6 +1170,9 @@ impl Interaction {
             resize_grab_radius_side,
             resize_grab_radius_corner,
             show_tooltips_only_when_still,
+            tooltip_delay,
+            tooltip_grace_time,
+            tooltip_fade_in_time,
         } = self;
         ui.add(Slider::new(resize_grab_radius_side, 0.0..=20.0).text("resize_grab_radius_side"));
         ui.add(
@@ -1042,11 +1182,74 @@ impl Interaction {
             show_tooltips_only_when_still,
             "Only show tooltips if mouse is still",
         );
+        ui.add(Slider::new(tooltip_delay, 0.0..=2.0).text("tooltip_delay"));
+        ui.add(Slider::new(tooltip_grace_time, 0.0..=2.0).text("tooltip_grace_time"));
+        ui.add(Slider::new(tooltip_fade_in_time, 0.0..=1.0).text("tooltip_fade_in_time"));
+
+        ui.vertical_centered(|ui| reset_button(ui, self));
+    }
+}
+
+impl Transitions {
+    pub fn ui(&mut self, ui: &mut crate::Ui) {
+        let Self {
+            window,
+            popup,
+            tooltip,
+            collapsing,
+        } = self;
+        ui.collapsing("Window", |ui| window.ui(ui));
+        ui.collapsing("Popup", |ui| popup.ui(ui));
+        ui.collapsing("Tooltip", |ui| tooltip.ui(ui));
+        ui.collapsing("Collapsing", |ui| collapsing.ui(ui));
 
         ui.vertical_centered(|ui| reset_button(ui, self));
     }
 }
 
+impl Transition {
+    pub fn ui(&mut self, ui: &mut crate::Ui) {
+        let Self {
+            fade,
+            slide,
+            scale,
+            duration,
+            easing,
+        } = self;
+        ui.checkbox(fade, "Fade");
+        ui.horizontal(|ui| {
+            ui.label("Slide from");
+            ui.add(DragValue::new(&mut slide.x).prefix("x: "));
+            ui.add(DragValue::new(&mut slide.y).prefix("y: "));
+        });
+        ui.add(Slider::new(scale, 0.0..=2.0).text("Scale from"));
+        ui.horizontal(|ui| {
+            let mut custom_duration = duration.is_some();
+            ui.checkbox(&mut custom_duration, "Duration");
+            if custom_duration {
+                let seconds = duration.get_or_insert(0.2);
+                ui.add(Slider::new(seconds, 0.0..=2.0).suffix(" s"));
+            } else {
+                *duration = None;
+            }
+        });
+        crate::ComboBox::from_label("Easing")
+            .selected_text(format!("{:?}", easing))
+            .show_ui(ui, |ui| {
+                for value in [
+                    crate::Easing::Linear,
+                    crate::Easing::CubicIn,
+                    crate::Easing::CubicOut,
+                    crate::Easing::CubicInOut,
+                    crate::Easing::Spring,
+                    crate::Easing::Bounce,
+                ] {
+                    ui.selectable_value(easing, value, format!("{:?}", value));
+                }
+            });
+    }
+}
+
 impl Widgets {
     pub fn ui(&mut self, ui: &mut crate::Ui) {
         let Self {
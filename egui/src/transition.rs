@@ -0,0 +1,148 @@
+//! Playing [`Transition`]s, by transforming what has already been painted.
+
+use epaint::{tessellator::Tessellator, ClippedShape, Mesh, Shape};
+
+use crate::{layers::ShapeIdx, style::Transition, Context, Id, LayerId, Pos2, Rect};
+
+#[derive(Clone, Copy)]
+struct TransitionState {
+    openness: f32,
+    frame_nr: u64,
+}
+
+impl Transition {
+    /// Zero with [`crate::Style::reduce_motion`].
+    pub(crate) fn duration_in(&self, style: &crate::Style) -> f32 {
+        if style.reduce_motion || self.is_none() {
+            0.0
+        } else {
+            self.duration.unwrap_or(style.animation_time)
+        }
+    }
+
+    /// Move towards shown (`1.0`) or hidden (`0.0`), and return where we are.
+    ///
+    /// Starts out hidden if this wasn't animated last frame, so that e.g. a tooltip
+    /// gets its enter transition each time it is shown.
+    pub(crate) fn animate(&self, ctx: &Context, id: Id, is_open: bool) -> f32 {
+        if ctx.memory().everything_is_visible() {
+            return 1.0;
+        }
+
+        let duration = self.duration_in(&ctx.style());
+        if duration <= 0.0 {
+            return if is_open { 1.0 } else { 0.0 };
+        }
+
+        let frame_nr = ctx.frame_nr();
+        let openness = ctx
+            .data()
+            .get_temp::<TransitionState>(id)
+            .filter(|state| state.frame_nr + 1 >= frame_nr)
+            .map_or(0.0, |state| state.openness);
+
+        let step = ctx.input().stable_dt / duration;
+        let openness = if is_open {
+            (openness + step).min(1.0)
+        } else {
+            (openness - step).max(0.0)
+        };
+
+        if is_open && openness < 1.0 || !is_open && openness > 0.0 {
+            ctx.request_repaint();
+        }
+        ctx.data()
+            .insert_temp(id, TransitionState { openness, frame_nr });
+        openness
+    }
+
+    /// Transform what has been painted to `layer_id` since `start` (or all of it),
+    /// according to how far into the transition we are (`0.0` is hidden, `1.0` is shown).
+    ///
+    /// `rect` is what is scaled around.
+    pub(crate) fn apply(
+        &self,
+        ctx: &Context,
+        layer_id: LayerId,
+        start: Option<ShapeIdx>,
+        rect: Rect,
+        openness: f32,
+    ) {
+        if self.is_none() || openness >= 1.0 {
+            return;
+        }
+
+        let t = self.easing.apply(openness);
+        let mut opacity = if self.fade { t.clamp(0.0, 1.0) } else { 1.0 };
+        if start.is_none() && opacity < 1.0 {
+            // Cheaper to fade the whole layer at the end of the frame:
+            ctx.set_layer_opacity(layer_id, opacity * ctx.layer_opacity(layer_id));
+            opacity = 1.0;
+        }
+        let offset = self.slide * (1.0 - t);
+        let scale = crate::lerp(self.scale..=1.0, t).max(0.0);
+
+        // Scaling text and rounded corners is best done on the tessellated meshes:
+        let mut tessellator = (scale != 1.0).then(|| {
+            let texture_atlas = ctx.fonts().texture_atlas();
+            let font_tex_size = texture_atlas.lock().size();
+            let prepared_discs = texture_atlas.lock().prepared_discs();
+            Tessellator::new(
+                ctx.pixels_per_point(),
+                *ctx.tessellation_options(),
+                font_tex_size,
+                prepared_discs,
+            )
+        });
+        let pivot = rect.center();
+
+        let mut graphics = ctx.graphics();
+        for ClippedShape(clip_rect, shape) in graphics.list(layer_id).shapes_since_mut(start) {
+            if opacity < 1.0 {
+                epaint::shape_transform::adjust_colors(shape, &|color| {
+                    *color = color.linear_multiply(opacity);
+                });
+            }
+            if let Some(tessellator) = &mut tessellator {
+                scale_shape(tessellator, shape, pivot, scale);
+                *clip_rect = Rect::from_min_max(
+                    pivot + (clip_rect.min - pivot) * scale,
+                    pivot + (clip_rect.max - pivot) * scale,
+                );
+            }
+            if offset != crate::Vec2::ZERO {
+                *clip_rect = clip_rect.translate(offset);
+                shape.translate(offset);
+            }
+        }
+    }
+}
+
+fn scale_shape(tessellator: &mut Tessellator, shape: &mut Shape, pivot: Pos2, scale: f32) {
+    let scale_mesh = |mesh: &mut Mesh| {
+        for vertex in &mut mesh.vertices {
+            vertex.pos = pivot + (vertex.pos - pivot) * scale;
+        }
+    };
+    match shape {
+        Shape::Noop | Shape::Callback(_) => {}
+        Shape::Vec(shapes) => {
+            for shape in shapes {
+                scale_shape(tessellator, shape, pivot, scale);
+            }
+        }
+        Shape::Mesh(mesh) => scale_mesh(mesh),
+        Shape::Instanced(instanced) => {
+            for instance in &mut instanced.instances {
+                instance.offset = pivot.to_vec2() + (instance.offset - pivot.to_vec2()) * scale;
+                instance.scale *= scale;
+            }
+        }
+        _ => {
+            let mut mesh = Mesh::default();
+            tessellator.tessellate_shape(std::mem::replace(shape, Shape::Noop), &mut mesh);
+            scale_mesh(&mut mesh);
+            *shape = Shape::mesh(mesh);
+        }
+    }
+}
@@ -0,0 +1,258 @@
+//! Animating values over time with easing curves, see [`Tween`] and [`crate::Context::animate_to`].
+
+use std::sync::Arc;
+
+use crate::{emath::lerp, Color32, Pos2, Rgba, Vec2};
+
+/// How an animation progresses over time.
+///
+/// See <https://easings.net/> for what most of these look like.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum Easing {
+    /// Constant speed.
+    Linear,
+
+    /// Start slow, end fast.
+    CubicIn,
+
+    /// Start fast, end slow.
+    CubicOut,
+
+    /// Start and end slow.
+    CubicInOut,
+
+    /// Overshoot the target and oscillate around it, like a spring.
+    Spring,
+
+    /// Bounce against the target, like a dropped ball.
+    Bounce,
+}
+
+impl Default for Easing {
+    fn default() -> Self {
+        Self::CubicOut
+    }
+}
+
+impl Easing {
+    /// Map the linear progress `t` in `[0, 1]` to eased progress.
+    ///
+    /// Always `0` at `t = 0` and `1` at `t = 1`, but may go outside of `[0, 1]` in between.
+    pub fn apply(self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Self::Linear => t,
+            Self::CubicIn => t * t * t,
+            Self::CubicOut => 1.0 - (1.0 - t).powi(3),
+            Self::CubicInOut => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+                }
+            }
+            Self::Spring => {
+                if t == 1.0 {
+                    1.0
+                } else {
+                    1.0 - (-6.0 * t).exp() * (3.0 * std::f32::consts::TAU * t).cos()
+                }
+            }
+            Self::Bounce => {
+                const N: f32 = 7.5625;
+                const D: f32 = 2.75;
+                if t < 1.0 / D {
+                    N * t * t
+                } else if t < 2.0 / D {
+                    let t = t - 1.5 / D;
+                    N * t * t + 0.75
+                } else if t < 2.5 / D {
+                    let t = t - 2.25 / D;
+                    N * t * t + 0.9375
+                } else {
+                    let t = t - 2.625 / D;
+                    N * t * t + 0.984375
+                }
+            }
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Something that can be animated with a [`Tween`] or [`crate::Context::animate_to`].
+pub trait Tweenable: Copy + PartialEq + Send + Sync + 'static {
+    /// Interpolate between `from` (`t = 0`) and `to` (`t = 1`).
+    ///
+    /// Must extrapolate for `t` outside of `[0, 1]`, since some [`Easing`]s overshoot.
+    fn tween(from: Self, to: Self, t: f32) -> Self;
+}
+
+impl Tweenable for f32 {
+    fn tween(from: Self, to: Self, t: f32) -> Self {
+        lerp(from..=to, t)
+    }
+}
+
+impl Tweenable for Vec2 {
+    fn tween(from: Self, to: Self, t: f32) -> Self {
+        from + (to - from) * t
+    }
+}
+
+impl Tweenable for Pos2 {
+    fn tween(from: Self, to: Self, t: f32) -> Self {
+        from + (to - from) * t
+    }
+}
+
+impl Tweenable for Color32 {
+    /// Interpolates in linear space, so that e.g. fading between two colors doesn't go dark halfway.
+    fn tween(from: Self, to: Self, t: f32) -> Self {
+        let (from, to) = (Rgba::from(from), Rgba::from(to));
+        (from * (1.0 - t) + to * t).into()
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+#[derive(Clone, Copy, Debug)]
+struct Step<T> {
+    to: T,
+    duration: f32,
+    easing: Easing,
+}
+
+/// A sequence of animation steps, played with [`crate::Context::play_tween`].
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui::{Easing, Tween};
+///
+/// let bounce_in = Tween::new(-100.0)
+///     .to(20.0, 0.3, Easing::CubicOut)
+///     .to(0.0, 0.2, Easing::Spring)
+///     .on_complete(|| println!("Done!"));
+/// let offset = ui.ctx().play_tween(ui.id().with("bounce_in"), &bounce_in);
+/// # });
+/// ```
+#[derive(Clone)]
+pub struct Tween<T> {
+    start: T,
+    delay: f32,
+    steps: Vec<Step<T>>,
+    on_complete: Option<Arc<dyn Fn() + Send + Sync>>,
+}
+
+impl<T: std::fmt::Debug> std::fmt::Debug for Tween<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Tween")
+            .field("start", &self.start)
+            .field("delay", &self.delay)
+            .field("steps", &self.steps)
+            .finish()
+    }
+}
+
+impl<T: Tweenable> Tween<T> {
+    /// A tween that starts at (and, until you add steps, stays at) `start`.
+    pub fn new(start: T) -> Self {
+        Self {
+            start,
+            delay: 0.0,
+            steps: vec![],
+            on_complete: None,
+        }
+    }
+
+    /// Animate from the end of the previous step to `target`, taking `duration` seconds.
+    pub fn to(mut self, target: T, duration: f32, easing: Easing) -> Self {
+        self.steps.push(Step {
+            to: target,
+            duration: duration.max(0.0),
+            easing,
+        });
+        self
+    }
+
+    /// Stay at the current value for a while.
+    pub fn hold(self, duration: f32) -> Self {
+        let value = self.end_value();
+        self.to(value, duration, Easing::Linear)
+    }
+
+    /// Wait this many seconds before starting.
+    pub fn delay(mut self, delay: f32) -> Self {
+        self.delay = delay.max(0.0);
+        self
+    }
+
+    /// Called once, on the first frame the tween has finished.
+    pub fn on_complete(mut self, on_complete: impl Fn() + Send + Sync + 'static) -> Self {
+        self.on_complete = Some(Arc::new(on_complete));
+        self
+    }
+
+    /// Delay plus the duration of all steps, in seconds.
+    pub fn duration(&self) -> f32 {
+        self.delay + self.steps.iter().map(|step| step.duration).sum::<f32>()
+    }
+
+    /// The value at the end of the last step.
+    pub fn end_value(&self) -> T {
+        self.steps.last().map_or(self.start, |step| step.to)
+    }
+
+    /// The value `time` seconds after the tween started.
+    pub fn value_at(&self, time: f32) -> T {
+        let mut time = time - self.delay;
+        let mut from = self.start;
+        for step in &self.steps {
+            if time < step.duration {
+                let t = if time <= 0.0 {
+                    0.0
+                } else {
+                    time / step.duration
+                };
+                return T::tween(from, step.to, step.easing.apply(t));
+            }
+            time -= step.duration;
+            from = step.to;
+        }
+        from
+    }
+
+    pub(crate) fn call_on_complete(&self) {
+        if let Some(on_complete) = &self.on_complete {
+            on_complete();
+        }
+    }
+}
+
+#[test]
+fn test_tween() {
+    for easing in [
+        Easing::Linear,
+        Easing::CubicIn,
+        Easing::CubicOut,
+        Easing::CubicInOut,
+        Easing::Spring,
+        Easing::Bounce,
+    ] {
+        assert_eq!(easing.apply(0.0), 0.0, "{:?}", easing);
+        assert!((easing.apply(1.0) - 1.0).abs() < 1e-6, "{:?}", easing);
+    }
+
+    let tween = Tween::new(0.0)
+        .delay(1.0)
+        .to(10.0, 2.0, Easing::Linear)
+        .hold(1.0)
+        .to(0.0, 1.0, Easing::Linear);
+    assert_eq!(tween.duration(), 5.0);
+    assert_eq!(tween.value_at(0.5), 0.0);
+    assert_eq!(tween.value_at(2.0), 5.0);
+    assert_eq!(tween.value_at(3.5), 10.0);
+    assert_eq!(tween.value_at(4.5), 5.0);
+    assert_eq!(tween.value_at(100.0), 0.0);
+}
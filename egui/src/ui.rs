@@ -1,5 +1,6 @@
 // #![warn(missing_docs)]
 
+use std::any::Any;
 use std::hash::Hash;
 use std::sync::Arc;
 
@@ -281,6 +282,20 @@ impl Ui {
         }
     }
 
+    /// Multiply the colors of all widgets added from now on by this, in `[0, 1]`.
+    ///
+    /// Unlike [`Self::set_visible`], the widgets stay interactive.
+    /// This is used to fade in tooltips, see [`crate::style::Interaction::tooltip_fade_in_time`].
+    pub fn set_opacity(&mut self, opacity: f32) {
+        self.painter.set_opacity(opacity);
+    }
+
+    /// See [`Self::set_opacity`].
+    #[inline]
+    pub fn opacity(&self) -> f32 {
+        self.painter.opacity()
+    }
+
     #[inline]
     pub fn layout(&self) -> &Layout {
         self.placer.layout()
@@ -382,6 +397,48 @@ impl Ui {
         self.ctx().output()
     }
 
+    /// Put an image in the system clipboard, e.g. a plot. See [`PlatformOutput::copy_image`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui::plot::{Line, Plot, PlotPoints};
+    /// let response = Plot::new("my_plot").show(ui, |plot_ui| {
+    ///     plot_ui.line(Line::new(PlotPoints::new(vec![[0.0, 0.0], [1.0, 1.0]])));
+    /// });
+    /// if ui.button("Copy plot").clicked() {
+    ///     ui.copy_image(response.snapshot().to_image());
+    /// }
+    /// # });
+    /// ```
+    pub fn copy_image(&self, image: ColorImage) {
+        self.output().copy_image(image);
+    }
+
+    /// Put HTML in the system clipboard, with `text` for applications that don't understand HTML.
+    /// See [`PlatformOutput::copy_html`].
+    pub fn copy_html(&self, html: impl ToString, text: impl ToString) {
+        self.output().copy_html(html, text);
+    }
+
+    /// Put a table in the system clipboard, as HTML and as tab-separated text.
+    /// See [`PlatformOutput::copy_table`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let rows = [["Name", "Price"], ["Apple", "1.20"]];
+    /// if ui.button("Copy table").clicked() {
+    ///     ui.copy_table(&rows);
+    /// }
+    /// # });
+    /// ```
+    pub fn copy_table<Row, Cell>(&self, rows: &[Row])
+    where
+        Row: AsRef<[Cell]>,
+        Cell: AsRef<str>,
+    {
+        self.output().copy_table(rows);
+    }
+
     /// The [`Fonts`] of the [`Context`] associated with this ui.
     /// Equivalent to `.ctx().fonts()`.
     #[inline]
@@ -411,6 +468,18 @@ impl Ui {
     pub fn is_rect_visible(&self, rect: Rect) -> bool {
         self.is_visible() && rect.intersects(self.clip_rect())
     }
+
+    /// Request a repaint of this [`Ui`] after the given duration, in the case of no new input events.
+    ///
+    /// Unlike [`Context::request_repaint_after`] this is ignored if this [`Ui`] is invisible,
+    /// or if its layer (e.g. its [`Window`]) is no longer shown at the end of the frame.
+    /// See [`Context::request_layer_repaint_after`].
+    pub fn request_repaint_after(&self, duration: std::time::Duration) {
+        if self.is_visible() {
+            self.ctx()
+                .request_layer_repaint_after(self.layer_id(), duration);
+        }
+    }
 }
 
 // ------------------------------------------------------------------------
@@ -605,6 +674,7 @@ impl Ui {
 /// # Interaction
 impl Ui {
     /// Check for clicks, drags and/or hover on a specific region of this [`Ui`].
+    #[track_caller]
     pub fn interact(&self, rect: Rect, id: Id, sense: Sense) -> Response {
         self.ctx().interact(
             self.clip_rect(),
@@ -655,6 +725,7 @@ impl Ui {
     /// ui.painter().rect_stroke(response.rect, 0.0, (1.0, egui::Color32::WHITE));
     /// # });
     /// ```
+    #[track_caller]
     pub fn allocate_response(&mut self, desired_size: Vec2, sense: Sense) -> Response {
         let (id, rect) = self.allocate_space(desired_size);
         self.interact(rect, id, sense)
@@ -665,6 +736,7 @@ impl Ui {
     /// The response rect will be larger if this is part of a justified layout or similar.
     /// This means that if this is a narrow widget in a wide justified layout, then
     /// the widget will react to interactions outside the returned [`Rect`].
+    #[track_caller]
     pub fn allocate_exact_size(&mut self, desired_size: Vec2, sense: Sense) -> (Rect, Response) {
         let response = self.allocate_response(desired_size, sense);
         let rect = self
@@ -676,6 +748,7 @@ impl Ui {
     /// Allocate at least as much space as needed, and interact with that rect.
     ///
     /// The returned [`Rect`] will be the same size as `Response::rect`.
+    #[track_caller]
     pub fn allocate_at_least(&mut self, desired_size: Vec2, sense: Sense) -> (Rect, Response) {
         let response = self.allocate_response(desired_size, sense);
         (response.rect, response)
@@ -767,6 +840,7 @@ impl Ui {
     ///
     /// Ignore the layout of the [`Ui`]: just put my widget here!
     /// The layout cursor will advance to past this `rect`.
+    #[track_caller]
     pub fn allocate_rect(&mut self, rect: Rect, sense: Sense) -> Response {
         let id = self.advance_cursor_after_rect(rect);
         self.interact(rect, id, sense)
@@ -2137,6 +2211,171 @@ impl Ui {
 
 // ----------------------------------------------------------------------------
 
+/// # Drag and drop
+impl Ui {
+    /// Make the contents draggable, carrying `payload`.
+    ///
+    /// While being dragged, the contents are painted on top of everything else, following the pointer.
+    /// Pick up the payload with [`Self::dnd_drop_zone`] or [`Response::dnd_release_payload`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let item_index = 3_usize;
+    /// ui.dnd_drag_source(egui::Id::new("item").with(item_index), item_index, |ui| {
+    ///     ui.label("Drag me");
+    /// });
+    ///
+    /// let (_, dropped) = ui.dnd_drop_zone::<usize, _>(|ui| {
+    ///     ui.label("Drop here");
+    /// });
+    /// if let Some(dropped) = dropped {
+    ///     println!("Item {} was dropped", dropped);
+    /// }
+    /// # });
+    /// ```
+    pub fn dnd_drag_source<Payload: Any + Send + Sync, R>(
+        &mut self,
+        id: Id,
+        payload: Payload,
+        add_contents: impl FnOnce(&mut Self) -> R,
+    ) -> InnerResponse<R> {
+        let is_being_dragged = self.memory().is_being_dragged(id);
+
+        let InnerResponse { inner, response } = if is_being_dragged {
+            // Paint the contents to a new layer, and move that to where the pointer is.
+            // A dragged widget cannot be interacted with anyway,
+            // since anything with `Order::Tooltip` always gets an empty [`Response`].
+            let layer_id = LayerId::new(Order::Tooltip, id);
+            let inner_response = self.with_layer_id(layer_id, add_contents);
+            if let Some(pointer_pos) = self.ctx().pointer_interact_pos() {
+                let delta = pointer_pos - inner_response.response.rect.center();
+                self.ctx().translate_layer(layer_id, delta);
+            }
+            inner_response
+        } else {
+            self.scope(add_contents)
+        };
+
+        let response = self
+            .interact(response.rect, id, Sense::drag())
+            .dnd_drag_source(payload);
+        InnerResponse::new(inner, response)
+    }
+
+    /// A frame around the contents that accepts drops of payloads of type `Payload`.
+    ///
+    /// It is highlighted while such a payload hovers it,
+    /// and grayed out while a payload of another type is being dragged.
+    ///
+    /// Also returns the payload if it was dropped here this frame.
+    pub fn dnd_drop_zone<Payload: Any + Send + Sync, R>(
+        &mut self,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> (InnerResponse<R>, Option<Arc<Payload>>) {
+        let is_anything_being_dragged = self.ctx().has_dragged_payload();
+        let can_accept_payload = self.ctx().dragged_payload::<Payload>().is_some();
+        let inner_response = crate::drag_and_drop::drop_zone_ui(
+            self,
+            is_anything_being_dragged,
+            can_accept_payload,
+            Response::hovered,
+            add_contents,
+        );
+        let payload = inner_response.response.dnd_release_payload();
+        (inner_response, payload)
+    }
+
+    /// A frame around the contents that accepts files dropped from outside the app.
+    ///
+    /// It is highlighted while files are dragged over it.
+    ///
+    /// Also returns the files that were dropped here this frame,
+    /// see [`Response::dnd_dropped_files`].
+    pub fn file_drop_zone<R>(
+        &mut self,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> (InnerResponse<R>, Vec<DroppedFile>) {
+        let is_anything_being_dragged = !self.input().raw.hovered_files.is_empty();
+        let inner_response = crate::drag_and_drop::drop_zone_ui(
+            self,
+            is_anything_being_dragged,
+            true,
+            |response| !response.dnd_hover_files().is_empty(),
+            add_contents,
+        );
+        let files = inner_response.response.dnd_dropped_files();
+        (inner_response, files)
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// # Async
+impl Ui {
+    /// A button that starts some background work, and shows a spinner until it is done.
+    ///
+    /// When clicked, `start` is called and the returned [`Promise`] is stored in `promise`.
+    /// The button can't be clicked while the promise is pending.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut promise: Option<egui::Promise<String>> = None;
+    /// let ctx = ui.ctx().clone();
+    /// ui.async_button("Fetch", &mut promise, || {
+    ///     egui::Promise::spawn_thread(&ctx, "fetch", || "Hello".to_owned())
+    /// });
+    /// if let Some(text) = promise.as_mut().and_then(|promise| promise.ready()) {
+    ///     ui.label(text.as_str());
+    /// }
+    /// # });
+    /// ```
+    pub fn async_button<T>(
+        &mut self,
+        text: impl Into<WidgetText>,
+        promise: &mut Option<Promise<T>>,
+        start: impl FnOnce() -> Promise<T>,
+    ) -> Response {
+        let busy = promise.as_mut().map_or(false, Promise::is_pending);
+        let response = self.add(ProgressButton::new(text).busy(busy));
+        if response.clicked() {
+            *promise = Some(start());
+        }
+        response
+    }
+
+    /// Show a spinner while the promise is pending, an error message if it failed,
+    /// and otherwise call `add_ready` with the value.
+    ///
+    /// Returns what `add_ready` returns, or `None` if the value isn't (successfully) ready.
+    pub fn show_promise<T, E: std::fmt::Display, R>(
+        &mut self,
+        promise: &mut Promise<Result<T, E>>,
+        add_ready: impl FnOnce(&mut Ui, &T) -> R,
+    ) -> Option<R> {
+        if promise.is_abandoned() {
+            let text = self
+                .ctx()
+                .translate("egui.promise.abandoned", "Task was abandoned");
+            self.colored_label(self.visuals().error_fg_color, text);
+            return None;
+        }
+
+        match promise.ready() {
+            None => {
+                self.add(Spinner::new());
+                None
+            }
+            Some(Err(err)) => {
+                self.colored_label(self.visuals().error_fg_color, err.to_string());
+                None
+            }
+            Some(Ok(value)) => Some(add_ready(self, value)),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
 /// # Debug stuff
 impl Ui {
     /// Shows where the next widget is going to be placed
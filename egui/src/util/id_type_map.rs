@@ -73,6 +73,9 @@ enum Element {
         /// How to clone the value.
         clone_fn: fn(&Box<dyn Any + 'static + Send + Sync>) -> Box<dyn Any + 'static + Send + Sync>,
 
+        /// For diagnostics, see [`IdTypeMap::entries`].
+        type_name: &'static str,
+
         /// How to serialize the value.
         /// None if non-serializable type.
         #[cfg(feature = "persistence")]
@@ -95,11 +98,13 @@ impl Clone for Element {
             Self::Value {
                 value,
                 clone_fn,
+                type_name,
                 #[cfg(feature = "persistence")]
                 serialize_fn,
             } => Self::Value {
                 value: clone_fn(value),
                 clone_fn: *clone_fn,
+                type_name: *type_name,
                 #[cfg(feature = "persistence")]
                 serialize_fn: *serialize_fn,
             },
@@ -115,9 +120,12 @@ impl Clone for Element {
 impl std::fmt::Debug for Element {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match &self {
-            Self::Value { value, .. } => f
+            Self::Value {
+                value, type_name, ..
+            } => f
                 .debug_struct("MaybeSerializable::Value")
                 .field("type_id", &value.type_id())
+                .field("type_name", type_name)
                 .finish_non_exhaustive(),
             Self::Serialized { type_id, ron } => f
                 .debug_struct("MaybeSerializable::Serialized")
@@ -138,6 +146,7 @@ impl Element {
                 let x = x.downcast_ref::<T>().unwrap(); // This unwrap will never panic, because we always construct this type using this `new` function and because we return &mut reference only with this type `T`, so type cannot change.
                 Box::new(x.clone())
             },
+            type_name: std::any::type_name::<T>(),
             #[cfg(feature = "persistence")]
             serialize_fn: None,
         }
@@ -152,6 +161,7 @@ impl Element {
                 let x = x.downcast_ref::<T>().unwrap(); // This unwrap will never panic, because we always construct this type using this `new` function and because we return &mut reference only with this type `T`, so type cannot change.
                 Box::new(x.clone())
             },
+            type_name: std::any::type_name::<T>(),
             #[cfg(feature = "persistence")]
             serialize_fn: Some(|x| {
                 let x = x.downcast_ref::<T>().unwrap(); // This will never panic too, for same reason.
@@ -169,6 +179,37 @@ impl Element {
         }
     }
 
+    /// See [`IdTypeMap::entries`].
+    fn info(&self, key: u64) -> IdTypeMapEntry {
+        match self {
+            Self::Value {
+                value,
+                type_name,
+                #[cfg(feature = "persistence")]
+                serialize_fn,
+                ..
+            } => IdTypeMapEntry {
+                key,
+                type_id: (**value).type_id().into(),
+                type_name: Some(*type_name),
+                #[cfg(feature = "persistence")]
+                persisted: serialize_fn.is_some(),
+                #[cfg(not(feature = "persistence"))]
+                persisted: false,
+                serialized: false,
+                size: std::mem::size_of_val(&**value),
+            },
+            Self::Serialized { type_id, ron } => IdTypeMapEntry {
+                key,
+                type_id: *type_id,
+                type_name: None,
+                persisted: true,
+                serialized: true,
+                size: ron.len(),
+            },
+        }
+    }
+
     #[inline]
     pub(crate) fn get_mut_temp<T: 'static>(&mut self) -> Option<&mut T> {
         match self {
@@ -294,6 +335,36 @@ fn from_ron_str<T: serde::de::DeserializeOwned>(ron: &str) -> Option<T> {
 
 use crate::Id;
 
+/// Information about a value stored in an [`IdTypeMap`], see [`IdTypeMap::entries`].
+///
+/// Useful for hunting down state that keeps piling up in long-running apps.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct IdTypeMapEntry {
+    /// The combined hash of the [`Id`] and the type. Use with [`IdTypeMap::remove_entry`].
+    pub key: u64,
+
+    pub type_id: TypeId,
+
+    /// Name of the stored type.
+    ///
+    /// `None` for values that were loaded from disk but have not been read yet,
+    /// since then we don't know the type until it is deserialized.
+    pub type_name: Option<&'static str>,
+
+    /// Will this value be saved on shutdown?
+    ///
+    /// Always `false` without the `persistence` feature.
+    pub persisted: bool,
+
+    /// Is this value still in its serialized form?
+    pub serialized: bool,
+
+    /// The (shallow) size of the value in bytes, or the size of the serialized data.
+    ///
+    /// Heap allocations owned by the value (e.g. the contents of a `Vec`) are not included.
+    pub size: usize,
+}
+
 // TODO(emilk): make IdTypeMap generic over the key (`Id`), and make a library of IdTypeMap.
 /// Stores values identified by an [`Id`] AND a the [`std::any::TypeId`] of the value.
 ///
@@ -454,6 +525,42 @@ impl IdTypeMap {
         });
     }
 
+    /// Remove all state with the given type.
+    ///
+    /// Like [`Self::remove_by_type`], but for when you only know the [`TypeId`],
+    /// e.g. from [`Self::entries`].
+    pub fn remove_by_type_id(&mut self, type_id: TypeId) {
+        self.0.retain(|_, e| {
+            let e: &Element = e;
+            e.type_id() != type_id
+        });
+    }
+
+    /// Remove a single value, identified by [`IdTypeMapEntry::key`].
+    #[inline]
+    pub fn remove_entry(&mut self, key: u64) {
+        self.0.remove(&key);
+    }
+
+    /// Only keep the values for which `keep` returns `true`.
+    ///
+    /// Useful for discarding state persisted by an older version of your app,
+    /// see [`crate::Memory::migrate`].
+    pub fn retain(&mut self, mut keep: impl FnMut(&IdTypeMapEntry) -> bool) {
+        self.0.retain(|&key, element| {
+            let element: &Element = element;
+            keep(&element.info(key))
+        });
+    }
+
+    /// Describe everything that is stored, in no particular order.
+    pub fn entries(&self) -> Vec<IdTypeMapEntry> {
+        self.0
+            .iter()
+            .map(|(&key, element)| element.info(key))
+            .collect()
+    }
+
     #[inline]
     pub fn clear(&mut self) {
         self.0.clear();
@@ -657,6 +764,30 @@ fn test_mix() {
     assert_eq!(map.get_persisted::<Foo>(id), None);
 }
 
+#[test]
+fn test_entries() {
+    let a = Id::new("a");
+
+    let mut map: IdTypeMap = Default::default();
+    map.insert_persisted(a, 13.37_f64);
+    map.insert_temp(a, 42_i32);
+
+    let mut entries = map.entries();
+    entries.sort_by_key(|entry| entry.size);
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0].type_name, Some("i32"));
+    assert_eq!(entries[1].type_name, Some("f64"));
+    assert_eq!(entries[1].size, 8);
+    assert!(!entries[0].persisted);
+
+    map.remove_entry(entries[0].key);
+    assert_eq!(map.get_temp::<i32>(a), None);
+    assert_eq!(map.get_temp::<f64>(a), Some(13.37));
+
+    map.remove_by_type_id(TypeId::of::<f64>());
+    assert!(map.is_empty());
+}
+
 #[cfg(feature = "persistence")]
 #[test]
 fn test_mix_serialize() {
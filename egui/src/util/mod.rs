@@ -4,9 +4,10 @@ pub mod cache;
 pub(crate) mod fixed_cache;
 mod history;
 pub mod id_type_map;
+pub mod undo_stack;
 pub mod undoer;
 
 pub use history::History;
-pub use id_type_map::IdTypeMap;
+pub use id_type_map::{IdTypeMap, IdTypeMapEntry};
 
 pub use epaint::util::{hash, hash_with};
@@ -0,0 +1,283 @@
+//! Command-based undo and redo, see [`UndoStack`].
+
+use std::collections::VecDeque;
+
+use crate::{Context, Key, Modifiers};
+
+/// A reversible change to some `Target`, for use with [`UndoStack`].
+pub trait Command {
+    /// What the command operates on, e.g. your document.
+    type Target;
+
+    /// Perform (or redo) the change.
+    fn apply(&mut self, target: &mut Self::Target);
+
+    /// Revert what [`Self::apply`] did.
+    fn undo(&mut self, target: &mut Self::Target);
+
+    /// A short name for menus, e.g. `"Move shape"`.
+    fn description(&self) -> String {
+        String::new()
+    }
+
+    /// Try to absorb a command that was just applied after this one,
+    /// so that both are undone in a single step (e.g. all the small moves of a drag).
+    ///
+    /// Return `true` if `next` was merged into `self`. Default: never merge.
+    fn merge(&mut self, next: &Self) -> bool {
+        let _ = next;
+        false
+    }
+}
+
+/// Undo and redo history made out of [`Command`]s.
+///
+/// Unlike [`super::undoer::Undoer`], which takes snapshots of the whole state,
+/// this records each change, which is better when the state is large.
+///
+/// ```
+/// use egui::util::undo_stack::{Command, UndoStack};
+///
+/// struct Add(i32);
+///
+/// impl Command for Add {
+///     type Target = i32;
+///     fn apply(&mut self, target: &mut i32) {
+///         *target += self.0;
+///     }
+///     fn undo(&mut self, target: &mut i32) {
+///         *target -= self.0;
+///     }
+/// }
+///
+/// let mut value = 0;
+/// let mut undo_stack = UndoStack::default();
+/// undo_stack.execute(&mut value, Add(5));
+/// assert_eq!(value, 5);
+/// undo_stack.undo(&mut value);
+/// assert_eq!(value, 0);
+/// undo_stack.redo(&mut value);
+/// assert_eq!(value, 5);
+/// ```
+pub struct UndoStack<C> {
+    /// Applied commands, oldest first.
+    undos: VecDeque<C>,
+
+    /// Undone commands, most recently undone last.
+    redos: Vec<C>,
+
+    capacity: usize,
+
+    /// The length of `undos` when [`Self::mark_clean`] was last called,
+    /// or `None` if that state can no longer be reached.
+    clean_len: Option<usize>,
+
+    /// Can the next command be merged into the latest one?
+    can_merge: bool,
+}
+
+impl<C> Default for UndoStack<C> {
+    fn default() -> Self {
+        Self::new(100)
+    }
+}
+
+impl<C> std::fmt::Debug for UndoStack<C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UndoStack")
+            .field("undo count", &self.undos.len())
+            .field("redo count", &self.redos.len())
+            .finish()
+    }
+}
+
+impl<C> UndoStack<C> {
+    /// Remember at most `capacity` commands. The oldest ones are forgotten first.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            undos: VecDeque::new(),
+            redos: Vec::new(),
+            capacity: capacity.max(1),
+            clean_len: Some(0),
+            can_merge: false,
+        }
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.undos.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.redos.is_empty()
+    }
+
+    /// Forget all history. The current state is considered clean.
+    pub fn clear(&mut self) {
+        self.undos.clear();
+        self.redos.clear();
+        self.clean_len = Some(0);
+        self.can_merge = false;
+    }
+
+    /// Has anything changed since the last call to [`Self::mark_clean`] (e.g. since the document was saved)?
+    pub fn is_dirty(&self) -> bool {
+        self.clean_len != Some(self.undos.len())
+    }
+
+    /// Call this when saving, so that [`Self::is_dirty`] returns `false`
+    /// until something is done or undone.
+    pub fn mark_clean(&mut self) {
+        self.clean_len = Some(self.undos.len());
+        self.can_merge = false;
+    }
+
+    /// Don't merge the next command into the latest one, e.g. when a drag has ended.
+    pub fn seal(&mut self) {
+        self.can_merge = false;
+    }
+}
+
+impl<C: Command> UndoStack<C> {
+    /// Apply the command and add it to the history.
+    ///
+    /// This clears the redo history.
+    pub fn execute(&mut self, target: &mut C::Target, mut command: C) {
+        command.apply(target);
+
+        if self
+            .clean_len
+            .map_or(false, |clean_len| clean_len > self.undos.len())
+        {
+            self.clean_len = None; // the clean state was in the redo history
+        }
+        self.redos.clear();
+
+        let merged = self.can_merge
+            && self.clean_len != Some(self.undos.len())
+            && self
+                .undos
+                .back_mut()
+                .map_or(false, |latest| latest.merge(&command));
+        if !merged {
+            self.undos.push_back(command);
+            while self.undos.len() > self.capacity {
+                self.undos.pop_front();
+                self.clean_len = match self.clean_len {
+                    Some(clean_len) if clean_len > 0 => Some(clean_len - 1),
+                    _ => None,
+                };
+            }
+        }
+        self.can_merge = true;
+    }
+
+    /// Returns `false` if there was nothing to undo.
+    pub fn undo(&mut self, target: &mut C::Target) -> bool {
+        if let Some(mut command) = self.undos.pop_back() {
+            command.undo(target);
+            self.redos.push(command);
+            self.can_merge = false;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Returns `false` if there was nothing to redo.
+    pub fn redo(&mut self, target: &mut C::Target) -> bool {
+        if let Some(mut command) = self.redos.pop() {
+            command.apply(target);
+            self.undos.push_back(command);
+            self.can_merge = false;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// The [`Command::description`] of what would be undone.
+    pub fn undo_description(&self) -> Option<String> {
+        self.undos.back().map(Command::description)
+    }
+
+    /// The [`Command::description`] of what would be redone.
+    pub fn redo_description(&self) -> Option<String> {
+        self.redos.last().map(Command::description)
+    }
+
+    /// Undo on `Cmd/Ctrl+Z`, and redo on `Cmd/Ctrl+Shift+Z` or `Cmd/Ctrl+Y`.
+    ///
+    /// Call this once per frame, e.g. from your top-level ui code.
+    /// Nothing happens while a widget has keyboard focus,
+    /// so that e.g. a [`crate::TextEdit`] can handle its own undo.
+    ///
+    /// Returns `true` if something was undone or redone.
+    pub fn handle_shortcuts(&mut self, ctx: &Context, target: &mut C::Target) -> bool {
+        if ctx.memory().focus().is_some() {
+            return false;
+        }
+
+        let (undo, redo) = {
+            let mut input = ctx.input_mut();
+            let redo = input.consume_key(Modifiers::COMMAND | Modifiers::SHIFT, Key::Z)
+                || input.consume_key(Modifiers::COMMAND, Key::Y);
+            let undo = input.consume_key(Modifiers::COMMAND, Key::Z);
+            (undo, redo)
+        };
+
+        if redo {
+            self.redo(target)
+        } else if undo {
+            self.undo(target)
+        } else {
+            false
+        }
+    }
+}
+
+#[test]
+fn test_undo_stack() {
+    struct Add(i32);
+
+    impl Command for Add {
+        type Target = i32;
+        fn apply(&mut self, target: &mut i32) {
+            *target += self.0;
+        }
+        fn undo(&mut self, target: &mut i32) {
+            *target -= self.0;
+        }
+        fn merge(&mut self, next: &Self) -> bool {
+            self.0 += next.0;
+            true
+        }
+    }
+
+    let mut value = 0;
+    let mut undo_stack = UndoStack::new(2);
+    undo_stack.execute(&mut value, Add(1));
+    undo_stack.execute(&mut value, Add(2)); // merged
+    assert_eq!(value, 3);
+    assert!(undo_stack.is_dirty());
+    undo_stack.undo(&mut value);
+    assert_eq!(value, 0);
+    assert!(!undo_stack.is_dirty());
+
+    undo_stack.redo(&mut value);
+    undo_stack.mark_clean();
+    undo_stack.execute(&mut value, Add(4)); // not merged into the clean state
+    assert_eq!(value, 7);
+    undo_stack.undo(&mut value);
+    assert_eq!(value, 3);
+    assert!(!undo_stack.is_dirty());
+
+    // Capacity is 2, so the oldest command is forgotten:
+    undo_stack.execute(&mut value, Add(10));
+    undo_stack.seal();
+    undo_stack.execute(&mut value, Add(100));
+    assert!(undo_stack.undo(&mut value));
+    assert!(undo_stack.undo(&mut value));
+    assert!(!undo_stack.undo(&mut value));
+    assert_eq!(value, 3);
+    assert!(!undo_stack.is_dirty());
+}
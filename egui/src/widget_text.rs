@@ -23,7 +23,7 @@ use crate::{
 /// ```
 #[derive(Clone, Default, PartialEq)]
 pub struct RichText {
-    text: String,
+    text: Text,
     size: Option<f32>,
     family: Option<FontFamily>,
     text_style: Option<TextStyle>,
@@ -38,6 +38,31 @@ pub struct RichText {
     raised: bool,
 }
 
+/// The text of a [`RichText`].
+#[derive(Clone, PartialEq)]
+enum Text {
+    Owned(String),
+
+    /// E.g. from [`crate::Context::intern`].
+    Shared(Arc<str>),
+}
+
+impl Default for Text {
+    fn default() -> Self {
+        Self::Owned(String::new())
+    }
+}
+
+impl Text {
+    #[inline]
+    fn as_str(&self) -> &str {
+        match self {
+            Self::Owned(text) => text,
+            Self::Shared(text) => text,
+        }
+    }
+}
+
 impl From<&str> for RichText {
     #[inline]
     fn from(text: &str) -> Self {
@@ -73,23 +98,34 @@ impl From<Cow<'_, str>> for RichText {
     }
 }
 
+/// Doesn't allocate, e.g. for text from [`crate::Context::intern`].
+impl From<Arc<str>> for RichText {
+    #[inline]
+    fn from(text: Arc<str>) -> Self {
+        Self {
+            text: Text::Shared(text),
+            ..Default::default()
+        }
+    }
+}
+
 impl RichText {
     #[inline]
     pub fn new(text: impl Into<String>) -> Self {
         Self {
-            text: text.into(),
+            text: Text::Owned(text.into()),
             ..Default::default()
         }
     }
 
     #[inline]
     pub fn is_empty(&self) -> bool {
-        self.text.is_empty()
+        self.text().is_empty()
     }
 
     #[inline]
     pub fn text(&self) -> &str {
-        &self.text
+        self.text.as_str()
     }
 
     /// Select the font size (in points).
@@ -248,7 +284,24 @@ impl RichText {
         fallback_font: FontSelection,
         default_valign: Align,
     ) -> WidgetTextJob {
+        let (text, mut text_job) = self.into_text_and_job(style, fallback_font, default_valign);
+        text_job.job.text = match text {
+            Text::Owned(text) => text,
+            Text::Shared(text) => text.as_ref().to_owned(),
+        };
+        text_job
+    }
+
+    /// The text, and a job for it with an empty [`LayoutJob::text`],
+    /// for [`epaint::Fonts::layout_job_with_text`].
+    fn into_text_and_job(
+        self,
+        style: &Style,
+        fallback_font: FontSelection,
+        default_valign: Align,
+    ) -> (Text, WidgetTextJob) {
         let text_color = self.get_text_color(&style.visuals);
+        let text_len = self.text().len();
 
         let Self {
             text,
@@ -317,8 +370,9 @@ impl RichText {
             valign,
         };
 
-        let job = LayoutJob::single_section(text, text_format);
-        WidgetTextJob { job, job_has_color }
+        let mut job = LayoutJob::single_section(String::new(), text_format);
+        job.sections[0].byte_range = 0..text_len;
+        (text, WidgetTextJob { job, job_has_color })
     }
 
     fn get_text_color(&self, visuals: &Visuals) -> Option<Color32> {
@@ -570,10 +624,12 @@ impl WidgetText {
         match self {
             Self::RichText(text) => {
                 let valign = ui.layout().vertical_align();
-                let mut text_job = text.into_text_job(ui.style(), fallback_font.into(), valign);
+                let (text, mut text_job) =
+                    text.into_text_and_job(ui.style(), fallback_font.into(), valign);
                 text_job.job.wrap.max_width = wrap_width;
                 WidgetTextGalley {
-                    galley: ui.fonts().layout_job(text_job.job),
+                    // Only copies the text if it isn't already laid out:
+                    galley: ui.fonts().layout_job_with_text(text.as_str(), text_job.job),
                     galley_has_color: text_job.job_has_color,
                 }
             }
@@ -613,6 +669,13 @@ impl From<String> for WidgetText {
     }
 }
 
+impl From<Arc<str>> for WidgetText {
+    #[inline]
+    fn from(text: Arc<str>) -> Self {
+        Self::RichText(RichText::from(text))
+    }
+}
+
 impl From<Cow<'_, str>> for WidgetText {
     #[inline]
     fn from(text: Cow<'_, str>) -> Self {
@@ -0,0 +1,97 @@
+use crate::*;
+
+/// A small bubble with a count or a short text, usually overlaid on the corner of another widget.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// let response = ui.button("Inbox");
+/// egui::Badge::count(3).paint_on(ui, &response);
+///
+/// // Or on its own:
+/// ui.add(egui::Badge::new("New"));
+/// # });
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);` or call `paint_on`"]
+pub struct Badge {
+    text: String,
+    fill: Option<Color32>,
+    text_color: Option<Color32>,
+}
+
+impl Badge {
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn new(text: impl ToString) -> Self {
+        Self {
+            text: text.to_string(),
+            fill: None,
+            text_color: None,
+        }
+    }
+
+    /// Show a count, capped at 99 (shown as `99+`).
+    pub fn count(count: usize) -> Self {
+        Self::count_with_max(count, 99)
+    }
+
+    /// Show a count, capped at `max` (shown as e.g. `9+`).
+    pub fn count_with_max(count: usize, max: usize) -> Self {
+        if count > max {
+            Self::new(format!("{}+", max))
+        } else {
+            Self::new(count)
+        }
+    }
+
+    /// Background color. Defaults to [`Visuals::error_fg_color`].
+    pub fn fill(mut self, fill: impl Into<Color32>) -> Self {
+        self.fill = Some(fill.into());
+        self
+    }
+
+    /// Defaults to white.
+    pub fn text_color(mut self, text_color: impl Into<Color32>) -> Self {
+        self.text_color = Some(text_color.into());
+        self
+    }
+
+    /// Paint the badge centered on the top right corner of another widget,
+    /// without allocating any space.
+    pub fn paint_on(self, ui: &Ui, response: &Response) {
+        let galley = self.layout(ui);
+        let rect = Rect::from_center_size(response.rect.right_top(), Self::size(&galley));
+        self.paint_at(ui, rect, galley);
+    }
+
+    fn layout(&self, ui: &Ui) -> std::sync::Arc<Galley> {
+        let font_id = TextStyle::Small.resolve(ui.style());
+        ui.fonts()
+            .layout_no_wrap(self.text.clone(), font_id, Color32::WHITE)
+    }
+
+    fn size(galley: &Galley) -> Vec2 {
+        let height = galley.size().y + 2.0;
+        vec2((galley.size().x + 0.6 * height).at_least(height), height)
+    }
+
+    fn paint_at(self, ui: &Ui, rect: Rect, galley: std::sync::Arc<Galley>) {
+        if !ui.is_rect_visible(rect) {
+            return;
+        }
+        let fill = self.fill.unwrap_or(ui.visuals().error_fg_color);
+        let text_color = self.text_color.unwrap_or(Color32::WHITE);
+        let painter = ui.painter();
+        painter.rect_filled(rect, 0.5 * rect.height(), fill);
+        let text_pos = rect.center() - 0.5 * galley.size();
+        painter.galley_with_color(text_pos, galley, text_color);
+    }
+}
+
+impl Widget for Badge {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let galley = self.layout(ui);
+        let (rect, response) = ui.allocate_exact_size(Self::size(&galley), Sense::hover());
+        response.widget_info(|| WidgetInfo::labeled(WidgetType::Label, galley.text()));
+        self.paint_at(ui, rect, galley);
+        response
+    }
+}
@@ -0,0 +1,136 @@
+use crate::*;
+
+/// The response of showing a [`Breadcrumb`].
+pub struct BreadcrumbResponse {
+    /// The response of the whole breadcrumb.
+    pub response: Response,
+
+    /// The index of the segment the user clicked, if any.
+    pub clicked: Option<usize>,
+}
+
+/// A path of clickable segments, e.g. `Home > Documents > Photos`, for navigating a hierarchy.
+///
+/// The last segment is the current location and is not clickable.
+/// If there isn't enough room for all segments, the ones in the middle are collapsed into
+/// a `…` button, which opens a menu with the hidden segments.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// let mut path = vec!["Home", "Documents", "Photos"];
+/// if let Some(index) = egui::Breadcrumb::new(path.iter().copied()).show(ui).clicked {
+///     path.truncate(index + 1);
+/// }
+/// # });
+/// ```
+#[must_use = "You should call .show()"]
+pub struct Breadcrumb {
+    segments: Vec<WidgetText>,
+    separator: WidgetText,
+}
+
+impl Breadcrumb {
+    pub fn new(segments: impl IntoIterator<Item = impl Into<WidgetText>>) -> Self {
+        Self {
+            segments: segments.into_iter().map(Into::into).collect(),
+            separator: "›".into(),
+        }
+    }
+
+    /// What to show between the segments. Default: `›`.
+    pub fn separator(mut self, separator: impl Into<WidgetText>) -> Self {
+        self.separator = separator.into();
+        self
+    }
+
+    pub fn show(self, ui: &mut Ui) -> BreadcrumbResponse {
+        let Self {
+            segments,
+            separator,
+        } = self;
+
+        let n = segments.len();
+        let text_width = |ui: &Ui, text: &WidgetText| {
+            text.clone()
+                .into_galley(ui, Some(false), f32::INFINITY, TextStyle::Button)
+                .size()
+                .x
+        };
+        let spacing = ui.spacing().item_spacing.x;
+        let widths: Vec<f32> = segments.iter().map(|s| text_width(ui, s)).collect();
+        let separator_width = text_width(ui, &separator) + spacing;
+        let ellipsis_width = text_width(ui, &"…".into()) + 2.0 * ui.spacing().button_padding.x;
+        let available_width = ui.available_width();
+
+        // Between two segments there is a separator, with spacing on both sides.
+        let gap = separator_width + spacing;
+        // Width of the segments in `range`, including the gaps between them:
+        let width_of = |range: std::ops::Range<usize>| -> f32 {
+            let count = range.len() as f32;
+            widths[range].iter().sum::<f32>() + (count - 1.0).at_least(0.0) * gap
+        };
+
+        // Which segments to hide behind the `…`:
+        let hidden = if n <= 2 || width_of(0..n) <= available_width {
+            0..0
+        } else {
+            // Always keep the last segment, and the first one if there is room for it:
+            let keep_first =
+                widths[0] + gap + ellipsis_width + gap + widths[n - 1] <= available_width;
+            let head_width = if keep_first {
+                widths[0] + gap + ellipsis_width + gap
+            } else {
+                ellipsis_width + gap
+            };
+            let first_kept = if keep_first { 1 } else { 0 };
+            let mut first_tail = n - 1;
+            while first_tail > first_kept + 1
+                && head_width + width_of((first_tail - 1)..n) <= available_width
+            {
+                first_tail -= 1;
+            }
+            first_kept..first_tail
+        };
+        let mut hidden_segments: Vec<(usize, WidgetText)> =
+            hidden.clone().map(|i| (i, segments[i].clone())).collect();
+
+        let mut clicked = None;
+        let response = ui
+            .horizontal(|ui| {
+                let mut needs_separator = false;
+                for (i, segment) in segments.into_iter().enumerate() {
+                    if hidden.contains(&i) {
+                        if i == hidden.start {
+                            if needs_separator {
+                                ui.label(separator.clone());
+                            }
+                            let hidden_segments = std::mem::take(&mut hidden_segments);
+                            ui.menu_button("…", |ui| {
+                                for (i, text) in hidden_segments {
+                                    if ui.button(text).clicked() {
+                                        clicked = Some(i);
+                                        ui.close_menu();
+                                    }
+                                }
+                            });
+                            needs_separator = true;
+                        }
+                        continue;
+                    }
+
+                    if needs_separator {
+                        ui.label(separator.clone());
+                    }
+                    if i + 1 == n {
+                        ui.label(segment.strong());
+                    } else if ui.link(segment).clicked() {
+                        clicked = Some(i);
+                    }
+                    needs_separator = true;
+                }
+            })
+            .response;
+
+        BreadcrumbResponse { response, clicked }
+    }
+}
@@ -203,6 +203,133 @@ impl Widget for Button {
 
 // ----------------------------------------------------------------------------
 
+/// A button for starting a slow action, which shows a spinner (and optionally the progress)
+/// while that action is running.
+///
+/// While busy the button can't be clicked.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # fn start_download() {}
+/// # let download_progress: Option<f32> = None;
+/// let button = egui::ProgressButton::new("Download")
+///     .busy(download_progress.is_some())
+///     .busy_text("Downloading…")
+///     .progress(download_progress);
+/// if ui.add(button).clicked() {
+///     start_download();
+/// }
+/// # });
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct ProgressButton {
+    text: WidgetText,
+    busy_text: Option<WidgetText>,
+    busy: bool,
+    progress: Option<f32>,
+}
+
+impl ProgressButton {
+    pub fn new(text: impl Into<WidgetText>) -> Self {
+        Self {
+            text: text.into(),
+            busy_text: None,
+            busy: false,
+            progress: None,
+        }
+    }
+
+    /// Is the action running? If so, show a spinner and don't allow clicks.
+    pub fn busy(mut self, busy: bool) -> Self {
+        self.busy = busy;
+        self
+    }
+
+    /// Show this text instead while busy.
+    pub fn busy_text(mut self, busy_text: impl Into<WidgetText>) -> Self {
+        self.busy_text = Some(busy_text.into());
+        self
+    }
+
+    /// While busy, fill the button background up to this fraction, in the `[0, 1]` range.
+    ///
+    /// `None` (the default) means the progress is unknown.
+    pub fn progress(mut self, progress: Option<f32>) -> Self {
+        self.progress = progress.map(|progress| progress.clamp(0.0, 1.0));
+        self
+    }
+}
+
+impl Widget for ProgressButton {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let ProgressButton {
+            text,
+            busy_text,
+            busy,
+            progress,
+        } = self;
+
+        let button_padding = ui.spacing().button_padding;
+        let icon_spacing = ui.spacing().icon_spacing;
+
+        let text = if busy {
+            busy_text.unwrap_or(text)
+        } else {
+            text
+        };
+        let text = text.into_galley(ui, Some(false), f32::INFINITY, TextStyle::Button);
+
+        let spinner_size = text.size().y;
+        let mut desired_size = text.size() + 2.0 * button_padding;
+        if busy {
+            desired_size.x += spinner_size + icon_spacing;
+        }
+        desired_size.y = desired_size.y.at_least(ui.spacing().interact_size.y);
+
+        let sense = if busy { Sense::hover() } else { Sense::click() };
+        let (rect, response) = ui.allocate_at_least(desired_size, sense);
+        response.widget_info(|| WidgetInfo::labeled(WidgetType::Button, text.text()));
+
+        if ui.is_rect_visible(rect) {
+            let visuals = ui.style().interact(&response);
+            let frame_rect = rect.expand(visuals.expansion);
+            ui.painter().rect(
+                frame_rect,
+                visuals.rounding,
+                visuals.bg_fill,
+                visuals.bg_stroke,
+            );
+
+            if let (true, Some(progress)) = (busy, progress) {
+                let mut progress_rect = frame_rect;
+                progress_rect.max.x = lerp(frame_rect.x_range(), progress);
+                ui.painter().with_clip_rect(progress_rect).rect_filled(
+                    frame_rect,
+                    visuals.rounding,
+                    ui.visuals().selection.bg_fill,
+                );
+            }
+
+            let mut text_pos = pos2(
+                rect.min.x + button_padding.x,
+                rect.center().y - 0.5 * text.size().y,
+            );
+            if busy {
+                let spinner_rect = Rect::from_min_size(text_pos, Vec2::splat(spinner_size));
+                Spinner::new()
+                    .color(visuals.text_color())
+                    .paint_at(ui, spinner_rect);
+                text_pos.x += spinner_size + icon_spacing;
+            }
+            text.paint_with_visuals(ui.painter(), text_pos, visuals);
+        }
+
+        response
+    }
+}
+
+// ----------------------------------------------------------------------------
+
 // TODO(emilk): allow checkbox without a text label
 /// Boolean on/off control with text label.
 ///
@@ -0,0 +1,143 @@
+use crate::*;
+
+/// The response of showing a [`Chip`].
+pub struct ChipResponse {
+    /// Clicks on the remove button are not reported as clicks of the chip.
+    pub response: Response,
+
+    /// Was the remove button clicked?
+    pub removed: bool,
+}
+
+/// A small rounded tag, e.g. for showing active filters.
+///
+/// A chip can be selectable (like a [`SelectableLabel`]) and removable (with a small `✖` button).
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut tags = vec!["rust".to_owned(), "gui".to_owned()];
+/// # let mut show_archived = false;
+/// ui.horizontal(|ui| {
+///     tags.retain(|tag| !egui::Chip::new(tag.as_str()).removable(true).show(ui).removed);
+///
+///     if ui.add(egui::Chip::new("Archived").selected(show_archived)).clicked() {
+///         show_archived = !show_archived;
+///     }
+/// });
+/// # });
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);` or call `show`"]
+pub struct Chip {
+    text: WidgetText,
+    selected: bool,
+    removable: bool,
+    fill: Option<Color32>,
+}
+
+impl Chip {
+    pub fn new(text: impl Into<WidgetText>) -> Self {
+        Self {
+            text: text.into(),
+            selected: false,
+            removable: false,
+            fill: None,
+        }
+    }
+
+    /// Show the chip as selected.
+    pub fn selected(mut self, selected: bool) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    /// Add a small button for removing the chip, see [`ChipResponse::removed`].
+    pub fn removable(mut self, removable: bool) -> Self {
+        self.removable = removable;
+        self
+    }
+
+    /// Background color when not selected.
+    pub fn fill(mut self, fill: impl Into<Color32>) -> Self {
+        self.fill = Some(fill.into());
+        self
+    }
+
+    pub fn show(self, ui: &mut Ui) -> ChipResponse {
+        let Self {
+            text,
+            selected,
+            removable,
+            fill,
+        } = self;
+
+        let padding = vec2(ui.spacing().button_padding.x, 1.0);
+        let text = text.into_galley(ui, Some(false), f32::INFINITY, TextStyle::Button);
+
+        let height = (text.size().y + 2.0 * padding.y).at_least(ui.spacing().interact_size.y);
+        let remove_size = if removable { text.size().y } else { 0.0 };
+        let mut desired_size = vec2(text.size().x + 2.0 * padding.x + remove_size, height);
+        desired_size.x = desired_size.x.at_least(height);
+
+        let (rect, mut response) = ui.allocate_exact_size(desired_size, Sense::click());
+
+        let remove_rect = Rect::from_center_size(
+            pos2(
+                rect.right() - padding.x - 0.5 * remove_size,
+                rect.center().y,
+            ),
+            Vec2::splat(remove_size),
+        );
+        let remove_hovered = removable
+            && response
+                .hover_pos()
+                .map_or(false, |pos| remove_rect.expand(2.0).contains(pos));
+        let removed = remove_hovered && response.clicked();
+        if removed {
+            response.set_clicked_by(PointerButton::Primary, false);
+        }
+
+        response.widget_info(|| {
+            WidgetInfo::selected(WidgetType::SelectableLabel, selected, text.text())
+        });
+
+        if ui.is_rect_visible(rect) {
+            let visuals = ui.style().interact_selectable(&response, selected);
+            let fill = if selected {
+                visuals.bg_fill
+            } else {
+                fill.unwrap_or(visuals.bg_fill)
+            };
+            let rect = rect.expand(visuals.expansion);
+            let rounding = 0.5 * rect.height();
+            ui.painter().rect(rect, rounding, fill, visuals.bg_stroke);
+
+            let text_pos = pos2(
+                rect.left() + padding.x,
+                rect.center().y - 0.5 * text.size().y,
+            );
+            text.paint_with_visuals(ui.painter(), text_pos, &visuals);
+
+            if removable {
+                let color = if remove_hovered {
+                    ui.visuals().strong_text_color()
+                } else {
+                    visuals.text_color()
+                };
+                let cross_rect = remove_rect.shrink(0.25 * remove_size);
+                let stroke = Stroke::new(visuals.fg_stroke.width, color);
+                ui.painter()
+                    .line_segment([cross_rect.left_top(), cross_rect.right_bottom()], stroke);
+                ui.painter()
+                    .line_segment([cross_rect.right_top(), cross_rect.left_bottom()], stroke);
+            }
+        }
+
+        ChipResponse { response, removed }
+    }
+}
+
+impl Widget for Chip {
+    fn ui(self, ui: &mut Ui) -> Response {
+        self.show(ui).response
+    }
+}
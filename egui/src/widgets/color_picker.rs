@@ -226,7 +226,11 @@ fn color_text_ui(ui: &mut Ui, color: impl Into<Color32>, alpha: Alpha) {
     ui.horizontal(|ui| {
         let [r, g, b, a] = color.to_array();
 
-        if ui.button("📋").on_hover_text("Click to copy").clicked() {
+        if ui
+            .button("📋")
+            .on_hover_text(ui.ctx().translate("egui.color.copy", "Click to copy"))
+            .clicked()
+        {
             if alpha == Alpha::Opaque {
                 ui.output().copied_text = format!("{}, {}, {}", r, g, b);
             } else {
@@ -236,17 +240,21 @@ fn color_text_ui(ui: &mut Ui, color: impl Into<Color32>, alpha: Alpha) {
 
         if alpha == Alpha::Opaque {
             ui.label(format!("rgb({}, {}, {})", r, g, b))
-                .on_hover_text("Red Green Blue");
+                .on_hover_text(ui.ctx().translate("egui.color.rgb", "Red Green Blue"));
         } else {
             ui.label(format!("rgba({}, {}, {}, {})", r, g, b, a))
-                .on_hover_text("Red Green Blue with premultiplied Alpha");
+                .on_hover_text(
+                    ui.ctx()
+                        .translate("egui.color.rgba", "Red Green Blue with premultiplied Alpha"),
+                );
         }
     });
 }
 
 fn color_picker_hsvag_2d(ui: &mut Ui, hsva: &mut HsvaGamma, alpha: Alpha) {
     let current_color_size = vec2(ui.spacing().slider_width, ui.spacing().interact_size.y);
-    show_color(ui, *hsva, current_color_size).on_hover_text("Selected color");
+    show_color(ui, *hsva, current_color_size)
+        .on_hover_text(ui.ctx().translate("egui.color.selected", "Selected color"));
 
     color_text_ui(ui, *hsva, alpha);
 
@@ -255,9 +263,17 @@ fn color_picker_hsvag_2d(ui: &mut Ui, hsva: &mut HsvaGamma, alpha: Alpha) {
         let a = &mut hsva.a;
         let mut additive = *a < 0.0;
         ui.horizontal(|ui| {
-            ui.label("Blending:");
-            ui.radio_value(&mut additive, false, "Normal");
-            ui.radio_value(&mut additive, true, "Additive");
+            ui.label(ui.ctx().translate("egui.color.blending", "Blending:"));
+            ui.radio_value(
+                &mut additive,
+                false,
+                ui.ctx().translate("egui.color.normal", "Normal"),
+            );
+            ui.radio_value(
+                &mut additive,
+                true,
+                ui.ctx().translate("egui.color.additive", "Additive"),
+            );
 
             if additive {
                 *a = -a.abs();
@@ -276,14 +292,17 @@ fn color_picker_hsvag_2d(ui: &mut Ui, hsva: &mut HsvaGamma, alpha: Alpha) {
         hsva.a = 1.0;
     } else {
         let a = &mut hsva.a;
+        let alpha_label = ui.ctx().translate("egui.color.alpha", "Alpha");
 
         if alpha == Alpha::OnlyBlend {
             if *a < 0.0 {
                 *a = 0.5; // was additive, but isn't allowed to be
             }
-            color_slider_1d(ui, a, |a| HsvaGamma { a, ..opaque }.into()).on_hover_text("Alpha");
+            color_slider_1d(ui, a, |a| HsvaGamma { a, ..opaque }.into())
+                .on_hover_text(alpha_label.clone());
         } else if !additive {
-            color_slider_1d(ui, a, |a| HsvaGamma { a, ..opaque }.into()).on_hover_text("Alpha");
+            color_slider_1d(ui, a, |a| HsvaGamma { a, ..opaque }.into())
+                .on_hover_text(alpha_label.clone());
         }
     }
 
@@ -298,7 +317,7 @@ fn color_picker_hsvag_2d(ui: &mut Ui, hsva: &mut HsvaGamma, alpha: Alpha) {
         }
         .into()
     })
-    .on_hover_text("Hue");
+    .on_hover_text(ui.ctx().translate("egui.color.hue", "Hue"));
 
     if false {
         color_slider_1d(ui, s, |s| HsvaGamma { s, ..opaque }.into()).on_hover_text("Saturation");
@@ -344,7 +363,8 @@ pub fn color_edit_button_hsva(ui: &mut Ui, hsva: &mut Hsva, alpha: Alpha) -> Res
     let open = ui.memory().is_popup_open(popup_id);
     let mut button_response = color_button(ui, (*hsva).into(), open);
     if ui.style().explanation_tooltips {
-        button_response = button_response.on_hover_text("Click to edit color");
+        button_response = button_response
+            .on_hover_text(ui.ctx().translate("egui.color.edit", "Click to edit color"));
     }
 
     if button_response.clicked() {
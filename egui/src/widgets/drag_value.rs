@@ -200,13 +200,18 @@ impl<'a> Widget for DragValue<'a> {
 
         let max_decimals = max_decimals.unwrap_or(auto_decimals + 2);
         let auto_decimals = auto_decimals.clamp(min_decimals, max_decimals);
+        let locale_format = ui.ctx().locale_format();
+        let is_custom_formatted = custom_formatter.is_some();
         let value_text = match custom_formatter {
             Some(custom_formatter) => custom_formatter(value, auto_decimals..=max_decimals),
             None => {
                 if value == 0.0 {
                     "0".to_owned()
                 } else {
-                    emath::format_with_decimals_in_range(value, auto_decimals..=max_decimals)
+                    locale_format.format_number(&emath::format_with_decimals_in_range(
+                        value,
+                        auto_decimals..=max_decimals,
+                    ))
                 }
             }
         };
@@ -228,7 +233,9 @@ impl<'a> Widget for DragValue<'a> {
                     .desired_width(button_width)
                     .font(TextStyle::Monospace),
             );
-            if let Ok(parsed_value) = value_text.parse() {
+            if let Some(parsed_value) =
+                parse_value_text(&value_text, &locale_format, is_custom_formatted)
+            {
                 let parsed_value = clamp_to_range(parsed_value, clamp_range);
                 set(&mut get_set_value, parsed_value);
             }
@@ -254,7 +261,8 @@ impl<'a> Widget for DragValue<'a> {
                 response = response .on_hover_text(format!(
                     "{}{}{}\nDrag to edit or click to enter a value.\nPress 'Shift' while dragging for better control.",
                     prefix,
-                    value as f32, // Show full precision value on-hover. TODO(emilk): figure out f64 vs f32
+                    // Show full precision value on-hover. TODO(emilk): figure out f64 vs f32
+                    locale_format.format_number(&(value as f32).to_string()),
                     suffix
                 ));
             }
@@ -326,3 +334,28 @@ fn clamp_to_range(x: f64, range: RangeInclusive<f64>) -> f64 {
         range.start().max(*range.end()),
     )
 }
+
+/// Parse text typed into a [`DragValue`]: the way [`crate::Context::locale_format`] writes numbers,
+/// unless they are written with a [`DragValue::custom_formatter`].
+fn parse_value_text(
+    text: &str,
+    locale_format: &crate::i18n::LocaleFormat,
+    is_custom_formatted: bool,
+) -> Option<f64> {
+    if is_custom_formatted {
+        text.parse().ok()
+    } else {
+        locale_format.parse_number(text)
+    }
+}
+
+#[test]
+fn test_parse_value_text() {
+    let german = crate::i18n::LocaleFormat {
+        decimal_separator: ',',
+        digit_group_separator: Some('.'),
+        ..Default::default()
+    };
+    assert_eq!(parse_value_text("1.234,5", &german, false), Some(1234.5));
+    assert_eq!(parse_value_text("1.5", &german, true), Some(1.5));
+}
@@ -83,6 +83,8 @@ impl Widget for Link {
 pub struct Hyperlink {
     url: String,
     text: WidgetText,
+    open_in_new_tab: bool,
+    open_on_middle_click: bool,
 }
 
 impl Hyperlink {
@@ -92,6 +94,8 @@ impl Hyperlink {
         Self {
             url: url.clone(),
             text: url.into(),
+            open_in_new_tab: false,
+            open_on_middle_click: true,
         }
     }
 
@@ -100,27 +104,47 @@ impl Hyperlink {
         Self {
             url: url.to_string(),
             text: text.into(),
+            open_in_new_tab: false,
+            open_on_middle_click: true,
         }
     }
+
+    /// Always open the link in a new tab.
+    ///
+    /// By default the link only opens in a new tab if clicked with a modifier key held down
+    /// or with the middle mouse button.
+    /// Only matters when in a web browser.
+    pub fn open_in_new_tab(mut self, open_in_new_tab: bool) -> Self {
+        self.open_in_new_tab = open_in_new_tab;
+        self
+    }
+
+    /// Open the link in a new tab when clicked with the middle mouse button. Default: `true`.
+    pub fn open_on_middle_click(mut self, open_on_middle_click: bool) -> Self {
+        self.open_on_middle_click = open_on_middle_click;
+        self
+    }
 }
 
 impl Widget for Hyperlink {
     fn ui(self, ui: &mut Ui) -> Response {
-        let Self { url, text } = self;
+        let Self {
+            url,
+            text,
+            open_in_new_tab,
+            open_on_middle_click,
+        } = self;
 
         let response = ui.add(Link::new(text));
         if response.clicked() {
             let modifiers = ui.ctx().input().modifiers;
-            ui.ctx().output().open_url = Some(crate::output::OpenUrl {
+            ui.ctx().open_url(crate::output::OpenUrl {
                 url: url.clone(),
-                new_tab: modifiers.any(),
+                new_tab: open_in_new_tab || modifiers.any(),
             });
         }
-        if response.middle_clicked() {
-            ui.ctx().output().open_url = Some(crate::output::OpenUrl {
-                url: url.clone(),
-                new_tab: true,
-            });
+        if open_on_middle_click && response.middle_clicked() {
+            ui.ctx().open_url(crate::output::OpenUrl::new_tab(&url));
         }
         response.on_hover_text(url)
     }
@@ -6,12 +6,16 @@
 
 use crate::*;
 
+mod badge;
+mod breadcrumb;
 mod button;
+mod chip;
 pub mod color_picker;
 pub(crate) mod drag_value;
 mod hyperlink;
 mod image;
 mod label;
+mod pagination;
 pub mod plot;
 mod progress_bar;
 mod selected_label;
@@ -20,11 +24,15 @@ mod slider;
 mod spinner;
 pub mod text_edit;
 
+pub use badge::Badge;
+pub use breadcrumb::{Breadcrumb, BreadcrumbResponse};
 pub use button::*;
+pub use chip::{Chip, ChipResponse};
 pub use drag_value::DragValue;
 pub use hyperlink::*;
 pub use image::Image;
 pub use label::*;
+pub use pagination::Pagination;
 pub use progress_bar::ProgressBar;
 pub use selected_label::SelectableLabel;
 pub use separator::Separator;
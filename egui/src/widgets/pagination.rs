@@ -0,0 +1,269 @@
+use crate::*;
+
+enum PageCount<'a> {
+    Pages(usize),
+    Items {
+        num_items: usize,
+        items_per_page: &'a mut usize,
+        options: Vec<usize>,
+    },
+}
+
+/// Buttons for paging through data that is too large to show all at once.
+///
+/// Shows first/previous/next/last buttons, numbered pages around the current one,
+/// and optionally a field for jumping to a page and a selector for the number of items per page.
+///
+/// The page is zero-based. [`Response::changed`] is set when the user changes the page.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let items: Vec<String> = (0..1000).map(|i| format!("Item {}", i)).collect();
+/// # let mut page = 0;
+/// # let mut items_per_page = 25;
+/// ui.add(egui::Pagination::for_items(&mut page, items.len(), &mut items_per_page));
+/// for item in items.iter().skip(page * items_per_page).take(items_per_page) {
+///     ui.label(item);
+/// }
+/// # });
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct Pagination<'a> {
+    page: &'a mut usize,
+    count: PageCount<'a>,
+    max_page_buttons: usize,
+    jump_to_page: bool,
+}
+
+impl<'a> Pagination<'a> {
+    /// Page through this many pages.
+    pub fn new(page: &'a mut usize, num_pages: usize) -> Self {
+        Self {
+            page,
+            count: PageCount::Pages(num_pages),
+            max_page_buttons: 7,
+            jump_to_page: false,
+        }
+    }
+
+    /// Page through `num_items` items, with a selector for the number of items per page.
+    pub fn for_items(page: &'a mut usize, num_items: usize, items_per_page: &'a mut usize) -> Self {
+        Self {
+            page,
+            count: PageCount::Items {
+                num_items,
+                items_per_page,
+                options: vec![10, 25, 50, 100],
+            },
+            max_page_buttons: 7,
+            jump_to_page: false,
+        }
+    }
+
+    /// The choices for the number of items per page, when using [`Self::for_items`].
+    pub fn items_per_page_options(mut self, new_options: impl Into<Vec<usize>>) -> Self {
+        if let PageCount::Items { options, .. } = &mut self.count {
+            *options = new_options.into();
+        }
+        self
+    }
+
+    /// At most this many numbered page buttons, including the `…` placeholders.
+    /// Default: 7.
+    pub fn max_page_buttons(mut self, max_page_buttons: usize) -> Self {
+        self.max_page_buttons = max_page_buttons.at_least(5);
+        self
+    }
+
+    /// Show a field for typing in the page to go to. Default: `false`.
+    pub fn jump_to_page(mut self, jump_to_page: bool) -> Self {
+        self.jump_to_page = jump_to_page;
+        self
+    }
+}
+
+impl<'a> Widget for Pagination<'a> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            page,
+            count,
+            max_page_buttons,
+            jump_to_page,
+        } = self;
+
+        let old_page = *page;
+
+        let mut response = ui
+            .horizontal(|ui| {
+                let num_pages = match &count {
+                    PageCount::Pages(num_pages) => *num_pages,
+                    PageCount::Items {
+                        num_items,
+                        items_per_page,
+                        ..
+                    } => div_ceil(*num_items, (**items_per_page).at_least(1)),
+                }
+                .at_least(1);
+                let last = num_pages - 1;
+                *page = (*page).at_most(last);
+
+                let can_go_back = *page > 0;
+                let can_go_forward = *page < last;
+                if ui
+                    .add_enabled(can_go_back, Button::new("⏮"))
+                    .on_hover_text(ui.ctx().translate("egui.pagination.first", "First page"))
+                    .clicked()
+                {
+                    *page = 0;
+                }
+                if ui
+                    .add_enabled(can_go_back, Button::new("⏴"))
+                    .on_hover_text(
+                        ui.ctx()
+                            .translate("egui.pagination.previous", "Previous page"),
+                    )
+                    .clicked()
+                {
+                    *page -= 1;
+                }
+
+                for button in page_buttons(*page, num_pages, max_page_buttons) {
+                    if let Some(number) = button {
+                        let label = SelectableLabel::new(number == *page, (number + 1).to_string());
+                        if ui.add(label).clicked() {
+                            *page = number;
+                        }
+                    } else {
+                        ui.label("…");
+                    }
+                }
+
+                if ui
+                    .add_enabled(can_go_forward, Button::new("⏵"))
+                    .on_hover_text(ui.ctx().translate("egui.pagination.next", "Next page"))
+                    .clicked()
+                {
+                    *page += 1;
+                }
+                if ui
+                    .add_enabled(can_go_forward, Button::new("⏭"))
+                    .on_hover_text(ui.ctx().translate("egui.pagination.last", "Last page"))
+                    .clicked()
+                {
+                    *page = last;
+                }
+
+                if jump_to_page {
+                    let mut page_nr = *page + 1;
+                    let ctx = ui.ctx().clone();
+                    ui.add(
+                        DragValue::new(&mut page_nr)
+                            .clamp_range(1..=num_pages)
+                            .speed(0.1)
+                            .prefix(format!(
+                                "{} ",
+                                ctx.translate("egui.pagination.page", "Page")
+                            ))
+                            .suffix(format!(
+                                " {} {}",
+                                ctx.translate("egui.pagination.of", "of"),
+                                num_pages
+                            )),
+                    );
+                    *page = page_nr - 1;
+                }
+
+                if let PageCount::Items {
+                    items_per_page,
+                    options,
+                    ..
+                } = count
+                {
+                    let first_item = *page * *items_per_page;
+                    let before = *items_per_page;
+                    let per_page = ui.ctx().translate("egui.pagination.per_page", "/ page");
+                    ComboBox::from_id_source(ui.id().with("items_per_page"))
+                        .selected_text(format!("{} {}", items_per_page, per_page))
+                        .show_ui(ui, |ui| {
+                            for option in options {
+                                ui.selectable_value(
+                                    items_per_page,
+                                    option,
+                                    format!("{} {}", option, per_page),
+                                );
+                            }
+                        });
+                    if *items_per_page != before {
+                        // Stay on the page with the first item we were looking at:
+                        *page = first_item / (*items_per_page).at_least(1);
+                    }
+                }
+            })
+            .response;
+
+        if *page != old_page {
+            response.mark_changed();
+        }
+        response
+    }
+}
+
+fn div_ceil(numerator: usize, denominator: usize) -> usize {
+    (numerator + denominator - 1) / denominator
+}
+
+/// The numbered page buttons to show, with `None` for a `…` placeholder.
+///
+/// The first and last pages are always shown, together with the pages around `current`.
+fn page_buttons(current: usize, num_pages: usize, max_buttons: usize) -> Vec<Option<usize>> {
+    if num_pages <= max_buttons {
+        return (0..num_pages).map(Some).collect();
+    }
+
+    let last = num_pages - 1;
+    let edge_len = max_buttons - 2; // the buttons next to either end, plus the end itself
+    let mut buttons = vec![];
+    if current + 4 <= max_buttons {
+        buttons.extend((0..edge_len).map(Some));
+        buttons.push(None);
+        buttons.push(Some(last));
+    } else if current + max_buttons >= last + 4 {
+        buttons.push(Some(0));
+        buttons.push(None);
+        buttons.extend((num_pages - edge_len..num_pages).map(Some));
+    } else {
+        let window_len = max_buttons - 4;
+        let start = current - (window_len - 1) / 2;
+        buttons.push(Some(0));
+        buttons.push(None);
+        buttons.extend((start..start + window_len).map(Some));
+        buttons.push(None);
+        buttons.push(Some(last));
+    }
+    buttons
+}
+
+#[test]
+fn test_page_buttons() {
+    assert_eq!(page_buttons(0, 3, 7), vec![Some(0), Some(1), Some(2)]);
+    assert_eq!(
+        page_buttons(2, 20, 7),
+        vec![Some(0), Some(1), Some(2), Some(3), Some(4), None, Some(19)]
+    );
+    assert_eq!(
+        page_buttons(10, 20, 7),
+        vec![Some(0), None, Some(9), Some(10), Some(11), None, Some(19)]
+    );
+    assert_eq!(
+        page_buttons(17, 20, 7),
+        vec![
+            Some(0),
+            None,
+            Some(15),
+            Some(16),
+            Some(17),
+            Some(18),
+            Some(19)
+        ]
+    );
+}
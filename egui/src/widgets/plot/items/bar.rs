@@ -1,8 +1,11 @@
 use crate::emath::NumExt;
-use crate::epaint::{Color32, RectShape, Rounding, Shape, Stroke};
+use crate::epaint::{Color32, Mesh, RectShape, Rounding, Shape, Stroke};
 
-use super::{add_rulers_and_text, highlighted_color, Orientation, PlotConfig, RectElement};
+use super::{
+    add_rect_to_mesh, add_rulers_and_text, highlighted_color, Orientation, PlotConfig, RectElement,
+};
 use crate::plot::{BarChart, PlotPoint, ScreenTransform};
+use crate::Context;
 
 /// One bar in a [`BarChart`]. Potentially floating, allowing stacked bar charts.
 /// Width can be changed to allow variable-width histograms.
@@ -31,6 +34,10 @@ pub struct Bar {
 
     /// Fill color
     pub fill: Color32,
+
+    /// The sum of all the values at this argument, for a bar in a stack made by [`BarChart::stack`].
+    /// Shown below the value by the default formatter.
+    pub stack_total: Option<f64>,
 }
 
 impl Bar {
@@ -50,6 +57,7 @@ impl Bar {
             bar_width: 0.5,
             stroke: Stroke::new(1.0, Color32::TRANSPARENT),
             fill: Color32::TRANSPARENT,
+            stack_total: None,
         }
     }
 
@@ -137,6 +145,12 @@ impl Bar {
         shapes.push(rect);
     }
 
+    /// Like [`Self::add_shapes`] when not highlighted, but into the one mesh of the whole chart.
+    pub(super) fn add_to_mesh(&self, transform: &ScreenTransform, mesh: &mut Mesh) {
+        let rect = transform.rect_from_values(&self.bounds_min(), &self.bounds_max());
+        add_rect_to_mesh(mesh, rect, self.fill, self.stroke);
+    }
+
     pub(super) fn add_rulers_and_text(
         &self,
         parent: &BarChart,
@@ -182,9 +196,19 @@ impl RectElement for Bar {
         self.orientation
     }
 
-    fn default_values_format(&self, transform: &ScreenTransform) -> String {
+    fn default_values_format(&self, transform: &ScreenTransform, ctx: &Context) -> String {
         let scale = transform.dvalue_dpos();
         let y_decimals = ((-scale[1].abs().log10()).ceil().at_least(0.0) as usize).at_most(6);
-        format!("\n{:.*}", y_decimals, self.value)
+        match self.stack_total {
+            Some(total) => format!(
+                "\n{:.*}\n{}: {:.*}",
+                y_decimals,
+                self.value,
+                ctx.translate("egui.plot.bar.total", "Total"),
+                y_decimals,
+                total
+            ),
+            None => format!("\n{:.*}", y_decimals, self.value),
+        }
     }
 }
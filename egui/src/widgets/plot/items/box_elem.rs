@@ -3,6 +3,7 @@ use crate::epaint::{Color32, RectShape, Rounding, Shape, Stroke};
 
 use super::{add_rulers_and_text, highlighted_color, Orientation, PlotConfig, RectElement};
 use crate::plot::{BoxPlot, PlotPoint, ScreenTransform};
+use crate::Context;
 
 /// Contains the values of a single box in a box plot.
 #[derive(Clone, Debug, PartialEq)]
@@ -266,15 +267,20 @@ impl RectElement for BoxElem {
         self.point_at(self.argument, self.spread.upper_whisker)
     }
 
-    fn default_values_format(&self, transform: &ScreenTransform) -> String {
+    fn default_values_format(&self, transform: &ScreenTransform, ctx: &Context) -> String {
         let scale = transform.dvalue_dpos();
         let y_decimals = ((-scale[1].abs().log10()).ceil().at_least(0.0) as usize).at_most(6);
         format!(
-            "\nMax = {max:.decimals$}\
-             \nQuartile 3 = {q3:.decimals$}\
-             \nMedian = {med:.decimals$}\
-             \nQuartile 1 = {q1:.decimals$}\
-             \nMin = {min:.decimals$}",
+            "\n{max_label} = {max:.decimals$}\
+             \n{q3_label} = {q3:.decimals$}\
+             \n{med_label} = {med:.decimals$}\
+             \n{q1_label} = {q1:.decimals$}\
+             \n{min_label} = {min:.decimals$}",
+            max_label = ctx.translate("egui.plot.box.max", "Max"),
+            q3_label = ctx.translate("egui.plot.box.quartile3", "Quartile 3"),
+            med_label = ctx.translate("egui.plot.box.median", "Median"),
+            q1_label = ctx.translate("egui.plot.box.quartile1", "Quartile 1"),
+            min_label = ctx.translate("egui.plot.box.min", "Min"),
             max = self.spread.upper_whisker,
             q3 = self.spread.quartile3,
             med = self.spread.median,
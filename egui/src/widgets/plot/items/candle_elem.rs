@@ -1,8 +1,12 @@
 use crate::emath::NumExt;
-use crate::epaint::{Color32, RectShape, Rounding, Shape, Stroke};
+use crate::epaint::{Color32, Mesh, RectShape, Rounding, Shape, Stroke};
 
-use super::{add_rulers_and_text, highlighted_color, Orientation, PlotConfig, RectElement};
+use super::{
+    add_rect_to_mesh, add_rulers_and_text, add_segment_to_mesh, highlighted_color, Orientation,
+    PlotConfig, RectElement,
+};
 use crate::plot::{ChartPlot, PlotPoint, ScreenTransform};
+use crate::Context;
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct Candle {
@@ -23,6 +27,30 @@ impl Candle {
             volume,
         }
     }
+
+    /// The values of the candle, one per line, for the hover text.
+    pub(super) fn values_format(&self, transform: &ScreenTransform, ctx: &Context) -> String {
+        let scale = transform.dvalue_dpos();
+        let y_decimals = ((-scale[1].abs().log10()).ceil().at_least(0.0) as usize).at_most(6);
+        format!(
+            "\n{open_label} = {open:.decimals$}\
+             \n{high_label} = {high:.decimals$}\
+             \n{low_label} = {low:.decimals$}\
+             \n{close_label} = {close:.decimals$}\
+             \n{volume_label} = {volume:.decimals$}",
+            open_label = ctx.translate("egui.plot.candle.open", "Open"),
+            high_label = ctx.translate("egui.plot.candle.high", "High"),
+            low_label = ctx.translate("egui.plot.candle.low", "Low"),
+            close_label = ctx.translate("egui.plot.candle.close", "Close"),
+            volume_label = ctx.translate("egui.plot.candle.volume", "Volume"),
+            open = self.open,
+            high = self.high,
+            low = self.low,
+            close = self.close,
+            volume = self.volume,
+            decimals = y_decimals
+        )
+    }
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -71,16 +99,32 @@ impl CandleElem {
         self
     }
 
+    /// Its own stroke and fill, or else the color of its chart for rising or falling candles.
+    pub(super) fn colors(&self, parent: &ChartPlot) -> (Stroke, Color32) {
+        if self.fill == Color32::TRANSPARENT && self.stroke.color == Color32::TRANSPARENT {
+            let color = if self.candle.close >= self.candle.open {
+                parent.color_up
+            } else {
+                parent.color_down
+            };
+            (Stroke::new(self.stroke.width, color), color)
+        } else {
+            (self.stroke, self.fill)
+        }
+    }
+
     pub(super) fn add_shapes(
         &self,
+        parent: &ChartPlot,
         transform: &ScreenTransform,
         highlighted: bool,
         shapes: &mut Vec<Shape>,
     ) {
+        let (stroke, fill) = self.colors(parent);
         let (stroke, fill) = if highlighted {
-            highlighted_color(self.stroke, self.fill)
+            highlighted_color(stroke, fill)
         } else {
-            (self.stroke, self.fill)
+            (stroke, fill)
         };
 
         let rect = transform.rect_from_values(
@@ -113,6 +157,26 @@ impl CandleElem {
         shapes.push(whisker);
     }
 
+    /// Like [`Self::add_shapes`] when not highlighted, but into the one mesh of the whole chart.
+    pub(super) fn add_to_mesh(
+        &self,
+        parent: &ChartPlot,
+        transform: &ScreenTransform,
+        mesh: &mut Mesh,
+    ) {
+        let (stroke, fill) = self.colors(parent);
+        let body = transform.rect_from_values(
+            &self.point_at(self.x - self.candle_width / 2.0, self.candle.open),
+            &self.point_at(self.x + self.candle_width / 2.0, self.candle.close),
+        );
+        add_rect_to_mesh(mesh, body, fill, stroke);
+        let whisker = [
+            transform.position_from_point(&self.point_at(self.x, self.candle.low)),
+            transform.position_from_point(&self.point_at(self.x, self.candle.high)),
+        ];
+        add_segment_to_mesh(mesh, whisker, stroke);
+    }
+
     pub(super) fn add_rulers_and_text(
         &self,
         parent: &ChartPlot,
@@ -163,21 +227,7 @@ impl RectElement for CandleElem {
         self.point_at(self.x, self.candle.high)
     }
 
-    fn default_values_format(&self, transform: &ScreenTransform) -> String {
-        let scale = transform.dvalue_dpos();
-        let y_decimals = ((-scale[1].abs().log10()).ceil().at_least(0.0) as usize).at_most(6);
-        format!(
-            "\nOpen = {open:.decimals$}\
-             \nHigh = {high:.decimals$}\
-             \nLow = {low:.decimals$}\
-             \nClose = {close:.decimals$}\
-             \nVolume = {volume:.decimals$}",
-            open = self.candle.open,
-            high = self.candle.high,
-            low = self.candle.low,
-            close = self.candle.close,
-            volume = self.candle.volume,
-            decimals = y_decimals
-        )
+    fn default_values_format(&self, transform: &ScreenTransform, ctx: &Context) -> String {
+        self.candle.values_format(transform, ctx)
     }
 }
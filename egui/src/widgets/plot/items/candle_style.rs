@@ -0,0 +1,145 @@
+use super::{Candle, CandleElem};
+
+/// How a [`crate::plot::ChartPlot`] derives the candles it shows from its own,
+/// see [`crate::plot::ChartPlot::style`].
+///
+/// The candles must be sorted by x.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CandleStyle {
+    /// The candles as they are.
+    Candles,
+
+    /// Heikin-Ashi candles, which average out the noise so that trends stand out:
+    /// each closes at the mean of the open, high, low and close of its candle,
+    /// and opens at the middle of the body of the one before.
+    HeikinAshi,
+
+    /// Renko bricks of `brick_size`, by the close: a brick is added above the last one whenever
+    /// the close rises a whole brick beyond it, and below whenever it falls a whole brick beneath it.
+    ///
+    /// The bricks that a candle adds are spread out up to its place on the x axis,
+    /// and share its volume.
+    Renko { brick_size: f64 },
+}
+
+impl Default for CandleStyle {
+    fn default() -> Self {
+        Self::Candles
+    }
+}
+
+impl CandleStyle {
+    /// The candles to show in this style.
+    pub(crate) fn apply(&self, candles: &[CandleElem]) -> Vec<CandleElem> {
+        match *self {
+            Self::Candles => candles.to_vec(),
+            Self::HeikinAshi => heikin_ashi(candles),
+            Self::Renko { brick_size } => renko(candles, brick_size),
+        }
+    }
+}
+
+fn heikin_ashi(candles: &[CandleElem]) -> Vec<CandleElem> {
+    let mut previous: Option<Candle> = None;
+    candles
+        .iter()
+        .map(|elem| {
+            let c = &elem.candle;
+            let close = (c.open + c.high + c.low + c.close) / 4.0;
+            let open = previous
+                .as_ref()
+                .map_or((c.open + c.close) / 2.0, |p| (p.open + p.close) / 2.0);
+            let candle = Candle::new(
+                open,
+                c.high.max(open).max(close),
+                c.low.min(open).min(close),
+                close,
+                c.volume,
+            );
+            previous = Some(candle.clone());
+            CandleElem {
+                candle,
+                ..elem.clone()
+            }
+        })
+        .collect()
+}
+
+fn renko(candles: &[CandleElem], brick_size: f64) -> Vec<CandleElem> {
+    let first = match candles.first() {
+        Some(first) if brick_size > 0.0 => first,
+        _ => return vec![],
+    };
+    // The bottom and the top of the last brick:
+    let (mut bottom, mut top) = (first.candle.close, first.candle.close);
+    let mut bricks = vec![];
+    let mut previous_x = first.x - first.candle_width;
+    for elem in candles {
+        let close = elem.candle.close;
+        // Each new brick as its open and close:
+        let mut new_bricks = vec![];
+        while close >= top + brick_size {
+            new_bricks.push((top, top + brick_size));
+            bottom = top;
+            top += brick_size;
+        }
+        while close <= bottom - brick_size {
+            new_bricks.push((bottom, bottom - brick_size));
+            top = bottom;
+            bottom -= brick_size;
+        }
+
+        let count = new_bricks.len() as f64;
+        let step = (elem.x - previous_x) / count;
+        for (i, (open, close)) in new_bricks.into_iter().enumerate() {
+            bricks.push(CandleElem {
+                x: elem.x - (count - 1.0 - i as f64) * step,
+                candle: Candle::new(
+                    open,
+                    open.max(close),
+                    open.min(close),
+                    close,
+                    elem.candle.volume / count,
+                ),
+                candle_width: elem.candle_width / count,
+                ..elem.clone()
+            });
+        }
+        previous_x = elem.x;
+    }
+    bricks
+}
+
+#[test]
+fn test_candle_styles() {
+    let candles: Vec<CandleElem> = [(10.0, 12.0), (12.0, 11.5), (11.5, 14.5)]
+        .iter()
+        .enumerate()
+        .map(|(i, &(open, close))| {
+            let high = open.max(close) + 1.0;
+            let low = open.min(close) - 1.0;
+            let mut elem = CandleElem::new(Candle::new(open, high, low, close, 2.0));
+            elem.x = i as f64;
+            elem
+        })
+        .collect();
+
+    let heikin_ashi = CandleStyle::HeikinAshi.apply(&candles);
+    assert_eq!(
+        heikin_ashi[0].candle,
+        Candle::new(11.0, 13.0, 9.0, 11.0, 2.0)
+    );
+    assert_eq!(heikin_ashi[1].candle.open, 11.0);
+    assert_eq!(heikin_ashi[1].candle.close, 11.75);
+
+    let bricks = CandleStyle::Renko { brick_size: 1.0 }.apply(&candles);
+    // None at the first close, nor for the fall by less than a brick, then two up:
+    assert_eq!(bricks.len(), 2);
+    assert_eq!(bricks[0].candle, Candle::new(12.0, 13.0, 12.0, 13.0, 1.0));
+    assert_eq!(bricks[1].candle.close, 14.0);
+    assert_eq!((bricks[0].x, bricks[1].x), (1.5, 2.0));
+
+    assert!(CandleStyle::Renko { brick_size: 0.0 }
+        .apply(&candles)
+        .is_empty());
+}
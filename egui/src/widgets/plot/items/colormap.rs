@@ -0,0 +1,96 @@
+use epaint::Color32;
+
+/// How the values of a [`crate::plot::HeatMap`] are turned into colors.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Colormap {
+    /// From dark purple through teal to yellow. Evenly bright steps, and readable in grayscale.
+    Viridis,
+
+    /// From dark blue through magenta to yellow.
+    Plasma,
+
+    /// These colors, evenly spaced from the lowest value to the highest, blended in between.
+    Gradient(Vec<Color32>),
+}
+
+impl Default for Colormap {
+    fn default() -> Self {
+        Self::Viridis
+    }
+}
+
+/// Samples of matplotlib's viridis at every eighth of the range.
+const VIRIDIS: [Color32; 9] = [
+    Color32::from_rgb(0x44, 0x01, 0x54),
+    Color32::from_rgb(0x47, 0x2d, 0x7b),
+    Color32::from_rgb(0x3b, 0x52, 0x8b),
+    Color32::from_rgb(0x2c, 0x72, 0x8e),
+    Color32::from_rgb(0x21, 0x91, 0x8c),
+    Color32::from_rgb(0x28, 0xae, 0x80),
+    Color32::from_rgb(0x5e, 0xc9, 0x62),
+    Color32::from_rgb(0xad, 0xdc, 0x30),
+    Color32::from_rgb(0xfd, 0xe7, 0x25),
+];
+
+/// Samples of matplotlib's plasma at every eighth of the range.
+const PLASMA: [Color32; 9] = [
+    Color32::from_rgb(0x0d, 0x08, 0x87),
+    Color32::from_rgb(0x4c, 0x02, 0xa1),
+    Color32::from_rgb(0x7e, 0x03, 0xa8),
+    Color32::from_rgb(0xa9, 0x23, 0x95),
+    Color32::from_rgb(0xcc, 0x47, 0x78),
+    Color32::from_rgb(0xe5, 0x6b, 0x5d),
+    Color32::from_rgb(0xf8, 0x94, 0x41),
+    Color32::from_rgb(0xfd, 0xc3, 0x28),
+    Color32::from_rgb(0xf0, 0xf9, 0x21),
+];
+
+impl Colormap {
+    /// The color at `t`, from `0.0` for the lowest value to `1.0` for the highest.
+    ///
+    /// `t` is clamped to that range. A [`Self::Gradient`] without colors is transparent.
+    pub fn color_at(&self, t: f64) -> Color32 {
+        match self {
+            Self::Viridis => interpolate(&VIRIDIS, t),
+            Self::Plasma => interpolate(&PLASMA, t),
+            Self::Gradient(colors) => interpolate(colors, t),
+        }
+    }
+}
+
+fn interpolate(colors: &[Color32], t: f64) -> Color32 {
+    let last = match colors.len().checked_sub(1) {
+        Some(0) => return colors[0],
+        Some(last) => last,
+        None => return Color32::TRANSPARENT,
+    };
+    let t = if t.is_nan() { 0.0 } else { t.clamp(0.0, 1.0) };
+    let position = t * last as f64;
+    let index = (position.floor() as usize).min(last - 1);
+    let fraction = (position - index as f64) as f32;
+    let (from, to) = (colors[index], colors[index + 1]);
+    let channel = |i: usize| {
+        let (from, to) = (from[i] as f32, to[i] as f32);
+        (from + (to - from) * fraction).round() as u8
+    };
+    Color32::from_rgba_premultiplied(channel(0), channel(1), channel(2), channel(3))
+}
+
+#[test]
+fn test_colormap() {
+    assert_eq!(Colormap::Viridis.color_at(0.0), VIRIDIS[0]);
+    assert_eq!(Colormap::Viridis.color_at(2.0), VIRIDIS[8]);
+    assert_eq!(Colormap::Plasma.color_at(0.5), PLASMA[4]);
+
+    let gradient = Colormap::Gradient(vec![Color32::BLACK, Color32::WHITE]);
+    assert_eq!(gradient.color_at(0.5), Color32::from_gray(128));
+    assert_eq!(gradient.color_at(f64::NAN), Color32::BLACK);
+    assert_eq!(
+        Colormap::Gradient(vec![Color32::RED]).color_at(0.7),
+        Color32::RED
+    );
+    assert_eq!(
+        Colormap::Gradient(vec![]).color_at(0.7),
+        Color32::TRANSPARENT
+    );
+}
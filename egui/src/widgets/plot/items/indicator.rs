@@ -0,0 +1,157 @@
+use super::CandleElem;
+
+/// A technical indicator that a [`crate::plot::ChartPlot`] computes from its candles and overlays,
+/// see [`crate::plot::ChartPlot::with_indicator`].
+///
+/// The candles must be sorted by x.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Indicator {
+    /// Simple moving average of the close over this many candles.
+    Sma(usize),
+
+    /// Exponential moving average of the close over this many candles,
+    /// starting with the simple moving average of the first of them.
+    Ema(usize),
+
+    /// Bollinger bands: the simple moving average of the close over `period` candles,
+    /// and the lines `k` standard deviations above and below it.
+    Bollinger { period: usize, k: f64 },
+
+    /// Volume weighted average price, of the typical price `(high + low + close) / 3`
+    /// since the first candle.
+    Vwap,
+}
+
+impl Indicator {
+    /// E.g. `"SMA 20"` or `"BB 20 2"`. Used for the legend.
+    pub fn name(&self) -> String {
+        match self {
+            Self::Sma(period) => format!("SMA {}", period),
+            Self::Ema(period) => format!("EMA {}", period),
+            Self::Bollinger { period, k } => format!("BB {} {}", period, k),
+            Self::Vwap => "VWAP".to_owned(),
+        }
+    }
+
+    /// The lines of the indicator. Bollinger bands have three: the average, the upper and the lower band.
+    pub(crate) fn lines(&self, candles: &[CandleElem]) -> Vec<Vec<[f64; 2]>> {
+        match *self {
+            Self::Sma(period) => vec![sma(candles, period)],
+            Self::Ema(period) => vec![ema(candles, period)],
+            Self::Bollinger { period, k } => {
+                let middle = sma(candles, period);
+                let deviations = std_dev(candles, period);
+                let band = |sign: f64| -> Vec<[f64; 2]> {
+                    middle
+                        .iter()
+                        .zip(&deviations)
+                        .map(|(&[x, mean], deviation)| [x, mean + sign * k * deviation])
+                        .collect()
+                };
+                let upper = band(1.0);
+                let lower = band(-1.0);
+                vec![middle, upper, lower]
+            }
+            Self::Vwap => vec![vwap(candles)],
+        }
+    }
+}
+
+/// The windows of `period` candles, with the x of their last candle.
+fn windows(candles: &[CandleElem], period: usize) -> impl Iterator<Item = (f64, &[CandleElem])> {
+    // `windows` panics on zero:
+    let period = if period == 0 {
+        candles.len() + 1
+    } else {
+        period
+    };
+    candles
+        .windows(period)
+        .map(|window| (window[window.len() - 1].x, window))
+}
+
+fn mean_close(window: &[CandleElem]) -> f64 {
+    window.iter().map(|c| c.candle.close).sum::<f64>() / window.len() as f64
+}
+
+fn sma(candles: &[CandleElem], period: usize) -> Vec<[f64; 2]> {
+    windows(candles, period)
+        .map(|(x, window)| [x, mean_close(window)])
+        .collect()
+}
+
+fn std_dev(candles: &[CandleElem], period: usize) -> Vec<f64> {
+    windows(candles, period)
+        .map(|(_, window)| {
+            let mean = mean_close(window);
+            let variance = window
+                .iter()
+                .map(|c| (c.candle.close - mean).powi(2))
+                .sum::<f64>()
+                / window.len() as f64;
+            variance.sqrt()
+        })
+        .collect()
+}
+
+fn ema(candles: &[CandleElem], period: usize) -> Vec<[f64; 2]> {
+    if period == 0 || candles.len() < period {
+        return vec![];
+    }
+    let alpha = 2.0 / (period as f64 + 1.0);
+    let mut average = mean_close(&candles[..period]);
+    let mut points = vec![[candles[period - 1].x, average]];
+    for c in &candles[period..] {
+        average += alpha * (c.candle.close - average);
+        points.push([c.x, average]);
+    }
+    points
+}
+
+fn vwap(candles: &[CandleElem]) -> Vec<[f64; 2]> {
+    let mut price_volume = 0.0;
+    let mut volume = 0.0;
+    candles
+        .iter()
+        .filter_map(|c| {
+            let typical_price = (c.candle.high + c.candle.low + c.candle.close) / 3.0;
+            price_volume += typical_price * c.candle.volume;
+            volume += c.candle.volume;
+            (volume > 0.0).then(|| [c.x, price_volume / volume])
+        })
+        .collect()
+}
+
+#[test]
+fn test_indicators() {
+    use super::Candle;
+
+    let candles: Vec<CandleElem> = [1.0, 2.0, 3.0, 4.0]
+        .iter()
+        .enumerate()
+        .map(|(i, &close)| {
+            let mut elem = CandleElem::new(Candle::new(close, close, close, close, 1.0));
+            elem.x = i as f64;
+            elem
+        })
+        .collect();
+
+    assert_eq!(
+        Indicator::Sma(2).lines(&candles),
+        vec![vec![[1.0, 1.5], [2.0, 2.5], [3.0, 3.5]]]
+    );
+    assert_eq!(
+        Indicator::Ema(3).lines(&candles),
+        vec![vec![[2.0, 2.0], [3.0, 3.0]]]
+    );
+    assert_eq!(
+        Indicator::Bollinger { period: 2, k: 2.0 }.lines(&candles)[1],
+        vec![[1.0, 2.5], [2.0, 3.5], [3.0, 4.5]]
+    );
+    assert_eq!(
+        Indicator::Vwap.lines(&candles),
+        vec![vec![[0.0, 1.0], [1.0, 1.5], [2.0, 2.0], [3.0, 2.5]]]
+    );
+    assert!(Indicator::Sma(0).lines(&candles)[0].is_empty());
+    assert!(Indicator::Ema(5).lines(&candles)[0].is_empty());
+}
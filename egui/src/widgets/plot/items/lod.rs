@@ -0,0 +1,264 @@
+use std::cell::RefCell;
+use std::ops::Range;
+
+use epaint::util::FloatOrd;
+
+use super::{Candle, CandleElem, PlotPoint};
+use crate::plot::{AxisScale, ScreenTransform};
+
+/// How many values may fall into the same point-wide column of the plot before they are merged.
+pub(super) const DEFAULT_LOD_THRESHOLD: usize = 4;
+
+/// Values that can be merged when too many of them fall into the same column of the plot.
+pub(super) trait LodValue: Clone {
+    fn x(&self) -> f64;
+
+    /// Add what is left of a run of values in the same column to `out`,
+    /// each with the range of indices in `run` of the values it stands for.
+    fn merge_run(run: &[Self], out: &mut Vec<(Self, Range<usize>)>);
+}
+
+/// Keeps the first, lowest, highest and last value, which draws the same line.
+impl LodValue for PlotPoint {
+    fn x(&self) -> f64 {
+        self.x
+    }
+
+    fn merge_run(run: &[Self], out: &mut Vec<(Self, Range<usize>)>) {
+        let lowest = (0..run.len()).min_by_key(|&i| run[i].y.ord());
+        let highest = (0..run.len()).max_by_key(|&i| run[i].y.ord());
+        let mut indices = [Some(0), lowest, highest, Some(run.len() - 1)];
+        indices.sort_unstable();
+        let mut last = None;
+        for i in indices.into_iter().flatten() {
+            if last != Some(i) {
+                out.push((run[i], i..i + 1));
+                last = Some(i);
+            }
+        }
+    }
+}
+
+/// One candle from the open of the first to the close of the last, spanning all of them.
+impl LodValue for CandleElem {
+    fn x(&self) -> f64 {
+        self.x
+    }
+
+    fn merge_run(run: &[Self], out: &mut Vec<(Self, Range<usize>)>) {
+        let (first, last) = (&run[0], &run[run.len() - 1]);
+        let high = run
+            .iter()
+            .map(|c| c.candle.high)
+            .fold(f64::NEG_INFINITY, f64::max);
+        let low = run
+            .iter()
+            .map(|c| c.candle.low)
+            .fold(f64::INFINITY, f64::min);
+        let volume = run.iter().map(|c| c.candle.volume).sum();
+        let mut merged = first.clone();
+        merged.x = (first.x + last.x) / 2.0;
+        merged.candle = Candle::new(first.candle.open, high, low, last.candle.close, volume);
+        merged.candle_width = (last.x - first.x).abs() + first.candle_width;
+        out.push((merged, 0..run.len()));
+    }
+}
+
+/// What the merged values depend on: the visible x range, and where it is on the screen.
+#[derive(Clone, Copy, PartialEq)]
+struct LodKey {
+    x_range: (f64, f64),
+    frame_x: (f32, f32),
+    x_scale: AxisScale,
+}
+
+impl LodKey {
+    fn new(transform: &ScreenTransform) -> Self {
+        let bounds = transform.bounds();
+        let frame = transform.frame();
+        Self {
+            x_range: (bounds.min[0], bounds.max[0]),
+            frame_x: (frame.left(), frame.right()),
+            x_scale: transform.scales()[0],
+        }
+    }
+}
+
+/// The merged values, each with the range of indices of the values it stands for.
+struct Merged<T> {
+    values: Vec<T>,
+    sources: Vec<Range<usize>>,
+}
+
+/// Level of detail: merges the values of an item when too many fall into the same column of the plot.
+///
+/// The merged values are kept for the zoom level they were made for, so that hovering and
+/// highlighting the item don't merge them again.
+pub(super) struct Lod<T> {
+    pub threshold: usize,
+    cache: RefCell<Option<(LodKey, Option<Merged<T>>)>>,
+}
+
+impl<T: LodValue> Default for Lod<T> {
+    fn default() -> Self {
+        Self {
+            threshold: DEFAULT_LOD_THRESHOLD,
+            cache: RefCell::new(None),
+        }
+    }
+}
+
+impl<T: LodValue> Lod<T> {
+    /// Calls `f` with the values to draw at the zoom level of `transform`: merged, if needed.
+    pub fn with<R>(
+        &self,
+        values: &[T],
+        transform: &ScreenTransform,
+        f: impl FnOnce(&[T]) -> R,
+    ) -> R {
+        self.update(values, transform);
+        let cache = self.cache.borrow();
+        let merged = cache.as_ref().and_then(|(_, merged)| merged.as_ref());
+        f(merged.map_or(values, |merged| &merged.values))
+    }
+
+    /// The range of indices in `values` of the ones that the value at `index` of [`Self::with`] stands for.
+    pub fn sources(&self, values: &[T], transform: &ScreenTransform, index: usize) -> Range<usize> {
+        self.update(values, transform);
+        let cache = self.cache.borrow();
+        cache
+            .as_ref()
+            .and_then(|(_, merged)| merged.as_ref())
+            .and_then(|merged| merged.sources.get(index).cloned())
+            .unwrap_or(index..index + 1)
+    }
+
+    /// Merges the values again if they were merged for another zoom level.
+    fn update(&self, values: &[T], transform: &ScreenTransform) {
+        let key = LodKey::new(transform);
+        let mut cache = self.cache.borrow_mut();
+        if !matches!(&*cache, Some((cached_key, _)) if *cached_key == key) {
+            *cache = Some((key, merge(values, transform, self.threshold)));
+        }
+    }
+}
+
+/// The values with each run of more than `threshold` of them in the same column merged,
+/// or `None` if there is no such run.
+fn merge<T: LodValue>(
+    values: &[T],
+    transform: &ScreenTransform,
+    threshold: usize,
+) -> Option<Merged<T>> {
+    let runs = column_runs(values, transform);
+    if runs.iter().all(|run| run.len() <= threshold) {
+        return None;
+    }
+    let mut merged = Merged {
+        values: Vec::new(),
+        sources: Vec::new(),
+    };
+    let mut run_merged = Vec::new();
+    for run in runs {
+        let start = run.start;
+        if run.len() <= threshold {
+            merged.values.extend_from_slice(&values[run.clone()]);
+            merged.sources.extend(run.map(|i| i..i + 1));
+        } else {
+            T::merge_run(&values[run], &mut run_merged);
+            for (value, sources) in run_merged.drain(..) {
+                merged.values.push(value);
+                merged
+                    .sources
+                    .push(start + sources.start..start + sources.end);
+            }
+        }
+    }
+    Some(merged)
+}
+
+/// Splits the values into runs of consecutive values in the same column of the plot.
+///
+/// Everything beyond either edge of the plot counts as one column, since it isn't seen anyway.
+fn column_runs<T: LodValue>(values: &[T], transform: &ScreenTransform) -> Vec<Range<usize>> {
+    let frame = transform.frame();
+    let (left, right) = (frame.left().floor() - 1.0, frame.right().floor());
+    let mut runs: Vec<Range<usize>> = Vec::new();
+    let mut last_column = None;
+    for (i, value) in values.iter().enumerate() {
+        let x = transform
+            .position_from_point(&PlotPoint::new(value.x(), 0.0))
+            .x;
+        let column = x.floor().clamp(left, right);
+        match runs.last_mut() {
+            Some(run) if last_column == Some(column) => run.end = i + 1,
+            _ => {
+                runs.push(i..i + 1);
+                last_column = Some(column);
+            }
+        }
+    }
+    runs
+}
+
+#[test]
+fn test_lod() {
+    use crate::plot::PlotBounds;
+    use crate::{pos2, Rect};
+
+    let frame = Rect::from_min_max(pos2(0.0, 0.0), pos2(10.0, 10.0));
+    let bounds = PlotBounds {
+        min: [0.0, -1.0],
+        max: [10.0, 1.0],
+    };
+    let transform = ScreenTransform::new(frame, bounds, false, false, [AxisScale::Linear; 2]);
+
+    // 100 values in each column, with the extremes in the middle:
+    let points: Vec<PlotPoint> = (0..1000)
+        .map(|i| {
+            let y = match i % 100 {
+                40 => 1.0,
+                60 => -1.0,
+                _ => 0.0,
+            };
+            PlotPoint::new(i as f64 / 100.0, y)
+        })
+        .collect();
+    let merged = merge(&points, &transform, DEFAULT_LOD_THRESHOLD).unwrap();
+    assert_eq!(merged.values.len(), 40);
+    assert_eq!(
+        &merged.values[..4],
+        &[points[0], points[40], points[60], points[99]]
+    );
+    assert_eq!(
+        &merged.sources[..5],
+        &[0..1, 40..41, 60..61, 99..100, 100..101]
+    );
+
+    let candles: Vec<CandleElem> = (0..1000)
+        .map(|i| {
+            let close = i as f64;
+            let mut elem = CandleElem::new(Candle::new(
+                close - 0.5,
+                close + 1.0,
+                close - 1.0,
+                close,
+                1.0,
+            ));
+            elem.x = i as f64 / 100.0;
+            elem
+        })
+        .collect();
+    let lod = Lod::default();
+    let merged = lod.with(&candles, &transform, |merged| merged.to_vec());
+    assert_eq!(merged.len(), 10);
+    assert_eq!(
+        merged[0].candle,
+        Candle::new(-0.5, 100.0, -1.0, 99.0, 100.0)
+    );
+    // Each merged candle is found back among the candles it stands for:
+    assert_eq!(lod.sources(&candles, &transform, 1), 100..200);
+
+    // Few enough values are drawn as they are:
+    assert!(merge(&points[..4], &transform, DEFAULT_LOD_THRESHOLD).is_none());
+}
@@ -1,24 +1,39 @@
 //! Contains items that can be added to a plot.
 
-use std::ops::RangeInclusive;
+use std::ops::{Range, RangeInclusive};
 
 use epaint::util::FloatOrd;
-use epaint::Mesh;
+use epaint::{Instance, InstancedMesh, Mesh, Tessellator};
 
 use crate::*;
 
-use super::{LabelFormatter, PlotBounds, ScreenTransform};
+use super::series::DrawKey;
+use super::{LabelFormatter, PlotBounds, PlotSeriesHandle, ScreenTransform, TimeFormat, YAxis};
+use lod::Lod;
 use rect_elem::*;
-use values::{ClosestElem, PlotGeometry};
+use values::ClosestElem;
+pub(super) use values::PlotGeometry;
 
 pub use bar::Bar;
 pub use box_elem::{BoxElem, BoxSpread};
 pub use candle_elem::{Candle, CandleElem};
-pub use values::{LineStyle, MarkerShape, Orientation, PlotPoint, PlotPoints};
+pub use candle_style::CandleStyle;
+pub use colormap::Colormap;
+pub use indicator::Indicator;
+pub use ohlc_elem::OhlcElem;
+pub use values::{
+    LineStyle, MarkerShape, Orientation, PlotElement, PlotElementValue, PlotPoint, PlotPoints,
+    Steps,
+};
 
 mod bar;
 mod box_elem;
 mod candle_elem;
+mod candle_style;
+mod colormap;
+mod indicator;
+mod lod;
+mod ohlc_elem;
 mod rect_elem;
 mod values;
 
@@ -30,6 +45,9 @@ pub(super) struct PlotConfig<'a> {
     pub transform: &'a ScreenTransform,
     pub show_x: bool,
     pub show_y: bool,
+    pub x_axis_time: Option<TimeFormat>,
+    /// The units to append to the x and y values, if not empty.
+    pub units: &'a [String; 2],
 }
 
 /// Trait shared by things that can be drawn in the plot.
@@ -50,19 +68,41 @@ pub(super) trait PlotItem {
 
     fn bounds(&self) -> PlotBounds;
 
+    /// The bounds of the part of the item that lies within the given x range.
+    ///
+    /// Used to fit the y axis when a [`ViewPreset`] is active.
+    fn bounds_within_x(&self, _x_range: RangeInclusive<f64>) -> PlotBounds {
+        self.bounds()
+    }
+
+    /// The view presets to show in the plot toolbar, if any.
+    fn view_presets(&self) -> &[ViewPreset] {
+        &[]
+    }
+
+    /// Can the elements of this item be dragged with the pointer?
+    fn draggable(&self) -> bool {
+        false
+    }
+
+    /// Move the point at `index` to `point`, while the user drags it, see [`Self::draggable`].
+    fn drag_point(&mut self, _index: usize, _point: PlotPoint) {}
+
+    /// The y axis that the values of this item are on, see [`super::PlotUi::set_y_axis`].
+    fn y_axis(&self) -> YAxis {
+        YAxis::Left
+    }
+
+    /// The volume of each element, for the volume pane of the plot, see [`super::Plot::volume_pane`].
+    fn volume_bars(&self) -> Vec<Bar> {
+        Vec::new()
+    }
+
     fn find_closest(&self, point: Pos2, transform: &ScreenTransform) -> Option<ClosestElem> {
         match self.geometry() {
             PlotGeometry::None => None,
 
-            PlotGeometry::Points(points) => points
-                .iter()
-                .enumerate()
-                .map(|(index, value)| {
-                    let pos = transform.position_from_point(value);
-                    let dist_sq = point.distance_sq(pos);
-                    ClosestElem { index, dist_sq }
-                })
-                .min_by_key(|e| e.dist_sq.ord()),
+            PlotGeometry::Points(points) => find_closest_point(points, point, transform),
 
             PlotGeometry::Rects => {
                 panic!("If the PlotItem is made of rects, it should implement find_closest()")
@@ -70,6 +110,39 @@ pub(super) trait PlotItem {
         }
     }
 
+    /// Like [`Self::find_closest`], but only by the distance along the x axis.
+    ///
+    /// Used for the highlight of a [`super::LinkGroup`].
+    fn find_closest_x(&self, x: f32, transform: &ScreenTransform) -> Option<ClosestElem> {
+        match self.geometry() {
+            PlotGeometry::Points(points) => find_closest_point_x(points, x, transform),
+            PlotGeometry::None | PlotGeometry::Rects => None,
+        }
+    }
+
+    /// The value of the element at `index` of [`Self::find_closest`], as it is shown with `transform`.
+    fn element_value(
+        &self,
+        index: usize,
+        _transform: &ScreenTransform,
+    ) -> Option<PlotElementValue> {
+        match self.geometry() {
+            PlotGeometry::Points(points) => points.get(index).copied().map(PlotElementValue::Point),
+            PlotGeometry::None | PlotGeometry::Rects => None,
+        }
+    }
+
+    /// The elements, as they were given to the item, that the element at `index` of
+    /// [`Self::find_closest`] stands for with `transform`: the range of their indices, and the value of the first.
+    fn source_element(
+        &self,
+        index: usize,
+        transform: &ScreenTransform,
+    ) -> Option<(Range<usize>, PlotElementValue)> {
+        self.element_value(index, transform)
+            .map(|value| (index..index + 1, value))
+    }
+
     fn on_hover(
         &self,
         elem: ClosestElem,
@@ -104,12 +177,106 @@ pub(super) trait PlotItem {
 
 // ----------------------------------------------------------------------------
 
+/// An item on the [`YAxis::Right`], drawn and hovered with the transform of that axis.
+pub(super) struct OnRightYAxis(pub Box<dyn PlotItem>);
+
+impl PlotItem for OnRightYAxis {
+    fn shapes(&self, ui: &mut Ui, transform: &ScreenTransform, shapes: &mut Vec<Shape>) {
+        self.0.shapes(ui, transform, shapes);
+    }
+
+    fn initialize(&mut self, x_range: RangeInclusive<f64>) {
+        self.0.initialize(x_range);
+    }
+
+    fn name(&self) -> &str {
+        self.0.name()
+    }
+
+    fn color(&self) -> Color32 {
+        self.0.color()
+    }
+
+    fn highlight(&mut self) {
+        self.0.highlight();
+    }
+
+    fn highlighted(&self) -> bool {
+        self.0.highlighted()
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        self.0.geometry()
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        self.0.bounds()
+    }
+
+    fn bounds_within_x(&self, x_range: RangeInclusive<f64>) -> PlotBounds {
+        self.0.bounds_within_x(x_range)
+    }
+
+    fn view_presets(&self) -> &[ViewPreset] {
+        self.0.view_presets()
+    }
+
+    fn draggable(&self) -> bool {
+        self.0.draggable()
+    }
+
+    fn drag_point(&mut self, index: usize, point: PlotPoint) {
+        self.0.drag_point(index, point);
+    }
+
+    fn y_axis(&self) -> YAxis {
+        YAxis::Right
+    }
+
+    fn volume_bars(&self) -> Vec<Bar> {
+        self.0.volume_bars()
+    }
+
+    fn find_closest(&self, point: Pos2, transform: &ScreenTransform) -> Option<ClosestElem> {
+        self.0.find_closest(point, transform)
+    }
+
+    fn find_closest_x(&self, x: f32, transform: &ScreenTransform) -> Option<ClosestElem> {
+        self.0.find_closest_x(x, transform)
+    }
+
+    fn element_value(&self, index: usize, transform: &ScreenTransform) -> Option<PlotElementValue> {
+        self.0.element_value(index, transform)
+    }
+
+    fn source_element(
+        &self,
+        index: usize,
+        transform: &ScreenTransform,
+    ) -> Option<(Range<usize>, PlotElementValue)> {
+        self.0.source_element(index, transform)
+    }
+
+    fn on_hover(
+        &self,
+        elem: ClosestElem,
+        shapes: &mut Vec<Shape>,
+        plot: &PlotConfig<'_>,
+        label_formatter: &LabelFormatter,
+    ) {
+        self.0.on_hover(elem, shapes, plot, label_formatter);
+    }
+}
+
+// ----------------------------------------------------------------------------
+
 /// A horizontal line in a plot, filling the full width
 #[derive(Clone, Debug, PartialEq)]
 pub struct HLine {
     pub(super) y: f64,
     pub(super) stroke: Stroke,
     pub(super) name: String,
+    pub(super) label: String,
     pub(super) highlight: bool,
     pub(super) style: LineStyle,
 }
@@ -120,6 +287,7 @@ impl HLine {
             y: y.into(),
             stroke: Stroke::new(1.0, Color32::TRANSPARENT),
             name: String::default(),
+            label: String::default(),
             highlight: false,
             style: LineStyle::Solid,
         }
@@ -155,6 +323,15 @@ impl HLine {
         self
     }
 
+    /// A text next to the line, at the right edge of the plot, in the color of the line.
+    ///
+    /// Unlike the name, it isn't shown in the legend.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn label(mut self, label: impl ToString) -> Self {
+        self.label = label.to_string();
+        self
+    }
+
     /// Name of this horizontal line.
     ///
     /// This name will show up in the plot legend, if legends are turned on.
@@ -169,10 +346,11 @@ impl HLine {
 }
 
 impl PlotItem for HLine {
-    fn shapes(&self, _ui: &mut Ui, transform: &ScreenTransform, shapes: &mut Vec<Shape>) {
+    fn shapes(&self, ui: &mut Ui, transform: &ScreenTransform, shapes: &mut Vec<Shape>) {
         let HLine {
             y,
             stroke,
+            label,
             highlight,
             style,
             ..
@@ -181,7 +359,16 @@ impl PlotItem for HLine {
             transform.position_from_point(&PlotPoint::new(transform.bounds().min[0], *y)),
             transform.position_from_point(&PlotPoint::new(transform.bounds().max[0], *y)),
         ];
+        let label_pos = points[1] + vec2(-4.0, -2.0);
         style.style_line(points, *stroke, *highlight, shapes);
+        add_label(
+            ui,
+            label,
+            label_pos,
+            Align2::RIGHT_BOTTOM,
+            stroke.color,
+            shapes,
+        );
     }
 
     fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
@@ -220,6 +407,7 @@ pub struct VLine {
     pub(super) x: f64,
     pub(super) stroke: Stroke,
     pub(super) name: String,
+    pub(super) label: String,
     pub(super) highlight: bool,
     pub(super) style: LineStyle,
 }
@@ -230,6 +418,7 @@ impl VLine {
             x: x.into(),
             stroke: Stroke::new(1.0, Color32::TRANSPARENT),
             name: String::default(),
+            label: String::default(),
             highlight: false,
             style: LineStyle::Solid,
         }
@@ -265,6 +454,15 @@ impl VLine {
         self
     }
 
+    /// A text next to the line, at the top of the plot, in the color of the line.
+    ///
+    /// Unlike the name, it isn't shown in the legend.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn label(mut self, label: impl ToString) -> Self {
+        self.label = label.to_string();
+        self
+    }
+
     /// Name of this vertical line.
     ///
     /// This name will show up in the plot legend, if legends are turned on.
@@ -279,10 +477,11 @@ impl VLine {
 }
 
 impl PlotItem for VLine {
-    fn shapes(&self, _ui: &mut Ui, transform: &ScreenTransform, shapes: &mut Vec<Shape>) {
+    fn shapes(&self, ui: &mut Ui, transform: &ScreenTransform, shapes: &mut Vec<Shape>) {
         let VLine {
             x,
             stroke,
+            label,
             highlight,
             style,
             ..
@@ -291,7 +490,9 @@ impl PlotItem for VLine {
             transform.position_from_point(&PlotPoint::new(*x, transform.bounds().min[1])),
             transform.position_from_point(&PlotPoint::new(*x, transform.bounds().max[1])),
         ];
+        let label_pos = points[1] + vec2(4.0, 2.0);
         style.style_line(points, *stroke, *highlight, shapes);
+        add_label(ui, label, label_pos, Align2::LEFT_TOP, stroke.color, shapes);
     }
 
     fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
@@ -332,6 +533,9 @@ pub struct Line {
     pub(super) highlight: bool,
     pub(super) fill: Option<f32>,
     pub(super) style: LineStyle,
+    pub(super) steps: Option<Steps>,
+    pub(super) draggable: bool,
+    lod: Lod<PlotPoint>,
 }
 
 impl Line {
@@ -343,9 +547,17 @@ impl Line {
             highlight: false,
             fill: None,
             style: LineStyle::Solid,
+            steps: None,
+            draggable: false,
+            lod: Lod::default(),
         }
     }
 
+    /// The values of a series that is kept between frames, see [`PlotSeriesHandle`].
+    pub fn from_series(series: &PlotSeriesHandle<PlotPoint>) -> Self {
+        Self::new(PlotPoints::Owned(series.values()))
+    }
+
     /// Highlight this line in the plot by scaling up the line.
     pub fn highlight(mut self, highlight: bool) -> Self {
         self.highlight = highlight;
@@ -382,6 +594,32 @@ impl Line {
         self
     }
 
+    /// When more than this many consecutive values fall into the same point-wide column of the
+    /// plot, only the first, lowest, highest and last of them are drawn. This looks the same,
+    /// but keeps a line of millions of values fast. Hovering still finds every value.
+    ///
+    /// Default is 4. `usize::MAX` draws all values.
+    pub fn lod_threshold(mut self, threshold: usize) -> Self {
+        self.lod.threshold = threshold;
+        self
+    }
+
+    /// Draw the line as steps from one value to the next, for piecewise-constant series like
+    /// positions, rates or a cumulative histogram. The fill and hovering follow the steps.
+    pub fn steps(mut self, steps: Steps) -> Self {
+        self.steps = Some(steps);
+        self
+    }
+
+    /// Let the user drag the values of this line with the pointer, e.g. for a curve editor.
+    /// The edits are reported by [`super::PlotResponse::dragged_point`].
+    ///
+    /// Values from a function can't be dragged. Default is `false`.
+    pub fn draggable(mut self, draggable: bool) -> Self {
+        self.draggable = draggable;
+        self
+    }
+
     /// Name of this line.
     ///
     /// This name will show up in the plot legend, if legends are turned on.
@@ -410,14 +648,24 @@ impl PlotItem for Line {
             highlight,
             mut fill,
             style,
+            steps,
+            lod,
             ..
         } = self;
 
-        let values_tf: Vec<_> = series
-            .points()
-            .iter()
-            .map(|v| transform.position_from_point(v))
-            .collect();
+        let values_tf: Vec<_> = lod.with(series.points(), transform, |points| {
+            let stairs;
+            let points = if let Some(steps) = steps {
+                stairs = steps.stairs(points);
+                &stairs[..]
+            } else {
+                points
+            };
+            points
+                .iter()
+                .map(|v| transform.position_from_point(v))
+                .collect()
+        });
         let n_values = values_tf.len();
 
         // Fill the area between the line and a reference line, if required.
@@ -489,6 +737,57 @@ impl PlotItem for Line {
     fn bounds(&self) -> PlotBounds {
         self.series.bounds()
     }
+
+    fn find_closest(&self, point: Pos2, transform: &ScreenTransform) -> Option<ClosestElem> {
+        let points = self.series.points();
+        match self.steps {
+            // The closest value is the one of the closest step.
+            Some(steps) => (0..points.len())
+                .map(|index| {
+                    let (start, end) = steps.x_range(points, index);
+                    let y = points[index].y;
+                    let start = transform.position_from_point(&PlotPoint::new(start, y));
+                    let end = transform.position_from_point(&PlotPoint::new(end, y));
+                    let dx = (start.x.min(end.x) - point.x).max(point.x - start.x.max(end.x));
+                    let dy = point.y - start.y;
+                    ClosestElem {
+                        index,
+                        dist_sq: dx.at_least(0.0).powi(2) + dy * dy,
+                    }
+                })
+                .min_by_key(|e| e.dist_sq.ord()),
+            None => find_closest_point(points, point, transform),
+        }
+    }
+
+    fn find_closest_x(&self, x: f32, transform: &ScreenTransform) -> Option<ClosestElem> {
+        let steps = match self.steps {
+            Some(steps) => steps,
+            None => return find_closest_point_x(self.series.points(), x, transform),
+        };
+        // The value of the step that `x` is on.
+        let points = self.series.points();
+        (0..points.len())
+            .map(|index| {
+                let (start, end) = steps.x_range(points, index);
+                let start = transform.position_from_point(&PlotPoint::new(start, 0.0)).x;
+                let end = transform.position_from_point(&PlotPoint::new(end, 0.0)).x;
+                let dx = (start.min(end) - x).max(x - start.max(end)).at_least(0.0);
+                ClosestElem {
+                    index,
+                    dist_sq: dx * dx,
+                }
+            })
+            .min_by_key(|e| e.dist_sq.ord())
+    }
+
+    fn draggable(&self) -> bool {
+        self.draggable
+    }
+
+    fn drag_point(&mut self, index: usize, point: PlotPoint) {
+        self.series.set_point(index, point);
+    }
 }
 
 /// A convex polygon.
@@ -630,6 +929,7 @@ pub struct Text {
     pub(super) highlight: bool,
     pub(super) color: Color32,
     pub(super) anchor: Align2,
+    pub(super) offset: Vec2,
 }
 
 impl Text {
@@ -641,6 +941,7 @@ impl Text {
             highlight: false,
             color: Color32::TRANSPARENT,
             anchor: Align2::CENTER_CENTER,
+            offset: Vec2::ZERO,
         }
     }
 
@@ -662,6 +963,13 @@ impl Text {
         self
     }
 
+    /// Move the text this far away from its position, in points, e.g. to label a point
+    /// without covering it. It stays this far away when zooming.
+    pub fn offset(mut self, offset: impl Into<Vec2>) -> Self {
+        self.offset = offset.into();
+        self
+    }
+
     /// Name of this text.
     ///
     /// This name will show up in the plot legend, if legends are turned on.
@@ -688,7 +996,7 @@ impl PlotItem for Text {
                 .clone()
                 .into_galley(ui, Some(false), f32::INFINITY, TextStyle::Small);
 
-        let pos = transform.position_from_point(&self.position);
+        let pos = transform.position_from_point(&self.position) + self.offset;
         let rect = self
             .anchor
             .anchor_rect(Rect::from_min_size(pos, galley.size()));
@@ -750,6 +1058,13 @@ pub struct Points {
     pub(super) name: String,
     pub(super) highlight: bool,
     pub(super) stems: Option<f32>,
+    pub(super) draggable: bool,
+    /// A value per point that decides the color, and maybe the radius, of its marker.
+    pub(super) point_values: Vec<f64>,
+    pub(super) colormap: Colormap,
+    pub(super) value_range: Option<RangeInclusive<f64>>,
+    pub(super) radius_range: Option<RangeInclusive<f32>>,
+    pub(super) color_bar: bool,
 }
 
 impl Points {
@@ -763,6 +1078,12 @@ impl Points {
             name: Default::default(),
             highlight: false,
             stems: None,
+            draggable: false,
+            point_values: Vec::new(),
+            colormap: Colormap::default(),
+            value_range: None,
+            radius_range: None,
+            color_bar: false,
         }
     }
 
@@ -802,6 +1123,73 @@ impl Points {
         self
     }
 
+    /// A value for each point, in the same order, that picks the color of its marker from
+    /// [`Self::colormap`], e.g. the density or the intensity at the point.
+    ///
+    /// Points without a value, or with a `NaN` value, keep the color of the item.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui::plot::{Colormap, Plot, PlotPoints, Points};
+    /// let points: PlotPoints = (0..100).map(|i| [i as f64, (i as f64 * 0.1).sin()]).collect();
+    /// let intensity = (0..100).map(|i| i as f64).collect();
+    ///
+    /// Plot::new("intensity").show(ui, |plot_ui| {
+    ///     plot_ui.points(
+    ///         Points::new(points)
+    ///             .point_values(intensity)
+    ///             .colormap(Colormap::Plasma)
+    ///             .radius_range(1.0..=5.0)
+    ///             .color_bar(true),
+    ///     );
+    /// });
+    /// # });
+    /// ```
+    pub fn point_values(mut self, values: Vec<f64>) -> Self {
+        self.point_values = values;
+        self
+    }
+
+    /// How the [`Self::point_values`] are turned into colors. Default: [`Colormap::Viridis`].
+    pub fn colormap(mut self, colormap: Colormap) -> Self {
+        self.colormap = colormap;
+        self
+    }
+
+    /// The point values at the ends of the colormap, and of the [`Self::radius_range`].
+    /// Values outside of it get the color and the radius of its ends.
+    ///
+    /// Default: the lowest and the highest value.
+    pub fn value_range(mut self, value_range: RangeInclusive<f64>) -> Self {
+        self.value_range = Some(value_range);
+        self
+    }
+
+    /// Also scale the markers with their [`Self::point_values`], from the smallest radius
+    /// at the lowest value to the largest at the highest. Default: all markers have [`Self::radius`].
+    pub fn radius_range(mut self, radius_range: RangeInclusive<f32>) -> Self {
+        self.radius_range = Some(radius_range);
+        self
+    }
+
+    /// Show the colormap of the [`Self::point_values`] and the values at its ends
+    /// along the right edge of the plot.
+    ///
+    /// Default: `false`.
+    pub fn color_bar(mut self, color_bar: bool) -> Self {
+        self.color_bar = color_bar;
+        self
+    }
+
+    /// Let the user drag these points with the pointer.
+    /// The edits are reported by [`super::PlotResponse::dragged_point`].
+    ///
+    /// Points from a function can't be dragged. Default is `false`.
+    pub fn draggable(mut self, draggable: bool) -> Self {
+        self.draggable = draggable;
+        self
+    }
+
     /// Name of this set of points.
     ///
     /// This name will show up in the plot legend, if legends are turned on.
@@ -816,131 +1204,69 @@ impl Points {
 }
 
 impl PlotItem for Points {
-    fn shapes(&self, _ui: &mut Ui, transform: &ScreenTransform, shapes: &mut Vec<Shape>) {
-        let sqrt_3 = 3_f32.sqrt();
-        let frac_sqrt_3_2 = 3_f32.sqrt() / 2.0;
-        let frac_1_sqrt_2 = 1.0 / 2_f32.sqrt();
-
+    fn shapes(&self, ui: &mut Ui, transform: &ScreenTransform, shapes: &mut Vec<Shape>) {
         let Self {
             series,
             shape,
             color,
             filled,
-            mut radius,
+            radius,
             highlight,
             stems,
+            point_values,
+            colormap,
+            value_range,
+            radius_range,
+            color_bar,
             ..
         } = self;
 
-        let stroke_size = radius / 5.0;
-
-        let default_stroke = Stroke::new(stroke_size, *color);
-        let mut stem_stroke = default_stroke;
-        let stroke = (!filled)
-            .then(|| default_stroke)
-            .unwrap_or_else(Stroke::none);
-        let fill = filled.then(|| *color).unwrap_or_default();
-
-        if *highlight {
-            radius *= 2f32.sqrt();
-            stem_stroke.width *= 2.0;
-        }
+        let extent = colormap_extent(point_values, value_range);
 
         let y_reference = stems.map(|y| transform.position_from_point(&PlotPoint::new(0.0, y)).y);
 
-        series
+        let markers: Vec<(Pos2, f32, Color32)> = series
             .points()
             .iter()
-            .map(|value| transform.position_from_point(value))
-            .for_each(|center| {
-                let tf = |dx: f32, dy: f32| -> Pos2 { center + radius * vec2(dx, dy) };
-
-                if let Some(y) = y_reference {
-                    let stem = Shape::line_segment([center, pos2(center.x, y)], stem_stroke);
-                    shapes.push(stem);
+            .enumerate()
+            .map(|(index, value)| {
+                let center = transform.position_from_point(value);
+                match point_values.get(index) {
+                    Some(&value) if !value.is_nan() => {
+                        let t = colormap_position(value, extent);
+                        let radius = radius_range.as_ref().map_or(*radius, |range| {
+                            let t = t.clamp(0.0, 1.0) as f32;
+                            range.start() + t * (range.end() - range.start())
+                        });
+                        (center, radius, colormap.color_at(t))
+                    }
+                    _ => (center, *radius, *color),
                 }
+            })
+            .collect();
 
-                match shape {
-                    MarkerShape::Circle => {
-                        shapes.push(Shape::Circle(epaint::CircleShape {
-                            center,
-                            radius,
-                            fill,
-                            stroke,
-                        }));
-                    }
-                    MarkerShape::Diamond => {
-                        let points = vec![
-                            tf(0.0, 1.0),  // bottom
-                            tf(-1.0, 0.0), // left
-                            tf(0.0, -1.0), // top
-                            tf(1.0, 0.0),  // right
-                        ];
-                        shapes.push(Shape::convex_polygon(points, fill, stroke));
-                    }
-                    MarkerShape::Square => {
-                        let points = vec![
-                            tf(-frac_1_sqrt_2, frac_1_sqrt_2),
-                            tf(-frac_1_sqrt_2, -frac_1_sqrt_2),
-                            tf(frac_1_sqrt_2, -frac_1_sqrt_2),
-                            tf(frac_1_sqrt_2, frac_1_sqrt_2),
-                        ];
-                        shapes.push(Shape::convex_polygon(points, fill, stroke));
-                    }
-                    MarkerShape::Cross => {
-                        let diagonal1 = [
-                            tf(-frac_1_sqrt_2, -frac_1_sqrt_2),
-                            tf(frac_1_sqrt_2, frac_1_sqrt_2),
-                        ];
-                        let diagonal2 = [
-                            tf(frac_1_sqrt_2, -frac_1_sqrt_2),
-                            tf(-frac_1_sqrt_2, frac_1_sqrt_2),
-                        ];
-                        shapes.push(Shape::line_segment(diagonal1, default_stroke));
-                        shapes.push(Shape::line_segment(diagonal2, default_stroke));
-                    }
-                    MarkerShape::Plus => {
-                        let horizontal = [tf(-1.0, 0.0), tf(1.0, 0.0)];
-                        let vertical = [tf(0.0, -1.0), tf(0.0, 1.0)];
-                        shapes.push(Shape::line_segment(horizontal, default_stroke));
-                        shapes.push(Shape::line_segment(vertical, default_stroke));
-                    }
-                    MarkerShape::Up => {
-                        let points =
-                            vec![tf(0.0, -1.0), tf(0.5 * sqrt_3, 0.5), tf(-0.5 * sqrt_3, 0.5)];
-                        shapes.push(Shape::convex_polygon(points, fill, stroke));
-                    }
-                    MarkerShape::Down => {
-                        let points = vec![
-                            tf(0.0, 1.0),
-                            tf(-0.5 * sqrt_3, -0.5),
-                            tf(0.5 * sqrt_3, -0.5),
-                        ];
-                        shapes.push(Shape::convex_polygon(points, fill, stroke));
-                    }
-                    MarkerShape::Left => {
-                        let points =
-                            vec![tf(-1.0, 0.0), tf(0.5, -0.5 * sqrt_3), tf(0.5, 0.5 * sqrt_3)];
-                        shapes.push(Shape::convex_polygon(points, fill, stroke));
-                    }
-                    MarkerShape::Right => {
-                        let points = vec![
-                            tf(1.0, 0.0),
-                            tf(-0.5, 0.5 * sqrt_3),
-                            tf(-0.5, -0.5 * sqrt_3),
-                        ];
-                        shapes.push(Shape::convex_polygon(points, fill, stroke));
-                    }
-                    MarkerShape::Asterisk => {
-                        let vertical = [tf(0.0, -1.0), tf(0.0, 1.0)];
-                        let diagonal1 = [tf(-frac_sqrt_3_2, 0.5), tf(frac_sqrt_3_2, -0.5)];
-                        let diagonal2 = [tf(-frac_sqrt_3_2, -0.5), tf(frac_sqrt_3_2, 0.5)];
-                        shapes.push(Shape::line_segment(vertical, default_stroke));
-                        shapes.push(Shape::line_segment(diagonal1, default_stroke));
-                        shapes.push(Shape::line_segment(diagonal2, default_stroke));
-                    }
+        if let Some(y) = y_reference {
+            for &(center, radius, color) in &markers {
+                let mut stem_stroke = Stroke::new(radius / 5.0, color);
+                if *highlight {
+                    stem_stroke.width *= 2.0;
                 }
-            });
+                shapes.push(Shape::line_segment(
+                    [center, pos2(center.x, y)],
+                    stem_stroke,
+                ));
+            }
+        }
+
+        let mut tessellator = tessellator(ui);
+        let markers = markers_mesh(&mut tessellator, *shape, *filled, *highlight, &markers);
+        if !markers.is_empty() {
+            shapes.push(Shape::Instanced(markers));
+        }
+
+        if *color_bar && !point_values.is_empty() {
+            paint_color_bar(colormap, ui, transform, extent, shapes);
+        }
     }
 
     fn initialize(&mut self, x_range: RangeInclusive<f64>) {
@@ -952,7 +1278,11 @@ impl PlotItem for Points {
     }
 
     fn color(&self) -> Color32 {
-        self.color
+        if self.point_values.is_empty() {
+            self.color
+        } else {
+            self.colormap.color_at(0.5)
+        }
     }
 
     fn highlight(&mut self) {
@@ -970,12 +1300,21 @@ impl PlotItem for Points {
     fn bounds(&self) -> PlotBounds {
         self.series.bounds()
     }
+
+    fn draggable(&self) -> bool {
+        self.draggable
+    }
+
+    fn drag_point(&mut self, index: usize, point: PlotPoint) {
+        self.series.set_point(index, point);
+    }
 }
 
 /// A set of arrows.
 pub struct Arrows {
     pub(super) origins: PlotPoints,
     pub(super) tips: PlotPoints,
+    pub(super) tip_length: Option<f32>,
     pub(super) color: Color32,
     pub(super) name: String,
     pub(super) highlight: bool,
@@ -986,6 +1325,7 @@ impl Arrows {
         Self {
             origins: origins.into(),
             tips: tips.into(),
+            tip_length: None,
             color: Color32::TRANSPARENT,
             name: Default::default(),
             highlight: false,
@@ -1004,6 +1344,13 @@ impl Arrows {
         self
     }
 
+    /// The length of the arrow heads, in points, so that they keep their size when zooming,
+    /// e.g. for an arrow pointing out an event. Default: a quarter of the length of each arrow.
+    pub fn tip_length(mut self, tip_length: impl Into<f32>) -> Self {
+        self.tip_length = Some(tip_length.into());
+        self
+    }
+
     /// Name of this set of arrows.
     ///
     /// This name will show up in the plot legend, if legends are turned on.
@@ -1023,6 +1370,7 @@ impl PlotItem for Arrows {
         let Self {
             origins,
             tips,
+            tip_length,
             color,
             highlight,
             ..
@@ -1041,7 +1389,7 @@ impl PlotItem for Arrows {
             .for_each(|(origin, tip)| {
                 let vector = tip - origin;
                 let rot = Rot2::from_angle(std::f32::consts::TAU / 10.0);
-                let tip_length = vector.length() / 4.0;
+                let tip_length = tip_length.unwrap_or(vector.length() / 4.0);
                 let tip = origin + vector;
                 let dir = vector.normalized();
                 shapes.push(Shape::line_segment([origin, tip], stroke));
@@ -1345,12 +1693,82 @@ impl BarChart {
         }
         self
     }
+
+    /// Stack these charts, each on top of the ones before it.
+    ///
+    /// Unlike [`Self::stack_on`], bars are matched by their argument rather than their index,
+    /// so the charts may have bars at different arguments. Positive values are stacked upwards
+    /// from zero and negative values downwards, and each bar remembers the total of its stack
+    /// to show it when hovered. Each chart keeps its own name, and so its own legend entry.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui::plot::{Bar, BarChart, Plot};
+    /// let charts = BarChart::stack(vec![
+    ///     BarChart::new(vec![Bar::new(0.0, 1.0), Bar::new(1.0, 2.0)]).name("Apples"),
+    ///     BarChart::new(vec![Bar::new(0.0, 3.0), Bar::new(1.0, 0.5)]).name("Pears"),
+    /// ]);
+    /// Plot::new("fruit").legend(Default::default()).show(ui, |plot_ui| {
+    ///     for chart in charts {
+    ///         plot_ui.bar_chart(chart);
+    ///     }
+    /// });
+    /// # });
+    /// ```
+    pub fn stack(mut charts: Vec<BarChart>) -> Vec<BarChart> {
+        // The top of the positive and the bottom of the negative part of each stack so far.
+        let mut ends: epaint::ahash::AHashMap<u64, [f64; 2]> = Default::default();
+        let mut totals: epaint::ahash::AHashMap<u64, f64> = Default::default();
+        // `-0.0` is the same argument as `0.0`:
+        let key = |argument: f64| (argument + 0.0).to_bits();
+        for bar in charts.iter_mut().flat_map(|chart| chart.bars.iter_mut()) {
+            let key = key(bar.argument);
+            let end = ends.entry(key).or_insert([0.0; 2]);
+            let side = if bar.value.is_sign_positive() { 0 } else { 1 };
+            bar.base_offset = Some(end[side]);
+            end[side] += bar.value;
+            *totals.entry(key).or_insert(0.0) += bar.value;
+        }
+        for bar in charts.iter_mut().flat_map(|chart| chart.bars.iter_mut()) {
+            bar.stack_total = totals.get(&key(bar.argument)).copied();
+        }
+        charts
+    }
+
+    /// Place the bars of these charts side by side at each argument, in the order of `charts`.
+    ///
+    /// The bars at one argument share `group_width` between them, centered on the argument.
+    /// Each chart keeps its own name, and so its own legend entry.
+    pub fn group(mut charts: Vec<BarChart>, group_width: f64) -> Vec<BarChart> {
+        let width = group_width / charts.len().max(1) as f64;
+        for (i, chart) in charts.iter_mut().enumerate() {
+            let offset = (i as f64 + 0.5) * width - group_width / 2.0;
+            for bar in &mut chart.bars {
+                bar.argument += offset;
+                bar.bar_width = width;
+            }
+        }
+        charts
+    }
 }
 
 impl PlotItem for BarChart {
     fn shapes(&self, _ui: &mut Ui, transform: &ScreenTransform, shapes: &mut Vec<Shape>) {
-        for b in &self.bars {
-            b.add_shapes(transform, self.highlight, shapes);
+        if self.highlight {
+            for b in &self.bars {
+                b.add_shapes(transform, self.highlight, shapes);
+            }
+        } else {
+            // One mesh for all the bars is much cheaper to tessellate than a shape per bar.
+            let mut mesh = Mesh::default();
+            mesh.reserve_vertices(self.bars.len() * 20);
+            mesh.reserve_triangles(self.bars.len() * 10);
+            for b in &self.bars {
+                b.add_to_mesh(transform, &mut mesh);
+            }
+            if !mesh.is_empty() {
+                shapes.push(Shape::Mesh(mesh));
+            }
         }
     }
 
@@ -1390,6 +1808,14 @@ impl PlotItem for BarChart {
         find_closest_rect(&self.bars, point, transform)
     }
 
+    fn find_closest_x(&self, x: f32, transform: &ScreenTransform) -> Option<ClosestElem> {
+        find_closest_rect_x(&self.bars, x, transform)
+    }
+
+    fn element_value(&self, index: usize, _: &ScreenTransform) -> Option<PlotElementValue> {
+        self.bars.get(index).cloned().map(PlotElementValue::Bar)
+    }
+
     fn on_hover(
         &self,
         elem: ClosestElem,
@@ -1532,6 +1958,14 @@ impl PlotItem for BoxPlot {
         find_closest_rect(&self.boxes, point, transform)
     }
 
+    fn find_closest_x(&self, x: f32, transform: &ScreenTransform) -> Option<ClosestElem> {
+        find_closest_rect_x(&self.boxes, x, transform)
+    }
+
+    fn element_value(&self, index: usize, _: &ScreenTransform) -> Option<PlotElementValue> {
+        self.boxes.get(index).cloned().map(PlotElementValue::Box)
+    }
+
     fn on_hover(
         &self,
         elem: ClosestElem,
@@ -1549,13 +1983,83 @@ impl PlotItem for BoxPlot {
 // ----------------------------------------------------------------------------
 // Chart plot
 
+/// A trailing time window that the x axis of a [`ChartPlot`] can be set to,
+/// see [`ChartPlot::view_presets`].
+///
+/// The x values of the chart are interpreted as unix timestamps (seconds since 1970-01-01 UTC).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum ViewPreset {
+    /// The last day.
+    Day,
+
+    /// The last seven days.
+    Week,
+
+    /// The last 30 days.
+    Month,
+
+    /// From the first of January of the year of the latest candle.
+    YearToDate,
+
+    /// All of the data.
+    All,
+}
+
+impl ViewPreset {
+    /// `1D`, `1W`, `1M`, `YTD` and `All`.
+    pub const STANDARD: [Self; 5] = [
+        Self::Day,
+        Self::Week,
+        Self::Month,
+        Self::YearToDate,
+        Self::All,
+    ];
+
+    /// The text of the toolbar button.
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Day => "1D",
+            Self::Week => "1W",
+            Self::Month => "1M",
+            Self::YearToDate => "YTD",
+            Self::All => "All",
+        }
+    }
+
+    /// The x range to show for data that ends at `last_x`, or `None` to show all of it.
+    pub fn x_range(self, last_x: f64) -> Option<RangeInclusive<f64>> {
+        const DAY: f64 = 24.0 * 60.0 * 60.0;
+        let start = match self {
+            Self::Day => last_x - DAY,
+            Self::Week => last_x - 7.0 * DAY,
+            Self::Month => last_x - 30.0 * DAY,
+            Self::YearToDate => {
+                let (year, _, _) = super::civil_from_days((last_x / DAY).floor() as i64);
+                super::days_from_civil(year, 1, 1) as f64 * DAY
+            }
+            Self::All => return None,
+        };
+        Some(start..=last_x)
+    }
+}
+
 pub struct ChartPlot {
     pub(super) candle_elems: Vec<CandleElem>,
     pub(super) default_color: Color32,
+    pub(super) color_up: Color32,
+    pub(super) color_down: Color32,
     pub(super) name: String,
     /// A custom element formatter
     pub(super) element_formatter: Option<Box<dyn Fn(&CandleElem, &ChartPlot) -> String>>,
     highlight: bool,
+    view_presets: Vec<ViewPreset>,
+    pub(super) comparisons: Vec<Comparison>,
+    pub(super) indicators: Vec<Indicator>,
+    pub(super) style: CandleStyle,
+    lod: Lod<CandleElem>,
+    /// The series the candles were copied from, and its revision at the time.
+    pub(super) series: Option<(PlotSeriesHandle<CandleElem>, u64)>,
 }
 
 impl ChartPlot {
@@ -1564,16 +2068,36 @@ impl ChartPlot {
         Self {
             candle_elems: candles,
             default_color: Color32::TRANSPARENT,
+            color_up: Color32::from_rgb(38, 166, 91),
+            color_down: Color32::from_rgb(222, 60, 60),
             name: String::new(),
             element_formatter: None,
             highlight: false,
+            view_presets: Vec::new(),
+            comparisons: Vec::new(),
+            indicators: Vec::new(),
+            style: CandleStyle::default(),
+            lod: Lod::default(),
+            series: None,
         }
     }
 
-    /// Set the default color. It is set on all elements that do not already have a specific color.
+    /// The candles of a series that is kept between frames, see [`PlotSeriesHandle`].
+    ///
+    /// Only the candles that changed since the last frame are drawn again, as long as the view
+    /// stays the same.
+    pub fn from_series(series: &PlotSeriesHandle<CandleElem>) -> Self {
+        let (candles, revision) = series.snapshot();
+        let mut chart_plot = Self::new(candles);
+        chart_plot.series = Some((series.clone(), revision));
+        chart_plot
+    }
+
+    /// Set the default color. It is set on all elements that do not already have a specific color,
+    /// instead of [`Self::color_up`] and [`Self::color_down`].
     /// This is the color that shows up in the legend.
-    /// It can be overridden at the element level (see [`BoxElem`]).
-    /// Default is `Color32::TRANSPARENT` which means a color will be auto-assigned.
+    /// It can be overridden at the element level (see [`CandleElem`]).
+    /// Default is `Color32::TRANSPARENT`, which means that the legend shows [`Self::color_up`].
     pub fn color(mut self, color: impl Into<Color32>) -> Self {
         let plot_color = color.into();
         self.default_color = plot_color;
@@ -1588,11 +2112,27 @@ impl ChartPlot {
         self
     }
 
-    /// Name of this box plot diagram.
+    /// The color of the candles that close at or above their open. Default: green.
     ///
-    /// This name will show up in the plot legend, if legends are turned on. Multiple series may
-    /// share the same name, in which case they will also share an entry in the legend.
-    #[allow(clippy::needless_pass_by_value)]
+    /// Candles with a fill or stroke color of their own keep it.
+    pub fn color_up(mut self, color: impl Into<Color32>) -> Self {
+        self.color_up = color.into();
+        self
+    }
+
+    /// The color of the candles that close below their open. Default: red.
+    ///
+    /// Candles with a fill or stroke color of their own keep it.
+    pub fn color_down(mut self, color: impl Into<Color32>) -> Self {
+        self.color_down = color.into();
+        self
+    }
+
+    /// Name of this box plot diagram.
+    ///
+    /// This name will show up in the plot legend, if legends are turned on. Multiple series may
+    /// share the same name, in which case they will also share an entry in the legend.
+    #[allow(clippy::needless_pass_by_value)]
     pub fn name(mut self, name: impl ToString) -> Self {
         self.name = name.to_string();
         self
@@ -1604,6 +2144,16 @@ impl ChartPlot {
         self
     }
 
+    /// When more than this many consecutive candles fall into the same point-wide column of the
+    /// plot, they are drawn and hovered as one: from the open of the first to the close of the
+    /// last, with the highest high, the lowest low and the total volume.
+    ///
+    /// Default is 4. `usize::MAX` draws all candles.
+    pub fn lod_threshold(mut self, threshold: usize) -> Self {
+        self.lod.threshold = threshold;
+        self
+    }
+
     /// Add a custom way to format an element.
     /// Can be used to display a set number of decimals or custom labels.
     pub fn element_formatter(
@@ -1613,13 +2163,175 @@ impl ChartPlot {
         self.element_formatter = Some(formatter);
         self
     }
+
+    /// Show a toolbar with these presets in the top left corner of the plot.
+    ///
+    /// Clicking a preset sets the x axis to that trailing window of the candles (by their x values,
+    /// interpreted as unix timestamps) and fits the y axis to the candles in it.
+    /// The preset stays active until the user pans or zooms, so the view follows new candles.
+    /// The picked preset is reported in [`crate::plot::PlotResponse::view_preset`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui::plot::{ChartPlot, Plot, ViewPreset};
+    /// let response = Plot::new("prices").show(ui, |plot_ui| {
+    ///     plot_ui.chart_plot(ChartPlot::new(vec![]).view_presets(ViewPreset::STANDARD));
+    /// });
+    /// if let Some(preset) = response.view_preset {
+    ///     println!("Showing {}", preset.label());
+    /// }
+    /// # });
+    /// ```
+    pub fn view_presets(mut self, presets: impl IntoIterator<Item = ViewPreset>) -> Self {
+        self.view_presets = presets.into_iter().collect();
+        self
+    }
+
+    /// Overlay another price series on this chart.
+    ///
+    /// As soon as there is a comparison, the candles and all comparisons are shown as
+    /// the percent change since the left edge of the view, so that they can be compared.
+    /// Each comparison gets its own legend entry and a badge on the right edge of the plot.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui::plot::{Candle, CandleElem, ChartPlot, Comparison, Plot};
+    /// let candles = vec![CandleElem::new(Candle::new(10.0, 12.0, 9.0, 11.0, 100.0))];
+    /// Plot::new("compare").show(ui, |plot_ui| {
+    ///     plot_ui.chart_plot(
+    ///         ChartPlot::new(candles)
+    ///             .name("AAA")
+    ///             .compare_with(Comparison::new("BBB", vec![[0.0, 40.0], [1.0, 42.0]])),
+    ///     );
+    /// });
+    /// # });
+    /// ```
+    pub fn compare_with(mut self, comparison: Comparison) -> Self {
+        self.comparisons.push(comparison);
+        self
+    }
+
+    /// Overlay a technical indicator, computed from the candles (which must be sorted by x).
+    ///
+    /// Each indicator gets its own legend entry and color, and shows its value on hover.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui::plot::{Candle, CandleElem, ChartPlot, Indicator, Plot};
+    /// let candles = vec![CandleElem::new(Candle::new(10.0, 12.0, 9.0, 11.0, 100.0))];
+    /// Plot::new("indicators").show(ui, |plot_ui| {
+    ///     plot_ui.chart_plot(
+    ///         ChartPlot::new(candles)
+    ///             .with_indicator(Indicator::Sma(20))
+    ///             .with_indicator(Indicator::Bollinger { period: 20, k: 2.0 }),
+    ///     );
+    /// });
+    /// # });
+    /// ```
+    pub fn with_indicator(mut self, indicator: Indicator) -> Self {
+        self.indicators.push(indicator);
+        self
+    }
+
+    /// Show the candles in another style, e.g. as Heikin-Ashi candles or as Renko bricks.
+    ///
+    /// The shown candles are derived from the given ones every frame, and are what is hovered,
+    /// compared and fed to the indicators.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui::plot::{Candle, CandleElem, CandleStyle, ChartPlot, Plot};
+    /// let candles = vec![CandleElem::new(Candle::new(10.0, 12.0, 9.0, 11.0, 100.0))];
+    /// Plot::new("renko").show(ui, |plot_ui| {
+    ///     plot_ui.chart_plot(ChartPlot::new(candles).style(CandleStyle::Renko { brick_size: 0.5 }));
+    /// });
+    /// # });
+    /// ```
+    pub fn style(mut self, style: CandleStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Replace the candles with the ones to show in [`Self::style`].
+    pub(super) fn apply_style(&mut self) {
+        match self.style {
+            CandleStyle::Candles => {}
+            CandleStyle::HeikinAshi => {
+                // Each only depends on those before it, so a series still redraws just the changes.
+                self.candle_elems = self.style.apply(&self.candle_elems);
+            }
+            CandleStyle::Renko { .. } => {
+                self.candle_elems = self.style.apply(&self.candle_elems);
+                // The bricks don't match the candles in the series one to one.
+                self.series = None;
+            }
+        }
+    }
+
+    /// Show the candles as percent change relative to the close of the first candle at or after `left_x`.
+    pub(super) fn normalize_to_percent(&mut self, left_x: f64) {
+        let base = self
+            .candle_elems
+            .iter()
+            .find(|c| c.x >= left_x)
+            .or_else(|| self.candle_elems.last())
+            .map(|c| c.candle.close)
+            .filter(is_percent_base);
+        if let Some(base) = base {
+            for c in &mut self.candle_elems {
+                let candle = &mut c.candle;
+                candle.open = percent_change(base, candle.open);
+                candle.high = percent_change(base, candle.high);
+                candle.low = percent_change(base, candle.low);
+                candle.close = percent_change(base, candle.close);
+            }
+        }
+    }
+}
+
+/// Values can only be shown as percent change relative to a finite, nonzero one;
+/// otherwise they are shown as they are.
+fn is_percent_base(base: &f64) -> bool {
+    *base != 0.0 && base.is_finite()
+}
+
+fn percent_change(base: f64, value: f64) -> f64 {
+    (value / base - 1.0) * 100.0
 }
 
 impl PlotItem for ChartPlot {
     fn shapes(&self, _ui: &mut Ui, transform: &ScreenTransform, shapes: &mut Vec<Shape>) {
-        for b in &self.candle_elems {
-            b.add_shapes(transform, self.highlight, shapes);
-        }
+        self.lod.with(&self.candle_elems, transform, |candles| {
+            let draw = |b: &CandleElem, shapes: &mut Vec<Shape>| {
+                b.add_shapes(self, transform, self.highlight, shapes);
+            };
+            match &self.series {
+                // Merged candles are drawn fresh, since they change with the zoom anyway.
+                Some((series, revision)) if candles.len() == self.candle_elems.len() => {
+                    let colors = [self.default_color, self.color_up, self.color_down];
+                    let key = DrawKey::new(transform, self.highlight, colors);
+                    series.draw_cached(*revision, key, candles, draw, shapes);
+                }
+                // One mesh for all the candles is much cheaper to tessellate than their shapes,
+                // which are only needed for the thicker outlines of a highlighted chart.
+                _ if !self.highlight => {
+                    let mut mesh = Mesh::default();
+                    mesh.reserve_vertices(candles.len() * 24);
+                    mesh.reserve_triangles(candles.len() * 12);
+                    for b in candles {
+                        b.add_to_mesh(self, transform, &mut mesh);
+                    }
+                    if !mesh.is_empty() {
+                        shapes.push(Shape::Mesh(mesh));
+                    }
+                }
+                _ => {
+                    for b in candles {
+                        draw(b, shapes);
+                    }
+                }
+            }
+        });
     }
 
     fn initialize(&mut self, _x_range: RangeInclusive<f64>) {
@@ -1654,90 +2366,1040 @@ impl PlotItem for ChartPlot {
         bounds
     }
 
+    fn bounds_within_x(&self, x_range: RangeInclusive<f64>) -> PlotBounds {
+        let mut bounds = PlotBounds::NOTHING;
+        for c in &self.candle_elems {
+            if x_range.contains(&c.x) {
+                bounds.merge(&c.bounds());
+            }
+        }
+        bounds
+    }
+
+    fn view_presets(&self) -> &[ViewPreset] {
+        &self.view_presets
+    }
+
+    fn find_closest(&self, point: Pos2, transform: &ScreenTransform) -> Option<ClosestElem> {
+        self.lod.with(&self.candle_elems, transform, |candles| {
+            find_closest_rect(candles, point, transform)
+        })
+    }
+
+    fn find_closest_x(&self, x: f32, transform: &ScreenTransform) -> Option<ClosestElem> {
+        self.lod.with(&self.candle_elems, transform, |candles| {
+            find_closest_rect_x(candles, x, transform)
+        })
+    }
+
+    fn element_value(&self, index: usize, transform: &ScreenTransform) -> Option<PlotElementValue> {
+        self.lod.with(&self.candle_elems, transform, |candles| {
+            candles.get(index).cloned().map(PlotElementValue::Candle)
+        })
+    }
+
+    fn source_element(
+        &self,
+        index: usize,
+        transform: &ScreenTransform,
+    ) -> Option<(Range<usize>, PlotElementValue)> {
+        let indices = self.lod.sources(&self.candle_elems, transform, index);
+        self.candle_elems
+            .get(indices.start)
+            .cloned()
+            .map(|candle| (indices, PlotElementValue::Candle(candle)))
+    }
+
+    fn on_hover(
+        &self,
+        elem: ClosestElem,
+        shapes: &mut Vec<Shape>,
+        plot: &PlotConfig<'_>,
+        _: &LabelFormatter,
+    ) {
+        self.lod
+            .with(&self.candle_elems, plot.transform, |candles| {
+                let chart_plot = &candles[elem.index];
+
+                chart_plot.add_shapes(self, plot.transform, true, shapes);
+                chart_plot.add_rulers_and_text(self, plot, shapes);
+            });
+    }
+
+    fn volume_bars(&self) -> Vec<Bar> {
+        self.candle_elems
+            .iter()
+            .map(|c| {
+                let (stroke, fill) = c.colors(self);
+                Bar::new(c.x, c.candle.volume)
+                    .width(c.candle_width)
+                    .stroke(stroke)
+                    .fill(fill)
+            })
+            .collect()
+    }
+}
+
+// ----------------------------------------------------------------------------
+// OhlcChart
+
+/// A chart of classic OHLC bars ([`OhlcElem`]), as an alternative to the candles of a [`ChartPlot`].
+pub struct OhlcChart {
+    pub(super) elems: Vec<OhlcElem>,
+    pub(super) default_color: Color32,
+    pub(super) color_up: Color32,
+    pub(super) color_down: Color32,
+    pub(super) name: String,
+    /// A custom element formatter
+    pub(super) element_formatter: Option<Box<dyn Fn(&OhlcElem, &OhlcChart) -> String>>,
+    highlight: bool,
+}
+
+impl OhlcChart {
+    /// Create a chart of multiple OHLC `bars`.
+    pub fn new(bars: Vec<OhlcElem>) -> Self {
+        Self {
+            elems: bars,
+            default_color: Color32::TRANSPARENT,
+            color_up: Color32::from_rgb(38, 166, 91),
+            color_down: Color32::from_rgb(222, 60, 60),
+            name: String::new(),
+            element_formatter: None,
+            highlight: false,
+        }
+    }
+
+    /// Set the default color. It is set on all elements that do not already have a specific color,
+    /// instead of [`Self::color_up`] and [`Self::color_down`].
+    /// This is the color that shows up in the legend.
+    /// It can be overridden at the element level (see [`OhlcElem`]).
+    /// Default is `Color32::TRANSPARENT`, which means that the legend shows [`Self::color_up`].
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        let plot_color = color.into();
+        self.default_color = plot_color;
+        for elem in &mut self.elems {
+            if elem.stroke.color == Color32::TRANSPARENT {
+                elem.stroke.color = plot_color;
+            }
+        }
+        self
+    }
+
+    /// The color of the bars that close at or above their open. Default: green.
+    ///
+    /// Bars with a stroke color of their own keep it.
+    pub fn color_up(mut self, color: impl Into<Color32>) -> Self {
+        self.color_up = color.into();
+        self
+    }
+
+    /// The color of the bars that close below their open. Default: red.
+    ///
+    /// Bars with a stroke color of their own keep it.
+    pub fn color_down(mut self, color: impl Into<Color32>) -> Self {
+        self.color_down = color.into();
+        self
+    }
+
+    /// Name of this chart.
+    ///
+    /// This name will show up in the plot legend, if legends are turned on. Multiple series may
+    /// share the same name, in which case they will also share an entry in the legend.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Highlight all plot elements.
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.highlight = highlight;
+        self
+    }
+
+    /// Add a custom way to format an element.
+    /// Can be used to display a set number of decimals or custom labels.
+    pub fn element_formatter(
+        mut self,
+        formatter: Box<dyn Fn(&OhlcElem, &OhlcChart) -> String>,
+    ) -> Self {
+        self.element_formatter = Some(formatter);
+        self
+    }
+}
+
+impl PlotItem for OhlcChart {
+    fn shapes(&self, _ui: &mut Ui, transform: &ScreenTransform, shapes: &mut Vec<Shape>) {
+        for elem in &self.elems {
+            elem.add_shapes(self, transform, self.highlight, shapes);
+        }
+    }
+
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {
+        // nothing to do
+    }
+
+    fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn color(&self) -> Color32 {
+        self.default_color
+    }
+
+    fn highlight(&mut self) {
+        self.highlight = true;
+    }
+
+    fn highlighted(&self) -> bool {
+        self.highlight
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::Rects
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        let mut bounds = PlotBounds::NOTHING;
+        for elem in &self.elems {
+            bounds.merge(&elem.bounds());
+        }
+        bounds
+    }
+
+    fn find_closest(&self, point: Pos2, transform: &ScreenTransform) -> Option<ClosestElem> {
+        find_closest_rect(&self.elems, point, transform)
+    }
+
+    fn find_closest_x(&self, x: f32, transform: &ScreenTransform) -> Option<ClosestElem> {
+        find_closest_rect_x(&self.elems, x, transform)
+    }
+
+    fn element_value(&self, index: usize, _: &ScreenTransform) -> Option<PlotElementValue> {
+        self.elems.get(index).cloned().map(PlotElementValue::Ohlc)
+    }
+
+    fn on_hover(
+        &self,
+        elem: ClosestElem,
+        shapes: &mut Vec<Shape>,
+        plot: &PlotConfig<'_>,
+        _: &LabelFormatter,
+    ) {
+        let ohlc = &self.elems[elem.index];
+
+        ohlc.add_shapes(self, plot.transform, true, shapes);
+        ohlc.add_rulers_and_text(self, plot, shapes);
+    }
+
+    fn volume_bars(&self) -> Vec<Bar> {
+        self.elems
+            .iter()
+            .map(|elem| {
+                let stroke = elem.stroke_color(self);
+                Bar::new(elem.x, elem.candle.volume)
+                    .width(elem.tick_width)
+                    .stroke(stroke)
+                    .fill(stroke.color.linear_multiply(0.2))
+            })
+            .collect()
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Comparison
+
+/// A price series shown on top of a [`ChartPlot`], see [`ChartPlot::compare_with`].
+pub struct Comparison {
+    pub(super) series: Vec<PlotPoint>,
+    pub(super) stroke: Stroke,
+    pub(super) name: String,
+    pub(super) highlight: bool,
+    pub(super) badge: bool,
+}
+
+impl Comparison {
+    /// The points are `[x, price]`, with the same x values as the candles.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn new(name: impl ToString, series: impl Into<PlotPoints>) -> Self {
+        Self {
+            series: series.into().points().to_vec(),
+            stroke: Stroke::new(1.5, Color32::TRANSPARENT),
+            name: name.to_string(),
+            highlight: false,
+            badge: true,
+        }
+    }
+
+    /// Stroke color. Default is `Color32::TRANSPARENT` which means a color will be auto-assigned.
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.stroke.color = color.into();
+        self
+    }
+
+    /// Stroke width.
+    pub fn width(mut self, width: impl Into<f32>) -> Self {
+        self.stroke.width = width.into();
+        self
+    }
+
+    /// Highlight this series in the plot by scaling up the line.
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.highlight = highlight;
+        self
+    }
+
+    /// Show the latest visible percent change in a badge on the right edge of the plot. Default: `true`.
+    pub fn badge(mut self, badge: bool) -> Self {
+        self.badge = badge;
+        self
+    }
+
+    pub(super) fn normalize_to_percent(&mut self, left_x: f64) {
+        let base = self
+            .series
+            .iter()
+            .find(|p| p.x >= left_x)
+            .or_else(|| self.series.last())
+            .map(|p| p.y)
+            .filter(is_percent_base);
+        if let Some(base) = base {
+            for point in &mut self.series {
+                point.y = percent_change(base, point.y);
+            }
+        }
+    }
+}
+
+impl PlotItem for Comparison {
+    fn shapes(&self, ui: &mut Ui, transform: &ScreenTransform, shapes: &mut Vec<Shape>) {
+        let points = self
+            .series
+            .iter()
+            .map(|p| transform.position_from_point(p))
+            .collect();
+        LineStyle::Solid.style_line(points, self.stroke, self.highlight, shapes);
+
+        let bounds = transform.bounds();
+        let last_visible = self.series.iter().rev().find(|p| p.x <= bounds.max[0]);
+        if let (true, Some(last)) = (self.badge, last_visible) {
+            let text_color = if Rgba::from(self.stroke.color).intensity() > 0.5 {
+                Color32::BLACK
+            } else {
+                Color32::WHITE
+            };
+            let text = format!("{:+.2}", last.y);
+            let text = format!("{}%", ui.ctx().locale_format().format_number(&text));
+            let galley =
+                ui.painter()
+                    .layout_no_wrap(text, TextStyle::Small.resolve(ui.style()), text_color);
+            let frame = transform.frame();
+            let y = transform
+                .position_from_point(last)
+                .y
+                .clamp(frame.top(), frame.bottom());
+            let size = galley.size() + vec2(6.0, 2.0);
+            let rect = Rect::from_min_size(pos2(frame.right() - size.x, y - size.y / 2.0), size);
+            shapes.push(Shape::rect_filled(rect, 2.0, self.stroke.color));
+            shapes.push(Shape::galley(rect.min + vec2(3.0, 1.0), galley));
+        }
+    }
+
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
+
+    fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn color(&self) -> Color32 {
+        self.stroke.color
+    }
+
+    fn highlight(&mut self) {
+        self.highlight = true;
+    }
+
+    fn highlighted(&self) -> bool {
+        self.highlight
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::Points(&self.series)
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        let mut bounds = PlotBounds::NOTHING;
+        for point in &self.series {
+            bounds.extend_with(point);
+        }
+        bounds
+    }
+
+    fn bounds_within_x(&self, x_range: RangeInclusive<f64>) -> PlotBounds {
+        let mut bounds = PlotBounds::NOTHING;
+        for point in self.series.iter().filter(|p| x_range.contains(&p.x)) {
+            bounds.extend_with(point);
+        }
+        bounds
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Filled range
+
+/// A range of x or y values filled across the whole plot, e.g. a market session or a target zone.
+///
+/// Items are drawn in the order they are added, so add it first to shade behind the other items.
+pub struct FilledRange {
+    pub(super) start: f64,
+    pub(super) end: f64,
+    pub(super) orientation: Orientation,
+    pub(super) stroke: Stroke,
+    pub(super) name: String,
+    pub(super) label: String,
+    pub(super) highlight: bool,
+    pub(super) fill_alpha: f32,
+    pub(super) style: LineStyle,
+}
+
+impl FilledRange {
+    pub fn new(orientation: Orientation, start: f64, end: f64) -> Self {
+        Self {
+            start,
+            end,
+            orientation,
+            stroke: Stroke::new(1.0, Color32::TRANSPARENT),
+            name: Default::default(),
+            label: Default::default(),
+            highlight: false,
+            fill_alpha: DEFAULT_FILL_ALPHA,
+            style: LineStyle::Solid,
+        }
+    }
+
+    /// The x values in `range`, from the bottom of the plot to the top.
+    pub fn x(range: RangeInclusive<f64>) -> Self {
+        Self::new(Orientation::Vertical, *range.start(), *range.end())
+    }
+
+    /// The y values in `range`, from the left edge of the plot to the right.
+    pub fn y(range: RangeInclusive<f64>) -> Self {
+        Self::new(Orientation::Horizontal, *range.start(), *range.end())
+    }
+
+    /// Highlight this polygon in the plot by scaling up the stroke and reducing the fill
+    /// transparency.
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.highlight = highlight;
+        self
+    }
+
+    /// Add a custom stroke.
+    pub fn stroke(mut self, stroke: impl Into<Stroke>) -> Self {
+        self.stroke = stroke.into();
+        self
+    }
+
+    /// Set the stroke width.
+    pub fn width(mut self, width: impl Into<f32>) -> Self {
+        self.stroke.width = width.into();
+        self
+    }
+
+    /// Stroke color. Default is `Color32::TRANSPARENT` which means a color will be auto-assigned.
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.stroke.color = color.into();
+        self
+    }
+
+    /// Alpha of the filled area.
+    pub fn fill_alpha(mut self, alpha: impl Into<f32>) -> Self {
+        self.fill_alpha = alpha.into();
+        self
+    }
+
+    /// Set the outline's style. Default is `LineStyle::Solid`.
+    pub fn style(mut self, style: LineStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// A text in the top left corner of the range.
+    ///
+    /// Unlike the name, it isn't shown in the legend.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn label(mut self, label: impl ToString) -> Self {
+        self.label = label.to_string();
+        self
+    }
+
+    /// Name of this polygon.
+    ///
+    /// This name will show up in the plot legend, if legends are turned on.
+    ///
+    /// Multiple plot items may share the same name, in which case they will also share an entry in
+    /// the legend.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.name = name.to_string();
+        self
+    }
+}
+
+impl PlotItem for FilledRange {
+    fn shapes(&self, ui: &mut Ui, transform: &ScreenTransform, shapes: &mut Vec<Shape>) {
+        let Self {
+            stroke,
+            label,
+            highlight,
+            mut fill_alpha,
+            style,
+            ..
+        } = self;
+
+        if *highlight {
+            fill_alpha = (2.0 * fill_alpha).at_most(1.0);
+        }
+
+        let series = if self.orientation == Orientation::Horizontal {
+            vec![
+                transform
+                    .position_from_point(&PlotPoint::new(transform.bounds().min[0], self.start)),
+                transform
+                    .position_from_point(&PlotPoint::new(transform.bounds().max[0], self.start)),
+                transform.position_from_point(&PlotPoint::new(transform.bounds().max[0], self.end)),
+                transform.position_from_point(&PlotPoint::new(transform.bounds().min[0], self.end)),
+                transform
+                    .position_from_point(&PlotPoint::new(transform.bounds().min[0], self.start)),
+            ]
+        } else {
+            vec![
+                transform
+                    .position_from_point(&PlotPoint::new(self.start, transform.bounds().min[1])),
+                transform
+                    .position_from_point(&PlotPoint::new(self.start, transform.bounds().max[1])),
+                transform.position_from_point(&PlotPoint::new(self.end, transform.bounds().max[1])),
+                transform.position_from_point(&PlotPoint::new(self.end, transform.bounds().min[1])),
+                transform
+                    .position_from_point(&PlotPoint::new(self.start, transform.bounds().min[1])),
+            ]
+        };
+
+        let fill = Rgba::from(stroke.color).to_opaque().multiply(fill_alpha);
+
+        let label_pos = Rect::from_points(&series).left_top() + vec2(4.0, 2.0);
+        let shape = Shape::convex_polygon(series.clone(), fill, Stroke::none());
+        shapes.push(shape);
+        style.style_line(series, *stroke, *highlight, shapes);
+        add_label(ui, label, label_pos, Align2::LEFT_TOP, stroke.color, shapes);
+    }
+
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
+
+    fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn color(&self) -> Color32 {
+        self.stroke.color
+    }
+
+    fn highlight(&mut self) {
+        self.highlight = true;
+    }
+
+    fn highlighted(&self) -> bool {
+        self.highlight
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::None
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        let axis = match self.orientation {
+            Orientation::Horizontal => 1,
+            Orientation::Vertical => 0,
+        };
+        let mut bounds = PlotBounds::NOTHING;
+        bounds.min[axis] = self.start.min(self.end);
+        bounds.max[axis] = self.start.max(self.end);
+        bounds
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Band
+
+/// The area between a lower and an upper series, e.g. a confidence interval or Bollinger bands.
+///
+/// The values of the two series are paired by their index, so they should have the same x values.
+pub struct Band {
+    pub(super) lower: PlotPoints,
+    pub(super) upper: PlotPoints,
+    pub(super) stroke: Stroke,
+    pub(super) name: String,
+    pub(super) highlight: bool,
+    pub(super) fill_alpha: f32,
+    pub(super) boundaries: bool,
+    pub(super) style: LineStyle,
+}
+
+impl Band {
+    pub fn new(lower: impl Into<PlotPoints>, upper: impl Into<PlotPoints>) -> Self {
+        Self {
+            lower: lower.into(),
+            upper: upper.into(),
+            stroke: Stroke::new(1.0, Color32::TRANSPARENT),
+            name: Default::default(),
+            highlight: false,
+            fill_alpha: DEFAULT_FILL_ALPHA,
+            boundaries: true,
+            style: LineStyle::Solid,
+        }
+    }
+
+    /// A band from `[x, lower, upper]` values.
+    pub fn from_values(values: impl IntoIterator<Item = [f64; 3]>) -> Self {
+        let (lower, upper): (Vec<_>, Vec<_>) = values
+            .into_iter()
+            .map(|[x, lower, upper]| (PlotPoint::new(x, lower), PlotPoint::new(x, upper)))
+            .unzip();
+        Self::new(PlotPoints::Owned(lower), PlotPoints::Owned(upper))
+    }
+
+    /// Highlight this band in the plot by scaling up the boundaries and reducing the fill
+    /// transparency.
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.highlight = highlight;
+        self
+    }
+
+    /// Add a custom stroke for the boundaries.
+    pub fn stroke(mut self, stroke: impl Into<Stroke>) -> Self {
+        self.stroke = stroke.into();
+        self
+    }
+
+    /// Set the width of the boundaries.
+    pub fn width(mut self, width: impl Into<f32>) -> Self {
+        self.stroke.width = width.into();
+        self
+    }
+
+    /// Stroke color. Default is `Color32::TRANSPARENT` which means a color will be auto-assigned.
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.stroke.color = color.into();
+        self
+    }
+
+    /// Alpha of the filled area.
+    pub fn fill_alpha(mut self, alpha: impl Into<f32>) -> Self {
+        self.fill_alpha = alpha.into();
+        self
+    }
+
+    /// Whether to draw the lower and upper series as lines. Default is `true`.
+    pub fn boundaries(mut self, boundaries: bool) -> Self {
+        self.boundaries = boundaries;
+        self
+    }
+
+    /// Set the style of the boundaries. Default is `LineStyle::Solid`.
+    pub fn style(mut self, style: LineStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Name of this band.
+    ///
+    /// This name will show up in the plot legend, if legends are turned on.
+    ///
+    /// Multiple plot items may share the same name, in which case they will also share an entry in
+    /// the legend.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// The lower values, then the upper ones.
+    fn value(&self, index: usize) -> PlotPoint {
+        let lower = self.lower.points();
+        if index < lower.len() {
+            lower[index]
+        } else {
+            self.upper.points()[index - lower.len()]
+        }
+    }
+}
+
+impl PlotItem for Band {
+    fn shapes(&self, _ui: &mut Ui, transform: &ScreenTransform, shapes: &mut Vec<Shape>) {
+        let Self {
+            lower,
+            upper,
+            stroke,
+            highlight,
+            mut fill_alpha,
+            boundaries,
+            style,
+            ..
+        } = self;
+
+        if *highlight {
+            fill_alpha = (2.0 * fill_alpha).at_most(1.0);
+        }
+        let to_screen = |points: &PlotPoints| -> Vec<Pos2> {
+            points
+                .points()
+                .iter()
+                .map(|point| transform.position_from_point(point))
+                .collect()
+        };
+        let (lower, upper) = (to_screen(lower), to_screen(upper));
+
+        // A quad between each two pairs of values.
+        let fill = Rgba::from(stroke.color)
+            .to_opaque()
+            .multiply(fill_alpha)
+            .into();
+        let n_values = lower.len().min(upper.len());
+        let mut mesh = Mesh::default();
+        mesh.reserve_vertices(2 * n_values);
+        mesh.reserve_triangles(2 * n_values.saturating_sub(1));
+        for (i, (lower, upper)) in lower.iter().zip(&upper).enumerate() {
+            mesh.colored_vertex(*lower, fill);
+            mesh.colored_vertex(*upper, fill);
+            if i > 0 {
+                let i = 2 * i as u32;
+                mesh.add_triangle(i - 2, i - 1, i);
+                mesh.add_triangle(i - 1, i, i + 1);
+            }
+        }
+        shapes.push(Shape::mesh(mesh));
+
+        if *boundaries {
+            style.style_line(lower, *stroke, *highlight, shapes);
+            style.style_line(upper, *stroke, *highlight, shapes);
+        }
+    }
+
+    fn initialize(&mut self, x_range: RangeInclusive<f64>) {
+        self.lower.generate_points(x_range.clone());
+        self.upper.generate_points(x_range);
+    }
+
+    fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn color(&self) -> Color32 {
+        self.stroke.color
+    }
+
+    fn highlight(&mut self) {
+        self.highlight = true;
+    }
+
+    fn highlighted(&self) -> bool {
+        self.highlight
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        // Made of two series, so hovering is implemented below.
+        PlotGeometry::Rects
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        let mut bounds = self.lower.bounds();
+        bounds.merge(&self.upper.bounds());
+        bounds
+    }
+
+    fn find_closest(&self, point: Pos2, transform: &ScreenTransform) -> Option<ClosestElem> {
+        let lower = self.lower.points();
+        let closest_lower = find_closest_point(lower, point, transform);
+        let closest_upper =
+            find_closest_point(self.upper.points(), point, transform).map(|elem| ClosestElem {
+                index: lower.len() + elem.index,
+                dist_sq: elem.dist_sq,
+            });
+        closest_lower
+            .into_iter()
+            .chain(closest_upper)
+            .min_by_key(|e| e.dist_sq.ord())
+    }
+
+    fn find_closest_x(&self, x: f32, transform: &ScreenTransform) -> Option<ClosestElem> {
+        find_closest_point_x(self.lower.points(), x, transform)
+    }
+
+    fn element_value(&self, index: usize, _: &ScreenTransform) -> Option<PlotElementValue> {
+        let len = self.lower.points().len() + self.upper.points().len();
+        (index < len).then(|| PlotElementValue::Point(self.value(index)))
+    }
+
+    fn on_hover(
+        &self,
+        elem: ClosestElem,
+        shapes: &mut Vec<Shape>,
+        plot: &PlotConfig<'_>,
+        label_formatter: &LabelFormatter,
+    ) {
+        let value = self.value(elem.index);
+        let pointer = plot.transform.position_from_point(&value);
+        shapes.push(Shape::circle_filled(pointer, 3.0, rulers_color(plot.ui)));
+
+        rulers_at_value(pointer, value, self.name(), plot, shapes, label_formatter);
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Envelope
+
+/// The minimum and maximum of a dense series for each point-wide column of the plot, e.g. for an
+/// audio waveform or tick data.
+///
+/// Unlike a [`Line`], this doesn't get slower the more values there are in each column,
+/// since only the visible values are looked at, once each. The values must be sorted by x.
+///
+/// When zoomed in so far that there are fewer values than columns, they are connected by a line instead.
+pub struct Envelope {
+    pub(super) series: PlotPoints,
+    pub(super) color: Color32,
+    pub(super) name: String,
+    pub(super) highlight: bool,
+}
+
+impl Envelope {
+    pub fn new(series: impl Into<PlotPoints>) -> Self {
+        Self {
+            series: series.into(),
+            color: Color32::TRANSPARENT,
+            name: Default::default(),
+            highlight: false,
+        }
+    }
+
+    /// Highlight this envelope in the plot by making it thicker.
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.highlight = highlight;
+        self
+    }
+
+    /// Default is `Color32::TRANSPARENT` which means a color will be auto-assigned.
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.color = color.into();
+        self
+    }
+
+    /// Name of this envelope.
+    ///
+    /// This name will show up in the plot legend, if legends are turned on.
+    ///
+    /// Multiple plot items may share the same name, in which case they will also share an entry in
+    /// the legend.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// The values within the x range of the plot, plus one on either side.
+    fn visible_points(&self, bounds: &PlotBounds) -> &[PlotPoint] {
+        let points = self.series.points();
+        let start = points
+            .partition_point(|point| point.x < bounds.min[0])
+            .saturating_sub(1);
+        let end = (points.partition_point(|point| point.x <= bounds.max[0]) + 1).min(points.len());
+        &points[start..end.max(start)]
+    }
+}
+
+impl PlotItem for Envelope {
+    fn shapes(&self, _ui: &mut Ui, transform: &ScreenTransform, shapes: &mut Vec<Shape>) {
+        let points = self.visible_points(transform.bounds());
+        if points.len() < 2 {
+            return;
+        }
+        let half_thickness = if self.highlight { 1.0 } else { 0.5 };
+
+        if (points.len() as f32) < transform.frame().width() {
+            let line = points
+                .iter()
+                .map(|point| transform.position_from_point(point))
+                .collect();
+            let stroke = Stroke::new(2.0 * half_thickness, self.color);
+            shapes.push(Shape::line(line, stroke));
+            return;
+        }
+
+        let mut mesh = Mesh::default();
+        let mut add_column = |x: f32, min_y: f32, max_y: f32| {
+            let rect = Rect::from_min_max(
+                pos2(x, min_y - half_thickness),
+                pos2(x + 1.0, max_y + half_thickness),
+            );
+            mesh.add_colored_rect(rect, self.color);
+        };
+
+        // (left edge, min y, max y) of the column being collected, in screen coordinates.
+        let mut column: Option<(f32, f32, f32)> = None;
+        let mut last_y = None;
+        for point in points {
+            let pos = transform.position_from_point(point);
+            let x = pos.x.floor();
+            match &mut column {
+                Some((column_x, min_y, max_y)) if *column_x == x => {
+                    *min_y = min_y.min(pos.y);
+                    *max_y = max_y.max(pos.y);
+                }
+                _ => {
+                    if let Some((x, min_y, max_y)) = column.take() {
+                        add_column(x, min_y, max_y);
+                    }
+                    // Include the last value of the previous column, so that there are no gaps:
+                    let last_y: f32 = last_y.unwrap_or(pos.y);
+                    column = Some((x, last_y.min(pos.y), last_y.max(pos.y)));
+                }
+            }
+            last_y = Some(pos.y);
+        }
+        if let Some((x, min_y, max_y)) = column {
+            add_column(x, min_y, max_y);
+        }
+        shapes.push(Shape::mesh(mesh));
+    }
+
+    fn initialize(&mut self, x_range: RangeInclusive<f64>) {
+        self.series.generate_points(x_range);
+    }
+
+    fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn color(&self) -> Color32 {
+        self.color
+    }
+
+    fn highlight(&mut self) {
+        self.highlight = true;
+    }
+
+    fn highlighted(&self) -> bool {
+        self.highlight
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::Points(self.series.points())
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        self.series.bounds()
+    }
+
     fn find_closest(&self, point: Pos2, transform: &ScreenTransform) -> Option<ClosestElem> {
-        find_closest_rect(&self.candle_elems, point, transform)
-    }
-
-    fn on_hover(
-        &self,
-        elem: ClosestElem,
-        shapes: &mut Vec<Shape>,
-        plot: &PlotConfig<'_>,
-        _: &LabelFormatter,
-    ) {
-        let chart_plot = &self.candle_elems[elem.index];
-
-        chart_plot.add_shapes(plot.transform, true, shapes);
-        chart_plot.add_rulers_and_text(self, plot, shapes);
+        // Only look at the values that are close enough horizontally to be hovered:
+        let min_x = transform
+            .value_from_position(point - vec2(super::HOVER_RADIUS, 0.0))
+            .x;
+        let max_x = transform
+            .value_from_position(point + vec2(super::HOVER_RADIUS, 0.0))
+            .x;
+        let points = self.series.points();
+        let start = points.partition_point(|value| value.x < min_x);
+        let end = points.partition_point(|value| value.x <= max_x);
+        (start..end.max(start))
+            .map(|index| {
+                let pos = transform.position_from_point(&points[index]);
+                let dist_sq = point.distance_sq(pos);
+                ClosestElem { index, dist_sq }
+            })
+            .min_by_key(|e| e.dist_sq.ord())
     }
 }
 
 // ----------------------------------------------------------------------------
-// Filled range
-
-pub struct FilledRange {
-    pub(super) start: f64,
-    pub(super) end: f64,
-    pub(super) orientation: Orientation,
-    pub(super) stroke: Stroke,
+// HeatMap
+
+/// A grid of values shown as colored cells, e.g. a correlation matrix or a volatility surface.
+///
+/// The colors come from a [`Colormap`], spread over the range of the values or over
+/// [`Self::value_range`]. Hovering a cell shows its value. `NaN` values leave their cell empty.
+pub struct HeatMap {
+    /// The values of the rows, one after the other.
+    pub(super) values: Vec<f64>,
+    pub(super) columns: usize,
+    pub(super) x_range: RangeInclusive<f64>,
+    pub(super) y_range: RangeInclusive<f64>,
+    pub(super) colormap: Colormap,
+    pub(super) value_range: Option<RangeInclusive<f64>>,
+    pub(super) color_bar: bool,
     pub(super) name: String,
     pub(super) highlight: bool,
-    pub(super) fill_alpha: f32,
-    pub(super) style: LineStyle,
 }
 
-impl FilledRange {
-    pub fn new(orientation: Orientation, start: f64, end: f64) -> Self {
+impl HeatMap {
+    /// The cells of each row of `rows` are spread evenly over `x_range`,
+    /// and the rows over `y_range`, the first row at its start.
+    ///
+    /// Rows shorter than the longest one are filled up with empty cells.
+    pub fn new(
+        rows: Vec<Vec<f64>>,
+        x_range: RangeInclusive<f64>,
+        y_range: RangeInclusive<f64>,
+    ) -> Self {
+        let columns = rows.iter().map(Vec::len).max().unwrap_or(0);
+        let mut values = Vec::with_capacity(rows.len() * columns);
+        for row in rows {
+            let missing = columns - row.len();
+            values.extend(row);
+            values.extend(std::iter::repeat(f64::NAN).take(missing));
+        }
         Self {
-            start,
-            end,
-            orientation,
-            stroke: Stroke::new(1.0, Color32::TRANSPARENT),
+            values,
+            columns,
+            x_range,
+            y_range,
+            colormap: Colormap::default(),
+            value_range: None,
+            color_bar: false,
             name: Default::default(),
             highlight: false,
-            fill_alpha: DEFAULT_FILL_ALPHA,
-            style: LineStyle::Solid,
         }
     }
 
-    /// Highlight this polygon in the plot by scaling up the stroke and reducing the fill
-    /// transparency.
-    pub fn highlight(mut self, highlight: bool) -> Self {
-        self.highlight = highlight;
-        self
-    }
-
-    /// Add a custom stroke.
-    pub fn stroke(mut self, stroke: impl Into<Stroke>) -> Self {
-        self.stroke = stroke.into();
-        self
-    }
-
-    /// Set the stroke width.
-    pub fn width(mut self, width: impl Into<f32>) -> Self {
-        self.stroke.width = width.into();
+    /// How the values are turned into colors. Default: [`Colormap::Viridis`].
+    pub fn colormap(mut self, colormap: Colormap) -> Self {
+        self.colormap = colormap;
         self
     }
 
-    /// Stroke color. Default is `Color32::TRANSPARENT` which means a color will be auto-assigned.
-    pub fn color(mut self, color: impl Into<Color32>) -> Self {
-        self.stroke.color = color.into();
+    /// The values at the ends of the colormap. Values outside of it get the color of its ends.
+    ///
+    /// Default: the lowest and the highest value, e.g. use `-1.0..=1.0` for a correlation matrix instead.
+    pub fn value_range(mut self, value_range: RangeInclusive<f64>) -> Self {
+        self.value_range = Some(value_range);
         self
     }
 
-    /// Alpha of the filled area.
-    pub fn fill_alpha(mut self, alpha: impl Into<f32>) -> Self {
-        self.fill_alpha = alpha.into();
+    /// Show the colormap and the values at its ends along the right edge of the plot.
+    ///
+    /// Default: `false`.
+    pub fn color_bar(mut self, color_bar: bool) -> Self {
+        self.color_bar = color_bar;
         self
     }
 
-    /// Set the outline's style. Default is `LineStyle::Solid`.
-    pub fn style(mut self, style: LineStyle) -> Self {
-        self.style = style;
+    /// Highlight this heat map in the plot by outlining it.
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.highlight = highlight;
         self
     }
 
-    /// Name of this polygon.
+    /// Name of this heat map.
     ///
     /// This name will show up in the plot legend, if legends are turned on.
     ///
@@ -1748,63 +3410,325 @@ impl FilledRange {
         self.name = name.to_string();
         self
     }
+
+    fn rows(&self) -> usize {
+        if self.columns == 0 {
+            0
+        } else {
+            self.values.len() / self.columns
+        }
+    }
+
+    /// The values at the ends of the colormap.
+    fn color_range(&self) -> (f64, f64) {
+        colormap_extent(&self.values, &self.value_range)
+    }
+
+    fn color_of(&self, value: f64, range: (f64, f64)) -> Color32 {
+        if value.is_nan() {
+            return Color32::TRANSPARENT;
+        }
+        self.colormap.color_at(colormap_position(value, range))
+    }
+
+    /// The corners of the cell with the value at `index`.
+    fn cell_bounds(&self, index: usize) -> (PlotPoint, PlotPoint) {
+        let (row, column) = (index / self.columns, index % self.columns);
+        let (x_start, y_start) = (*self.x_range.start(), *self.y_range.start());
+        let width = (self.x_range.end() - x_start) / self.columns as f64;
+        let height = (self.y_range.end() - y_start) / self.rows() as f64;
+        let min = PlotPoint::new(
+            x_start + column as f64 * width,
+            y_start + row as f64 * height,
+        );
+        (min, PlotPoint::new(min.x + width, min.y + height))
+    }
 }
 
-impl PlotItem for FilledRange {
-    fn shapes(&self, _ui: &mut Ui, transform: &ScreenTransform, shapes: &mut Vec<Shape>) {
-        let Self {
-            start: top,
-            end: bottom,
-            stroke,
+/// A [`Tessellator`] for meshes that are made while painting the plot, like the template of [`markers_mesh`].
+fn tessellator(ui: &Ui) -> Tessellator {
+    let ctx = ui.ctx();
+    let pixels_per_point = ctx.pixels_per_point();
+    let options = *ctx.tessellation_options();
+    let texture_atlas = ctx.fonts().texture_atlas();
+    let (font_tex_size, prepared_discs) = {
+        let atlas = texture_atlas.lock();
+        (atlas.size(), atlas.prepared_discs())
+    };
+    Tessellator::new(pixels_per_point, options, font_tex_size, prepared_discs)
+}
+
+/// The `markers` of [`Points`], each a center, a radius and a color, as the template of their
+/// `shape` painted once for each of them. The template is made at the largest radius and scaled down.
+fn markers_mesh(
+    tessellator: &mut Tessellator,
+    shape: MarkerShape,
+    filled: bool,
+    highlight: bool,
+    markers: &[(Pos2, f32, Color32)],
+) -> InstancedMesh {
+    let template_radius = markers
+        .iter()
+        .map(|&(_, radius, _)| radius)
+        .fold(0.0, f32::max);
+    let mut template = Mesh::default();
+    if template_radius > 0.0 {
+        let mut template_shapes = vec![];
+        marker_shapes(
+            shape,
+            filled,
             highlight,
-            mut fill_alpha,
-            style,
-            ..
-        } = self;
+            template_radius,
+            &mut template_shapes,
+        );
+        for template_shape in template_shapes {
+            tessellator.tessellate_shape(template_shape, &mut template);
+        }
+    }
 
-        if *highlight {
-            fill_alpha = (2.0 * fill_alpha).at_most(1.0);
+    let mut instanced = InstancedMesh::new(template);
+    for &(center, radius, color) in markers {
+        if radius > 0.0 {
+            instanced.add(Instance {
+                offset: center.to_vec2(),
+                scale: radius / template_radius,
+                color,
+            });
         }
+    }
+    instanced
+}
 
-        let series = if self.orientation == Orientation::Horizontal {
-            vec![
-                transform
-                    .position_from_point(&PlotPoint::new(transform.bounds().min[0], self.start)),
-                transform
-                    .position_from_point(&PlotPoint::new(transform.bounds().max[0], self.start)),
-                transform.position_from_point(&PlotPoint::new(transform.bounds().max[0], self.end)),
-                transform.position_from_point(&PlotPoint::new(transform.bounds().min[0], self.end)),
-                transform
-                    .position_from_point(&PlotPoint::new(transform.bounds().min[0], self.start)),
-            ]
-        } else {
-            vec![
-                transform
-                    .position_from_point(&PlotPoint::new(self.start, transform.bounds().min[1])),
-                transform
-                    .position_from_point(&PlotPoint::new(self.start, transform.bounds().max[1])),
-                transform.position_from_point(&PlotPoint::new(self.end, transform.bounds().max[1])),
-                transform.position_from_point(&PlotPoint::new(self.end, transform.bounds().min[1])),
-                transform
-                    .position_from_point(&PlotPoint::new(self.start, transform.bounds().min[1])),
-            ]
-        };
+/// The shapes of a white marker of this `radius` around the origin, see [`markers_mesh`].
+fn marker_shapes(
+    shape: MarkerShape,
+    filled: bool,
+    highlight: bool,
+    mut radius: f32,
+    shapes: &mut Vec<Shape>,
+) {
+    let sqrt_3 = 3_f32.sqrt();
+    let frac_sqrt_3_2 = 3_f32.sqrt() / 2.0;
+    let frac_1_sqrt_2 = 1.0 / 2_f32.sqrt();
+
+    let color = Color32::WHITE;
+    let default_stroke = Stroke::new(radius / 5.0, color);
+    let stroke = (!filled)
+        .then(|| default_stroke)
+        .unwrap_or_else(Stroke::none);
+    let fill = filled.then(|| color).unwrap_or_default();
+
+    if highlight {
+        radius *= 2f32.sqrt();
+    }
+
+    let tf = |dx: f32, dy: f32| -> Pos2 { pos2(radius * dx, radius * dy) };
+
+    match shape {
+        MarkerShape::Circle => {
+            shapes.push(Shape::Circle(epaint::CircleShape {
+                center: Pos2::ZERO,
+                radius,
+                fill,
+                stroke,
+            }));
+        }
+        MarkerShape::Diamond => {
+            let points = vec![
+                tf(0.0, 1.0),  // bottom
+                tf(-1.0, 0.0), // left
+                tf(0.0, -1.0), // top
+                tf(1.0, 0.0),  // right
+            ];
+            shapes.push(Shape::convex_polygon(points, fill, stroke));
+        }
+        MarkerShape::Square => {
+            let points = vec![
+                tf(-frac_1_sqrt_2, frac_1_sqrt_2),
+                tf(-frac_1_sqrt_2, -frac_1_sqrt_2),
+                tf(frac_1_sqrt_2, -frac_1_sqrt_2),
+                tf(frac_1_sqrt_2, frac_1_sqrt_2),
+            ];
+            shapes.push(Shape::convex_polygon(points, fill, stroke));
+        }
+        MarkerShape::Cross => {
+            let diagonal1 = [
+                tf(-frac_1_sqrt_2, -frac_1_sqrt_2),
+                tf(frac_1_sqrt_2, frac_1_sqrt_2),
+            ];
+            let diagonal2 = [
+                tf(frac_1_sqrt_2, -frac_1_sqrt_2),
+                tf(-frac_1_sqrt_2, frac_1_sqrt_2),
+            ];
+            shapes.push(Shape::line_segment(diagonal1, default_stroke));
+            shapes.push(Shape::line_segment(diagonal2, default_stroke));
+        }
+        MarkerShape::Plus => {
+            let horizontal = [tf(-1.0, 0.0), tf(1.0, 0.0)];
+            let vertical = [tf(0.0, -1.0), tf(0.0, 1.0)];
+            shapes.push(Shape::line_segment(horizontal, default_stroke));
+            shapes.push(Shape::line_segment(vertical, default_stroke));
+        }
+        MarkerShape::Up => {
+            let points = vec![tf(0.0, -1.0), tf(0.5 * sqrt_3, 0.5), tf(-0.5 * sqrt_3, 0.5)];
+            shapes.push(Shape::convex_polygon(points, fill, stroke));
+        }
+        MarkerShape::Down => {
+            let points = vec![
+                tf(0.0, 1.0),
+                tf(-0.5 * sqrt_3, -0.5),
+                tf(0.5 * sqrt_3, -0.5),
+            ];
+            shapes.push(Shape::convex_polygon(points, fill, stroke));
+        }
+        MarkerShape::Left => {
+            let points = vec![tf(-1.0, 0.0), tf(0.5, -0.5 * sqrt_3), tf(0.5, 0.5 * sqrt_3)];
+            shapes.push(Shape::convex_polygon(points, fill, stroke));
+        }
+        MarkerShape::Right => {
+            let points = vec![
+                tf(1.0, 0.0),
+                tf(-0.5, 0.5 * sqrt_3),
+                tf(-0.5, -0.5 * sqrt_3),
+            ];
+            shapes.push(Shape::convex_polygon(points, fill, stroke));
+        }
+        MarkerShape::Asterisk => {
+            let vertical = [tf(0.0, -1.0), tf(0.0, 1.0)];
+            let diagonal1 = [tf(-frac_sqrt_3_2, 0.5), tf(frac_sqrt_3_2, -0.5)];
+            let diagonal2 = [tf(-frac_sqrt_3_2, -0.5), tf(frac_sqrt_3_2, 0.5)];
+            shapes.push(Shape::line_segment(vertical, default_stroke));
+            shapes.push(Shape::line_segment(diagonal1, default_stroke));
+            shapes.push(Shape::line_segment(diagonal2, default_stroke));
+        }
+    }
+}
 
-        let fill = Rgba::from(stroke.color).to_opaque().multiply(fill_alpha);
+/// The values at the ends of a colormap: `value_range`, or else the lowest and the highest value.
+fn colormap_extent(values: &[f64], value_range: &Option<RangeInclusive<f64>>) -> (f64, f64) {
+    if let Some(range) = value_range {
+        return (*range.start(), *range.end());
+    }
+    values
+        .iter()
+        .filter(|value| value.is_finite())
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), &value| {
+            (min.min(value), max.max(value))
+        })
+}
 
-        let shape = Shape::convex_polygon(series.clone(), fill, Stroke::none());
-        shapes.push(shape);
-        style.style_line(series, *stroke, *highlight, shapes);
+/// Where `value` is between the ends of a colormap, for [`Colormap::color_at`].
+fn colormap_position(value: f64, (min, max): (f64, f64)) -> f64 {
+    if max > min {
+        (value - min) / (max - min)
+    } else {
+        0.5
+    }
+}
+
+/// Show the colormap and the values at its ends along the right edge of the plot.
+fn paint_color_bar(
+    colormap: &Colormap,
+    ui: &Ui,
+    transform: &ScreenTransform,
+    (min, max): (f64, f64),
+    shapes: &mut Vec<Shape>,
+) {
+    const WIDTH: f32 = 12.0;
+    const MARGIN: f32 = 8.0;
+    const STEPS: usize = 64;
+
+    if !(min.is_finite() && max.is_finite()) {
+        return;
+    }
+    let frame = transform.frame();
+    let bar = Rect::from_min_max(
+        pos2(frame.right() - MARGIN - WIDTH, frame.top() + MARGIN),
+        pos2(frame.right() - MARGIN, frame.bottom() - MARGIN),
+    );
+    let step_height = bar.height() / STEPS as f32;
+    let mut mesh = Mesh::default();
+    for step in 0..STEPS {
+        let bottom = bar.bottom() - step as f32 * step_height;
+        let rect = Rect::from_min_max(
+            pos2(bar.left(), bottom - step_height),
+            pos2(bar.right(), bottom),
+        );
+        let t = (step as f64 + 0.5) / STEPS as f64;
+        mesh.add_colored_rect(rect, colormap.color_at(t));
+    }
+    shapes.push(Shape::mesh(mesh));
+    shapes.push(Shape::rect_stroke(
+        bar,
+        0.0,
+        ui.visuals().widgets.noninteractive.bg_stroke,
+    ));
+
+    let font_id = TextStyle::Small.resolve(ui.style());
+    let locale_format = ui.ctx().locale_format();
+    let decimals = value_decimals(min, max);
+    for (value, pos, anchor) in [
+        (max, bar.left_top(), Align2::RIGHT_TOP),
+        (min, bar.left_bottom(), Align2::RIGHT_BOTTOM),
+    ] {
+        shapes.push(Shape::text(
+            &*ui.fonts(),
+            pos - vec2(3.0, 0.0),
+            anchor,
+            locale_format.format_number(&format!("{:.*}", decimals, value)),
+            font_id.clone(),
+            ui.visuals().text_color(),
+        ));
+    }
+}
+
+/// Enough decimals to tell apart the values between `min` and `max`.
+fn value_decimals(min: f64, max: f64) -> usize {
+    ((2.0 - (max - min).abs().log10().floor()).at_least(0.0) as usize).at_most(6)
+}
+
+impl PlotItem for HeatMap {
+    fn shapes(&self, ui: &mut Ui, transform: &ScreenTransform, shapes: &mut Vec<Shape>) {
+        let range = self.color_range();
+        let mut mesh = Mesh::default();
+        for (index, &value) in self.values.iter().enumerate() {
+            if value.is_nan() {
+                continue;
+            }
+            let (min, max) = self.cell_bounds(index);
+            let rect = transform.rect_from_values(&min, &max);
+            if rect.intersects(*transform.frame()) {
+                mesh.add_colored_rect(rect, self.color_of(value, range));
+            }
+        }
+        shapes.push(Shape::mesh(mesh));
+
+        if self.highlight {
+            let bounds = self.bounds();
+            let rect = transform.rect_from_values(
+                &PlotPoint::new(bounds.min[0], bounds.min[1]),
+                &PlotPoint::new(bounds.max[0], bounds.max[1]),
+            );
+            shapes.push(Shape::rect_stroke(
+                rect,
+                0.0,
+                Stroke::new(1.0, ui.visuals().strong_text_color()),
+            ));
+        }
+
+        if self.color_bar {
+            paint_color_bar(&self.colormap, ui, transform, range, shapes);
+        }
     }
 
-    fn initialize(&mut self, x_range: RangeInclusive<f64>) {}
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
 
     fn name(&self) -> &str {
         self.name.as_str()
     }
 
     fn color(&self) -> Color32 {
-        self.stroke.color
+        self.colormap.color_at(0.5)
     }
 
     fn highlight(&mut self) {
@@ -1816,21 +3740,159 @@ impl PlotItem for FilledRange {
     }
 
     fn geometry(&self) -> PlotGeometry<'_> {
-        PlotGeometry::None
+        PlotGeometry::Rects
     }
 
     fn bounds(&self) -> PlotBounds {
         let mut bounds = PlotBounds::NOTHING;
-        bounds.min[1] = self.start;
-        bounds.max[1] = self.start;
+        if !self.values.is_empty() {
+            bounds.extend_with(&PlotPoint::new(
+                *self.x_range.start(),
+                *self.y_range.start(),
+            ));
+            bounds.extend_with(&PlotPoint::new(*self.x_range.end(), *self.y_range.end()));
+        }
         bounds
     }
+
+    fn find_closest(&self, point: Pos2, transform: &ScreenTransform) -> Option<ClosestElem> {
+        if self.values.is_empty() {
+            return None;
+        }
+        let value = transform.value_from_position(point);
+        let (x_start, y_start) = (*self.x_range.start(), *self.y_range.start());
+        let column = (value.x - x_start) / (self.x_range.end() - x_start) * self.columns as f64;
+        let row = (value.y - y_start) / (self.y_range.end() - y_start) * self.rows() as f64;
+        let inside = (0.0..self.columns as f64).contains(&column)
+            && (0.0..self.rows() as f64).contains(&row);
+        let index = row as usize * self.columns + column as usize;
+        (inside && !self.values[index].is_nan()).then(|| ClosestElem {
+            index,
+            // Any other item that is close enough to the pointer is hovered instead of the cell.
+            dist_sq: super::HOVER_RADIUS.powi(2),
+        })
+    }
+
+    fn element_value(&self, index: usize, _: &ScreenTransform) -> Option<PlotElementValue> {
+        self.values.get(index).copied().map(PlotElementValue::Cell)
+    }
+
+    fn on_hover(
+        &self,
+        elem: ClosestElem,
+        shapes: &mut Vec<Shape>,
+        plot: &PlotConfig<'_>,
+        label_formatter: &LabelFormatter,
+    ) {
+        let (min, max) = self.cell_bounds(elem.index);
+        let rect = plot.transform.rect_from_values(&min, &max);
+        shapes.push(Shape::rect_stroke(
+            rect,
+            0.0,
+            Stroke::new(2.0, plot.ui.visuals().strong_text_color()),
+        ));
+
+        let center = PlotPoint::new((min.x + max.x) / 2.0, (min.y + max.y) / 2.0);
+        let locale_format = plot.ui.ctx().locale_format();
+        let (range_min, range_max) = self.color_range();
+        let value = locale_format.format_number(&format!(
+            "{:.*}",
+            value_decimals(range_min, range_max),
+            self.values[elem.index]
+        ));
+        let value_label = plot.ui.ctx().translate("egui.plot.heatmap.value", "Value");
+        let position = if let Some(custom_label) = label_formatter {
+            custom_label(&self.name, &center)
+        } else {
+            let scale = plot.transform.dvalue_dpos();
+            let x_decimals = ((-scale[0].abs().log10()).ceil().at_least(0.0) as usize).at_most(6);
+            let y_decimals = ((-scale[1].abs().log10()).ceil().at_least(0.0) as usize).at_most(6);
+            let x = match plot.x_axis_time {
+                Some(TimeFormat::Auto) => TimeFormat::DateTime.format(center.x, &locale_format),
+                Some(time_format) => time_format.format(center.x, &locale_format),
+                None => locale_format.format_number(&format!("{:.*}", x_decimals, center.x)),
+            };
+            let y = locale_format.format_number(&format!("{:.*}", y_decimals, center.y));
+            let (x, y) = (with_unit(x, &plot.units[0]), with_unit(y, &plot.units[1]));
+            let mut position = format!("x = {}\ny = {}", x, y);
+            if !self.name.is_empty() {
+                position = format!("{}\n{}", self.name, position);
+            }
+            position
+        };
+        let text = format!("{}\n{} = {}", position, value_label, value);
+
+        shapes.push(Shape::text(
+            &*plot.ui.fonts(),
+            rect.right_top() + vec2(3.0, -2.0),
+            Align2::LEFT_BOTTOM,
+            text,
+            TextStyle::Body.resolve(plot.ui.style()),
+            plot.ui.visuals().text_color(),
+        ));
+    }
 }
 
 // ----------------------------------------------------------------------------
 // Helper functions
 
-fn rulers_color(ui: &Ui) -> Color32 {
+fn find_closest_point(
+    points: &[PlotPoint],
+    point: Pos2,
+    transform: &ScreenTransform,
+) -> Option<ClosestElem> {
+    points
+        .iter()
+        .enumerate()
+        .map(|(index, value)| {
+            let pos = transform.position_from_point(value);
+            let dist_sq = point.distance_sq(pos);
+            ClosestElem { index, dist_sq }
+        })
+        .min_by_key(|e| e.dist_sq.ord())
+}
+
+fn find_closest_point_x(
+    points: &[PlotPoint],
+    x: f32,
+    transform: &ScreenTransform,
+) -> Option<ClosestElem> {
+    points
+        .iter()
+        .enumerate()
+        .map(|(index, value)| {
+            let dx = transform.position_from_point(value).x - x;
+            ClosestElem {
+                index,
+                dist_sq: dx * dx,
+            }
+        })
+        .min_by_key(|e| e.dist_sq.ord())
+}
+
+/// Adds `label`, unless it's empty, as a small text with its `anchor` at `pos`.
+fn add_label(
+    ui: &Ui,
+    label: &str,
+    pos: Pos2,
+    anchor: Align2,
+    color: Color32,
+    shapes: &mut Vec<Shape>,
+) {
+    if label.is_empty() {
+        return;
+    }
+    shapes.push(Shape::text(
+        &*ui.fonts(),
+        pos,
+        anchor,
+        label,
+        TextStyle::Small.resolve(ui.style()),
+        color,
+    ));
+}
+
+pub(super) fn rulers_color(ui: &Ui) -> Color32 {
     if ui.visuals().dark_mode {
         Color32::from_gray(100).additive()
     } else {
@@ -1838,7 +3900,11 @@ fn rulers_color(ui: &Ui) -> Color32 {
     }
 }
 
-fn vertical_line(pointer: Pos2, transform: &ScreenTransform, line_color: Color32) -> Shape {
+pub(super) fn vertical_line(
+    pointer: Pos2,
+    transform: &ScreenTransform,
+    line_color: Color32,
+) -> Shape {
     let frame = transform.frame();
     Shape::line_segment(
         [
@@ -1849,7 +3915,11 @@ fn vertical_line(pointer: Pos2, transform: &ScreenTransform, line_color: Color32
     )
 }
 
-fn horizontal_line(pointer: Pos2, transform: &ScreenTransform, line_color: Color32) -> Shape {
+pub(super) fn horizontal_line(
+    pointer: Pos2,
+    transform: &ScreenTransform,
+    line_color: Color32,
+) -> Shape {
     let frame = transform.frame();
     Shape::line_segment(
         [
@@ -1911,7 +3981,7 @@ fn add_rulers_and_text(
         let mut text = elem.name().to_owned(); // could be empty
 
         if show_values {
-            text.push_str(&elem.default_values_format(plot.transform));
+            text.push_str(&elem.default_values_format(plot.transform, plot.ui.ctx()));
         }
 
         text
@@ -1930,6 +4000,15 @@ fn add_rulers_and_text(
     ));
 }
 
+/// `value` followed by `unit`, unless either is empty.
+pub(super) fn with_unit(value: String, unit: &str) -> String {
+    if value.is_empty() || unit.is_empty() {
+        value
+    } else {
+        format!("{} {}", value, unit)
+    }
+}
+
 /// Draws a cross of horizontal and vertical ruler at the `pointer` position.
 /// `value` is used to for text displaying X/Y coordinates.
 #[allow(clippy::too_many_arguments)]
@@ -1959,17 +4038,22 @@ pub(super) fn rulers_at_value(
         let scale = plot.transform.dvalue_dpos();
         let x_decimals = ((-scale[0].abs().log10()).ceil().at_least(0.0) as usize).at_most(6);
         let y_decimals = ((-scale[1].abs().log10()).ceil().at_least(0.0) as usize).at_most(6);
+        let locale_format = plot.ui.ctx().locale_format();
+        let x = match plot.x_axis_time {
+            Some(TimeFormat::Auto) => TimeFormat::DateTime.format(value.x, &locale_format),
+            Some(time_format) => time_format.format(value.x, &locale_format),
+            None => locale_format.format_number(&format!("{:.*}", x_decimals, value.x)),
+        };
+        let y = locale_format.format_number(&format!("{:.*}", y_decimals, value.y));
+        let (x, y) = (with_unit(x, &plot.units[0]), with_unit(y, &plot.units[1]));
         if let Some(custom_label) = label_formatter {
             custom_label(name, &value)
         } else if plot.show_x && plot.show_y {
-            format!(
-                "{}x = {:.*}\ny = {:.*}",
-                prefix, x_decimals, value.x, y_decimals, value.y
-            )
+            format!("{}x = {}\ny = {}", prefix, x, y)
         } else if plot.show_x {
-            format!("{}x = {:.*}", prefix, x_decimals, value.x)
+            format!("{}x = {}", prefix, x)
         } else if plot.show_y {
-            format!("{}y = {:.*}", prefix, y_decimals, value.y)
+            format!("{}y = {}", prefix, y)
         } else {
             unreachable!()
         }
@@ -1987,6 +4071,31 @@ pub(super) fn rulers_at_value(
     ));
 }
 
+/// Like [`find_closest_rect`], but only by the distance along the x axis.
+fn find_closest_rect_x<'a, T>(
+    rects: impl IntoIterator<Item = &'a T>,
+    x: f32,
+    transform: &ScreenTransform,
+) -> Option<ClosestElem>
+where
+    T: 'a + RectElement,
+{
+    rects
+        .into_iter()
+        .enumerate()
+        .map(|(index, bar)| {
+            let bar_rect: Rect = transform.rect_from_values(&bar.bounds_min(), &bar.bounds_max());
+            let dx = (bar_rect.left() - x)
+                .max(x - bar_rect.right())
+                .at_least(0.0);
+            ClosestElem {
+                index,
+                dist_sq: dx * dx,
+            }
+        })
+        .min_by_key(|e| e.dist_sq.ord())
+}
+
 fn find_closest_rect<'a, T>(
     rects: impl IntoIterator<Item = &'a T>,
     point: Pos2,
@@ -2006,3 +4115,75 @@ where
         })
         .min_by_key(|e| e.dist_sq.ord())
 }
+
+#[test]
+fn test_markers_mesh() {
+    let mut tessellator = Tessellator::new(1.0, Default::default(), [1, 1], vec![]);
+    let markers = [
+        (pos2(10.0, 10.0), 2.0, Color32::RED),
+        (pos2(20.0, 10.0), 4.0, Color32::BLUE),
+        (pos2(30.0, 10.0), 0.0, Color32::GREEN),
+    ];
+    let instanced = markers_mesh(&mut tessellator, MarkerShape::Square, true, false, &markers);
+
+    // One template, made at the largest radius, for the markers that can be seen:
+    assert!(instanced.is_valid());
+    assert_eq!(instanced.instances.len(), 2);
+    assert_eq!(instanced.instances[0].scale, 0.5);
+    assert_eq!(instanced.instances[1].color, Color32::BLUE);
+    let template = instanced.template.calc_bounds();
+    assert!(template.center().distance(Pos2::ZERO) < 1e-3);
+    assert!(template.width() >= 4.0 * 2_f32.sqrt());
+
+    let mesh = instanced.to_mesh();
+    assert_eq!(mesh.vertices.len(), 2 * instanced.template.vertices.len());
+    let first_marker = &mesh.vertices[..instanced.template.vertices.len()];
+    assert!(first_marker
+        .iter()
+        .all(|vertex| vertex.color == Color32::RED || vertex.color == Color32::TRANSPARENT));
+
+    let empty = markers_mesh(&mut tessellator, MarkerShape::Circle, true, false, &[]);
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn test_stack_bars() {
+    let charts = BarChart::stack(vec![
+        BarChart::new(vec![Bar::new(0.0, 1.0), Bar::new(1.0, -2.0)]),
+        BarChart::new(vec![Bar::new(-0.0, 2.0), Bar::new(1.0, 3.0)]),
+    ]);
+    let top = &charts[1].bars;
+    assert_eq!(top[0].base_offset, Some(1.0));
+    assert_eq!(top[0].stack_total, Some(3.0));
+    // Negative values stack downwards, separately from the positive ones:
+    assert_eq!(top[1].base_offset, Some(0.0));
+    assert_eq!(top[1].stack_total, Some(1.0));
+}
+
+#[test]
+fn test_normalize_to_percent() {
+    let chart_plot = || {
+        let candles = [(0.0, 0.0), (1.0, 2.0)]
+            .iter()
+            .map(|&(x, close)| {
+                let mut elem = CandleElem::new(Candle::new(close, close, close, close, 1.0));
+                elem.x = x;
+                elem
+            })
+            .collect();
+        ChartPlot::new(candles)
+    };
+    let mut normalized = chart_plot();
+    normalized.normalize_to_percent(1.0);
+    assert_eq!(normalized.candle_elems[0].candle.close, -100.0);
+    assert_eq!(normalized.candle_elems[1].candle.close, 0.0);
+
+    // Relative to a close of zero the candles stay as they are:
+    let mut unchanged = chart_plot();
+    unchanged.normalize_to_percent(0.0);
+    assert_eq!(unchanged.candle_elems[1].candle.close, 2.0);
+
+    let mut comparison = Comparison::new("zero", vec![[0.0, 0.0], [1.0, 5.0]]);
+    comparison.normalize_to_percent(0.0);
+    assert_eq!(comparison.series[1].y, 5.0);
+}
@@ -0,0 +1,151 @@
+use crate::epaint::{Color32, Shape, Stroke};
+
+use super::{add_rulers_and_text, Candle, CandleElem, Orientation, PlotConfig, RectElement};
+use crate::plot::{OhlcChart, PlotPoint, ScreenTransform};
+use crate::Context;
+
+/// A classic OHLC bar: a vertical line from the low to the high,
+/// with a tick to the left at the open and a tick to the right at the close.
+#[derive(Clone, Debug, PartialEq)]
+pub struct OhlcElem {
+    pub x: f64,
+    pub candle: Candle,
+    /// From the end of the open tick to the end of the close tick.
+    pub tick_width: f64,
+    pub stroke: Stroke,
+}
+
+impl OhlcElem {
+    pub fn new(candle: Candle) -> Self {
+        Self {
+            x: 0.0,
+            candle,
+            tick_width: 0.25,
+            stroke: Stroke::new(1.0, Color32::TRANSPARENT),
+        }
+    }
+
+    /// Add a custom stroke.
+    pub fn stroke(mut self, stroke: impl Into<Stroke>) -> Self {
+        self.stroke = stroke.into();
+        self
+    }
+
+    /// Set the width from the end of the open tick to the end of the close tick.
+    pub fn tick_width(mut self, width: f64) -> Self {
+        self.tick_width = width;
+        self
+    }
+
+    /// Its own stroke, or else the color of its chart for rising or falling bars.
+    pub(super) fn stroke_color(&self, parent: &OhlcChart) -> Stroke {
+        if self.stroke.color == Color32::TRANSPARENT {
+            let color = if self.candle.close >= self.candle.open {
+                parent.color_up
+            } else {
+                parent.color_down
+            };
+            Stroke::new(self.stroke.width, color)
+        } else {
+            self.stroke
+        }
+    }
+
+    pub(super) fn add_shapes(
+        &self,
+        parent: &OhlcChart,
+        transform: &ScreenTransform,
+        highlighted: bool,
+        shapes: &mut Vec<Shape>,
+    ) {
+        let mut stroke = self.stroke_color(parent);
+        if highlighted {
+            stroke.width *= 2.0;
+        }
+
+        let line_between = |v1, v2| {
+            Shape::line_segment(
+                [
+                    transform.position_from_point(&v1),
+                    transform.position_from_point(&v2),
+                ],
+                stroke,
+            )
+        };
+
+        let half_width = self.tick_width / 2.0;
+        shapes.push(line_between(
+            self.point_at(self.x, self.candle.low),
+            self.point_at(self.x, self.candle.high),
+        ));
+        shapes.push(line_between(
+            self.point_at(self.x - half_width, self.candle.open),
+            self.point_at(self.x, self.candle.open),
+        ));
+        shapes.push(line_between(
+            self.point_at(self.x, self.candle.close),
+            self.point_at(self.x + half_width, self.candle.close),
+        ));
+    }
+
+    pub(super) fn add_rulers_and_text(
+        &self,
+        parent: &OhlcChart,
+        plot: &PlotConfig<'_>,
+        shapes: &mut Vec<Shape>,
+    ) {
+        let text: Option<String> = parent
+            .element_formatter
+            .as_ref()
+            .map(|fmt| fmt(self, parent));
+
+        add_rulers_and_text(self, plot, text, shapes);
+    }
+}
+
+/// The same candle at the same place, as wide as the candle body.
+impl From<CandleElem> for OhlcElem {
+    fn from(elem: CandleElem) -> Self {
+        Self {
+            x: elem.x,
+            candle: elem.candle,
+            tick_width: elem.candle_width,
+            stroke: elem.stroke,
+        }
+    }
+}
+
+impl RectElement for OhlcElem {
+    fn name(&self) -> &str {
+        ""
+    }
+
+    fn bounds_min(&self) -> PlotPoint {
+        self.point_at(self.x - self.tick_width / 2.0, self.candle.low)
+    }
+
+    fn bounds_max(&self) -> PlotPoint {
+        self.point_at(self.x + self.tick_width / 2.0, self.candle.high)
+    }
+
+    fn values_with_ruler(&self) -> Vec<PlotPoint> {
+        let open = self.point_at(self.x, self.candle.open);
+        let high = self.point_at(self.x, self.candle.high);
+        let low = self.point_at(self.x, self.candle.low);
+        let close = self.point_at(self.x, self.candle.close);
+
+        vec![open, high, low, close]
+    }
+
+    fn orientation(&self) -> Orientation {
+        Orientation::Vertical
+    }
+
+    fn corner_value(&self) -> PlotPoint {
+        self.point_at(self.x, self.candle.high)
+    }
+
+    fn default_values_format(&self, transform: &ScreenTransform, ctx: &Context) -> String {
+        self.candle.values_format(transform, ctx)
+    }
+}
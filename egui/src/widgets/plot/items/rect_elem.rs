@@ -1,7 +1,8 @@
 use super::{Orientation, PlotPoint};
 use crate::plot::transform::{PlotBounds, ScreenTransform};
-use epaint::emath::NumExt;
-use epaint::{Color32, Rgba, Stroke};
+use crate::Context;
+use epaint::emath::{pos2, NumExt, Pos2, Rect};
+use epaint::{Color32, Mesh, Rgba, Stroke};
 
 /// Trait that abstracts from rectangular 'Value'-like elements, such as bars or boxes
 pub(super) trait RectElement {
@@ -47,8 +48,10 @@ pub(super) trait RectElement {
         }
     }
 
-    /// Debug formatting for hovered-over value, if none is specified by the user
-    fn default_values_format(&self, transform: &ScreenTransform) -> String;
+    /// Debug formatting for hovered-over value, if none is specified by the user.
+    ///
+    /// Labels are translated with `ctx`, see [`crate::i18n`].
+    fn default_values_format(&self, transform: &ScreenTransform, ctx: &Context) -> String;
 }
 
 // ----------------------------------------------------------------------------
@@ -61,3 +64,46 @@ pub(super) fn highlighted_color(mut stroke: Stroke, fill: Color32) -> (Stroke, C
     let fill = fill.to_opaque().multiply(fill_alpha);
     (stroke, fill.into())
 }
+
+/// Add a filled and outlined rectangle to a mesh, like a [`epaint::RectShape`] without rounding.
+///
+/// It's made of plain quads without anti-aliasing, so that the elements of a whole chart can
+/// be drawn as one mesh, which is far cheaper to tessellate than a shape per element.
+pub(super) fn add_rect_to_mesh(mesh: &mut Mesh, rect: Rect, fill: Color32, stroke: Stroke) {
+    if fill != Color32::TRANSPARENT {
+        mesh.add_colored_rect(rect, fill);
+    }
+    if stroke.width <= 0.0 || stroke.color == Color32::TRANSPARENT {
+        return;
+    }
+    let outer = rect.expand(stroke.width / 2.0);
+    let inner = rect.shrink(stroke.width / 2.0);
+    if inner.width() <= 0.0 || inner.height() <= 0.0 {
+        // Too thin to show anything but its outline.
+        mesh.add_colored_rect(outer, stroke.color);
+        return;
+    }
+    let sides = [
+        Rect::from_min_max(outer.min, pos2(outer.max.x, inner.min.y)),
+        Rect::from_min_max(pos2(outer.min.x, inner.max.y), outer.max),
+        Rect::from_min_max(
+            pos2(outer.min.x, inner.min.y),
+            pos2(inner.min.x, inner.max.y),
+        ),
+        Rect::from_min_max(
+            pos2(inner.max.x, inner.min.y),
+            pos2(outer.max.x, inner.max.y),
+        ),
+    ];
+    for side in sides {
+        mesh.add_colored_rect(side, stroke.color);
+    }
+}
+
+/// Add a horizontal or vertical line segment to a mesh, see [`add_rect_to_mesh`].
+pub(super) fn add_segment_to_mesh(mesh: &mut Mesh, points: [Pos2; 2], stroke: Stroke) {
+    if stroke.width > 0.0 && stroke.color != Color32::TRANSPARENT {
+        let rect = Rect::from_two_pos(points[0], points[1]).expand(stroke.width / 2.0);
+        mesh.add_colored_rect(rect, stroke.color);
+    }
+}
@@ -1,6 +1,7 @@
 use epaint::{Pos2, Shape, Stroke, Vec2};
-use std::ops::{Bound, RangeBounds, RangeInclusive};
+use std::ops::{Bound, Range, RangeBounds, RangeInclusive};
 
+use super::{Bar, BoxElem, CandleElem, OhlcElem};
 use crate::plot::transform::PlotBounds;
 
 /// A point coordinate in the plot.
@@ -8,6 +9,7 @@ use crate::plot::transform::PlotBounds;
 /// Uses f64 for improved accuracy to enable plotting
 /// large values (e.g. unix time on x axis).
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct PlotPoint {
     /// This is often something monotonically increasing, such as time, but doesn't have to be.
     /// Goes from left to right.
@@ -133,6 +135,52 @@ impl ToString for LineStyle {
 
 // ----------------------------------------------------------------------------
 
+/// Where a [`super::Line`] drawn as steps goes from one value to the next, see [`super::Line::steps`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Steps {
+    /// Each value holds until the x of the next one, e.g. for a position that changes with each trade.
+    Post,
+
+    /// Each value holds since the x of the previous one, e.g. for a value that is reported at the
+    /// end of the period it is about.
+    Pre,
+
+    /// The steps are halfway between the x values.
+    Mid,
+}
+
+impl Steps {
+    /// The x range that the value at `index` holds for.
+    pub(super) fn x_range(self, points: &[PlotPoint], index: usize) -> (f64, f64) {
+        let x = points[index].x;
+        let previous = index.checked_sub(1).map(|i| points[i].x);
+        let next = points.get(index + 1).map(|point| point.x);
+        match self {
+            Steps::Post => (x, next.unwrap_or(x)),
+            Steps::Pre => (previous.unwrap_or(x), x),
+            Steps::Mid => (
+                previous.map_or(x, |previous| (previous + x) / 2.0),
+                next.map_or(x, |next| (x + next) / 2.0),
+            ),
+        }
+    }
+
+    /// The corners of the stairs through `points`.
+    pub(super) fn stairs(self, points: &[PlotPoint]) -> Vec<PlotPoint> {
+        let mut stairs = Vec::with_capacity(2 * points.len());
+        for (index, point) in points.iter().enumerate() {
+            let (start, end) = self.x_range(points, index);
+            stairs.push(PlotPoint::new(start, point.y));
+            if end != start {
+                stairs.push(PlotPoint::new(end, point.y));
+            }
+        }
+        stairs
+    }
+}
+
+// ----------------------------------------------------------------------------
+
 /// Determines whether a plot element is vertically or horizontally oriented.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum Orientation {
@@ -193,6 +241,15 @@ impl PlotPoints {
         }
     }
 
+    /// Replace the point at `index`, if there is one.
+    pub(super) fn set_point(&mut self, index: usize, point: PlotPoint) {
+        if let PlotPoints::Owned(points) = self {
+            if let Some(old) = points.get_mut(index) {
+                *old = point;
+            }
+        }
+    }
+
     /// Draw a line based on a function `y=f(x)`, a range (which can be infinite) for x and the number of points.
     pub fn from_explicit_callback(
         function: impl Fn(f64) -> f64 + 'static,
@@ -264,6 +321,17 @@ impl PlotPoints {
         ys.iter().enumerate().map(|(i, &y)| [i as f64, y]).collect()
     }
 
+    /// From a series of radii, at evenly spaced angles from `0` to almost a full turn,
+    /// e.g. for a radar chart in a [`crate::plot::PolarPlot`].
+    pub fn from_radii(radii: &[f64]) -> Self {
+        let step = std::f64::consts::TAU / radii.len() as f64;
+        radii
+            .iter()
+            .enumerate()
+            .map(|(i, &radius)| [i as f64 * step, radius])
+            .collect()
+    }
+
     /// Returns true if there are no data points available and there is no function to generate any.
     pub(crate) fn is_empty(&self) -> bool {
         match self {
@@ -274,7 +342,7 @@ impl PlotPoints {
 
     /// If initialized with a generator function, this will generate `n` evenly spaced points in the
     /// given range.
-    pub(super) fn generate_points(&mut self, x_range: RangeInclusive<f64>) {
+    pub(crate) fn generate_points(&mut self, x_range: RangeInclusive<f64>) {
         if let Self::Generator(generator) = self {
             *self = Self::range_intersection(&x_range, &generator.x_range)
                 .map(|intersection| {
@@ -430,3 +498,83 @@ pub(crate) struct ClosestElem {
     /// Squared distance from the mouse cursor (needed to compare against other PlotItems, which might be nearer)
     pub dist_sq: f32,
 }
+
+/// An element of a plot item under the pointer, see [`crate::plot::PlotResponse::hovered_element`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct PlotElement {
+    /// The name of the item.
+    pub name: String,
+
+    /// The index of the element in the item, as it was given to the item.
+    ///
+    /// When elements are merged because there are too many to show, see
+    /// [`crate::plot::ChartPlot::lod_threshold`], it is the first of the merged ones.
+    pub index: usize,
+
+    /// The range of indices of the elements that are shown merged into this one,
+    /// or just [`Self::index`] when it isn't merged.
+    pub indices: Range<usize>,
+
+    /// The value of the element, as it was given to the item.
+    pub value: PlotElementValue,
+
+    /// The value of the merged element that is shown for [`Self::indices`], if it is merged.
+    pub merged_value: Option<PlotElementValue>,
+}
+
+/// The value of a [`PlotElement`], depending on the kind of item it belongs to.
+#[derive(Clone, Debug, PartialEq)]
+pub enum PlotElementValue {
+    /// A value of a line, a set of points or another series.
+    Point(PlotPoint),
+
+    /// A bar of a [`crate::plot::BarChart`].
+    Bar(Bar),
+
+    /// A box of a [`crate::plot::BoxPlot`].
+    Box(BoxElem),
+
+    /// A candle of a [`crate::plot::ChartPlot`].
+    Candle(CandleElem),
+
+    /// A bar of an [`crate::plot::OhlcChart`].
+    Ohlc(OhlcElem),
+
+    /// The value of a cell of a [`crate::plot::HeatMap`], with its index counted row by row.
+    Cell(f64),
+}
+
+#[test]
+fn test_steps() {
+    let points = [
+        PlotPoint::new(0.0, 1.0),
+        PlotPoint::new(1.0, 2.0),
+        PlotPoint::new(3.0, 0.0),
+    ];
+    let stairs = |steps: Steps| -> Vec<[f64; 2]> {
+        steps
+            .stairs(&points)
+            .iter()
+            .map(|point| [point.x, point.y])
+            .collect()
+    };
+    assert_eq!(
+        stairs(Steps::Post),
+        vec![[0.0, 1.0], [1.0, 1.0], [1.0, 2.0], [3.0, 2.0], [3.0, 0.0]]
+    );
+    assert_eq!(
+        stairs(Steps::Pre),
+        vec![[0.0, 1.0], [0.0, 2.0], [1.0, 2.0], [1.0, 0.0], [3.0, 0.0]]
+    );
+    assert_eq!(
+        stairs(Steps::Mid),
+        vec![
+            [0.0, 1.0],
+            [0.5, 1.0],
+            [0.5, 2.0],
+            [2.0, 2.0],
+            [2.0, 0.0],
+            [3.0, 0.0]
+        ]
+    );
+}
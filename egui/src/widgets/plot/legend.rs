@@ -34,6 +34,8 @@ pub struct Legend {
     pub text_style: TextStyle,
     pub background_alpha: f32,
     pub position: Corner,
+    pub outside: bool,
+    pub order: Vec<String>,
 }
 
 impl Default for Legend {
@@ -42,6 +44,8 @@ impl Default for Legend {
             text_style: TextStyle::Body,
             background_alpha: 0.75,
             position: Corner::RightTop,
+            outside: false,
+            order: Vec::new(),
         }
     }
 }
@@ -64,6 +68,20 @@ impl Legend {
         self.position = corner;
         self
     }
+
+    /// Place the legend beside the plot instead of on top of it, on the side of its
+    /// [`Self::position`] corner. The plot area shrinks to make room for it. Default: `false`.
+    pub fn outside(mut self, outside: bool) -> Self {
+        self.outside = outside;
+        self
+    }
+
+    /// List the entries with these names first, in this order.
+    /// The others follow in alphabetical order. Default: all in alphabetical order.
+    pub fn order(mut self, names: impl IntoIterator<Item = impl ToString>) -> Self {
+        self.order = names.into_iter().map(|name| name.to_string()).collect();
+        self
+    }
 }
 
 #[derive(Clone)]
@@ -145,7 +163,12 @@ impl LegendEntry {
         };
 
         let text_position = pos2(text_position_x, rect.center().y - 0.5 * galley.size().y);
-        painter.galley_with_color(text_position, galley, visuals.text_color());
+        let text_color = if *checked {
+            visuals.text_color()
+        } else {
+            ui.visuals().weak_text_color()
+        };
+        painter.galley_with_color(text_position, galley, text_color);
 
         *checked ^= response.clicked_by(PointerButton::Primary);
         *hovered = response.hovered();
@@ -159,6 +182,8 @@ pub(super) struct LegendWidget {
     rect: Rect,
     entries: BTreeMap<String, LegendEntry>,
     config: Legend,
+    /// How wide the legend turned out, including its padding.
+    width: f32,
 }
 
 impl LegendWidget {
@@ -195,6 +220,7 @@ impl LegendWidget {
             rect,
             entries,
             config,
+            width: 0.0,
         })
     }
 
@@ -214,14 +240,41 @@ impl LegendWidget {
             .find(|(_, entry)| entry.hovered)
             .map(|(name, _)| name.to_string())
     }
+
+    /// How wide the legend was when it was last shown, including its padding.
+    pub fn width(&self) -> f32 {
+        self.width
+    }
+
+    /// The names of the entries in the order of [`Legend::order`], then alphabetically.
+    fn ordered_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self
+            .config
+            .order
+            .iter()
+            .filter(|name| self.entries.contains_key(*name))
+            .cloned()
+            .collect();
+        names.dedup();
+        let rest: Vec<String> = self
+            .entries
+            .keys()
+            .filter(|name| !names.contains(*name))
+            .cloned()
+            .collect();
+        names.extend(rest);
+        names
+    }
 }
 
 impl Widget for &mut LegendWidget {
     fn ui(self, ui: &mut Ui) -> Response {
+        let names = self.ordered_names();
         let LegendWidget {
             rect,
             entries,
             config,
+            width,
         } = self;
 
         let main_dir = match config.position {
@@ -236,27 +289,30 @@ impl Widget for &mut LegendWidget {
         let legend_pad = 4.0;
         let legend_rect = rect.shrink(legend_pad);
         let mut legend_ui = ui.child_ui(legend_rect, layout);
-        legend_ui
-            .scope(|ui| {
-                let background_frame = Frame {
-                    inner_margin: vec2(8.0, 4.0).into(),
-                    rounding: ui.style().visuals.window_rounding,
-                    shadow: epaint::Shadow::default(),
-                    fill: ui.style().visuals.extreme_bg_color,
-                    stroke: ui.style().visuals.window_stroke(),
-                    ..Default::default()
-                }
-                .multiply_with_opacity(config.background_alpha);
-                background_frame
-                    .show(ui, |ui| {
-                        entries
-                            .iter_mut()
-                            .map(|(name, entry)| entry.ui(ui, name.clone(), &config.text_style))
-                            .reduce(|r1, r2| r1.union(r2))
-                            .unwrap()
-                    })
-                    .inner
-            })
-            .inner
+        let scope = legend_ui.scope(|ui| {
+            let background_frame = Frame {
+                inner_margin: vec2(8.0, 4.0).into(),
+                rounding: ui.style().visuals.window_rounding,
+                shadow: epaint::Shadow::default(),
+                fill: ui.style().visuals.extreme_bg_color,
+                stroke: ui.style().visuals.window_stroke(),
+                ..Default::default()
+            }
+            .multiply_with_opacity(config.background_alpha);
+            background_frame
+                .show(ui, |ui| {
+                    names
+                        .into_iter()
+                        .filter_map(|name| {
+                            let entry = entries.get_mut(&name)?;
+                            Some(entry.ui(ui, name, &config.text_style))
+                        })
+                        .reduce(|r1, r2| r1.union(r2))
+                        .unwrap()
+                })
+                .inner
+        });
+        *width = scope.response.rect.width() + 2.0 * legend_pad;
+        scope.inner
     }
 }
@@ -9,18 +9,28 @@ use epaint::util::FloatOrd;
 
 use items::PlotItem;
 use legend::LegendWidget;
+use snapshot::PaintedRange;
 use transform::ScreenTransform;
 
 pub use items::{
-    Arrows, Bar, BarChart, BoxElem, BoxPlot, BoxSpread, Candle, CandleElem, ChartPlot, FilledRange,
-    HLine, Line, LineStyle, MarkerShape, Orientation, PlotImage, PlotPoint, PlotPoints, Points,
-    Polygon, Text, VLine,
+    Arrows, Band, Bar, BarChart, BoxElem, BoxPlot, BoxSpread, Candle, CandleElem, CandleStyle,
+    ChartPlot, Colormap, Comparison, Envelope, FilledRange, HLine, HeatMap, Indicator, Line,
+    LineStyle, MarkerShape, OhlcChart, OhlcElem, Orientation, PlotElement, PlotElementValue,
+    PlotImage, PlotPoint, PlotPoints, Points, Polygon, Steps, Text, VLine, ViewPreset,
 };
 pub use legend::{Corner, Legend};
-pub use transform::PlotBounds;
+pub use polar::{PolarPlot, PolarUi};
+pub use series::PlotSeriesHandle;
+pub use snapshot::PlotSnapshot;
+pub use time::{time_grid_spacer, TimeFormat};
+pub use transform::{AxisScale, PlotBounds};
 
 mod items;
 mod legend;
+mod polar;
+mod series;
+mod snapshot;
+mod time;
 mod transform;
 
 type LabelFormatterFn = dyn Fn(&str, &PlotPoint) -> String;
@@ -31,6 +41,9 @@ type AxisFormatter = Option<Box<AxisFormatterFn>>;
 type GridSpacerFn = dyn Fn(GridInput) -> Vec<GridMark>;
 type GridSpacer = Box<GridSpacerFn>;
 
+type TickGeneratorFn = dyn Fn(GridInput) -> Vec<Tick>;
+type TickGenerator = Option<Box<TickGeneratorFn>>;
+
 /// Specifies the coordinates formatting when passed to [`Plot::coordinates_formatter`].
 pub struct CoordinatesFormatter {
     function: Box<dyn Fn(&PlotPoint, &PlotBounds) -> String>,
@@ -66,8 +79,174 @@ impl Default for CoordinatesFormatter {
 
 // ----------------------------------------------------------------------------
 
+enum WatermarkContents {
+    Text(String),
+    Image { texture_id: TextureId, size: Vec2 },
+}
+
+/// Text or an image shown faintly in the middle of the plot, behind all items.
+///
+/// It is scaled with the plot and is not part of the bounds or the hover, see [`Plot::watermark`].
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui::plot::{Plot, Watermark};
+/// Plot::new("prices")
+///     .watermark(Watermark::text("BTCUSD · 1H"))
+///     .show(ui, |_plot_ui| {});
+/// # });
+/// ```
+pub struct Watermark {
+    contents: WatermarkContents,
+    color: Option<Color32>,
+    max_fraction: Vec2,
+}
+
+impl Watermark {
+    /// Show some text, e.g. the symbol and timeframe of a chart.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn text(text: impl ToString) -> Self {
+        Self::new(WatermarkContents::Text(text.to_string()))
+    }
+
+    /// Show an image, e.g. a logo. `size` only decides the aspect ratio.
+    pub fn image(texture_id: impl Into<TextureId>, size: impl Into<Vec2>) -> Self {
+        Self::new(WatermarkContents::Image {
+            texture_id: texture_id.into(),
+            size: size.into(),
+        })
+    }
+
+    fn new(contents: WatermarkContents) -> Self {
+        Self {
+            contents,
+            color: None,
+            max_fraction: vec2(0.6, 0.3),
+        }
+    }
+
+    /// The text color or image tint. Default: a faint version of the text color.
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// At most how large a fraction of the plot width and height the watermark may cover.
+    ///
+    /// Default: 60% of the width and 30% of the height.
+    pub fn max_fraction(mut self, max_fraction: impl Into<Vec2>) -> Self {
+        self.max_fraction = max_fraction.into();
+        self
+    }
+
+    fn add_shapes(&self, ui: &Ui, frame: Rect, shapes: &mut Vec<Shape>) {
+        let color = self
+            .color
+            .unwrap_or_else(|| ui.visuals().text_color().linear_multiply(0.08));
+        let max_size = frame.size() * self.max_fraction;
+
+        match &self.contents {
+            WatermarkContents::Text(text) => {
+                // Lay out at a reference size, then scale to fit:
+                const REFERENCE_SIZE: f32 = 100.0;
+                let fonts = ui.fonts();
+                let reference =
+                    fonts.layout_no_wrap(text.clone(), FontId::proportional(REFERENCE_SIZE), color);
+                let scale = (max_size / reference.size()).min_elem();
+                if !scale.is_finite() || scale <= 0.0 {
+                    return;
+                }
+                let galley = fonts.layout_no_wrap(
+                    text.clone(),
+                    FontId::proportional(REFERENCE_SIZE * scale),
+                    color,
+                );
+                let pos = frame.center() - galley.size() / 2.0;
+                shapes.push(Shape::galley(pos, galley));
+            }
+            WatermarkContents::Image { texture_id, size } => {
+                let scale = (max_size / *size).min_elem();
+                if !scale.is_finite() || scale <= 0.0 {
+                    return;
+                }
+                let rect = Rect::from_center_size(frame.center(), *size * scale);
+                let mut mesh = Mesh::with_texture(*texture_id);
+                mesh.add_rect_with_uv(
+                    rect,
+                    Rect::from_min_max(pos2(0.0, 0.0), pos2(1.0, 1.0)),
+                    color,
+                );
+                shapes.push(Shape::mesh(mesh));
+            }
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Which cursor icon a [`Plot`] shows for what the pointer is over or doing, see [`Plot::cursor_icons`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PlotCursors {
+    /// Over the plot area, if the x or y value is shown on hover. Default: [`CursorIcon::Crosshair`].
+    pub plot_area: CursorIcon,
+
+    /// Over the labels of the x axis. Default: [`CursorIcon::ResizeHorizontal`].
+    pub x_axis: CursorIcon,
+
+    /// Over the labels of the y axis. Default: [`CursorIcon::ResizeVertical`].
+    pub y_axis: CursorIcon,
+
+    /// Over an item that can be dragged. Default: [`CursorIcon::PointingHand`].
+    pub draggable_item: CursorIcon,
+
+    /// While dragging the plot around. Default: [`CursorIcon::Grabbing`].
+    pub panning: CursorIcon,
+
+    /// While dragging out a box to zoom into. Default: [`CursorIcon::ZoomIn`].
+    pub box_zoom: CursorIcon,
+
+    /// Over a corner of the plot, and while reshaping the plot from one. Default: [`CursorIcon::ResizeNwSe`].
+    pub corner: CursorIcon,
+
+    /// Over the view window of the minimap, see [`Plot::minimap`].
+    /// While dragging it, [`Self::panning`] is shown. Default: [`CursorIcon::Grab`].
+    pub minimap_window: CursorIcon,
+}
+
+impl Default for PlotCursors {
+    fn default() -> Self {
+        Self {
+            plot_area: CursorIcon::Crosshair,
+            x_axis: CursorIcon::ResizeHorizontal,
+            y_axis: CursorIcon::ResizeVertical,
+            draggable_item: CursorIcon::PointingHand,
+            panning: CursorIcon::Grabbing,
+            box_zoom: CursorIcon::ZoomIn,
+            corner: CursorIcon::ResizeNwSe,
+            minimap_window: CursorIcon::Grab,
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
 const MIN_LINE_SPACING_IN_POINTS: f64 = 6.0; // TODO(emilk): large enough for a wide label
 
+/// How close the pointer must be to an item to hover it, in points.
+const HOVER_RADIUS: f32 = 16.0;
+
+/// How wide the area with the y axis labels is assumed to be, in points.
+const Y_AXIS_ZONE_WIDTH: f32 = 40.0;
+
+/// How much dragging an axis by one point zooms it.
+const AXIS_DRAG_ZOOM_SPEED: f32 = 0.01;
+
+/// How close to a corner of the plot a drag must start to reshape the plot, in points.
+const CORNER_ZONE_SIZE: f32 = 12.0;
+
+/// How many boxed zooms can be stepped back out of.
+const ZOOM_HISTORY_LEN: usize = 16;
+
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 #[derive(Clone)]
 struct AutoBounds {
@@ -102,6 +281,26 @@ struct PlotMemory {
     last_screen_transform: ScreenTransform,
     /// Allows to remember the first click position when performing a boxed zoom
     last_click_pos_for_zoom: Option<Pos2>,
+    /// The bounds before each boxed zoom, the latest last, so that they can be stepped back to.
+    zoom_history: Vec<PlotBounds>,
+    /// The toolbar preset that decides the bounds, until the user pans or zooms.
+    view_preset: Option<ViewPreset>,
+    /// The axis (0 for x, 1 for y) whose labels the current primary drag started on.
+    axis_drag: Option<usize>,
+    /// The corner that stays in place while the current primary drag reshapes the plot.
+    corner_drag_anchor: Option<Align2>,
+    /// Where the current drag in the minimap grabbed its view window, relative to the window center.
+    minimap_grab: Option<f32>,
+    /// The largest x value of the items in the last frame, for [`Plot::follow_newest_x`].
+    newest_x: Option<f64>,
+    /// The range of the [`YAxis::Right`], or `None` to fit it to its items.
+    right_y: Option<[f64; 2]>,
+    /// The index of the item, and of its point, that the current primary drag moves.
+    point_drag: Option<(usize, usize)>,
+    /// Where the pointer last was over the plot, in plot coordinates.
+    last_cursor: Option<PlotPoint>,
+    /// What the user is measuring with [`Plot::measure`], or measured until the next click.
+    measurement: Option<PlotMeasurement>,
 }
 
 impl PlotMemory {
@@ -112,6 +311,207 @@ impl PlotMemory {
     pub fn store(self, ctx: &Context, id: Id) {
         ctx.data().insert_persisted(id, self);
     }
+
+    fn view_state(&self) -> PlotViewState {
+        let mut hidden_items: Vec<String> = self.hidden_items.iter().cloned().collect();
+        hidden_items.sort();
+        PlotViewState {
+            bounds: *self.last_screen_transform.bounds(),
+            auto_bounds: [self.auto_bounds.x, self.auto_bounds.y],
+            hidden_items,
+            last_cursor: self.last_cursor,
+        }
+    }
+
+    fn apply_view_state(&mut self, state: PlotViewState) {
+        if state.bounds.is_valid() {
+            self.last_screen_transform.set_bounds(state.bounds);
+        }
+        self.auto_bounds = AutoBounds {
+            x: state.auto_bounds[0],
+            y: state.auto_bounds[1],
+        };
+        self.hidden_items = state.hidden_items.into_iter().collect();
+        self.last_cursor = state.last_cursor;
+        // The state decides the bounds now, not the toolbar.
+        self.view_preset = None;
+    }
+}
+
+/// What the user has made of the view of a plot: where they panned and zoomed to,
+/// and which items they hid in the legend.
+///
+/// Take it from [`PlotResponse::view_state`] and give it back with [`Plot::view_state`],
+/// e.g. to restore the view in the next session, or to show the same view in another window.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PlotViewState {
+    /// The bounds that the plot shows.
+    pub bounds: PlotBounds,
+
+    /// Whether the x and the y axis still fit the items, because the user hasn't panned or zoomed them.
+    pub auto_bounds: [bool; 2],
+
+    /// The names of the items hidden in the legend, sorted.
+    pub hidden_items: Vec<String>,
+
+    /// Where the pointer last was over the plot, in plot coordinates.
+    pub last_cursor: Option<PlotPoint>,
+}
+
+/// A distance that the user dragged out on a plot with [`Plot::measure`].
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PlotMeasurement {
+    /// Where the drag started, in plot coordinates.
+    pub start: PlotPoint,
+
+    /// Where the pointer is, or where the drag ended, in plot coordinates.
+    pub end: PlotPoint,
+
+    /// Whether the drag has ended. The measurement is shown until the next click.
+    pub finished: bool,
+}
+
+impl PlotMeasurement {
+    /// The change of x and of y from the start to the end.
+    pub fn delta(&self) -> [f64; 2] {
+        [self.end.x - self.start.x, self.end.y - self.start.y]
+    }
+
+    /// The change of y in percent of its value at the start, or `None` if that is zero.
+    pub fn percent_change(&self) -> Option<f64> {
+        (self.start.y != 0.0).then(|| 100.0 * self.delta()[1] / self.start.y.abs())
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// What [`Plot::show`] returns.
+#[derive(Debug)]
+pub struct PlotResponse<R> {
+    /// What the user closure returned.
+    pub inner: R,
+
+    /// The response of the plot area.
+    pub response: Response,
+
+    /// The view preset that was picked in the toolbar this frame, see [`ChartPlot::view_presets`].
+    pub view_preset: Option<ViewPreset>,
+
+    /// The view of the plot at the end of this frame, to give to [`Plot::view_state`] later.
+    pub view_state: PlotViewState,
+
+    /// What the user is measuring, or last measured, with [`Plot::measure`].
+    pub measurement: Option<PlotMeasurement>,
+
+    dragged_point: Option<(String, usize, PlotPoint)>,
+
+    hovered_element: Option<PlotElement>,
+
+    /// The point, and the element, of the last secondary click, for [`Self::context_menu`].
+    context_target: Option<(PlotPoint, Option<PlotElement>)>,
+
+    painted: PaintedRange,
+}
+
+impl<R> PlotResponse<R> {
+    /// The point that the user is dragging this frame, see [`Line::draggable`] and [`Points::draggable`]:
+    /// the name of its item, its index in the item, and where it was dragged to.
+    ///
+    /// The item already shows it there, but it's up to you to store the new value.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui::plot::{Line, Plot, PlotPoint, PlotPoints};
+    /// // Kept in the app state:
+    /// let mut curve = vec![PlotPoint::new(0.0, 0.0), PlotPoint::new(1.0, 1.0)];
+    ///
+    /// let response = Plot::new("curve_editor")
+    ///     .drag_snap(0.1, 0.1)
+    ///     .show(ui, |plot_ui| {
+    ///         let line = Line::new(PlotPoints::Owned(curve.clone()));
+    ///         plot_ui.line(line.name("curve").draggable(true));
+    ///     });
+    /// if let Some(("curve", index, point)) = response.dragged_point() {
+    ///     curve[index] = point;
+    /// }
+    /// # });
+    /// ```
+    pub fn dragged_point(&self) -> Option<(&str, usize, PlotPoint)> {
+        self.dragged_point
+            .as_ref()
+            .map(|(name, index, point)| (name.as_str(), *index, *point))
+    }
+
+    /// The element of a named item that is under the pointer, the same that shows its values.
+    pub fn hovered_element(&self) -> Option<&PlotElement> {
+        self.hovered_element.as_ref()
+    }
+
+    /// The element that was clicked with the primary button this frame, see [`Self::hovered_element`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui::plot::{Candle, CandleElem, ChartPlot, Plot, PlotElementValue};
+    /// let candles = vec![CandleElem::new(Candle::new(1.0, 2.0, 0.5, 1.5, 100.0))];
+    /// let response = Plot::new("orders").show(ui, |plot_ui| {
+    ///     plot_ui.chart_plot(ChartPlot::new(candles).name("BTC"));
+    /// });
+    /// if let Some(element) = response.clicked_element() {
+    ///     if let PlotElementValue::Candle(candle) = &element.value {
+    ///         println!("Order {} at {}", element.name, candle.candle.close);
+    ///     }
+    /// }
+    /// # });
+    /// ```
+    pub fn clicked_element(&self) -> Option<&PlotElement> {
+        self.hovered_element
+            .as_ref()
+            .filter(|_| self.response.clicked_by(PointerButton::Primary))
+    }
+
+    /// Show a menu when the plot is clicked with the secondary button (usually the right one),
+    /// e.g. with actions at the clicked price.
+    ///
+    /// `add_contents` gets the clicked point, in plot coordinates, and the element under the pointer
+    /// at the time, if any. The menu only opens on a click, so that dragging still zooms.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui::plot::{Candle, CandleElem, ChartPlot, Plot};
+    /// let candles = vec![CandleElem::new(Candle::new(1.0, 2.0, 0.5, 1.5, 100.0))];
+    /// Plot::new("alerts")
+    ///     .show(ui, |plot_ui| plot_ui.chart_plot(ChartPlot::new(candles)))
+    ///     .context_menu(|ui, point, element| {
+    ///         if ui.button(format!("Add alert at {:.2}", point.y)).clicked() {
+    ///             println!("Alert at {}", point.y);
+    ///             ui.close_menu();
+    ///         }
+    ///         if let Some(element) = element {
+    ///             ui.label(format!("On {}", element.name));
+    ///         }
+    ///     });
+    /// # });
+    /// ```
+    pub fn context_menu(
+        self,
+        add_contents: impl FnOnce(&mut Ui, PlotPoint, Option<&PlotElement>),
+    ) -> Self {
+        if let Some((point, element)) = &self.context_target {
+            menu::context_menu_on_click(&self.response, |ui| {
+                add_contents(ui, *point, element.as_ref());
+            });
+        }
+        self
+    }
+
+    /// The shapes that the plot painted this frame, to save as SVG or as an image.
+    ///
+    /// Call it in the same frame, before the shapes are handed to the backend.
+    pub fn snapshot(&self) -> PlotSnapshot {
+        PlotSnapshot::new(&self.response.ctx, self.painted)
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -171,6 +571,88 @@ impl LinkedAxisGroup {
     }
 }
 
+/// Links multiple plots more closely than a [`LinkedAxisGroup`]: besides the axes, they also share
+/// the pointer, e.g. for price, volume and indicator plots below each other.
+///
+/// When one plot of the group is hovered, the others draw a ruler at the same x value
+/// (and y value, if the y axes are linked). With [`Self::link_highlight`] they also highlight
+/// their element at that x value. Add it to each plot with [`Plot::link_group`].
+/// Like a [`LinkedAxisGroup`], it should be stored by the user between frames.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui::plot::{Line, LinkGroup, Plot};
+/// let group = LinkGroup::x().link_highlight(true);
+/// let plots = [
+///     ("price", vec![[0.0, 10.0], [1.0, 11.0]]),
+///     ("rsi", vec![[0.0, 40.0], [1.0, 70.0]]),
+/// ];
+/// for (id, values) in plots {
+///     Plot::new(id)
+///         .height(100.0)
+///         .link_group(group.clone())
+///         .show(ui, |plot_ui| plot_ui.line(Line::new(values)));
+/// }
+/// # });
+/// ```
+#[derive(Clone)]
+pub struct LinkGroup {
+    axes: LinkedAxisGroup,
+    link_cursor: bool,
+    link_highlight: bool,
+    /// The plot that is hovered, and the value under the pointer.
+    cursor: Rc<Cell<Option<(Id, PlotPoint)>>>,
+}
+
+impl LinkGroup {
+    pub fn new(link_x: bool, link_y: bool) -> Self {
+        Self {
+            axes: LinkedAxisGroup::new(link_x, link_y),
+            link_cursor: true,
+            link_highlight: false,
+            cursor: Rc::new(Cell::new(None)),
+        }
+    }
+
+    /// Only link the x-axis.
+    pub fn x() -> Self {
+        Self::new(true, false)
+    }
+
+    /// Link both axes.
+    pub fn both() -> Self {
+        Self::new(true, true)
+    }
+
+    /// Draw a ruler where another plot of the group is hovered. Default: `true`.
+    pub fn link_cursor(mut self, link: bool) -> Self {
+        self.link_cursor = link;
+        self
+    }
+
+    /// Also highlight the element at the hovered x value, and show its values. Default: `false`.
+    pub fn link_highlight(mut self, link: bool) -> Self {
+        self.link_highlight = link;
+        self
+    }
+
+    /// Share the value under the pointer if this plot is hovered.
+    /// Otherwise returns the one shared by the hovered plot of the group, if any.
+    fn exchange_cursor(&self, plot_id: Id, hovered_value: Option<PlotPoint>) -> Option<PlotPoint> {
+        if let Some(value) = hovered_value {
+            self.cursor.set(Some((plot_id, value)));
+            return None;
+        }
+        match self.cursor.get() {
+            Some((id, _)) if id == plot_id => {
+                self.cursor.set(None);
+                None
+            }
+            cursor => cursor.map(|(_, value)| value),
+        }
+    }
+}
+
 // ----------------------------------------------------------------------------
 
 /// A 2D plot, e.g. a graph of a function.
@@ -200,7 +682,9 @@ pub struct Plot {
     margin_fraction: Vec2,
     allow_boxed_zoom: bool,
     boxed_zoom_pointer_button: PointerButton,
+    boxed_zoom_modifier: Option<Modifiers>,
     linked_axes: Option<LinkedAxisGroup>,
+    link_group: Option<LinkGroup>,
 
     min_size: Vec2,
     width: Option<f32>,
@@ -210,13 +694,27 @@ pub struct Plot {
 
     show_x: bool,
     show_y: bool,
+    crosshair: bool,
     label_formatter: LabelFormatter,
     coordinates_formatter: Option<(Corner, CoordinatesFormatter)>,
     axis_formatters: [AxisFormatter; 2],
+    x_axis_time: Option<TimeFormat>,
+    axis_scales: [AxisScale; 2],
+    axis_labels: [String; 2],
+    axis_units: [String; 2],
     legend_config: Option<Legend>,
     show_background: bool,
     show_axes: [bool; 2],
     grid_spacers: [GridSpacer; 2],
+    tick_generators: [TickGenerator; 2],
+    watermark: Option<Watermark>,
+    cursors: PlotCursors,
+    volume_pane_height: Option<f32>,
+    minimap_height: Option<f32>,
+    follow_newest_x: bool,
+    drag_snap: [f64; 2],
+    view_state: Option<PlotViewState>,
+    measure: bool,
 }
 
 impl Plot {
@@ -234,7 +732,9 @@ impl Plot {
             margin_fraction: Vec2::splat(0.05),
             allow_boxed_zoom: true,
             boxed_zoom_pointer_button: PointerButton::Secondary,
+            boxed_zoom_modifier: Some(Modifiers::SHIFT),
             linked_axes: None,
+            link_group: None,
 
             min_size: Vec2::splat(64.0),
             width: None,
@@ -244,13 +744,27 @@ impl Plot {
 
             show_x: true,
             show_y: true,
+            crosshair: false,
             label_formatter: None,
             coordinates_formatter: None,
             axis_formatters: [None, None], // [None; 2] requires Copy
+            x_axis_time: None,
+            axis_scales: [AxisScale::Linear; 2],
+            axis_labels: Default::default(),
+            axis_units: Default::default(),
             legend_config: None,
             show_background: true,
             show_axes: [true; 2],
             grid_spacers: [log_grid_spacer(10), log_grid_spacer(10)],
+            tick_generators: [None, None],
+            watermark: None,
+            cursors: PlotCursors::default(),
+            volume_pane_height: None,
+            minimap_height: None,
+            follow_newest_x: false,
+            drag_snap: [0.0; 2],
+            view_state: None,
+            measure: false,
         }
     }
 
@@ -304,6 +818,14 @@ impl Plot {
         self
     }
 
+    /// When hovering, draw lines across the whole plot through the pointer, and show its x and y
+    /// values in boxes at the bottom and left edges, formatted like the axis labels.
+    /// This replaces the text next to the pointer when no item is hovered. Default: `false`.
+    pub fn crosshair(mut self, crosshair: bool) -> Self {
+        self.crosshair = crosshair;
+        self
+    }
+
     /// Always keep the x-axis centered. Default: `false`.
     pub fn center_x_axis(mut self, on: bool) -> Self {
         self.center_x_axis = on;
@@ -317,6 +839,18 @@ impl Plot {
     }
 
     /// Whether to allow zooming in the plot. Default: `true`.
+    ///
+    /// Besides pinching and ctrl-scrolling, this allows zooming a single axis by dragging its labels:
+    /// dragging the y axis up or down scales the y range around the point where the drag started,
+    /// and dragging the x axis right or left zooms the x range in or out.
+    /// If zooming is off but [`Self::allow_drag`] is on, dragging the x axis scrolls it instead.
+    ///
+    /// It also allows reshaping the plot by dragging from one of its corners, or anywhere while holding alt:
+    /// x and y are then scaled independently, so that the opposite corner stays in place
+    /// and the value under the pointer follows it.
+    ///
+    /// On a touch screen, a pinch zooms each axis by how much the fingers spread along it,
+    /// so a horizontal pinch only zooms the x axis. With [`Self::data_aspect`] both axes zoom alike.
     pub fn allow_zoom(mut self, on: bool) -> Self {
         self.allow_zoom = on;
         self
@@ -336,7 +870,12 @@ impl Plot {
         self
     }
 
-    /// Whether to allow zooming in the plot by dragging out a box with the secondary mouse button.
+    /// Whether to allow zooming in the plot by dragging out a box with the secondary mouse button,
+    /// see also [`Self::boxed_zoom_modifier`].
+    ///
+    /// Each boxed zoom can be stepped back out of by double clicking the plot, or by pressing
+    /// backspace while hovering it. Once there is nothing left to step back to, this resets to
+    /// the automatic bounds.
     ///
     /// Default: `true`.
     pub fn allow_boxed_zoom(mut self, on: bool) -> Self {
@@ -350,7 +889,29 @@ impl Plot {
         self
     }
 
-    /// Whether to allow dragging in the plot to move the bounds. Default: `true`.
+    /// Holding these modifiers turns a drag with the primary mouse button into a boxed zoom
+    /// instead of panning. `None` leaves the primary button to panning.
+    ///
+    /// Default: `Some(Modifiers::SHIFT)`.
+    pub fn boxed_zoom_modifier(mut self, modifier: Option<Modifiers>) -> Self {
+        self.boxed_zoom_modifier = modifier;
+        self
+    }
+
+    /// Turn dragging with the primary mouse button into measuring instead of panning,
+    /// e.g. behind a toolbar button of the app.
+    ///
+    /// The plot shades the box from where the drag started to the pointer, and shows the change
+    /// of x (as a duration with [`Self::x_axis_time`]) and of y between them, and of y in percent.
+    /// The measurement is shown until the next click, and is reported by [`PlotResponse::measurement`].
+    /// Dragging the axes and draggable points still works. Default: `false`.
+    pub fn measure(mut self, on: bool) -> Self {
+        self.measure = on;
+        self
+    }
+
+    /// Whether to allow dragging in the plot to move the bounds,
+    /// also with two or more fingers on a touch screen. Default: `true`.
     pub fn allow_drag(mut self, on: bool) -> Self {
         self.allow_drag = on;
         self
@@ -410,6 +971,63 @@ impl Plot {
         self
     }
 
+    /// Treat the x values as unix timestamps, in seconds.
+    ///
+    /// The grid lines are put at calendar boundaries (see [`time_grid_spacer`]), and the axis labels and
+    /// the hover label show the time the given way. A custom [`Self::x_axis_formatter`] or
+    /// [`Self::label_formatter`] still takes precedence.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui::plot::{Line, Plot, TimeFormat};
+    /// let start = 1_646_611_200.0; // 2022-03-07
+    /// let prices: Vec<[f64; 2]> = (0..100)
+    ///     .map(|i| [start + i as f64 * 3600.0, 100.0 + i as f64])
+    ///     .collect();
+    /// Plot::new("prices")
+    ///     .x_axis_time(TimeFormat::Auto)
+    ///     .show(ui, |plot_ui| plot_ui.line(Line::new(prices)));
+    /// # });
+    /// ```
+    pub fn x_axis_time(mut self, format: TimeFormat) -> Self {
+        self.x_axis_time = Some(format);
+        self.grid_spacers[0] = time_grid_spacer();
+        self
+    }
+
+    /// How the x values are mapped to the screen. Default is [`AxisScale::Linear`].
+    ///
+    /// [`AxisScale::Log10`] also switches to log-spaced grid lines, see [`log_axis_grid_spacer`].
+    /// Panning and zooming then move and scale the view by ratios instead of differences.
+    pub fn x_axis_scale(mut self, scale: AxisScale) -> Self {
+        self.axis_scales[0] = scale;
+        if scale == AxisScale::Log10 {
+            self.grid_spacers[0] = log_axis_grid_spacer();
+        }
+        self
+    }
+
+    /// How the y values are mapped to the screen. Default is [`AxisScale::Linear`].
+    ///
+    /// See [`Self::x_axis_scale`] for explanation.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui::plot::{AxisScale, Line, Plot};
+    /// let growth: Vec<[f64; 2]> = (0..50).map(|i| [i as f64, 1.2_f64.powi(i)]).collect();
+    /// Plot::new("growth")
+    ///     .y_axis_scale(AxisScale::Log10)
+    ///     .show(ui, |plot_ui| plot_ui.line(Line::new(growth)));
+    /// # });
+    /// ```
+    pub fn y_axis_scale(mut self, scale: AxisScale) -> Self {
+        self.axis_scales[1] = scale;
+        if scale == AxisScale::Log10 {
+            self.grid_spacers[1] = log_axis_grid_spacer();
+        }
+        self
+    }
+
     /// Provide a function to customize the labels for the Y axis based on the current value range.
     ///
     /// This is useful for custom value representation, e.g. percentage or units.
@@ -425,6 +1043,31 @@ impl Plot {
         self
     }
 
+    /// A title below the plot, e.g. `"Time"`. The plot area shrinks to make room for it.
+    pub fn x_axis_label(mut self, label: impl ToString) -> Self {
+        self.axis_labels[0] = label.to_string();
+        self
+    }
+
+    /// A title left of the plot, written from bottom to top, e.g. `"Price (USD)"`.
+    /// The plot area shrinks to make room for it.
+    pub fn y_axis_label(mut self, label: impl ToString) -> Self {
+        self.axis_labels[1] = label.to_string();
+        self
+    }
+
+    /// Append this unit to the x values shown at the pointer and in the crosshair, e.g. `"s"`.
+    pub fn x_axis_unit(mut self, unit: impl ToString) -> Self {
+        self.axis_units[0] = unit.to_string();
+        self
+    }
+
+    /// Append this unit to the y values shown at the pointer and in the crosshair, e.g. `"USD"`.
+    pub fn y_axis_unit(mut self, unit: impl ToString) -> Self {
+        self.axis_units[1] = unit.to_string();
+        self
+    }
+
     /// Configure how the grid in the background is spaced apart along the X axis.
     ///
     /// Default is a log-10 grid, i.e. every plot unit is divided into 10 other units.
@@ -468,6 +1111,48 @@ impl Plot {
         self
     }
 
+    /// Place the grid lines and labels of the X axis yourself, e.g. at trading sessions.
+    ///
+    /// This replaces both the [`Self::x_grid_spacer`] and the [`Self::x_axis_formatter`]:
+    /// each returned [`Tick`] gets a grid line as strong as its importance, and its label.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui::plot::{Line, Plot, Tick};
+    /// let notes = ["C", "D", "E", "F", "G", "A", "B"];
+    /// Plot::new("scale")
+    ///     .x_tick_generator(move |input| {
+    ///         let (min, max) = input.bounds;
+    ///         (min.ceil() as i64..=max.floor() as i64)
+    ///             .map(|i| Tick {
+    ///                 value: i as f64,
+    ///                 importance: if i % 7 == 0 { 1.0 } else { 0.3 },
+    ///                 label: notes[i.rem_euclid(7) as usize].to_owned(),
+    ///             })
+    ///             .collect()
+    ///     })
+    ///     .show(ui, |plot_ui| plot_ui.line(Line::new(vec![[0.0, 0.0], [14.0, 1.0]])));
+    /// # });
+    /// ```
+    pub fn x_tick_generator(
+        mut self,
+        generator: impl Fn(GridInput) -> Vec<Tick> + 'static,
+    ) -> Self {
+        self.tick_generators[0] = Some(Box::new(generator));
+        self
+    }
+
+    /// Place the grid lines and labels of the Y axis yourself, e.g. at price increments.
+    ///
+    /// See [`Self::x_tick_generator`] for explanation.
+    pub fn y_tick_generator(
+        mut self,
+        generator: impl Fn(GridInput) -> Vec<Tick> + 'static,
+    ) -> Self {
+        self.tick_generators[1] = Some(Box::new(generator));
+        self
+    }
+
     /// Expand bounds to include the given x value.
     /// For instance, to always show the y axis, call `plot.include_x(0.0)`.
     pub fn include_x(mut self, x: impl Into<f64>) -> Self {
@@ -504,6 +1189,64 @@ impl Plot {
         self
     }
 
+    /// Change which cursor icons are shown, e.g. to show [`CursorIcon::Default`] over the axes.
+    pub fn cursor_icons(mut self, cursors: PlotCursors) -> Self {
+        self.cursors = cursors;
+        self
+    }
+
+    /// Show a [`Watermark`] behind all items.
+    pub fn watermark(mut self, watermark: Watermark) -> Self {
+        self.watermark = Some(watermark);
+        self
+    }
+
+    /// Show the volume of the candles of each [`ChartPlot`] as bars, in a pane of this height below the plot.
+    ///
+    /// The pane shares the x axis with the plot: dragging, zooming and scrolling it moves both,
+    /// and the pointer position is shown in both. Its y axis fits the volume of the candles in view.
+    /// The pane is taken from the height of the plot.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui::plot::{Candle, CandleElem, ChartPlot, Plot};
+    /// let candles = vec![CandleElem::new(Candle::new(10.0, 12.0, 9.0, 11.0, 1500.0))];
+    /// Plot::new("prices")
+    ///     .volume_pane(60.0)
+    ///     .show(ui, |plot_ui| plot_ui.chart_plot(ChartPlot::new(candles)));
+    /// # });
+    /// ```
+    pub fn volume_pane(mut self, height: f32) -> Self {
+        self.volume_pane_height = Some(height);
+        self
+    }
+
+    /// Show a strip of this height below the plot, with all of the data in it and a window around
+    /// the part that is in view.
+    ///
+    /// Drag the window, or press next to it, to move the view along the x axis.
+    /// The strip is taken from the height of the plot.
+    pub fn minimap(mut self, height: f32) -> Self {
+        self.minimap_height = Some(height);
+        self
+    }
+
+    /// Keep moving the view along with the largest x value of the items, e.g. for live data.
+    ///
+    /// Once the user moves the view away from the newest values, it stays where it is, until
+    /// it shows them again. Default is `false`.
+    pub fn follow_newest_x(mut self, follow: bool) -> Self {
+        self.follow_newest_x = follow;
+        self
+    }
+
+    /// Snap dragged points to multiples of these steps of the x and y values,
+    /// see [`Line::draggable`]. A step of `0.0` doesn't snap that axis, which is the default.
+    pub fn drag_snap(mut self, x_step: f64, y_step: f64) -> Self {
+        self.drag_snap = [x_step, y_step];
+        self
+    }
+
     /// Add a [`LinkedAxisGroup`] so that this plot will share the bounds with other plots that have this
     /// group assigned. A plot cannot belong to more than one group.
     pub fn link_axis(mut self, group: LinkedAxisGroup) -> Self {
@@ -511,8 +1254,44 @@ impl Plot {
         self
     }
 
+    /// Add a [`LinkGroup`] so that this plot shares its bounds like with [`Self::link_axis`],
+    /// and its pointer, with the other plots of the group.
+    pub fn link_group(mut self, group: LinkGroup) -> Self {
+        self.linked_axes = Some(group.axes.clone());
+        self.link_group = Some(group);
+        self
+    }
+
+    /// Show this view, e.g. one taken from [`PlotResponse::view_state`] in a previous session.
+    ///
+    /// It replaces the view of the plot in the frame that it's given, and the user can pan,
+    /// zoom and hide items from there. So give it once to restore a view, or every frame
+    /// to make the plot follow another one.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui::plot::{Line, Plot, PlotPoints, PlotViewState};
+    /// // Set when loading the app state:
+    /// let mut restored: Option<PlotViewState> = None;
+    ///
+    /// let mut plot = Plot::new("restored");
+    /// if let Some(state) = restored.take() {
+    ///     plot = plot.view_state(state);
+    /// }
+    /// let response = plot.show(ui, |plot_ui| {
+    ///     plot_ui.line(Line::new(PlotPoints::from_explicit_callback(|x| x.sin(), .., 100)));
+    /// });
+    /// // To be saved with the app state:
+    /// let saved: PlotViewState = response.view_state;
+    /// # });
+    /// ```
+    pub fn view_state(mut self, state: PlotViewState) -> Self {
+        self.view_state = Some(state);
+        self
+    }
+
     /// Interact with and add items to the plot and finally draw it.
-    pub fn show<R>(self, ui: &mut Ui, build_fn: impl FnOnce(&mut PlotUi) -> R) -> InnerResponse<R> {
+    pub fn show<R>(self, ui: &mut Ui, build_fn: impl FnOnce(&mut PlotUi) -> R) -> PlotResponse<R> {
         self.show_dyn(ui, Box::new(build_fn))
     }
 
@@ -520,7 +1299,7 @@ impl Plot {
         self,
         ui: &mut Ui,
         build_fn: Box<dyn FnOnce(&mut PlotUi) -> R + 'a>,
-    ) -> InnerResponse<R> {
+    ) -> PlotResponse<R> {
         let Self {
             id_source,
             center_x_axis,
@@ -530,6 +1309,7 @@ impl Plot {
             allow_drag,
             allow_boxed_zoom,
             boxed_zoom_pointer_button: boxed_zoom_pointer,
+            boxed_zoom_modifier,
             min_auto_bounds,
             margin_fraction,
             width,
@@ -539,14 +1319,29 @@ impl Plot {
             view_aspect,
             mut show_x,
             mut show_y,
+            crosshair,
             label_formatter,
             coordinates_formatter,
             axis_formatters,
+            x_axis_time,
+            axis_scales,
+            axis_labels,
+            axis_units,
             legend_config,
             show_background,
             show_axes,
             linked_axes,
+            link_group,
             grid_spacers,
+            tick_generators,
+            watermark,
+            cursors,
+            volume_pane_height,
+            minimap_height,
+            follow_newest_x,
+            drag_snap,
+            view_state,
+            measure,
         } = self;
 
         // Determine the size of the plot in the UI
@@ -573,11 +1368,79 @@ impl Plot {
             vec2(width, height)
         };
 
-        // Allocate the space.
-        let (rect, response) = ui.allocate_exact_size(size, Sense::drag());
+        // Allocate the space. The panes below the plot are taken from its height.
+        // A legend outside of the plot takes as much of its width as it took last frame,
+        // and the axis titles take a row of text each.
+        let plot_id = ui.make_persistent_id(id_source);
+        let legend_width_id = plot_id.with("legend_width");
+        let outside_legend = legend_config
+            .as_ref()
+            .filter(|config| config.outside)
+            .map(|config| {
+                let width = ui.data().get_temp::<f32>(legend_width_id).unwrap_or(0.0);
+                (config.position, width)
+            });
+        let pane_heights = [volume_pane_height, minimap_height];
+        let axis_label_font = TextStyle::Body.resolve(ui.style());
+        let (rect, response, pane_rects, legend_column, axis_label_rects) = if pane_heights
+            .iter()
+            .all(Option::is_none)
+            && outside_legend.is_none()
+            && axis_labels.iter().all(String::is_empty)
+        {
+            let (rect, response) = ui.allocate_exact_size(size, Sense::drag());
+            (rect, response, [None; 2], None, [None; 2])
+        } else {
+            let (full_rect, full_response) = ui.allocate_exact_size(size, Sense::hover());
+            let gap = ui.spacing().item_spacing.y;
+            let mut rect = full_rect;
+            let legend_column = outside_legend.map(|(corner, width)| {
+                let width = width.at_most(rect.width());
+                let gap = ui.spacing().item_spacing.x;
+                let mut column = rect;
+                if matches!(corner, Corner::LeftTop | Corner::LeftBottom) {
+                    column.max.x = rect.min.x + width;
+                    rect.min.x = (column.max.x + gap).at_most(rect.max.x);
+                } else {
+                    column.min.x = rect.max.x - width;
+                    rect.max.x = (column.min.x - gap).at_least(rect.min.x);
+                }
+                column
+            });
+            let label_size = ui.fonts().row_height(&axis_label_font) + gap;
+            let mut axis_label_rects = [None; 2];
+            if !axis_labels[1].is_empty() {
+                let right = (rect.min.x + label_size).at_most(rect.max.x);
+                axis_label_rects[1] = Some(Rect::from_min_max(rect.min, pos2(right, rect.max.y)));
+                rect.min.x = right;
+            }
+            if !axis_labels[0].is_empty() {
+                let top = (rect.max.y - label_size).at_least(rect.min.y);
+                axis_label_rects[0] = Some(Rect::from_min_max(pos2(rect.min.x, top), rect.max));
+                rect.max.y = top;
+            }
+            let mut pane_rects = [None; 2];
+            for (pane_rect, height) in pane_rects.iter_mut().zip(pane_heights).rev() {
+                if let Some(height) = height {
+                    let top = (rect.max.y - height).at_least(rect.min.y);
+                    *pane_rect = Some(Rect::from_min_max(pos2(rect.min.x, top), rect.max));
+                    rect.max.y = (top - gap).at_least(rect.min.y);
+                }
+            }
+            let response = ui.interact(rect, full_response.id, Sense::drag());
+            (rect, response, pane_rects, legend_column, axis_label_rects)
+        };
+        let [volume_pane_rect, minimap_rect] = pane_rects;
+        let painted_rect = pane_rects
+            .iter()
+            .chain(&[legend_column])
+            .chain(&axis_label_rects)
+            .flatten()
+            .fold(rect, |painted_rect, other_rect| {
+                painted_rect.union(*other_rect)
+            });
 
         // Load or initialize the memory.
-        let plot_id = ui.make_persistent_id(id_source);
         ui.ctx().check_for_id_clash(plot_id, rect, "Plot");
         let mut memory = PlotMemory::load(ui.ctx(), plot_id).unwrap_or_else(|| PlotMemory {
             auto_bounds: (!min_auto_bounds.is_valid()).into(),
@@ -589,8 +1452,19 @@ impl Plot {
                 min_auto_bounds,
                 center_x_axis,
                 center_y_axis,
+                axis_scales,
             ),
             last_click_pos_for_zoom: None,
+            zoom_history: Vec::new(),
+            view_preset: None,
+            axis_drag: None,
+            corner_drag_anchor: None,
+            minimap_grab: None,
+            newest_x: None,
+            right_y: None,
+            point_drag: None,
+            last_cursor: None,
+            measurement: None,
         });
 
         // If the min bounds changed, recalculate everything.
@@ -603,6 +1477,9 @@ impl Plot {
             };
             memory.clone().store(ui.ctx(), plot_id);
         }
+        if let Some(state) = view_state {
+            memory.apply_view_state(state);
+        }
 
         let PlotMemory {
             mut auto_bounds,
@@ -610,25 +1487,43 @@ impl Plot {
             mut hidden_items,
             last_screen_transform,
             mut last_click_pos_for_zoom,
+            mut zoom_history,
+            mut view_preset,
+            mut axis_drag,
+            mut corner_drag_anchor,
+            mut minimap_grab,
+            mut newest_x,
+            mut right_y,
+            mut point_drag,
+            mut last_cursor,
+            mut measurement,
             ..
         } = memory;
 
         // Call the plot build function.
         let mut plot_ui = PlotUi {
             items: Vec::new(),
+            y_axis: YAxis::Left,
+            y_axis_start: 0,
             next_auto_color_idx: 0,
             last_screen_transform,
             response,
             ctx: ui.ctx().clone(),
+            plot_id,
+            new_bounds: None,
         };
         let inner = build_fn(&mut plot_ui);
+        plot_ui.assign_y_axis();
         let PlotUi {
             mut items,
             mut response,
             last_screen_transform,
+            new_bounds,
             ..
         } = plot_ui;
 
+        let paint_start = ui.ctx().graphics().list(ui.layer_id()).next_idx();
+
         // Background
         if show_background {
             ui.painter().with_clip_rect(rect).add(epaint::RectShape {
@@ -639,9 +1534,34 @@ impl Plot {
             });
         }
 
+        // Axis titles
+        let text_color = ui.visuals().text_color();
+        if let Some(label_rect) = axis_label_rects[0] {
+            ui.painter().text(
+                pos2(rect.center().x, label_rect.bottom()),
+                Align2::CENTER_BOTTOM,
+                &axis_labels[0],
+                axis_label_font.clone(),
+                text_color,
+            );
+        }
+        if let Some(label_rect) = axis_label_rects[1] {
+            let galley =
+                ui.painter()
+                    .layout_no_wrap(axis_labels[1].clone(), axis_label_font, text_color);
+            // Turned a quarter counterclockwise around its top left corner, so it reads upwards:
+            let pos = pos2(label_rect.left(), rect.center().y + galley.size().x / 2.0);
+            ui.painter().add(epaint::TextShape {
+                angle: -std::f32::consts::FRAC_PI_2,
+                ..epaint::TextShape::new(pos, galley)
+            });
+        }
+
         // --- Legend ---
-        let legend = legend_config
-            .and_then(|config| LegendWidget::try_new(rect, config, &items, &hidden_items));
+        let legend = legend_config.and_then(|config| {
+            let legend_rect = legend_column.unwrap_or(rect);
+            LegendWidget::try_new(legend_rect, config, &items, &hidden_items)
+        });
         // Don't show hover cursor when hovering over legend.
         if hovered_entry.is_some() {
             show_x = false;
@@ -659,6 +1579,15 @@ impl Plot {
         // Move highlighted items to front.
         items.sort_by_key(|item| item.highlighted());
 
+        let view_presets = items
+            .iter()
+            .map(|item| item.view_presets())
+            .find(|presets| !presets.is_empty())
+            .map(<[ViewPreset]>::to_vec);
+        if view_presets.is_none() {
+            view_preset = None;
+        }
+
         // --- Bound computation ---
         let mut bounds = *last_screen_transform.bounds();
 
@@ -678,9 +1607,52 @@ impl Plot {
             }
         };
 
-        // Allow double clicking to reset to automatic bounds.
-        if response.double_clicked_by(PointerButton::Primary) {
-            auto_bounds = true.into();
+        // Allow double clicking, or backspace, to step back out of a boxed zoom,
+        // and reset to automatic bounds once there is none left.
+        let step_back_pressed = response.hovered()
+            && ui.memory().focus().is_none()
+            && ui.input().key_pressed(Key::Backspace);
+        if response.double_clicked_by(PointerButton::Primary) || step_back_pressed {
+            if let Some(previous_bounds) = zoom_history.pop() {
+                bounds = previous_bounds;
+                auto_bounds = false.into();
+            } else {
+                auto_bounds = true.into();
+            }
+            right_y = None;
+            view_preset = None;
+        }
+
+        if let Some(new_bounds) = new_bounds {
+            bounds = new_bounds;
+            auto_bounds = false.into();
+        }
+
+        if let Some(preset) = view_preset {
+            let preset_items = items.iter().filter(|item| !item.view_presets().is_empty());
+            let last_x = preset_items
+                .clone()
+                .map(|item| item.bounds().max[0])
+                .fold(f64::NEG_INFINITY, f64::max);
+            match preset.x_range(last_x) {
+                Some(x_range) if last_x.is_finite() => {
+                    let mut preset_bounds = PlotBounds::NOTHING;
+                    for item in preset_items {
+                        preset_bounds.merge(&item.bounds_within_x(x_range.clone()));
+                    }
+                    preset_bounds.extend_with_x(*x_range.start());
+                    preset_bounds.extend_with_x(*x_range.end());
+                    if preset_bounds.is_valid() {
+                        let mut scaled = preset_bounds.scaled(axis_scales);
+                        scaled.add_relative_margin_y(margin_fraction);
+                        bounds = scaled.unscaled(axis_scales);
+                        auto_bounds = false.into();
+                    }
+                }
+                _ => {
+                    auto_bounds = true.into();
+                }
+            }
         }
 
         if !bounds.is_valid() {
@@ -703,53 +1675,300 @@ impl Plot {
                 if auto_bounds.x {
                     bounds.merge_x(&item_bounds);
                 }
-                if auto_bounds.y {
+                if auto_bounds.y && item.y_axis() == YAxis::Left {
                     bounds.merge_y(&item_bounds);
                 }
             }
 
+            // The margins are added where the axes are linear, to look the same on log axes.
+            let mut scaled = bounds.scaled(axis_scales);
             if auto_bounds.x {
-                bounds.add_relative_margin_x(margin_fraction);
+                scaled.add_relative_margin_x(margin_fraction);
             }
 
             if auto_bounds.y {
-                bounds.add_relative_margin_y(margin_fraction);
+                scaled.add_relative_margin_y(margin_fraction);
+            }
+            bounds = scaled.unscaled(axis_scales);
+        }
+
+        // Move the view along with new data, unless it was moved away from the newest x value.
+        if follow_newest_x {
+            let newest = items
+                .iter()
+                .map(|item| item.bounds().max[0])
+                .fold(f64::NEG_INFINITY, f64::max);
+            if let Some(last_newest) = newest_x {
+                if !auto_bounds.x
+                    && view_preset.is_none()
+                    && newest > last_newest
+                    && bounds.max[0] >= last_newest
+                {
+                    let x_scale = axis_scales[0];
+                    let mut scaled = bounds.scaled(axis_scales);
+                    scaled.translate_x(x_scale.apply(newest) - x_scale.apply(last_newest));
+                    bounds = scaled.unscaled(axis_scales);
+                }
+            }
+            newest_x = newest.is_finite().then(|| newest);
+        }
+
+        let mut transform =
+            ScreenTransform::new(rect, bounds, center_x_axis, center_y_axis, axis_scales);
+
+        // Enforce aspect ratio
+        if let Some(data_aspect) = data_aspect {
+            if let Some(linked_axes) = &linked_axes {
+                let change_x = linked_axes.link_y && !linked_axes.link_x;
+                transform.set_aspect_by_changing_axis(data_aspect as f64, change_x);
+            } else {
+                transform.set_aspect_by_expanding(data_aspect as f64);
+            }
+        }
+
+        // The right y axis shares the x axis, and has a y range of its own.
+        let right_scales = [axis_scales[0], AxisScale::Linear];
+        let mut right_transform =
+            items
+                .iter()
+                .any(|item| item.y_axis() == YAxis::Right)
+                .then(|| {
+                    let mut right_bounds = *transform.bounds();
+                    if let Some([min, max]) = right_y {
+                        right_bounds.min[1] = min;
+                        right_bounds.max[1] = max;
+                    } else {
+                        let mut fit = PlotBounds::NOTHING;
+                        for item in items.iter().filter(|item| item.y_axis() == YAxis::Right) {
+                            fit.merge_y(&item.bounds());
+                        }
+                        if fit.min[1] == fit.max[1] {
+                            fit.expand_y(1.0);
+                        } else if fit.min[1] > fit.max[1] {
+                            fit.set_y(&PlotBounds::new_symmetrical(1.0));
+                        }
+                        fit.add_relative_margin_y(margin_fraction);
+                        right_bounds.set_y(&fit);
+                    }
+                    ScreenTransform::new(rect, right_bounds, false, false, right_scales)
+                });
+
+        let transform_before_interaction = transform.clone();
+        let bounds_before_interaction = *transform.bounds();
+        let mut interaction_cursor = None;
+
+        let label_height = ui.fonts().row_height(&TextStyle::Body.resolve(ui.style()));
+        let axis_zones = axis_zones(&transform, label_height);
+        let right_axis_zone = if right_transform.is_some() {
+            Rect::from_x_y_ranges(
+                rect.right() - Y_AXIS_ZONE_WIDTH..=rect.right(),
+                rect.y_range(),
+            )
+        } else {
+            Rect::NOTHING
+        };
+
+        // Two or more fingers that started on the plot pan and zoom it, instead of the pointer.
+        let multi_touch = ui
+            .input()
+            .multi_touch()
+            .filter(|touch| rect.contains(touch.start_pos));
+
+        // Save the click that starts a boxed zoom, which takes over the drag.
+        if response.drag_started() {
+            let modifier_held = boxed_zoom_modifier
+                .map_or(false, |modifier| ui.input().modifiers.matches(modifier));
+            let box_zoom_started = response.dragged_by(boxed_zoom_pointer)
+                || (modifier_held && response.dragged_by(PointerButton::Primary));
+            // it would be best for egui that input has a memory of the last click pos because it's a common pattern
+            last_click_pos_for_zoom = response
+                .hover_pos()
+                .filter(|_| allow_boxed_zoom && box_zoom_started);
+        }
+
+        // Grab the point of a draggable item that a primary drag starts on, which takes over the drag.
+        if response.dragged_by(PointerButton::Primary) {
+            if response.drag_started() {
+                point_drag = ui
+                    .input()
+                    .pointer
+                    .press_origin()
+                    .filter(|_| last_click_pos_for_zoom.is_none())
+                    .and_then(|origin| {
+                        closest_draggable_point(
+                            &items,
+                            origin,
+                            &transform,
+                            right_transform.as_ref(),
+                        )
+                    });
+                if point_drag.is_some() {
+                    // Keep the view still while the item changes under the pointer.
+                    auto_bounds = false.into();
+                }
+            }
+        } else {
+            point_drag = None;
+        }
+
+        // Dragging an axis
+        if response.dragged_by(PointerButton::Primary) {
+            if response.drag_started() {
+                let origin = ui
+                    .input()
+                    .pointer
+                    .press_origin()
+                    .filter(|_| last_click_pos_for_zoom.is_none() && point_drag.is_none());
+                corner_drag_anchor = origin.and_then(|origin| {
+                    let (anchor, in_corner) = opposite_corner(&rect, origin);
+                    (allow_zoom && (in_corner || ui.input().modifiers.alt)).then(|| anchor)
+                });
+                // Axis 2 is the right y axis.
+                axis_drag = origin
+                    .filter(|_| corner_drag_anchor.is_none())
+                    .and_then(|origin| {
+                        if right_axis_zone.contains(origin) {
+                            Some(2)
+                        } else {
+                            (0..2)
+                                .find(|&axis| show_axes[axis] && axis_zones[axis].contains(origin))
+                        }
+                    });
+            }
+        } else {
+            axis_drag = None;
+            corner_drag_anchor = None;
+        }
+        if let (Some(anchor), Some(pointer)) =
+            (corner_drag_anchor, ui.input().pointer.interact_pos())
+        {
+            interaction_cursor = Some(cursors.corner);
+            let anchor = anchor.pos_in_rect(transform.frame());
+            let offset = pointer - anchor;
+            let last_offset = offset - response.drag_delta();
+            // Scale so that the value under the pointer follows it, unless it crosses the anchor.
+            let scale = |offset: f32, last_offset: f32| {
+                if offset * last_offset > 0.0 && offset.abs() >= 1.0 && last_offset.abs() >= 1.0 {
+                    offset / last_offset
+                } else {
+                    1.0
+                }
+            };
+            let zoom_factor = vec2(
+                scale(offset.x, last_offset.x),
+                scale(offset.y, last_offset.y),
+            );
+            if zoom_factor != Vec2::splat(1.0) {
+                transform.zoom(zoom_factor, anchor);
+                auto_bounds = false.into();
+            }
+        }
+        if let (Some(axis), Some(origin)) = (axis_drag, ui.input().pointer.press_origin()) {
+            let delta = response.drag_delta();
+            if axis == 0 {
+                interaction_cursor = Some(cursors.x_axis);
+                if allow_zoom {
+                    let zoom_factor = (delta.x * AXIS_DRAG_ZOOM_SPEED).exp();
+                    transform.zoom(vec2(zoom_factor, 1.0), origin);
+                    auto_bounds.x = false;
+                } else if allow_drag {
+                    transform.translate_bounds(vec2(-delta.x, 0.0));
+                    auto_bounds.x = false;
+                }
+            } else if axis == 1 {
+                interaction_cursor = Some(cursors.y_axis);
+                if allow_zoom {
+                    let zoom_factor = (-delta.y * AXIS_DRAG_ZOOM_SPEED).exp();
+                    transform.zoom(vec2(1.0, zoom_factor), origin);
+                    auto_bounds.y = false;
+                }
+            } else if let Some(right_transform) = &mut right_transform {
+                interaction_cursor = Some(cursors.y_axis);
+                if allow_zoom {
+                    let zoom_factor = (-delta.y * AXIS_DRAG_ZOOM_SPEED).exp();
+                    right_transform.zoom(vec2(1.0, zoom_factor), origin);
+                    let right_bounds = right_transform.bounds();
+                    right_y = Some([right_bounds.min[1], right_bounds.max[1]]);
+                }
             }
         }
 
-        let mut transform = ScreenTransform::new(rect, bounds, center_x_axis, center_y_axis);
-
-        // Enforce aspect ratio
-        if let Some(data_aspect) = data_aspect {
-            if let Some(linked_axes) = &linked_axes {
-                let change_x = linked_axes.link_y && !linked_axes.link_x;
-                transform.set_aspect_by_changing_axis(data_aspect as f64, change_x);
-            } else {
-                transform.set_aspect_by_expanding(data_aspect as f64);
+        // Measuring, which takes over the drag from panning.
+        if !measure || response.clicked_by(PointerButton::Primary) {
+            measurement = None;
+        }
+        if measure
+            && response.drag_started()
+            && response.dragged_by(PointerButton::Primary)
+            && axis_drag.is_none()
+            && corner_drag_anchor.is_none()
+            && last_click_pos_for_zoom.is_none()
+            && point_drag.is_none()
+        {
+            measurement = ui.input().pointer.press_origin().map(|origin| {
+                let start = transform.value_from_position(origin);
+                PlotMeasurement {
+                    start,
+                    end: start,
+                    finished: false,
+                }
+            });
+        }
+        let measuring = measurement.map_or(false, |measurement| !measurement.finished);
+        if let Some(measurement) = measurement.as_mut().filter(|_| measuring) {
+            if let Some(pointer) = ui.input().pointer.interact_pos() {
+                measurement.end = transform.value_from_position(pointer);
             }
+            measurement.finished = !response.dragged_by(PointerButton::Primary);
         }
 
         // Dragging
-        if allow_drag && response.dragged_by(PointerButton::Primary) {
-            response = response.on_hover_cursor(CursorIcon::Grabbing);
+        if allow_drag
+            && axis_drag.is_none()
+            && corner_drag_anchor.is_none()
+            && last_click_pos_for_zoom.is_none()
+            && point_drag.is_none()
+            && !measuring
+            && multi_touch.is_none()
+            && response.dragged_by(PointerButton::Primary)
+        {
+            interaction_cursor = Some(cursors.panning);
             transform.translate_bounds(-response.drag_delta());
             auto_bounds = false.into();
         }
 
+        // Dragging a point
+        let mut dragged_point = None;
+        if let (Some((item_index, point_index)), Some(pointer)) =
+            (point_drag, ui.input().pointer.interact_pos())
+        {
+            if let Some(item) = items.get_mut(item_index) {
+                interaction_cursor = Some(cursors.draggable_item);
+                let value = item_transform(&**item, &transform, right_transform.as_ref())
+                    .value_from_position(pointer);
+                let snap = |value: f64, step: f64| {
+                    if step > 0.0 {
+                        (value / step).round() * step
+                    } else {
+                        value
+                    }
+                };
+                let point =
+                    PlotPoint::new(snap(value.x, drag_snap[0]), snap(value.y, drag_snap[1]));
+                item.drag_point(point_index, point);
+                dragged_point = Some((item.name().to_owned(), point_index, point));
+            }
+        }
+
         // Zooming
         let mut boxed_zoom_rect = None;
         if allow_boxed_zoom {
-            // Save last click to allow boxed zooming
-            if response.drag_started() && response.dragged_by(boxed_zoom_pointer) {
-                // it would be best for egui that input has a memory of the last click pos because it's a common pattern
-                last_click_pos_for_zoom = response.hover_pos();
-            }
             let box_start_pos = last_click_pos_for_zoom;
             let box_end_pos = response.hover_pos();
             if let (Some(box_start_pos), Some(box_end_pos)) = (box_start_pos, box_end_pos) {
                 // while dragging prepare a Shape and draw it later on top of the plot
-                if response.dragged_by(boxed_zoom_pointer) {
-                    response = response.on_hover_cursor(CursorIcon::ZoomIn);
+                if response.dragged() {
+                    interaction_cursor = Some(cursors.box_zoom);
                     let rect = epaint::Rect::from_two_pos(box_start_pos, box_end_pos);
                     boxed_zoom_rect = Some((
                         epaint::RectShape::stroke(
@@ -764,8 +1983,11 @@ impl Plot {
                         ), // Inner stroke
                     ));
                 }
-                // when the click is release perform the zoom
-                if response.drag_released() {
+                // when the click is release perform the zoom, unless it was a click without a drag,
+                // like the one that opens the context menu, which keeps the view as it is
+                let clicked = response.clicked_by(boxed_zoom_pointer)
+                    || response.clicked_by(PointerButton::Primary);
+                if response.drag_released() && !clicked && box_start_pos != box_end_pos {
                     let box_start_pos = transform.value_from_position(box_start_pos);
                     let box_end_pos = transform.value_from_position(box_end_pos);
                     let new_bounds = PlotBounds {
@@ -773,18 +1995,64 @@ impl Plot {
                         max: [box_end_pos.x, box_start_pos.y],
                     };
                     if new_bounds.is_valid() {
+                        zoom_history.push(*transform.bounds());
+                        if zoom_history.len() > ZOOM_HISTORY_LEN {
+                            zoom_history.remove(0);
+                        }
                         transform.set_bounds(new_bounds);
                         auto_bounds = false.into();
                     } else {
                         auto_bounds = true.into();
                     }
+                }
+                if response.drag_released() {
                     // reset the boxed zoom state
                     last_click_pos_for_zoom = None;
                 }
             }
         }
 
-        if let Some(hover_pos) = response.hover_pos() {
+        let hovered_right_axis = response
+            .hover_pos()
+            .filter(|&hover_pos| right_axis_zone.contains(hover_pos));
+        if let (Some(hover_pos), Some(right_transform)) = (hovered_right_axis, &mut right_transform)
+        {
+            // Over the labels of the right y axis, only that axis zooms and scrolls.
+            let right_bounds = *right_transform.bounds();
+            let zoom_factor = ui.input().zoom_delta_2d().y;
+            if allow_zoom && zoom_factor != 1.0 {
+                right_transform.zoom(vec2(1.0, zoom_factor), hover_pos);
+            }
+            let scroll_delta = ui.input().scroll_delta.y;
+            if allow_scroll && scroll_delta != 0.0 {
+                right_transform.translate_bounds(vec2(0.0, -scroll_delta));
+            }
+            if *right_transform.bounds() != right_bounds {
+                let right_bounds = right_transform.bounds();
+                right_y = Some([right_bounds.min[1], right_bounds.max[1]]);
+            }
+        } else if let Some(touch) = multi_touch {
+            // Pinching zooms each axis by how much the fingers spread along it,
+            // so that a horizontal pinch only zooms the x axis.
+            if allow_zoom {
+                let zoom_factor = if data_aspect.is_some() {
+                    Vec2::splat(touch.zoom_delta)
+                } else {
+                    touch.zoom_delta_axes
+                };
+                if zoom_factor != Vec2::splat(1.0) && zoom_factor.is_finite() {
+                    let center = response.hover_pos().unwrap_or(touch.start_pos);
+                    transform.zoom(zoom_factor, center);
+                    auto_bounds.x &= zoom_factor.x == 1.0;
+                    auto_bounds.y &= zoom_factor.y == 1.0;
+                }
+            }
+            if allow_drag && touch.translation_delta != Vec2::ZERO {
+                interaction_cursor = Some(cursors.panning);
+                transform.translate_bounds(-touch.translation_delta);
+                auto_bounds = false.into();
+            }
+        } else if let Some(hover_pos) = response.hover_pos() {
             if allow_zoom {
                 let zoom_factor = if data_aspect.is_some() {
                     Vec2::splat(ui.input().zoom_delta())
@@ -805,39 +2073,283 @@ impl Plot {
             }
         }
 
+        // The volume pane shares the x axis with the plot, so it can pan and zoom it too.
+        let volume_pane = volume_pane_rect.map(|volume_pane_rect| {
+            let volume_response =
+                ui.interact(volume_pane_rect, plot_id.with("volume_pane"), Sense::drag());
+            if allow_drag && volume_response.dragged_by(PointerButton::Primary) {
+                ui.output().cursor_icon = cursors.panning;
+                transform.translate_bounds(vec2(-volume_response.drag_delta().x, 0.0));
+                auto_bounds.x = false;
+            }
+            if let Some(hover_pos) = volume_response.hover_pos() {
+                let zoom_factor = ui.input().zoom_delta_2d().x;
+                if allow_zoom && zoom_factor != 1.0 {
+                    transform.zoom(vec2(zoom_factor, 1.0), hover_pos);
+                    auto_bounds.x = false;
+                }
+                let scroll_delta = ui.input().scroll_delta.x;
+                if allow_scroll && scroll_delta != 0.0 {
+                    transform.translate_bounds(vec2(-scroll_delta, 0.0));
+                    auto_bounds.x = false;
+                }
+            }
+            (volume_pane_rect, volume_response)
+        });
+
+        // The minimap shows all of the data, and dragging in it moves the view along the x axis.
+        let minimap = minimap_rect.map(|minimap_rect| {
+            let mut data_bounds = PlotBounds::NOTHING;
+            for item in &items {
+                data_bounds.merge(&item.bounds());
+            }
+            if !data_bounds.is_valid() {
+                data_bounds = *transform.bounds();
+            }
+            let mut scaled = data_bounds.scaled(axis_scales);
+            scaled.add_relative_margin_y(margin_fraction);
+            let data_bounds = scaled.unscaled(axis_scales);
+            let minimap_transform =
+                ScreenTransform::new(minimap_rect, data_bounds, false, false, axis_scales);
+            let minimap_response = ui.interact(
+                minimap_rect,
+                plot_id.with("minimap"),
+                Sense::click_and_drag(),
+            );
+            (minimap_transform, minimap_response)
+        });
+        if let Some((minimap_transform, minimap_response)) = &minimap {
+            let pointer = minimap_response.interact_pointer_pos();
+            if let (true, Some(pointer)) =
+                (minimap_response.dragged_by(PointerButton::Primary), pointer)
+            {
+                let window = minimap_window(minimap_transform, transform.bounds());
+                // Keep the window where it was grabbed, or center it on the pointer if it was missed.
+                let grab = *minimap_grab.get_or_insert_with(|| {
+                    if window.x_range().contains(&pointer.x) {
+                        pointer.x - window.center().x
+                    } else {
+                        0.0
+                    }
+                });
+                let center_x = minimap_transform
+                    .value_from_position(pos2(pointer.x - grab, pointer.y))
+                    .x;
+                let mut bounds = transform.scaled_bounds();
+                bounds.translate_x(axis_scales[0].apply(center_x) - bounds.center().x);
+                transform.set_bounds(bounds.unscaled(axis_scales));
+                auto_bounds.x = false;
+            } else {
+                minimap_grab = None;
+            }
+        }
+
+        if *transform.bounds() != bounds_before_interaction {
+            view_preset = None;
+        }
+
+        // The right y axis moves along with the y axis of the plot, unless the pointer is on the
+        // labels of the left one, and it always shows the same x range.
+        let right_transform = right_transform.map(|right_transform| {
+            let bounds = transform.bounds();
+            let y_changed = bounds.min[1] != bounds_before_interaction.min[1]
+                || bounds.max[1] != bounds_before_interaction.max[1];
+            let on_left_axis = axis_drag == Some(1)
+                || (!response.dragged()
+                    && response
+                        .hover_pos()
+                        .map_or(false, |hover_pos| axis_zones[1].contains(hover_pos)));
+            if y_changed && !on_left_axis {
+                // Where the new y range was on the screen before, in values of the right axis:
+                let right_y_at = |y: f64| {
+                    let pos = transform_before_interaction
+                        .position_from_point(&PlotPoint::new(bounds.min[0], y));
+                    right_transform.value_from_position(pos).y
+                };
+                let (min, max) = (right_y_at(bounds.min[1]), right_y_at(bounds.max[1]));
+                right_y = Some([min.min(max), min.max(max)]);
+            }
+            let mut right_bounds = *bounds;
+            right_bounds.set_y(right_transform.bounds());
+            if let Some([min, max]) = right_y {
+                right_bounds.min[1] = min;
+                right_bounds.max[1] = max;
+            }
+            ScreenTransform::new(rect, right_bounds, false, false, right_scales)
+        });
+
         // Initialize values from functions.
         for item in &mut items {
             item.initialize(transform.bounds().range_x());
         }
 
+        let pointer_over_draggable_item = response.hover_pos().map_or(false, |pointer| {
+            closest_draggable_point(&items, pointer, &transform, right_transform.as_ref()).is_some()
+        });
+
+        let volume_pane_pointer = volume_pane
+            .as_ref()
+            .and_then(|(_, volume_response)| volume_response.hover_pos());
+        if let Some((volume_pane_rect, volume_response)) = volume_pane {
+            // Show the cursor of either pane in both:
+            let pointer_x = response
+                .hover_pos()
+                .or_else(|| volume_response.hover_pos())
+                .map(|pointer| pointer.x);
+            let bars: Vec<Bar> = items.iter().flat_map(|item| item.volume_bars()).collect();
+            paint_volume_pane(
+                ui,
+                volume_pane_rect,
+                &transform,
+                bars,
+                margin_fraction,
+                pointer_x,
+            );
+        }
+
+        if let Some((minimap_transform, minimap_response)) = minimap {
+            paint_minimap(ui, &minimap_transform, &transform, &items);
+            let window = minimap_window(&minimap_transform, transform.bounds());
+            if minimap_grab.is_some() {
+                ui.output().cursor_icon = cursors.panning;
+            } else if minimap_response
+                .hover_pos()
+                .map_or(false, |pointer| window.contains(pointer))
+            {
+                ui.output().cursor_icon = cursors.minimap_window;
+            }
+        }
+
+        let measurement_shapes = measurement.map(|measurement| {
+            measurement_shapes(
+                ui,
+                &transform,
+                &measurement,
+                x_axis_time.is_some(),
+                &axis_units,
+            )
+        });
+
+        let linked_cursor = link_group
+            .as_ref()
+            .filter(|group| group.link_cursor)
+            .and_then(|group| {
+                let hovered_value = response
+                    .hover_pos()
+                    .map(|pointer| transform.value_from_position(pointer));
+                group.exchange_cursor(plot_id, hovered_value)
+            });
+        let link_highlight = link_group
+            .as_ref()
+            .map_or(false, |group| group.link_highlight);
+        let link_y = linked_axes.as_ref().map_or(false, |axes| axes.link_y);
+
         let prepared = PreparedPlot {
             items,
             show_x,
             show_y,
+            crosshair,
             label_formatter,
             coordinates_formatter,
             axis_formatters,
+            x_axis_time,
+            axis_units,
             show_axes,
             transform: transform.clone(),
+            right_transform,
             grid_spacers,
+            tick_generators,
+            watermark,
+            linked_cursor,
+            link_highlight,
+            link_y,
         };
-        prepared.ui(ui, &response);
+        let hovered_element = prepared.ui(ui, &response);
+
+        // Remember what a secondary click was on, for the context menu that it opens,
+        // in the view that it was clicked in.
+        let context_target_id = plot_id.with("context_target");
+        if response.clicked_by(PointerButton::Secondary) {
+            if let Some(pointer) = ui.input().pointer.interact_pos() {
+                let target = (
+                    transform_before_interaction.value_from_position(pointer),
+                    hovered_element.clone(),
+                );
+                ui.data().insert_temp(context_target_id, target);
+            }
+        }
+        let context_target = ui.data().get_temp(context_target_id);
+
+        if let Some(pointer) = volume_pane_pointer {
+            let line = items::vertical_line(pointer, &transform, items::rulers_color(ui));
+            ui.painter().with_clip_rect(rect).add(line);
+        }
 
         if let Some(boxed_zoom_rect) = boxed_zoom_rect {
             ui.painter().with_clip_rect(rect).add(boxed_zoom_rect.0);
             ui.painter().with_clip_rect(rect).add(boxed_zoom_rect.1);
         }
+        if let Some(measurement_shapes) = measurement_shapes {
+            ui.painter().with_clip_rect(rect).extend(measurement_shapes);
+        }
 
         if let Some(mut legend) = legend {
             ui.add(&mut legend);
             hidden_items = legend.hidden_items();
             hovered_entry = legend.hovered_entry_name();
+            if outside_legend.map_or(false, |(_, width)| width != legend.width()) {
+                ui.data().insert_temp(legend_width_id, legend.width());
+                ui.ctx().request_repaint();
+            }
+        }
+
+        // The preset is applied next frame, like the legend changes.
+        let mut picked_view_preset = None;
+        if let Some(view_presets) = view_presets {
+            let mut toolbar_ui = ui.child_ui(rect.shrink(4.0), Layout::left_to_right(Align::TOP));
+            for preset in view_presets {
+                if toolbar_ui
+                    .selectable_label(view_preset == Some(preset), preset.label())
+                    .clicked()
+                {
+                    picked_view_preset = Some(preset);
+                }
+            }
+            if picked_view_preset.is_some() {
+                view_preset = picked_view_preset;
+                ui.ctx().request_repaint();
+            }
         }
 
         if let Some(group) = linked_axes.as_ref() {
             group.set(*transform.bounds());
         }
 
+        let cursor = interaction_cursor.or_else(|| {
+            let pointer = response.hover_pos()?;
+            let [x_axis_zone, y_axis_zone] = axis_zones;
+            if allow_zoom && opposite_corner(&rect, pointer).1 {
+                Some(cursors.corner)
+            } else if show_axes[0] && x_axis_zone.contains(pointer) {
+                Some(cursors.x_axis)
+            } else if show_axes[1] && y_axis_zone.contains(pointer) {
+                Some(cursors.y_axis)
+            } else if pointer_over_draggable_item {
+                Some(cursors.draggable_item)
+            } else if show_x || show_y {
+                Some(cursors.plot_area)
+            } else {
+                None
+            }
+        });
+        if let Some(cursor) = cursor {
+            response = response.on_hover_cursor(cursor);
+        }
+
+        if let Some(pointer) = response.hover_pos() {
+            last_cursor = Some(transform.value_from_position(pointer));
+        }
+
         let memory = PlotMemory {
             auto_bounds,
             hovered_entry,
@@ -845,16 +2357,56 @@ impl Plot {
             min_auto_bounds,
             last_screen_transform: transform,
             last_click_pos_for_zoom,
+            zoom_history,
+            view_preset,
+            axis_drag,
+            corner_drag_anchor,
+            minimap_grab,
+            newest_x,
+            right_y,
+            point_drag,
+            last_cursor,
+            measurement,
         };
+        let view_state = memory.view_state();
         memory.store(ui.ctx(), plot_id);
 
-        let response = if show_x || show_y {
-            response.on_hover_cursor(CursorIcon::Crosshair)
-        } else {
-            response
-        };
+        PlotResponse {
+            inner,
+            response,
+            view_preset: picked_view_preset,
+            view_state,
+            measurement,
+            dragged_point,
+            hovered_element,
+            context_target,
+            painted: PaintedRange {
+                layer_id: ui.layer_id(),
+                start: paint_start,
+                end: ui.ctx().graphics().list(ui.layer_id()).next_idx(),
+                rect: painted_rect,
+            },
+        }
+    }
+}
+
+/// Which y axis the values of a plot item are on, see [`PlotUi::set_y_axis`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum YAxis {
+    /// The y axis of the plot, with its labels and grid lines.
+    Left,
+
+    /// A second y axis with its labels along the right edge of the plot, for values in a
+    /// different range, e.g. the open interest next to a price.
+    ///
+    /// It fits its own items, and can be zoomed and scrolled by itself by hovering its labels,
+    /// or by dragging them. Everything else pans and zooms both y axes. It is always linear.
+    Right,
+}
 
-        InnerResponse { inner, response }
+impl Default for YAxis {
+    fn default() -> Self {
+        Self::Left
     }
 }
 
@@ -862,10 +2414,15 @@ impl Plot {
 /// provided to [`Plot::show`]. See [`Plot`] for an example of how to use it.
 pub struct PlotUi {
     items: Vec<Box<dyn PlotItem>>,
+    /// The y axis of the items from `y_axis_start` on.
+    y_axis: YAxis,
+    y_axis_start: usize,
     next_auto_color_idx: usize,
     last_screen_transform: ScreenTransform,
     response: Response,
     ctx: Context,
+    plot_id: Id,
+    new_bounds: Option<PlotBounds>,
 }
 
 impl PlotUi {
@@ -881,6 +2438,46 @@ impl PlotUi {
         &self.ctx
     }
 
+    /// Put the items that are added from now on onto this y axis. Default: [`YAxis::Left`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui::plot::{Line, Plot, YAxis};
+    /// Plot::new("two axes").show(ui, |plot_ui| {
+    ///     plot_ui.line(Line::new(vec![[0.0, 101.5], [1.0, 102.0]]).name("Price"));
+    ///     plot_ui.set_y_axis(YAxis::Right);
+    ///     plot_ui.line(Line::new(vec![[0.0, 35_000.0], [1.0, 38_000.0]]).name("Open interest"));
+    /// });
+    /// # });
+    /// ```
+    pub fn set_y_axis(&mut self, axis: YAxis) {
+        self.assign_y_axis();
+        self.y_axis = axis;
+    }
+
+    /// Move the items that were added since the last [`Self::set_y_axis`] onto its y axis.
+    fn assign_y_axis(&mut self) {
+        if self.y_axis == YAxis::Right {
+            let items = self.items.split_off(self.y_axis_start);
+            self.items.extend(
+                items
+                    .into_iter()
+                    .map(|item| Box::new(items::OnRightYAxis(item)) as Box<dyn PlotItem>),
+            );
+        }
+        self.y_axis_start = self.items.len();
+    }
+
+    /// The series with this name in the memory of this plot. It starts out empty.
+    ///
+    /// See [`PlotSeriesHandle`].
+    pub fn series<T: 'static + Send + Sync>(&self, name: &str) -> PlotSeriesHandle<T> {
+        self.ctx
+            .data()
+            .get_temp_mut_or_default::<PlotSeriesHandle<T>>(self.plot_id.with(name))
+            .clone()
+    }
+
     /// The plot bounds as they were in the last frame. If called on the first frame and the bounds were not
     /// further specified in the plot builder, this will return bounds centered on the origin. The bounds do
     /// not change until the plot is drawn.
@@ -888,6 +2485,12 @@ impl PlotUi {
         *self.last_screen_transform.bounds()
     }
 
+    /// Show these bounds from this frame on, e.g. to navigate the plot in your own way.
+    /// They are kept until the user moves the plot.
+    pub fn set_plot_bounds(&mut self, bounds: PlotBounds) {
+        self.new_bounds = Some(bounds);
+    }
+
     /// Returns `true` if the plot area is currently hovered.
     pub fn plot_hovered(&self) -> bool {
         self.response.hovered()
@@ -999,12 +2602,44 @@ impl PlotUi {
         self.items.push(Box::new(hline));
     }
 
+    /// Add a filled range of x or y values, e.g. a market session.
+    /// Always fills the full height or width of the plot.
     pub fn filled_range(&mut self, mut filled_range: FilledRange) {
         if filled_range.stroke.color == Color32::TRANSPARENT {
             filled_range.stroke.color = self.auto_color();
         }
         self.items.push(Box::new(filled_range));
     }
+
+    /// Add a band between a lower and an upper series, e.g. a confidence interval.
+    pub fn band(&mut self, mut band: Band) {
+        if band.stroke.color == Color32::TRANSPARENT {
+            band.stroke.color = self.auto_color();
+        }
+        self.items.push(Box::new(band));
+    }
+
+    /// Add an envelope, e.g. of an audio waveform.
+    pub fn envelope(&mut self, mut envelope: Envelope) {
+        if envelope.series.is_empty() {
+            return;
+        };
+
+        // Give the envelope an automatic color if no color has been assigned.
+        if envelope.color == Color32::TRANSPARENT {
+            envelope.color = self.auto_color();
+        }
+        self.items.push(Box::new(envelope));
+    }
+
+    /// Add a heat map.
+    pub fn heatmap(&mut self, heatmap: HeatMap) {
+        if heatmap.values.is_empty() {
+            return;
+        }
+        self.items.push(Box::new(heatmap));
+    }
+
     /// Add a vertical line.
     /// Can be useful e.g. to show min/max bounds or similar.
     /// Always fills the full height of the plot.
@@ -1029,16 +2664,64 @@ impl PlotUi {
     }
 
     /// Add a chart plot diagram.
+    ///
+    /// Its comparisons (see [`ChartPlot::compare_with`]) are added as separate items,
+    /// normalized to the left edge of the plot bounds of the last frame.
     pub fn chart_plot(&mut self, mut chart_plot: ChartPlot) {
+        chart_plot.apply_style();
         if chart_plot.candle_elems.is_empty() {
             return;
         }
 
-        // Give the elements an automatic color if no color has been assigned.
+        // The candles are colored by direction if no color has been assigned.
         if chart_plot.default_color == Color32::TRANSPARENT {
-            chart_plot = chart_plot.color(self.auto_color());
+            chart_plot.default_color = chart_plot.color_up;
         }
+
+        let comparisons = std::mem::take(&mut chart_plot.comparisons);
+        let left_x = self.last_screen_transform.bounds().min[0];
+        if !comparisons.is_empty() {
+            chart_plot.normalize_to_percent(left_x);
+            // The candles no longer match the ones in the series:
+            chart_plot.series = None;
+        }
+        // Computed every frame, after the normalization, so that they always match the candles:
+        let indicators: Vec<_> = std::mem::take(&mut chart_plot.indicators)
+            .iter()
+            .map(|indicator| (indicator.name(), indicator.lines(&chart_plot.candle_elems)))
+            .collect();
         self.items.push(Box::new(chart_plot));
+
+        for (name, lines) in indicators {
+            let color = self.auto_color();
+            for line in lines {
+                self.line(Line::new(line).name(&name).color(color));
+            }
+        }
+
+        for mut comparison in comparisons {
+            if comparison.series.is_empty() {
+                continue;
+            }
+            if comparison.stroke.color == Color32::TRANSPARENT {
+                comparison.stroke.color = self.auto_color();
+            }
+            comparison.normalize_to_percent(left_x);
+            self.items.push(Box::new(comparison));
+        }
+    }
+
+    /// Add a chart of OHLC bars.
+    pub fn ohlc_chart(&mut self, mut ohlc_chart: OhlcChart) {
+        if ohlc_chart.elems.is_empty() {
+            return;
+        }
+
+        // The bars are colored by direction if no color has been assigned.
+        if ohlc_chart.default_color == Color32::TRANSPARENT {
+            ohlc_chart.default_color = ohlc_chart.color_up;
+        }
+        self.items.push(Box::new(ohlc_chart));
     }
 
     /// Add a bar chart.
@@ -1061,6 +2744,9 @@ impl PlotUi {
 /// Input for "grid spacer" functions.
 ///
 /// See [`Plot::x_grid_spacer()`] and [`Plot::y_grid_spacer()`].
+///
+/// Along an [`AxisScale::Log10`] axis the grid is in the log10 of the values,
+/// both these bounds and the returned [`GridMark`]s.
 pub struct GridInput {
     /// Min/max of the visible data range (the values at the two edges of the plot,
     /// for the current axis).
@@ -1071,6 +2757,9 @@ pub struct GridInput {
     /// Computed as the ratio between the diagram's bounds (in plot coordinates) and the viewport
     /// (in frame/window coordinates), scaled up to represent the minimal possible step.
     pub base_step_size: f64,
+
+    /// How many points on the screen one unit along the axis takes.
+    pub points_per_unit: f64,
 }
 
 /// One mark (horizontal or vertical line) in the background grid of a plot.
@@ -1087,6 +2776,18 @@ pub struct GridMark {
     pub step_size: f64,
 }
 
+/// A grid line with a label, see [`Plot::x_tick_generator()`] and [`Plot::y_tick_generator()`].
+pub struct Tick {
+    /// X or Y value in the plot.
+    pub value: f64,
+
+    /// From `0.0` for the faintest grid line to `1.0` for the strongest one. The label fades with it.
+    pub importance: f32,
+
+    /// Shown next to the grid line, unless it is empty.
+    pub label: String,
+}
+
 /// Recursively splits the grid into `base` subdivisions (e.g. 100, 10, 1).
 ///
 /// The logarithmic base, expressing how many times each grid unit is subdivided.
@@ -1127,40 +2828,95 @@ pub fn uniform_grid_spacer(spacer: impl Fn(GridInput) -> [f64; 3] + 'static) ->
     Box::new(get_marks)
 }
 
+/// Grid lines for an [`AxisScale::Log10`] axis, see [`Plot::x_axis_scale`].
+///
+/// There are lines at the powers of 10, and at 2 to 9 times them when there is room.
+/// When the decades get too dense, only every 10th or 100th power of 10 gets a line.
+pub fn log_axis_grid_spacer() -> GridSpacer {
+    Box::new(|input: GridInput| -> Vec<GridMark> {
+        let (min, max) = input.bounds;
+        if !(min.is_finite() && max.is_finite()) {
+            return vec![];
+        }
+        // The bounds and values are in decades:
+        let smallest_step = next_power(input.base_step_size, 10.0).at_least(1.0);
+        let mut marks = generate_marks(
+            [smallest_step, smallest_step * 10.0, smallest_step * 100.0],
+            input.bounds,
+        );
+        if input.base_step_size.abs() < 0.1 {
+            for decade in (min.floor() as i64)..=(max.floor() as i64) {
+                for factor in 2..10 {
+                    let value = decade as f64 + (factor as f64).log10();
+                    if min <= value && value <= max {
+                        marks.push(GridMark {
+                            value,
+                            step_size: 0.1,
+                        });
+                    }
+                }
+            }
+        }
+        marks
+    })
+}
+
 // ----------------------------------------------------------------------------
 
 struct PreparedPlot {
     items: Vec<Box<dyn PlotItem>>,
     show_x: bool,
     show_y: bool,
+    crosshair: bool,
     label_formatter: LabelFormatter,
     coordinates_formatter: Option<(Corner, CoordinatesFormatter)>,
     axis_formatters: [AxisFormatter; 2],
+    x_axis_time: Option<TimeFormat>,
+    axis_units: [String; 2],
     show_axes: [bool; 2],
     transform: ScreenTransform,
+    /// The transform of the items on the [`YAxis::Right`], if there are any.
+    right_transform: Option<ScreenTransform>,
     grid_spacers: [GridSpacer; 2],
+    tick_generators: [TickGenerator; 2],
+    watermark: Option<Watermark>,
+    /// Where another plot of the [`LinkGroup`] is hovered.
+    linked_cursor: Option<PlotPoint>,
+    link_highlight: bool,
+    link_y: bool,
 }
 
 impl PreparedPlot {
-    fn ui(self, ui: &mut Ui, response: &Response) {
+    /// Paints the plot, and returns the element that is hovered, if any.
+    fn ui(self, ui: &mut Ui, response: &Response) -> Option<PlotElement> {
         let mut shapes = Vec::new();
 
+        if let Some(watermark) = &self.watermark {
+            watermark.add_shapes(ui, *self.transform.frame(), &mut shapes);
+        }
+
         for d in 0..2 {
             if self.show_axes[d] {
                 self.paint_axis(ui, d, &mut shapes);
             }
         }
+        if self.show_axes[1] && self.right_transform.is_some() {
+            self.paint_axis(ui, 2, &mut shapes);
+        }
 
         let transform = &self.transform;
 
         let mut plot_ui = ui.child_ui(*transform.frame(), Layout::default());
         plot_ui.set_clip_rect(*transform.frame());
         for item in &self.items {
-            item.shapes(&mut plot_ui, transform, &mut shapes);
+            item.shapes(&mut plot_ui, self.transform_of(&**item), &mut shapes);
         }
 
+        let mut hovered_element = None;
         if let Some(pointer) = response.hover_pos() {
-            self.hover(ui, pointer, &mut shapes);
+            hovered_element = self.hover(ui, pointer, &mut shapes);
+        } else if let Some(value) = self.linked_cursor {
+            self.hover_linked(ui, value, &mut shapes);
         }
 
         let painter = ui.painter().with_clip_rect(*transform.frame());
@@ -1181,21 +2937,44 @@ impl PreparedPlot {
                 painter.text(position, anchor, text, font_id, ui.visuals().text_color());
             }
         }
+
+        hovered_element
+    }
+
+    /// The transform of the y axis that `item` is on.
+    fn transform_of(&self, item: &dyn PlotItem) -> &ScreenTransform {
+        item_transform(item, &self.transform, self.right_transform.as_ref())
     }
 
+    /// Axis 2 is the right y axis, which only has labels, next to the grid lines of the left one.
     fn paint_axis(&self, ui: &Ui, axis: usize, shapes: &mut Vec<Shape>) {
         let Self {
-            transform,
             axis_formatters,
+            x_axis_time,
             grid_spacers,
+            tick_generators,
             ..
         } = self;
 
+        let right_axis = axis == 2;
+        let right_grid_spacer = log_grid_spacer(10);
+        let (transform, grid_spacer, formatter, tick_generator) =
+            match (axis, &self.right_transform) {
+                (0 | 1, _) => (
+                    &self.transform,
+                    &grid_spacers[axis],
+                    axis_formatters[axis].as_deref(),
+                    tick_generators[axis].as_deref(),
+                ),
+                (2, Some(right_transform)) => (right_transform, &right_grid_spacer, None, None),
+                _ => panic!("Axis {} does not exist.", axis),
+            };
+        let axis = axis.min(1);
+
         let bounds = transform.bounds();
         let axis_range = match axis {
             0 => bounds.range_x(),
-            1 => bounds.range_y(),
-            _ => panic!("Axis {} does not exist.", axis),
+            _ => bounds.range_y(),
         };
 
         let font_id = TextStyle::Body.resolve(ui.style());
@@ -1204,14 +2983,47 @@ impl PreparedPlot {
         let bounds = transform.bounds();
         let value_cross = 0.0_f64.clamp(bounds.min[1 - axis], bounds.max[1 - axis]);
 
+        // The grid is spaced where the axis is linear, i.e. by decades along a log axis.
+        let scale = transform.scales()[axis];
+        let scaled_bounds = transform.scaled_bounds();
+        let dpos_dscaled = transform.dpos_dscaled()[axis];
         let input = GridInput {
-            bounds: (bounds.min[axis], bounds.max[axis]),
-            base_step_size: transform.dvalue_dpos()[axis] * MIN_LINE_SPACING_IN_POINTS,
+            bounds: (scaled_bounds.min[axis], scaled_bounds.max[axis]),
+            base_step_size: MIN_LINE_SPACING_IN_POINTS / dpos_dscaled,
+            points_per_unit: dpos_dscaled.abs(),
         };
-        let steps = (grid_spacers[axis])(input);
 
-        for step in steps {
-            let value_main = step.value;
+        // Each mark with the alpha of its line and of its label, and its label if it's given.
+        let marks: Vec<(f64, f32, f32, Option<String>)> = match tick_generator {
+            Some(tick_generator) => tick_generator(input)
+                .into_iter()
+                .map(|tick| {
+                    let importance = tick.importance.clamp(0.0, 1.0);
+                    (
+                        tick.value,
+                        0.15 * importance,
+                        0.4 * importance,
+                        Some(tick.label),
+                    )
+                })
+                .collect(),
+            None => grid_spacer(input)
+                .into_iter()
+                .map(|step| {
+                    let spacing_in_points = (dpos_dscaled * step.step_size).abs() as f32;
+                    let line_alpha = remap_clamp(
+                        spacing_in_points,
+                        (MIN_LINE_SPACING_IN_POINTS as f32)..=300.0,
+                        0.0..=0.15,
+                    );
+                    let text_alpha = remap_clamp(spacing_in_points, 40.0..=150.0, 0.0..=0.4);
+                    (step.value, line_alpha, text_alpha, None)
+                })
+                .collect(),
+        };
+
+        for (value, line_alpha, text_alpha, label) in marks {
+            let value_main = scale.invert(value);
 
             let value = if axis == 0 {
                 PlotPoint::new(value_main, value_cross)
@@ -1220,15 +3032,8 @@ impl PreparedPlot {
             };
 
             let pos_in_gui = transform.position_from_point(&value);
-            let spacing_in_points = (transform.dpos_dvalue()[axis] * step.step_size).abs() as f32;
 
-            let line_alpha = remap_clamp(
-                spacing_in_points,
-                (MIN_LINE_SPACING_IN_POINTS as f32)..=300.0,
-                0.0..=0.15,
-            );
-
-            if line_alpha > 0.0 {
+            if line_alpha > 0.0 && !right_axis {
                 let line_color = color_from_alpha(ui, line_alpha);
 
                 let mut p0 = pos_in_gui;
@@ -1238,15 +3043,24 @@ impl PreparedPlot {
                 shapes.push(Shape::line_segment([p0, p1], Stroke::new(1.0, line_color)));
             }
 
-            let text_alpha = remap_clamp(spacing_in_points, 40.0..=150.0, 0.0..=0.4);
-
             if text_alpha > 0.0 {
                 let color = color_from_alpha(ui, text_alpha);
 
-                let text: String = if let Some(formatter) = axis_formatters[axis].as_deref() {
+                let time_format = x_axis_time.filter(|_| axis == 0);
+                let text: String = if let Some(label) = label {
+                    label
+                } else if let Some(formatter) = formatter {
                     formatter(value_main, &axis_range)
+                } else if let Some(time_format) = time_format {
+                    time_format.format(value_main, &ui.ctx().locale_format())
                 } else {
-                    emath::round_to_decimals(value_main, 5).to_string() // hack
+                    // Small values on a log axis need more decimals:
+                    let decimals = match scale {
+                        AxisScale::Linear => 5,
+                        AxisScale::Log10 => (5.0 - value_main.log10().floor()).clamp(5.0, 20.0),
+                    } as usize;
+                    let text = emath::round_to_decimals(value_main, decimals).to_string(); // hack
+                    ui.ctx().locale_format().format_number(&text)
                 };
 
                 // Custom formatters can return empty string to signal "no label at this resolution"
@@ -1259,6 +3073,9 @@ impl PreparedPlot {
                     text_pos[1 - axis] = text_pos[1 - axis]
                         .at_most(transform.frame().max[1 - axis] - galley.size()[1 - axis] - 2.0)
                         .at_least(transform.frame().min[1 - axis] + 1.0);
+                    if right_axis {
+                        text_pos.x = transform.frame().right() - galley.size().x - 2.0;
+                    }
 
                     shapes.push(Shape::galley(text_pos, galley));
                 }
@@ -1274,25 +3091,29 @@ impl PreparedPlot {
         }
     }
 
-    fn hover(&self, ui: &Ui, pointer: Pos2, shapes: &mut Vec<Shape>) {
+    /// Shows the values under the pointer, and returns the element that is hovered, if any.
+    fn hover(&self, ui: &Ui, pointer: Pos2, shapes: &mut Vec<Shape>) -> Option<PlotElement> {
         let Self {
             transform,
             show_x,
             show_y,
+            crosshair,
             label_formatter,
+            x_axis_time,
+            axis_units,
             items,
             ..
         } = self;
 
         if !show_x && !show_y {
-            return;
+            return None;
         }
 
-        let interact_radius_sq: f32 = (16.0f32).powi(2);
+        let interact_radius_sq: f32 = HOVER_RADIUS.powi(2);
 
         let candidates = items.iter().filter_map(|item| {
             let item = &**item;
-            let closest = item.find_closest(pointer, transform);
+            let closest = item.find_closest(pointer, self.transform_of(item));
 
             Some(item).zip(closest)
         });
@@ -1306,17 +3127,411 @@ impl PreparedPlot {
             transform,
             show_x: *show_x,
             show_y: *show_y,
+            x_axis_time: *x_axis_time,
+            units: axis_units,
         };
 
+        let mut hovered = None;
         if let Some((item, elem)) = closest {
+            let plot = items::PlotConfig {
+                transform: self.transform_of(item),
+                ..plot
+            };
+            hovered = item
+                .source_element(elem.index, plot.transform)
+                .map(|(indices, value)| PlotElement {
+                    name: item.name().to_owned(),
+                    index: indices.start,
+                    merged_value: if indices.len() > 1 {
+                        item.element_value(elem.index, plot.transform)
+                    } else {
+                        None
+                    },
+                    indices,
+                    value,
+                });
             item.on_hover(elem, shapes, &plot, label_formatter);
-        } else {
+        } else if !crosshair {
             let value = transform.value_from_position(pointer);
             items::rulers_at_value(pointer, value, "", &plot, shapes, label_formatter);
         }
+
+        if *crosshair {
+            self.paint_crosshair(ui, pointer, shapes);
+        }
+
+        hovered
+    }
+
+    /// Shows where another plot of the [`LinkGroup`] is hovered.
+    fn hover_linked(&self, ui: &Ui, value: PlotPoint, shapes: &mut Vec<Shape>) {
+        let transform = &self.transform;
+        let pointer = transform.position_from_point(&value);
+        let line_color = items::rulers_color(ui);
+        if self.show_x {
+            shapes.push(items::vertical_line(pointer, transform, line_color));
+        }
+        if self.show_y && self.link_y {
+            shapes.push(items::horizontal_line(pointer, transform, line_color));
+        }
+
+        if self.link_highlight {
+            let closest = self
+                .items
+                .iter()
+                .filter_map(|item| {
+                    Some(item).zip(item.find_closest_x(pointer.x, self.transform_of(&**item)))
+                })
+                .min_by_key(|(_, elem)| elem.dist_sq.ord())
+                .filter(|(_, elem)| elem.dist_sq <= HOVER_RADIUS.powi(2));
+            if let Some((item, elem)) = closest {
+                let plot = items::PlotConfig {
+                    ui,
+                    transform: self.transform_of(&**item),
+                    show_x: self.show_x,
+                    show_y: self.show_y,
+                    x_axis_time: self.x_axis_time,
+                    units: &self.axis_units,
+                };
+                item.on_hover(elem, shapes, &plot, &self.label_formatter);
+            }
+        }
+    }
+
+    /// Lines through the pointer, and its values in boxes pinned to the bottom and left edges.
+    fn paint_crosshair(&self, ui: &Ui, pointer: Pos2, shapes: &mut Vec<Shape>) {
+        let transform = &self.transform;
+        let frame = *transform.frame();
+        let value = transform.value_from_position(pointer);
+        let line_color = items::rulers_color(ui);
+        let visuals = ui.visuals();
+        let font_id = TextStyle::Small.resolve(ui.style());
+
+        let label = |shapes: &mut Vec<Shape>, text: String, anchor: Align2, pos: Pos2| {
+            if text.is_empty() {
+                return;
+            }
+            let galley =
+                ui.painter()
+                    .layout_no_wrap(text, font_id.clone(), visuals.strong_text_color());
+            let padding = vec2(3.0, 1.0);
+            let rect = anchor.anchor_rect(Rect::from_min_size(pos, galley.size() + 2.0 * padding));
+            // Keep the box inside the plot:
+            let offset = vec2(
+                (frame.left() - rect.left()).at_least(0.0)
+                    + (frame.right() - rect.right()).at_most(0.0),
+                (frame.top() - rect.top()).at_least(0.0)
+                    + (frame.bottom() - rect.bottom()).at_most(0.0),
+            );
+            let rect = rect.translate(offset);
+            shapes.push(Shape::rect_filled(
+                rect,
+                Rounding::same(2.0),
+                visuals.extreme_bg_color,
+            ));
+            shapes.push(Shape::rect_stroke(
+                rect,
+                Rounding::same(2.0),
+                Stroke::new(1.0, line_color),
+            ));
+            shapes.push(Shape::galley(rect.min + padding, galley));
+        };
+
+        if self.show_x {
+            shapes.push(items::vertical_line(pointer, transform, line_color));
+            let text = items::with_unit(self.format_value(ui, 0, value.x), &self.axis_units[0]);
+            label(
+                shapes,
+                text,
+                Align2::CENTER_BOTTOM,
+                pos2(pointer.x, frame.bottom()),
+            );
+        }
+        if self.show_y {
+            shapes.push(items::horizontal_line(pointer, transform, line_color));
+            let text = items::with_unit(self.format_value(ui, 1, value.y), &self.axis_units[1]);
+            label(
+                shapes,
+                text,
+                Align2::LEFT_CENTER,
+                pos2(frame.left(), pointer.y),
+            );
+        }
+    }
+
+    /// A value along the axis, written like the axis labels, but as precise as a point on the screen.
+    fn format_value(&self, ui: &Ui, axis: usize, value: f64) -> String {
+        let bounds = self.transform.bounds();
+        if let Some(formatter) = self.axis_formatters[axis].as_deref() {
+            let axis_range = bounds.min[axis]..=bounds.max[axis];
+            return formatter(value, &axis_range);
+        }
+        let locale_format = ui.ctx().locale_format();
+        match self.x_axis_time.filter(|_| axis == 0) {
+            Some(TimeFormat::Auto) => TimeFormat::DateTime.format(value, &locale_format),
+            Some(time_format) => time_format.format(value, &locale_format),
+            None => {
+                let scale = self.transform.dvalue_dpos()[axis];
+                let decimals = ((-scale.abs().log10()).ceil().at_least(0.0) as usize).at_most(6);
+                locale_format.format_number(&format!("{:.*}", decimals, value))
+            }
+        }
+    }
+}
+
+fn paint_volume_pane(
+    ui: &mut Ui,
+    pane_rect: Rect,
+    transform: &ScreenTransform,
+    bars: Vec<Bar>,
+    margin_fraction: Vec2,
+    pointer_x: Option<f32>,
+) {
+    let visuals = ui.visuals();
+    let background = Shape::rect_filled(pane_rect, Rounding::same(2.0), visuals.extreme_bg_color);
+    let frame_stroke = visuals.widgets.noninteractive.bg_stroke;
+    let text_color = visuals.text_color();
+    let rulers_color = items::rulers_color(ui);
+
+    let x_range = transform.bounds().range_x();
+    let in_view = |bar: &&Bar| {
+        bar.argument + bar.bar_width / 2.0 >= *x_range.start()
+            && bar.argument - bar.bar_width / 2.0 <= *x_range.end()
+    };
+    let max_volume = bars
+        .iter()
+        .filter(in_view)
+        .map(|bar| bar.value)
+        .fold(0.0, f64::max);
+    let mut bounds = *transform.bounds();
+    bounds.min[1] = 0.0;
+    bounds.max[1] = if max_volume > 0.0 { max_volume } else { 1.0 };
+    bounds.max[1] += bounds.height() * margin_fraction.y as f64;
+    let scales = [transform.scales()[0], AxisScale::Linear];
+    let pane_transform = ScreenTransform::new(pane_rect, bounds, false, false, scales);
+
+    // The volume under the pointer, to show it in the corner:
+    let hovered = pointer_x.and_then(|pointer_x| {
+        let x = pane_transform.value_from_position(pos2(pointer_x, 0.0)).x;
+        bars.iter()
+            .filter(|bar| (bar.argument - x).abs() <= bar.bar_width / 2.0)
+            .map(|bar| bar.value)
+            .last()
+    });
+
+    let mut pane_ui = ui.child_ui(pane_rect, Layout::default());
+    pane_ui.set_clip_rect(pane_rect.intersect(ui.clip_rect()));
+    let mut shapes = vec![background];
+    BarChart::new(bars).shapes(&mut pane_ui, &pane_transform, &mut shapes);
+
+    if let Some(pointer_x) = pointer_x {
+        let pointer = pos2(pointer_x, pane_rect.center().y);
+        shapes.push(items::vertical_line(pointer, &pane_transform, rulers_color));
+    }
+    if let Some(volume) = hovered {
+        let text = pane_ui
+            .ctx()
+            .locale_format()
+            .format_number(&volume.to_string());
+        let label = pane_ui.ctx().translate("egui.plot.candle.volume", "Volume");
+        shapes.push(Shape::text(
+            &pane_ui.fonts(),
+            pane_rect.left_top() + Vec2::splat(4.0),
+            Align2::LEFT_TOP,
+            format!("{}: {}", label, text),
+            TextStyle::Small.resolve(pane_ui.style()),
+            text_color,
+        ));
+    }
+    shapes.push(Shape::rect_stroke(
+        pane_rect,
+        Rounding::same(2.0),
+        frame_stroke,
+    ));
+    pane_ui.painter().extend(shapes);
+}
+
+/// Where the plot bounds are within the minimap, spanning its full height.
+fn minimap_window(minimap_transform: &ScreenTransform, bounds: &PlotBounds) -> Rect {
+    let left = minimap_transform.position_from_point(&PlotPoint::new(bounds.min[0], 0.0));
+    let right = minimap_transform.position_from_point(&PlotPoint::new(bounds.max[0], 0.0));
+    Rect::from_x_y_ranges(left.x..=right.x, minimap_transform.frame().y_range())
+}
+
+fn paint_minimap(
+    ui: &mut Ui,
+    minimap_transform: &ScreenTransform,
+    transform: &ScreenTransform,
+    items: &[Box<dyn PlotItem>],
+) {
+    let frame = *minimap_transform.frame();
+    let visuals = ui.visuals();
+    let background = Shape::rect_filled(frame, Rounding::same(2.0), visuals.extreme_bg_color);
+    let window_fill = visuals.selection.bg_fill.linear_multiply(0.3);
+    let window_stroke = visuals.selection.stroke;
+    let frame_stroke = visuals.widgets.noninteractive.bg_stroke;
+
+    let mut minimap_ui = ui.child_ui(frame, Layout::default());
+    minimap_ui.set_clip_rect(frame.intersect(ui.clip_rect()));
+    let mut shapes = vec![background];
+    for item in items {
+        item.shapes(&mut minimap_ui, minimap_transform, &mut shapes);
+    }
+
+    let window = minimap_window(minimap_transform, transform.bounds()).intersect(frame);
+    shapes.push(Shape::rect_filled(window, 0.0, window_fill));
+    shapes.push(Shape::rect_stroke(window, 0.0, window_stroke));
+    shapes.push(Shape::rect_stroke(frame, Rounding::same(2.0), frame_stroke));
+    minimap_ui.painter().extend(shapes);
+}
+
+/// The shaded box of a measurement, and its changes written next to its end.
+fn measurement_shapes(
+    ui: &Ui,
+    transform: &ScreenTransform,
+    measurement: &PlotMeasurement,
+    x_is_time: bool,
+    units: &[String; 2],
+) -> Vec<Shape> {
+    let start = transform.position_from_point(&measurement.start);
+    let end = transform.position_from_point(&measurement.end);
+    let [dx, dy] = measurement.delta();
+    let color = if dy >= 0.0 {
+        Color32::from_rgb(38, 166, 91)
+    } else {
+        Color32::from_rgb(222, 60, 60)
+    };
+    let box_rect = Rect::from_two_pos(start, end);
+    let mut shapes = vec![
+        Shape::rect_filled(box_rect, 0.0, color.linear_multiply(0.15)),
+        Shape::rect_stroke(box_rect, 0.0, Stroke::new(1.0, color)),
+        Shape::line_segment([start, end], Stroke::new(1.0, color)),
+    ];
+
+    let locale_format = ui.ctx().locale_format();
+    let scale = transform.dvalue_dpos();
+    let signed = |value: f64, decimals: usize| {
+        let text = locale_format.format_number(&format!("{:.*}", decimals, value));
+        if value > 0.0 {
+            format!("+{}", text)
+        } else {
+            text
+        }
+    };
+    let number = |value: f64, axis: usize| {
+        let decimals = ((-scale[axis].abs().log10()).ceil().at_least(0.0) as usize).at_most(6);
+        items::with_unit(signed(value, decimals), &units[axis])
+    };
+    let dx_text = if x_is_time {
+        time::format_duration(dx)
+    } else {
+        number(dx, 0)
+    };
+    let mut dy_text = number(dy, 1);
+    if let Some(percent) = measurement.percent_change() {
+        dy_text = format!("{} ({}%)", dy_text, signed(percent, 2));
+    }
+    let text = format!("Δx: {}\nΔy: {}", dx_text, dy_text);
+
+    // Beyond the end of the box, so that the pointer doesn't cover it.
+    let galley = ui.fonts().layout_no_wrap(
+        text,
+        TextStyle::Body.resolve(ui.style()),
+        ui.visuals().text_color(),
+    );
+    let align = |towards_end: bool| if towards_end { Align::Min } else { Align::Max };
+    let anchor = Align2([align(end.x >= start.x), align(end.y >= start.y)]);
+    let offset = vec2(
+        if end.x >= start.x { 8.0 } else { -8.0 },
+        if end.y >= start.y { 8.0 } else { -8.0 },
+    );
+    let text_rect = anchor.anchor_rect(Rect::from_min_size(end + offset, galley.size()));
+    shapes.push(Shape::rect_filled(
+        text_rect.expand(4.0),
+        Rounding::same(2.0),
+        ui.visuals().extreme_bg_color,
+    ));
+    shapes.push(Shape::galley(text_rect.min, galley));
+    shapes
+}
+
+/// The corner of `frame` opposite to the one closest to `pos`,
+/// and whether `pos` is within [`CORNER_ZONE_SIZE`] of that closest corner.
+fn opposite_corner(frame: &Rect, pos: Pos2) -> (Align2, bool) {
+    let (x_anchor, x_corner) = if pos.x < frame.center().x {
+        (Align::Max, frame.left())
+    } else {
+        (Align::Min, frame.right())
+    };
+    let (y_anchor, y_corner) = if pos.y < frame.center().y {
+        (Align::Max, frame.top())
+    } else {
+        (Align::Min, frame.bottom())
+    };
+    let in_corner = (pos.x - x_corner).abs() <= CORNER_ZONE_SIZE
+        && (pos.y - y_corner).abs() <= CORNER_ZONE_SIZE;
+    (Align2([x_anchor, y_anchor]), in_corner)
+}
+
+/// The transform of the y axis that `item` is on.
+fn item_transform<'a>(
+    item: &dyn PlotItem,
+    transform: &'a ScreenTransform,
+    right_transform: Option<&'a ScreenTransform>,
+) -> &'a ScreenTransform {
+    match (item.y_axis(), right_transform) {
+        (YAxis::Right, Some(right_transform)) => right_transform,
+        _ => transform,
     }
 }
 
+/// The index of the draggable item, and of its point, that is closest to `pointer`, if it's close enough.
+fn closest_draggable_point(
+    items: &[Box<dyn PlotItem>],
+    pointer: Pos2,
+    transform: &ScreenTransform,
+    right_transform: Option<&ScreenTransform>,
+) -> Option<(usize, usize)> {
+    items
+        .iter()
+        .enumerate()
+        .filter(|(_, item)| item.draggable())
+        .filter_map(|(item_index, item)| {
+            let transform = item_transform(&**item, transform, right_transform);
+            let elem = item.find_closest(pointer, transform)?;
+            (elem.dist_sq <= HOVER_RADIUS.powi(2)).then(|| (elem.dist_sq, item_index, elem.index))
+        })
+        .min_by_key(|(dist_sq, ..)| dist_sq.ord())
+        .map(|(_, item_index, point_index)| (item_index, point_index))
+}
+
+/// Where the labels of the x and y axes are painted, see [`PreparedPlot::paint_axis`].
+///
+/// The labels follow the axis lines, but stay within the plot frame.
+fn axis_zones(transform: &ScreenTransform, label_height: f32) -> [Rect; 2] {
+    let frame = *transform.frame();
+    let bounds = transform.bounds();
+    let origin = transform.position_from_point(&PlotPoint::new(
+        0.0_f64.clamp(bounds.min[0], bounds.max[0]),
+        0.0_f64.clamp(bounds.min[1], bounds.max[1]),
+    ));
+
+    let x_label_top = (origin.y - label_height)
+        .at_most(frame.bottom() - label_height - 2.0)
+        .at_least(frame.top() + 1.0);
+    let x_axis = Rect::from_x_y_ranges(frame.x_range(), x_label_top..=x_label_top + label_height);
+
+    let y_label_left = (origin.x + 1.0)
+        .at_most(frame.right() - Y_AXIS_ZONE_WIDTH - 2.0)
+        .at_least(frame.left() + 1.0);
+    let y_axis = Rect::from_x_y_ranges(
+        y_label_left..=y_label_left + Y_AXIS_ZONE_WIDTH,
+        frame.y_range(),
+    );
+
+    [x_axis, y_axis]
+}
+
 /// Returns next bigger power in given base
 /// e.g.
 /// ```ignore
@@ -1351,3 +3566,52 @@ fn fill_marks_between(out: &mut Vec<GridMark>, step_size: f64, (min, max): (f64,
     });
     out.extend(marks_iter);
 }
+
+/// Days since 1970-01-01 to `(year, month, day)` in the proleptic Gregorian calendar.
+///
+/// From <http://howardhinnant.github.io/date_algorithms.html>.
+pub(crate) fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097); // [0, 146096]
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365; // [0, 399]
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100); // [0, 365]
+    let mp = (5 * doy + 2) / 153; // [0, 11]
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = (if mp < 10 { mp + 3 } else { mp - 9 }) as u32;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+/// `(year, month, day)` in the proleptic Gregorian calendar to days since 1970-01-01.
+pub(crate) fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year.rem_euclid(400); // [0, 399]
+    let mp = (i64::from(month) + 9) % 12; // March is 0
+    let doy = (153 * mp + 2) / 5 + i64::from(day) - 1; // [0, 365]
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy; // [0, 146096]
+    era * 146_097 + doe - 719_468
+}
+
+#[test]
+fn test_view_preset_ranges() {
+    assert_eq!(civil_from_days(0), (1970, 1, 1));
+    assert_eq!(civil_from_days(19_052), (2022, 3, 1));
+    for days in [-800_000, -1, 0, 59, 60, 19_052, 2_000_000] {
+        let (year, month, day) = civil_from_days(days);
+        assert_eq!(days_from_civil(year, month, day), days);
+    }
+
+    let last_x = days_from_civil(2022, 3, 1) as f64 * 86_400.0 + 3600.0;
+    assert_eq!(
+        ViewPreset::Day.x_range(last_x),
+        Some(last_x - 86_400.0..=last_x)
+    );
+    let year_start = days_from_civil(2022, 1, 1) as f64 * 86_400.0;
+    assert_eq!(
+        ViewPreset::YearToDate.x_range(last_x),
+        Some(year_start..=last_x)
+    );
+    assert_eq!(ViewPreset::All.x_range(last_x), None);
+}
@@ -0,0 +1,634 @@
+use std::cell::Cell;
+use std::f64::consts::TAU;
+use std::ops::RangeInclusive;
+use std::rc::Rc;
+
+use epaint::Mesh;
+
+use super::items::{PlotGeometry, PlotItem};
+use super::{
+    Legend, Line, Plot, PlotBounds, PlotElement, PlotElementValue, PlotPoint, PlotPoints,
+    PlotResponse, Points, Polygon, ScreenTransform,
+};
+use crate::*;
+
+/// How far a point of scrolling rotates a [`PolarPlot`], in radians.
+const SCROLL_ROTATION: f64 = 0.005;
+
+/// A plot in polar coordinates, e.g. of a measurement in each direction, or a radar chart.
+///
+/// Its items are given in `[angle, radius]`, with the angle in radians, counterclockwise from the
+/// right unless [`Self::rotation`] and [`Self::clockwise`] say otherwise. The grid is made of
+/// circles, and of spokes with their angles or with the names of [`Self::spokes`].
+///
+/// Dragging or scrolling rotates the plot, and zooming changes the radius in view.
+/// A double click resets both.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui::plot::{Line, PlotPoints, PolarPlot, Polygon};
+/// PolarPlot::new("skills")
+///     .spokes(["Speed", "Power", "Range", "Armor", "Luck"])
+///     .show(ui, |polar_ui| {
+///         let skills = PlotPoints::from_radii(&[4.0, 3.0, 5.0, 2.0, 4.5]);
+///         polar_ui.polygon(Polygon::new(skills).name("Hero"));
+///         let spiral = PlotPoints::from_parametric_callback(|t| (t, t / 3.0), 0.0..12.0, 200);
+///         polar_ui.line(Line::new(spiral).name("Spiral"));
+///     });
+/// # });
+/// ```
+pub struct PolarPlot {
+    id_source: Id,
+    width: Option<f32>,
+    height: Option<f32>,
+    rotation: f64,
+    clockwise: bool,
+    angle_step: f64,
+    spokes: Vec<String>,
+    radius: Option<f64>,
+    legend: Option<Legend>,
+    allow_rotate: bool,
+    allow_zoom: bool,
+    show_background: bool,
+}
+
+impl PolarPlot {
+    /// Give a unique id for each plot within the same [`Ui`].
+    pub fn new(id_source: impl std::hash::Hash) -> Self {
+        Self {
+            id_source: Id::new(id_source),
+            width: None,
+            height: None,
+            rotation: 0.0,
+            clockwise: false,
+            angle_step: 30.0,
+            spokes: Vec::new(),
+            radius: None,
+            legend: None,
+            allow_rotate: true,
+            allow_zoom: true,
+            show_background: true,
+        }
+    }
+
+    /// Width of the plot. By default it fills the ui it is in.
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Height of the plot. By default it fills the ui it is in.
+    pub fn height(mut self, height: f32) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    /// Where the angle `0` points, in radians counterclockwise from the right,
+    /// e.g. `TAU / 4.0` for up. Default: `0.0`.
+    pub fn rotation(mut self, rotation: f64) -> Self {
+        self.rotation = rotation;
+        self
+    }
+
+    /// Let the angles go clockwise, like on a compass. Default: `false`.
+    pub fn clockwise(mut self, clockwise: bool) -> Self {
+        self.clockwise = clockwise;
+        self
+    }
+
+    /// Draw a spoke every this many degrees. Default: `30.0`.
+    pub fn angle_step(mut self, degrees: f64) -> Self {
+        self.angle_step = degrees;
+        self
+    }
+
+    /// Draw evenly spaced spokes with these names instead of the angles, e.g. for the axes of a
+    /// radar chart, see [`PlotPoints::from_radii`].
+    pub fn spokes(mut self, names: impl IntoIterator<Item = impl ToString>) -> Self {
+        self.spokes = names.into_iter().map(|name| name.to_string()).collect();
+        self
+    }
+
+    /// The radius in view until the user zooms. Default: fit the items.
+    pub fn radius(mut self, radius: f64) -> Self {
+        self.radius = Some(radius);
+        self
+    }
+
+    /// Show a legend including all named items.
+    pub fn legend(mut self, legend: Legend) -> Self {
+        self.legend = Some(legend);
+        self
+    }
+
+    /// Whether to allow rotating the plot by dragging or scrolling. Default: `true`.
+    pub fn allow_rotate(mut self, on: bool) -> Self {
+        self.allow_rotate = on;
+        self
+    }
+
+    /// Whether to allow zooming the radius in view. Default: `true`.
+    pub fn allow_zoom(mut self, on: bool) -> Self {
+        self.allow_zoom = on;
+        self
+    }
+
+    /// Whether or not to show the background [`Rect`]. Default: `true`.
+    pub fn show_background(mut self, show: bool) -> Self {
+        self.show_background = show;
+        self
+    }
+
+    /// Interact with and add items to the plot and finally draw it.
+    pub fn show<R>(self, ui: &mut Ui, build_fn: impl FnOnce(&mut PolarUi) -> R) -> PlotResponse<R> {
+        let Self {
+            id_source,
+            width,
+            height,
+            rotation,
+            clockwise,
+            angle_step,
+            spokes,
+            radius,
+            legend,
+            allow_rotate,
+            allow_zoom,
+            show_background,
+        } = self;
+
+        let memory_id = ui.make_persistent_id(id_source).with("polar");
+        let mut memory = ui
+            .data()
+            .get_persisted::<PolarMemory>(memory_id)
+            .unwrap_or(PolarMemory {
+                rotation,
+                radius: None,
+            });
+
+        // The view can change while the items are added, so the hover labels look it up later.
+        let view = Rc::new(Cell::new(PolarView {
+            rotation: memory.rotation,
+            clockwise,
+        }));
+        let label_view = view.clone();
+        let mut plot = Plot::new(id_source)
+            .data_aspect(1.0)
+            .show_axes([false; 2])
+            .allow_drag(false)
+            .allow_zoom(false)
+            .allow_scroll(false)
+            .allow_boxed_zoom(false)
+            .show_background(show_background)
+            .label_formatter(move |name, value| {
+                let value = label_view.get().polar(*value);
+                let text = format!("θ = {:.1}°\nr = {:.3}", value.x.to_degrees(), value.y);
+                if name.is_empty() {
+                    text
+                } else {
+                    format!("{}\n{}", name, text)
+                }
+            });
+        if let Some(width) = width {
+            plot = plot.width(width);
+        }
+        if let Some(height) = height {
+            plot = plot.height(height);
+        }
+        if let Some(legend) = legend {
+            plot = plot.legend(legend);
+        }
+
+        let mut response = plot.show(ui, |plot_ui| {
+            let mut polar_ui = PolarUi { items: Vec::new() };
+            let inner = build_fn(&mut polar_ui);
+
+            // Dragging around the center turns the plot with the pointer.
+            let response = &plot_ui.response;
+            if allow_rotate {
+                if let Some(pointer) = response
+                    .interact_pointer_pos()
+                    .filter(|_| response.dragged_by(PointerButton::Primary))
+                {
+                    let center = response.rect.center();
+                    let from = pointer - response.drag_delta() - center;
+                    let to = pointer - center;
+                    // The y axis of the screen points down, unlike the one of the plot.
+                    memory.rotation += (from.y.atan2(from.x) - to.y.atan2(to.x)) as f64;
+                }
+                if response.hovered() {
+                    let scroll = plot_ui.ctx().input().scroll_delta.y;
+                    memory.rotation += scroll as f64 * SCROLL_ROTATION;
+                }
+            }
+
+            let fitted_radius = polar_ui
+                .items
+                .iter()
+                .flat_map(|item| item.series().points())
+                .map(|point| point.y.abs())
+                .fold(0.0, f64::max);
+            let mut view_radius = memory.radius.or(radius).unwrap_or(fitted_radius);
+            if !(view_radius.is_finite() && view_radius > 0.0) {
+                view_radius = 1.0;
+            }
+            if allow_zoom && response.hovered() {
+                let zoom = plot_ui.ctx().input().zoom_delta();
+                if zoom != 1.0 {
+                    view_radius /= zoom as f64;
+                    memory.radius = Some(view_radius);
+                }
+            }
+
+            let polar_view = PolarView {
+                rotation: memory.rotation,
+                clockwise,
+            };
+            view.set(polar_view);
+
+            // With room for the labels of the spokes around the circle.
+            plot_ui.set_plot_bounds(PlotBounds::new_symmetrical(1.2 * view_radius));
+            plot_ui.items.push(Box::new(PolarGrid {
+                radius: view_radius,
+                view: polar_view,
+                angle_step,
+                spokes,
+            }));
+            for item in polar_ui.items {
+                match item {
+                    PolarItem::Line(mut line) => {
+                        polar_view.to_cartesian(&mut line.series);
+                        // Dragging would move its x and y, not its angle and radius.
+                        line.draggable = false;
+                        plot_ui.line(line);
+                    }
+                    PolarItem::Points(mut points) => {
+                        polar_view.to_cartesian(&mut points.series);
+                        points.draggable = false;
+                        plot_ui.points(points);
+                    }
+                    PolarItem::Polygon(mut polygon) => {
+                        polar_view.to_cartesian(&mut polygon.series);
+                        if polygon.series.is_empty() {
+                            continue;
+                        }
+                        // A polygon can only fill convex shapes, so it's filled from the center instead.
+                        let mut fill = PolarFill {
+                            points: polygon.series.points().to_vec(),
+                            color: Color32::TRANSPARENT,
+                            fill_alpha: polygon.fill_alpha,
+                            name: polygon.name.clone(),
+                            highlight: false,
+                        };
+                        polygon.fill_alpha = 0.0;
+                        plot_ui.polygon(polygon);
+                        let index = plot_ui.items.len() - 1;
+                        fill.color = plot_ui.items[index].color();
+                        plot_ui.items.insert(index, Box::new(fill));
+                    }
+                }
+            }
+
+            inner
+        });
+
+        if response.response.double_clicked() {
+            memory = PolarMemory {
+                rotation,
+                radius: None,
+            };
+        }
+        ui.data().insert_persisted(memory_id, memory);
+
+        // The hovered value as it was given, in angle and radius.
+        if let Some(PlotElement {
+            value: PlotElementValue::Point(point),
+            ..
+        }) = &mut response.hovered_element
+        {
+            *point = view.get().polar(*point);
+        }
+
+        response
+    }
+}
+
+/// Provides methods to add items to a [`PolarPlot`], in `[angle, radius]`.
+pub struct PolarUi {
+    items: Vec<PolarItem>,
+}
+
+impl PolarUi {
+    /// Add a line through the points, e.g. of a measurement in each direction.
+    ///
+    /// A line from a function is sampled over one turn, unless its range says otherwise.
+    pub fn line(&mut self, line: Line) {
+        self.add(PolarItem::Line(line));
+    }
+
+    /// Add data points.
+    pub fn points(&mut self, points: Points) {
+        self.add(PolarItem::Points(points));
+    }
+
+    /// Add a polygon, e.g. the filled area of a radar chart, see [`PlotPoints::from_radii`].
+    ///
+    /// Unlike in a [`Plot`], it doesn't have to be convex, as long as the center is inside it.
+    pub fn polygon(&mut self, polygon: Polygon) {
+        self.add(PolarItem::Polygon(polygon));
+    }
+
+    fn add(&mut self, mut item: PolarItem) {
+        item.series_mut().generate_points(0.0..=TAU);
+        self.items.push(item);
+    }
+}
+
+enum PolarItem {
+    Line(Line),
+    Points(Points),
+    Polygon(Polygon),
+}
+
+impl PolarItem {
+    fn series(&self) -> &PlotPoints {
+        match self {
+            Self::Line(line) => &line.series,
+            Self::Points(points) => &points.series,
+            Self::Polygon(polygon) => &polygon.series,
+        }
+    }
+
+    fn series_mut(&mut self) -> &mut PlotPoints {
+        match self {
+            Self::Line(line) => &mut line.series,
+            Self::Points(points) => &mut points.series,
+            Self::Polygon(polygon) => &mut polygon.series,
+        }
+    }
+}
+
+/// What a [`PolarPlot`] keeps between frames.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Clone, Copy)]
+struct PolarMemory {
+    rotation: f64,
+    /// The radius the user zoomed to, if they did.
+    radius: Option<f64>,
+}
+
+/// How the angles of a [`PolarPlot`] are laid out.
+#[derive(Clone, Copy)]
+struct PolarView {
+    rotation: f64,
+    clockwise: bool,
+}
+
+impl PolarView {
+    /// The direction of `angle` in the plot, counterclockwise from the right.
+    fn direction(self, angle: f64) -> f64 {
+        if self.clockwise {
+            self.rotation - angle
+        } else {
+            self.rotation + angle
+        }
+    }
+
+    /// From `[angle, radius]` to the x and y that it is drawn at.
+    fn cartesian(self, point: PlotPoint) -> PlotPoint {
+        let direction = self.direction(point.x);
+        PlotPoint::new(point.y * direction.cos(), point.y * direction.sin())
+    }
+
+    /// From the x and y that it is drawn at back to `[angle, radius]`, with the angle in `0..TAU`.
+    fn polar(self, point: PlotPoint) -> PlotPoint {
+        let turned = point.y.atan2(point.x) - self.rotation;
+        let angle = if self.clockwise { -turned } else { turned };
+        PlotPoint::new(angle.rem_euclid(TAU), point.x.hypot(point.y))
+    }
+
+    fn to_cartesian(self, series: &mut PlotPoints) {
+        *series = PlotPoints::Owned(
+            series
+                .points()
+                .iter()
+                .map(|point| self.cartesian(*point))
+                .collect(),
+        );
+    }
+}
+
+/// The circles and spokes of a [`PolarPlot`], with their labels.
+struct PolarGrid {
+    radius: f64,
+    view: PolarView,
+    angle_step: f64,
+    spokes: Vec<String>,
+}
+
+impl PlotItem for PolarGrid {
+    fn shapes(&self, ui: &mut Ui, transform: &ScreenTransform, shapes: &mut Vec<Shape>) {
+        let center = transform.position_from_point(&PlotPoint::new(0.0, 0.0));
+        let point_at = |angle: f64, radius: f64| {
+            transform.position_from_point(&self.view.cartesian(PlotPoint::new(angle, radius)))
+        };
+        let stroke = Stroke::new(1.0, ui.visuals().widgets.noninteractive.bg_stroke.color);
+        let font_id = TextStyle::Small.resolve(ui.style());
+        let locale_format = ui.ctx().locale_format();
+
+        // Circles at round radii, and around the edge:
+        let step = round_step(self.radius / 4.0);
+        let count = (self.radius / step + 1e-9).floor() as usize;
+        let mut radii: Vec<f64> = (1..=count).map(|i| i as f64 * step).collect();
+        if self.radius - count as f64 * step > 1e-9 * step {
+            radii.push(self.radius);
+        }
+        let points_per_unit = transform.dpos_dvalue()[0].abs() as f32;
+        let label_angle = match self.spokes.len() {
+            0 => self.angle_step.to_radians() / 2.0,
+            len => TAU / len as f64 / 2.0,
+        };
+        for radius in radii {
+            shapes.push(Shape::circle_stroke(
+                center,
+                radius as f32 * points_per_unit,
+                stroke,
+            ));
+            let text = emath::round_to_decimals(radius, 6).to_string();
+            shapes.push(Shape::text(
+                &*ui.fonts(),
+                point_at(label_angle, radius),
+                Align2::LEFT_BOTTOM,
+                locale_format.format_number(&text),
+                font_id.clone(),
+                ui.visuals().weak_text_color(),
+            ));
+        }
+
+        // Spokes, with their angles or names just outside the edge:
+        let spokes: Vec<(f64, String)> = if self.spokes.is_empty() {
+            let count = if self.angle_step > 0.0 {
+                (360.0 / self.angle_step).round() as usize
+            } else {
+                0
+            };
+            (0..count)
+                .map(|i| {
+                    let degrees = i as f64 * self.angle_step;
+                    let text = emath::round_to_decimals(degrees, 2).to_string();
+                    let label = format!("{}°", locale_format.format_number(&text));
+                    (degrees.to_radians(), label)
+                })
+                .collect()
+        } else {
+            let step = TAU / self.spokes.len() as f64;
+            self.spokes
+                .iter()
+                .enumerate()
+                .map(|(i, name)| (i as f64 * step, name.clone()))
+                .collect()
+        };
+        for (angle, label) in spokes {
+            let edge = point_at(angle, self.radius);
+            shapes.push(Shape::line_segment([center, edge], stroke));
+
+            let direction = self.view.direction(angle);
+            let outwards = vec2(direction.cos() as f32, -direction.sin() as f32);
+            let align = |outwards: f32| {
+                if outwards > 0.3 {
+                    Align::Min
+                } else if outwards < -0.3 {
+                    Align::Max
+                } else {
+                    Align::Center
+                }
+            };
+            shapes.push(Shape::text(
+                &*ui.fonts(),
+                edge + 4.0 * outwards,
+                Align2([align(outwards.x), align(outwards.y)]),
+                label,
+                font_id.clone(),
+                ui.visuals().text_color(),
+            ));
+        }
+    }
+
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
+
+    fn name(&self) -> &str {
+        ""
+    }
+
+    fn color(&self) -> Color32 {
+        Color32::TRANSPARENT
+    }
+
+    fn highlight(&mut self) {}
+
+    fn highlighted(&self) -> bool {
+        false
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::None
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        PlotBounds::NOTHING
+    }
+}
+
+/// The fill of a polygon in a [`PolarPlot`], made of triangles from the center to each edge.
+struct PolarFill {
+    points: Vec<PlotPoint>,
+    color: Color32,
+    fill_alpha: f32,
+    name: String,
+    highlight: bool,
+}
+
+impl PlotItem for PolarFill {
+    fn shapes(&self, _ui: &mut Ui, transform: &ScreenTransform, shapes: &mut Vec<Shape>) {
+        let fill_alpha = if self.highlight {
+            (2.0 * self.fill_alpha).at_most(1.0)
+        } else {
+            self.fill_alpha
+        };
+        let fill: Color32 = Rgba::from(self.color)
+            .to_opaque()
+            .multiply(fill_alpha)
+            .into();
+
+        let mut mesh = Mesh::default();
+        mesh.colored_vertex(
+            transform.position_from_point(&PlotPoint::new(0.0, 0.0)),
+            fill,
+        );
+        for point in &self.points {
+            mesh.colored_vertex(transform.position_from_point(point), fill);
+        }
+        let count = self.points.len() as u32;
+        for i in 0..count {
+            mesh.add_triangle(0, 1 + i, 1 + (i + 1) % count);
+        }
+        shapes.push(Shape::mesh(mesh));
+    }
+
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn color(&self) -> Color32 {
+        self.color
+    }
+
+    fn highlight(&mut self) {
+        self.highlight = true;
+    }
+
+    fn highlighted(&self) -> bool {
+        self.highlight
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::None
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        let mut bounds = PlotBounds::NOTHING;
+        for point in &self.points {
+            bounds.extend_with(point);
+        }
+        bounds
+    }
+}
+
+/// The smallest of 1, 2 and 5 times a power of ten that is at least `min_step`.
+fn round_step(min_step: f64) -> f64 {
+    let power = 10.0_f64.powf(min_step.log10().floor());
+    [1.0, 2.0, 5.0, 10.0]
+        .iter()
+        .map(|factor| factor * power)
+        .find(|step| *step >= min_step)
+        .unwrap_or(10.0 * power)
+}
+
+#[test]
+fn test_polar_view() {
+    let view = PolarView {
+        rotation: TAU / 4.0,
+        clockwise: true,
+    };
+    // A quarter turn clockwise from the top is right:
+    let point = view.cartesian(PlotPoint::new(TAU / 4.0, 2.0));
+    assert!((point.x - 2.0).abs() < 1e-9 && point.y.abs() < 1e-9);
+
+    let back = view.polar(point);
+    assert!((back.x - TAU / 4.0).abs() < 1e-9 && (back.y - 2.0).abs() < 1e-9);
+
+    assert_eq!(round_step(0.3), 0.5);
+    assert_eq!(round_step(2.0), 2.0);
+    assert_eq!(round_step(7.0), 10.0);
+}
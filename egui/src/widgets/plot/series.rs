@@ -0,0 +1,285 @@
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use epaint::mutex::Mutex;
+
+use super::{AxisScale, PlotBounds, ScreenTransform};
+use crate::*;
+
+/// A series of plot values that is kept between frames, so that it can grow, e.g. with live ticks,
+/// instead of being built again every frame.
+///
+/// Cloning gives another handle to the same series, so it can also be filled from another thread.
+/// [`super::PlotUi::series`] gives the one stored in the plot memory under a name.
+/// Show it with [`super::Line::from_series`] or [`super::ChartPlot::from_series`].
+///
+/// The candles of a [`super::ChartPlot::from_series`] are only drawn again when they change or
+/// when the view changes. Pair it with [`super::Plot::follow_newest_x`] to keep the newest ones in view.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui::plot::{Candle, CandleElem, ChartPlot, Plot, PlotSeriesHandle};
+/// Plot::new("live").follow_newest_x(true).show(ui, |plot_ui| {
+///     let series: PlotSeriesHandle<CandleElem> = plot_ui.series("ticks");
+///     series.set_capacity(10_000);
+///
+///     // A tick came in:
+///     let price = 42.0;
+///     let in_last = series.update_last(|last| {
+///         last.candle.close = price;
+///         last.candle.high = last.candle.high.max(price);
+///         last.candle.low = last.candle.low.min(price);
+///     });
+///     if !in_last {
+///         series.push(CandleElem::new(Candle::new(price, price, price, price, 0.0)));
+///     }
+///
+///     plot_ui.chart_plot(ChartPlot::from_series(&series));
+/// });
+/// # });
+/// ```
+pub struct PlotSeriesHandle<T> {
+    series: Arc<Mutex<Series<T>>>,
+}
+
+struct Series<T> {
+    values: VecDeque<T>,
+
+    /// The most values to keep; the oldest ones are dropped beyond it.
+    capacity: usize,
+
+    /// Changes with every change of the values.
+    revision: u64,
+
+    /// The values from this index on changed since they were last drawn.
+    changed_from: usize,
+
+    /// The view the values were last drawn in.
+    drawn_key: Option<DrawKey>,
+
+    /// The shapes of each value, as they were last drawn.
+    drawn: VecDeque<Vec<Shape>>,
+}
+
+impl<T> Series<T> {
+    fn changed(&mut self, index: usize) {
+        self.revision += 1;
+        self.changed_from = self.changed_from.min(index);
+    }
+
+    fn drop_oldest(&mut self) {
+        while self.values.len() > self.capacity {
+            self.values.pop_front();
+            self.drawn.pop_front();
+            self.changed_from = self.changed_from.saturating_sub(1);
+            self.revision += 1;
+        }
+    }
+}
+
+impl<T> Clone for PlotSeriesHandle<T> {
+    fn clone(&self) -> Self {
+        Self {
+            series: self.series.clone(),
+        }
+    }
+}
+
+impl<T> Default for PlotSeriesHandle<T> {
+    fn default() -> Self {
+        Self::with_capacity(usize::MAX)
+    }
+}
+
+impl<T> PlotSeriesHandle<T> {
+    /// An empty series without a limit on its length.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// An empty series that keeps only the newest `capacity` values.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            series: Arc::new(Mutex::new(Series {
+                values: VecDeque::new(),
+                capacity,
+                revision: 0,
+                changed_from: 0,
+                drawn_key: None,
+                drawn: VecDeque::new(),
+            })),
+        }
+    }
+
+    /// Keep only the newest `capacity` values, dropping the oldest ones right away if there are more.
+    pub fn set_capacity(&self, capacity: usize) {
+        let mut series = self.series.lock();
+        series.capacity = capacity;
+        series.drop_oldest();
+    }
+
+    /// Add a value at the end, dropping the oldest one if the series is full.
+    pub fn push(&self, value: T) {
+        let mut series = self.series.lock();
+        series.values.push_back(value);
+        let index = series.values.len() - 1;
+        series.changed(index);
+        series.drop_oldest();
+    }
+
+    /// Change the last value, e.g. the candle that a tick belongs to.
+    ///
+    /// Returns `false` if the series is empty.
+    pub fn update_last(&self, update: impl FnOnce(&mut T)) -> bool {
+        let mut series = self.series.lock();
+        let index = match series.values.len().checked_sub(1) {
+            Some(index) => index,
+            None => return false,
+        };
+        update(&mut series.values[index]);
+        series.changed(index);
+        true
+    }
+
+    /// Remove all values.
+    pub fn clear(&self) {
+        let mut series = self.series.lock();
+        series.values.clear();
+        series.drawn.clear();
+        series.changed(0);
+    }
+
+    /// How many values the series has.
+    pub fn len(&self) -> usize {
+        self.series.lock().values.len()
+    }
+
+    /// Does the series have no values?
+    pub fn is_empty(&self) -> bool {
+        self.series.lock().values.is_empty()
+    }
+}
+
+impl<T: Clone> PlotSeriesHandle<T> {
+    /// The newest value.
+    pub fn last(&self) -> Option<T> {
+        self.series.lock().values.back().cloned()
+    }
+
+    /// A copy of all values, from the oldest to the newest.
+    pub fn values(&self) -> Vec<T> {
+        self.snapshot().0
+    }
+
+    /// A copy of all values, and the revision they are at.
+    pub(super) fn snapshot(&self) -> (Vec<T>, u64) {
+        let series = self.series.lock();
+        (series.values.iter().cloned().collect(), series.revision)
+    }
+
+    /// Add the shapes of `values`, which were copied at `revision`, drawing only those that
+    /// changed since they were last drawn with the same `key`.
+    pub(super) fn draw_cached(
+        &self,
+        revision: u64,
+        key: DrawKey,
+        values: &[T],
+        mut draw: impl FnMut(&T, &mut Vec<Shape>),
+        shapes: &mut Vec<Shape>,
+    ) {
+        let mut series = self.series.lock();
+        if series.revision != revision {
+            // Changed since the copy was made, so the cache doesn't match it. It catches up next frame.
+            for value in values {
+                draw(value, shapes);
+            }
+            return;
+        }
+
+        let series = &mut *series;
+        if series.drawn_key != Some(key) {
+            series.drawn_key = Some(key);
+            series.drawn.clear();
+        }
+        let unchanged = series.changed_from.min(series.drawn.len());
+        series.drawn.truncate(unchanged);
+        for value in &values[unchanged..] {
+            let mut value_shapes = Vec::new();
+            draw(value, &mut value_shapes);
+            series.drawn.push_back(value_shapes);
+        }
+        series.changed_from = values.len();
+
+        for value_shapes in &series.drawn {
+            shapes.extend(value_shapes.iter().cloned());
+        }
+    }
+}
+
+/// Everything but the values that the shapes of a series depend on.
+#[derive(Clone, Copy, PartialEq)]
+pub(super) struct DrawKey {
+    frame: Rect,
+    bounds: PlotBounds,
+    scales: [AxisScale; 2],
+    highlight: bool,
+    colors: [Color32; 3],
+}
+
+impl DrawKey {
+    pub fn new(transform: &ScreenTransform, highlight: bool, colors: [Color32; 3]) -> Self {
+        Self {
+            frame: *transform.frame(),
+            bounds: *transform.bounds(),
+            scales: transform.scales(),
+            highlight,
+            colors,
+        }
+    }
+}
+
+#[test]
+fn test_series_handle() {
+    let series = PlotSeriesHandle::with_capacity(3);
+    for i in 0..5 {
+        series.push(i);
+    }
+    assert_eq!(series.values(), vec![2, 3, 4]);
+    assert!(series.update_last(|last| *last = 40));
+    assert_eq!(series.last(), Some(40));
+
+    // Only the changed values are drawn again:
+    let frame = Rect::from_min_max(pos2(0.0, 0.0), pos2(10.0, 10.0));
+    let transform = ScreenTransform::new(
+        frame,
+        PlotBounds::new_symmetrical(1.0),
+        false,
+        false,
+        [AxisScale::Linear; 2],
+    );
+    let key = DrawKey::new(&transform, false, [Color32::RED; 3]);
+    let draw_count = |series: &PlotSeriesHandle<i32>| {
+        let (values, revision) = series.snapshot();
+        let mut count = 0;
+        series.draw_cached(
+            revision,
+            key,
+            &values,
+            |_, shapes| {
+                count += 1;
+                shapes.push(Shape::Noop);
+            },
+            &mut vec![],
+        );
+        count
+    };
+    assert_eq!(draw_count(&series), 3);
+    assert_eq!(draw_count(&series), 0);
+    series.push(5);
+    assert_eq!(draw_count(&series), 1);
+    series.update_last(|last| *last = 50);
+    assert_eq!(draw_count(&series), 1);
+
+    let empty = PlotSeriesHandle::<i32>::new();
+    assert!(!empty.update_last(|_| {}));
+}
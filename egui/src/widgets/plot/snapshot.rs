@@ -0,0 +1,470 @@
+//! Saving what a plot painted as SVG or as an image.
+
+use std::fmt::Write as _;
+
+use epaint::{tessellator, ClippedShape, Primitive, TextShape};
+
+use crate::layers::ShapeIdx;
+use crate::*;
+
+/// Where the shapes of a plot are in the paint list of its layer.
+#[derive(Clone, Copy, Debug)]
+pub(super) struct PaintedRange {
+    pub layer_id: LayerId,
+    pub start: ShapeIdx,
+    pub end: ShapeIdx,
+    pub rect: Rect,
+}
+
+/// The shapes that a [`super::Plot`] painted in one frame: the grid, the items, the axis labels
+/// and the legend, see [`super::PlotResponse::snapshot`].
+///
+/// Save it with [`Self::to_svg`] or [`Self::to_image`], or paint [`Self::shapes`] with a renderer of your own.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui::plot::{Line, Plot, PlotPoints};
+/// let response = Plot::new("report").show(ui, |plot_ui| {
+///     plot_ui.line(Line::new(PlotPoints::new(vec![[0.0, 0.0], [1.0, 1.0]])));
+/// });
+/// if ui.button("Save").clicked() {
+///     let svg: String = response.snapshot().to_svg();
+/// }
+/// # });
+/// ```
+#[derive(Clone)]
+pub struct PlotSnapshot {
+    ctx: Context,
+    rect: Rect,
+    shapes: Vec<ClippedShape>,
+}
+
+impl PlotSnapshot {
+    pub(super) fn new(ctx: &Context, painted: PaintedRange) -> Self {
+        let shapes = ctx
+            .graphics()
+            .list(painted.layer_id)
+            .shapes_between(painted.start, painted.end)
+            .to_vec();
+        Self {
+            ctx: ctx.clone(),
+            rect: painted.rect,
+            shapes,
+        }
+    }
+
+    /// The area of the plot on the screen, in points, including the panes below it.
+    pub fn rect(&self) -> Rect {
+        self.rect
+    }
+
+    /// The shapes in screen coordinates, each with the rectangle it is clipped to.
+    pub fn shapes(&self) -> &[ClippedShape] {
+        &self.shapes
+    }
+
+    /// An SVG document of the plot, with one unit per point.
+    ///
+    /// Images come out as meshes in the colors of their vertices, without the texture.
+    pub fn to_svg(&self) -> String {
+        let rect = self.rect;
+        let mut svg = String::new();
+        let _ = writeln!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}" viewBox="{} {} {} {}">"#,
+            rect.width(),
+            rect.height(),
+            rect.min.x,
+            rect.min.y,
+            rect.width(),
+            rect.height()
+        );
+
+        // Consecutive shapes with the same clip rectangle share a group.
+        let mut group_clip_rect = None;
+        for (i, ClippedShape(clip_rect, shape)) in self.shapes.iter().enumerate() {
+            let clip_rect = clip_rect.intersect(rect);
+            if group_clip_rect != Some(clip_rect) {
+                if group_clip_rect.is_some() {
+                    svg.push_str("</g>\n");
+                }
+                let _ = writeln!(
+                    svg,
+                    r#"<clipPath id="clip{}"><rect x="{}" y="{}" width="{}" height="{}"/></clipPath><g clip-path="url(#clip{})">"#,
+                    i,
+                    clip_rect.min.x,
+                    clip_rect.min.y,
+                    clip_rect.width().max(0.0),
+                    clip_rect.height().max(0.0),
+                    i
+                );
+                group_clip_rect = Some(clip_rect);
+            }
+            write_svg_shape(&mut svg, shape);
+        }
+        if group_clip_rect.is_some() {
+            svg.push_str("</g>\n");
+        }
+
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    /// The plot rasterized the way egui paints it, at the current `pixels_per_point`.
+    ///
+    /// Images come out as meshes in the colors of their vertices, without the texture,
+    /// since only the font texture is at hand.
+    pub fn to_image(&self) -> ColorImage {
+        let pixels_per_point = self.ctx.pixels_per_point();
+        let mut options = *self.ctx.tessellation_options();
+        options.instancing = false;
+        let texture_atlas = self.ctx.fonts().texture_atlas();
+        let texture_atlas = texture_atlas.lock();
+        let primitives = tessellator::tessellate_shapes(
+            pixels_per_point,
+            options,
+            texture_atlas.size(),
+            texture_atlas.prepared_discs(),
+            self.shapes.clone(),
+        );
+        rasterize(
+            &primitives,
+            texture_atlas.image(),
+            self.rect,
+            pixels_per_point,
+        )
+    }
+}
+
+// ----------------------------------------------------------------------------
+// SVG
+
+fn write_svg_shape(svg: &mut String, shape: &Shape) {
+    match shape {
+        Shape::Noop | Shape::Callback(_) => {}
+        Shape::Vec(shapes) => {
+            for shape in shapes {
+                write_svg_shape(svg, shape);
+            }
+        }
+        Shape::Circle(circle) => {
+            let _ = writeln!(
+                svg,
+                r#"<circle cx="{}" cy="{}" r="{}"{}{}/>"#,
+                circle.center.x,
+                circle.center.y,
+                circle.radius,
+                color_attr("fill", circle.fill),
+                stroke_attr(circle.stroke)
+            );
+        }
+        Shape::LineSegment { points, stroke } => {
+            let _ = writeln!(
+                svg,
+                r#"<line x1="{}" y1="{}" x2="{}" y2="{}"{}/>"#,
+                points[0].x,
+                points[0].y,
+                points[1].x,
+                points[1].y,
+                stroke_attr(*stroke)
+            );
+        }
+        Shape::Path(path) => {
+            let (tag, fill) = if path.closed {
+                ("polygon", path.fill)
+            } else {
+                ("polyline", Color32::TRANSPARENT)
+            };
+            let _ = writeln!(
+                svg,
+                r#"<{} points="{}"{}{}/>"#,
+                tag,
+                points_attr(&path.points),
+                color_attr("fill", fill),
+                stroke_attr(path.stroke)
+            );
+        }
+        Shape::Rect(rect_shape) => {
+            let rect = rect_shape.rect;
+            let _ = writeln!(
+                svg,
+                r#"<rect x="{}" y="{}" width="{}" height="{}" rx="{}"{}{}/>"#,
+                rect.min.x,
+                rect.min.y,
+                rect.width().max(0.0),
+                rect.height().max(0.0),
+                rect_shape.rounding.nw,
+                color_attr("fill", rect_shape.fill),
+                stroke_attr(rect_shape.stroke)
+            );
+        }
+        Shape::Text(text_shape) => write_svg_text(svg, text_shape),
+        Shape::Mesh(mesh) => write_svg_mesh(svg, mesh),
+        Shape::Instanced(instanced) => write_svg_mesh(svg, &instanced.to_mesh()),
+        Shape::QuadraticBezier(bezier) => {
+            let [from, control, to] = bezier.points;
+            let path = format!(
+                "M {} {} Q {} {} {} {}",
+                from.x, from.y, control.x, control.y, to.x, to.y
+            );
+            write_svg_curve(svg, &path, bezier.closed, bezier.fill, bezier.stroke);
+        }
+        Shape::CubicBezier(bezier) => {
+            let [from, control1, control2, to] = bezier.points;
+            let path = format!(
+                "M {} {} C {} {} {} {} {} {}",
+                from.x, from.y, control1.x, control1.y, control2.x, control2.y, to.x, to.y
+            );
+            write_svg_curve(svg, &path, bezier.closed, bezier.fill, bezier.stroke);
+        }
+    }
+}
+
+fn write_svg_curve(svg: &mut String, path: &str, closed: bool, fill: Color32, stroke: Stroke) {
+    let (close, fill) = if closed {
+        (" Z", fill)
+    } else {
+        ("", Color32::TRANSPARENT)
+    };
+    let _ = writeln!(
+        svg,
+        r#"<path d="{}{}"{}{}/>"#,
+        path,
+        close,
+        color_attr("fill", fill),
+        stroke_attr(stroke)
+    );
+}
+
+/// One `<text>` for each run of glyphs in a row that have the same format.
+fn write_svg_text(svg: &mut String, text_shape: &TextShape) {
+    let galley = &text_shape.galley;
+    let rotation = if text_shape.angle == 0.0 {
+        String::new()
+    } else {
+        format!(
+            r#" transform="rotate({} {} {})""#,
+            text_shape.angle.to_degrees(),
+            text_shape.pos.x,
+            text_shape.pos.y
+        )
+    };
+    for row in &galley.rows {
+        let mut glyphs = row.glyphs.as_slice();
+        while let Some(first) = glyphs.first() {
+            let run_len = glyphs
+                .iter()
+                .take_while(|glyph| glyph.section_index == first.section_index)
+                .count();
+            let (run, rest) = glyphs.split_at(run_len);
+            glyphs = rest;
+
+            let content: String = run
+                .iter()
+                .map(|glyph| glyph.chr)
+                .filter(|chr| !chr.is_control())
+                .collect();
+            if content.trim().is_empty() {
+                continue;
+            }
+            let format = &galley.job.sections[first.section_index as usize].format;
+            let color = text_shape.override_text_color.unwrap_or(format.color);
+            let family = match &format.font_id.family {
+                FontFamily::Proportional => "sans-serif",
+                FontFamily::Monospace => "monospace",
+                FontFamily::Name(name) => &**name,
+            };
+            // Glyphs are positioned by the top of their row, and the baseline of most fonts
+            // is about 0.8 of the font size below it.
+            let size = format.font_id.size;
+            let pos = text_shape.pos + first.pos.to_vec2() + vec2(0.0, 0.8 * size);
+            let _ = writeln!(
+                svg,
+                r#"<text x="{}" y="{}" font-family="{}" font-size="{}"{}{} xml:space="preserve">{}</text>"#,
+                pos.x,
+                pos.y,
+                escape(family),
+                size,
+                color_attr("fill", color),
+                rotation,
+                escape(&content)
+            );
+        }
+    }
+}
+
+/// One `<polygon>` for each triangle, in the color of its first vertex.
+fn write_svg_mesh(svg: &mut String, mesh: &Mesh) {
+    for triangle in mesh.indices.chunks_exact(3) {
+        let vertices = [0, 1, 2].map(|i| mesh.vertices[triangle[i] as usize]);
+        let _ = writeln!(
+            svg,
+            r#"<polygon points="{}"{}/>"#,
+            points_attr(&vertices.map(|vertex| vertex.pos)),
+            color_attr("fill", vertices[0].color)
+        );
+    }
+}
+
+fn points_attr(points: &[Pos2]) -> String {
+    let points: Vec<String> = points
+        .iter()
+        .map(|point| format!("{},{}", point.x, point.y))
+        .collect();
+    points.join(" ")
+}
+
+/// E.g. ` fill="#ff8000" fill-opacity="0.5"`, or ` fill="none"` for a transparent color.
+fn color_attr(name: &str, color: Color32) -> String {
+    if color.a() == 0 {
+        return format!(r#" {}="none""#, name);
+    }
+    let [r, g, b, a] = color.to_srgba_unmultiplied();
+    let mut attr = format!(r##" {}="#{:02x}{:02x}{:02x}""##, name, r, g, b);
+    if a < 255 {
+        let _ = write!(attr, r#" {}-opacity="{}""#, name, a as f32 / 255.0);
+    }
+    attr
+}
+
+fn stroke_attr(stroke: Stroke) -> String {
+    if stroke.is_empty() {
+        return r#" stroke="none""#.to_owned();
+    }
+    format!(
+        r#"{} stroke-width="{}""#,
+        color_attr("stroke", stroke.color),
+        stroke.width
+    )
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+// ----------------------------------------------------------------------------
+// Rasterizing
+
+/// Paints the triangles of `primitives` into an image of `rect`, sampling `font_image` for
+/// the font texture like a backend would, and ignoring other textures.
+fn rasterize(
+    primitives: &[ClippedPrimitive],
+    font_image: &FontImage,
+    rect: Rect,
+    pixels_per_point: f32,
+) -> ColorImage {
+    let size = [
+        (rect.width() * pixels_per_point).round().max(0.0) as usize,
+        (rect.height() * pixels_per_point).round().max(0.0) as usize,
+    ];
+    let image_rect = Rect::from_min_size(Pos2::ZERO, vec2(size[0] as f32, size[1] as f32));
+    let to_pixels = |pos: Pos2| ((pos - rect.min) * pixels_per_point).to_pos2();
+    let texels: Vec<Rgba> = font_image.srgba_pixels(1.0).map(Rgba::from).collect();
+    let sample = |uv: Pos2| {
+        let x = ((uv.x * font_image.width() as f32) as usize).min(font_image.width() - 1);
+        let y = ((uv.y * font_image.height() as f32) as usize).min(font_image.height() - 1);
+        texels[y * font_image.width() + x]
+    };
+
+    let mut pixels = vec![Rgba::TRANSPARENT; size[0] * size[1]];
+    for ClippedPrimitive {
+        clip_rect,
+        primitive,
+    } in primitives
+    {
+        let mesh = match primitive {
+            Primitive::Mesh(mesh) => mesh,
+            _ => continue,
+        };
+        let uses_font_texture = mesh.texture_id == TextureId::default();
+        let clip_rect = Rect::from_min_max(to_pixels(clip_rect.min), to_pixels(clip_rect.max))
+            .intersect(image_rect);
+        for triangle in mesh.indices.chunks_exact(3) {
+            let vertices = [0, 1, 2].map(|i| mesh.vertices[triangle[i] as usize]);
+            let [a, b, c] = vertices.map(|vertex| to_pixels(vertex.pos));
+            let area = edge(a, b, c);
+            if area == 0.0 {
+                continue;
+            }
+            let bounds = Rect::from_min_max(a.min(b).min(c), a.max(b).max(c)).intersect(clip_rect);
+            if !bounds.is_positive() {
+                continue;
+            }
+            for y in bounds.min.y.floor() as usize..bounds.max.y.ceil() as usize {
+                for x in bounds.min.x.floor() as usize..bounds.max.x.ceil() as usize {
+                    let center = pos2(x as f32 + 0.5, y as f32 + 0.5);
+                    if !clip_rect.contains(center) {
+                        continue;
+                    }
+                    let weights = [
+                        edge(b, c, center) / area,
+                        edge(c, a, center) / area,
+                        edge(a, b, center) / area,
+                    ];
+                    if weights.iter().any(|&weight| weight < 0.0) {
+                        continue;
+                    }
+                    let mut color = Rgba::TRANSPARENT;
+                    let mut uv = Vec2::ZERO;
+                    for (vertex, weight) in vertices.iter().zip(weights) {
+                        color = color + Rgba::from(vertex.color) * weight;
+                        uv += vertex.uv.to_vec2() * weight;
+                    }
+                    if uses_font_texture {
+                        color = color * sample(uv.to_pos2());
+                    }
+                    let pixel = &mut pixels[y * size[0] + x];
+                    *pixel = color + *pixel * (1.0 - color.a());
+                }
+            }
+        }
+    }
+
+    ColorImage {
+        size,
+        pixels: pixels.into_iter().map(Color32::from).collect(),
+    }
+}
+
+/// Twice the signed area of the triangle `a`, `b`, `c`.
+fn edge(a: Pos2, b: Pos2, c: Pos2) -> f32 {
+    (b.x - a.x) * (c.y - a.y) - (b.y - a.y) * (c.x - a.x)
+}
+
+#[test]
+fn test_snapshot() {
+    let red_square = Shape::rect_filled(
+        Rect::from_min_size(pos2(2.0, 2.0), vec2(4.0, 4.0)),
+        0.0,
+        Color32::RED,
+    );
+    let mut svg = String::new();
+    write_svg_shape(&mut svg, &red_square);
+    assert_eq!(
+        svg,
+        "<rect x=\"2\" y=\"2\" width=\"4\" height=\"4\" rx=\"0\" fill=\"#ff0000\" stroke=\"none\"/>\n"
+    );
+    assert_eq!(escape("a < \"b\""), "a &lt; &quot;b&quot;");
+
+    // The white texel that untextured shapes sample:
+    let mut font_image = FontImage::new([2, 2]);
+    font_image.pixels[0] = 1.0;
+    let mut mesh = Mesh::default();
+    mesh.add_colored_rect(
+        Rect::from_min_size(pos2(2.0, 2.0), vec2(4.0, 4.0)),
+        Color32::RED,
+    );
+    let primitives = [ClippedPrimitive {
+        clip_rect: Rect::EVERYTHING,
+        primitive: Primitive::Mesh(mesh),
+    }];
+    let rect = Rect::from_min_size(Pos2::ZERO, vec2(8.0, 8.0));
+    let image = rasterize(&primitives, &font_image, rect, 1.0);
+    assert_eq!(image.size, [8, 8]);
+    assert_eq!(image.pixels[3 * 8 + 3], Color32::RED);
+    assert_eq!(image.pixels[0], Color32::TRANSPARENT);
+    assert_eq!(image.pixels[7 * 8 + 7], Color32::TRANSPARENT);
+}
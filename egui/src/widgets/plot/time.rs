@@ -0,0 +1,282 @@
+//! Axes of unix timestamps, see [`super::Plot::x_axis_time`].
+
+use crate::i18n::{DateOrder, LocaleFormat};
+
+use super::{civil_from_days, days_from_civil, GridInput, GridMark, GridSpacer};
+
+const MINUTE: i64 = 60;
+const HOUR: i64 = 60 * MINUTE;
+const DAY: i64 = 24 * HOUR;
+
+/// The most grid lines that one step may make; more would be too dense to see, and slow to make.
+const MAX_MARKS: f64 = 1000.0;
+
+/// Timestamps beyond this (about three billion years) get no grid lines, so that they fit an `i64`.
+const MAX_TIMESTAMP: f64 = 1e17;
+
+/// How the labels of a time axis are written, see [`super::Plot::x_axis_time`].
+///
+/// Timestamps are in seconds since 1970-01-01 00:00 UTC, and are shown in UTC.
+/// Dates are written according to [`crate::Context::locale_format`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum TimeFormat {
+    /// Depends on what calendar boundary the time is on:
+    /// the year at the start of a year, the month at the start of a month,
+    /// the date at midnight, and the time of day otherwise.
+    ///
+    /// Hovering shows both the date and the time.
+    Auto,
+
+    /// E.g. `2022-03-07`.
+    Date,
+
+    /// E.g. `2022-03-07 14:30`.
+    DateTime,
+
+    /// E.g. `14:30`, or `14:30:15` if not on a whole minute.
+    Time,
+}
+
+impl Default for TimeFormat {
+    fn default() -> Self {
+        Self::Auto
+    }
+}
+
+impl TimeFormat {
+    /// Write a unix timestamp (in seconds) this way.
+    pub fn format(&self, timestamp: f64, locale_format: &LocaleFormat) -> String {
+        let seconds = timestamp.round() as i64;
+        let days = seconds.div_euclid(DAY);
+        let (year, month, day) = civil_from_days(days);
+        let time_of_day = seconds.rem_euclid(DAY);
+        let date = || locale_format.format_date(year as i32, month, day);
+        let time = || {
+            let (hours, minutes) = (time_of_day / HOUR, time_of_day % HOUR / MINUTE);
+            match time_of_day % MINUTE {
+                0 => format!("{:02}:{:02}", hours, minutes),
+                seconds => format!("{:02}:{:02}:{:02}", hours, minutes, seconds),
+            }
+        };
+
+        match self {
+            Self::Auto => {
+                if time_of_day != 0 {
+                    time()
+                } else if day != 1 {
+                    date()
+                } else if month != 1 {
+                    let sep = locale_format.date_separator;
+                    match locale_format.date_order {
+                        DateOrder::YearMonthDay => format!("{}{}{:02}", year, sep, month),
+                        DateOrder::DayMonthYear | DateOrder::MonthDayYear => {
+                            format!("{:02}{}{}", month, sep, year)
+                        }
+                    }
+                } else {
+                    year.to_string()
+                }
+            }
+            Self::Date => date(),
+            Self::DateTime => format!("{} {}", date(), time()),
+            Self::Time => time(),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// The distance between two grid lines of a time axis.
+#[derive(Clone, Copy)]
+enum TimeStep {
+    /// A fixed number of seconds, starting `offset` seconds after the unix epoch.
+    Seconds { step: i64, offset: i64 },
+    /// A number of calendar months, starting with January.
+    Months(i64),
+}
+
+impl TimeStep {
+    const fn seconds(step: i64) -> Self {
+        Self::Seconds { step, offset: 0 }
+    }
+
+    /// About how many seconds this is, for picking the steps and for [`GridMark::step_size`].
+    fn approx_seconds(self) -> f64 {
+        match self {
+            Self::Seconds { step, .. } => step as f64,
+            Self::Months(months) => months as f64 * 30.44 * DAY as f64,
+        }
+    }
+
+    fn marks(self, (min, max): (f64, f64), out: &mut Vec<GridMark>) {
+        let step_size = self.approx_seconds();
+        if (max - min) / step_size > MAX_MARKS || min.abs().max(max.abs()) > MAX_TIMESTAMP {
+            return;
+        }
+        match self {
+            Self::Seconds { step, offset } => {
+                let first = ((min - offset as f64) / step as f64).ceil() as i64;
+                let last = ((max - offset as f64) / step as f64).floor() as i64;
+                out.extend((first..=last).map(|i| GridMark {
+                    value: (i * step + offset) as f64,
+                    step_size,
+                }));
+            }
+            Self::Months(months) => {
+                let (year, _, _) = civil_from_days((min / DAY as f64).floor() as i64);
+                // Months since year 0, rounded down to a multiple of the step:
+                let mut month = (year * 12).div_euclid(months) * months;
+                loop {
+                    let days =
+                        days_from_civil(month.div_euclid(12), month.rem_euclid(12) as u32 + 1, 1);
+                    let value = (days * DAY) as f64;
+                    if value > max {
+                        break;
+                    }
+                    if value >= min {
+                        out.push(GridMark { value, step_size });
+                    }
+                    month += months;
+                }
+            }
+        }
+    }
+}
+
+/// From fine to coarse.
+const TIME_STEPS: [TimeStep; 27] = [
+    TimeStep::seconds(1),
+    TimeStep::seconds(5),
+    TimeStep::seconds(15),
+    TimeStep::seconds(30),
+    TimeStep::seconds(MINUTE),
+    TimeStep::seconds(5 * MINUTE),
+    TimeStep::seconds(15 * MINUTE),
+    TimeStep::seconds(30 * MINUTE),
+    TimeStep::seconds(HOUR),
+    TimeStep::seconds(3 * HOUR),
+    TimeStep::seconds(6 * HOUR),
+    TimeStep::seconds(12 * HOUR),
+    TimeStep::seconds(DAY),
+    // Mondays; 1970-01-01 was a Thursday.
+    TimeStep::Seconds {
+        step: 7 * DAY,
+        offset: 4 * DAY,
+    },
+    TimeStep::Months(1),
+    TimeStep::Months(3),
+    TimeStep::Months(6),
+    TimeStep::Months(12),
+    TimeStep::Months(2 * 12),
+    TimeStep::Months(5 * 12),
+    TimeStep::Months(10 * 12),
+    TimeStep::Months(20 * 12),
+    TimeStep::Months(50 * 12),
+    TimeStep::Months(100 * 12),
+    TimeStep::Months(200 * 12),
+    TimeStep::Months(500 * 12),
+    TimeStep::Months(1000 * 12),
+];
+
+/// Grid lines at calendar boundaries for an axis of unix timestamps (in seconds):
+/// minutes, hours, days, weeks, months or years, depending on the zoom.
+///
+/// [`super::Plot::x_axis_time`] uses it for the x axis.
+pub fn time_grid_spacer() -> GridSpacer {
+    Box::new(|input: GridInput| -> Vec<GridMark> {
+        if !(input.bounds.0.is_finite() && input.bounds.1.is_finite()) {
+            return vec![];
+        }
+        let first = TIME_STEPS
+            .iter()
+            .position(|step| step.approx_seconds() >= input.base_step_size)
+            .unwrap_or(TIME_STEPS.len() - 1);
+        let mut marks = vec![];
+        for step in TIME_STEPS.iter().skip(first).take(3) {
+            step.marks(input.bounds, &mut marks);
+        }
+        marks
+    })
+}
+
+/// A duration in seconds in its two largest units, e.g. `2d 4h`, `15m 30s` or `-3h`.
+pub(super) fn format_duration(seconds: f64) -> String {
+    let sign = if seconds < 0.0 { "-" } else { "" };
+    let units = [(DAY, "d"), (HOUR, "h"), (MINUTE, "m"), (1, "s")];
+    let parts: Vec<String> = units
+        .iter()
+        .scan(seconds.abs().round() as i64, |rest, &(unit, suffix)| {
+            let count = *rest / unit;
+            *rest %= unit;
+            Some((count, suffix))
+        })
+        .skip_while(|&(count, _)| count == 0)
+        .take(2)
+        .filter(|&(count, _)| count != 0)
+        .map(|(count, suffix)| format!("{}{}", count, suffix))
+        .collect();
+    if parts.is_empty() {
+        "0s".to_owned()
+    } else {
+        format!("{}{}", sign, parts.join(" "))
+    }
+}
+
+#[test]
+fn test_time_axis() {
+    let locale_format = LocaleFormat::default();
+    let march_7 = days_from_civil(2022, 3, 7) as f64 * DAY as f64;
+    let format = |format: TimeFormat, timestamp| format.format(timestamp, &locale_format);
+    assert_eq!(
+        format(TimeFormat::Auto, march_7 + 14.5 * HOUR as f64),
+        "14:30"
+    );
+    assert_eq!(format(TimeFormat::Auto, march_7 + 15.0), "00:00:15");
+    assert_eq!(format(TimeFormat::Auto, march_7), "2022-03-07");
+    assert_eq!(
+        format(TimeFormat::Auto, march_7 - 6.0 * DAY as f64),
+        "2022-03"
+    );
+    assert_eq!(format(TimeFormat::Auto, 0.0), "1970");
+    assert_eq!(
+        format(TimeFormat::DateTime, march_7 + 60.0),
+        "2022-03-07 00:01"
+    );
+
+    let mut marks = vec![];
+    TimeStep::Months(3).marks((march_7, march_7 + 365.0 * DAY as f64), &mut marks);
+    let months: Vec<_> = marks
+        .iter()
+        .map(|mark| civil_from_days((mark.value / DAY as f64) as i64))
+        .collect();
+    assert_eq!(
+        months,
+        vec![(2022, 4, 1), (2022, 7, 1), (2022, 10, 1), (2023, 1, 1)]
+    );
+
+    marks.clear();
+    TIME_STEPS[13].marks((march_7 - DAY as f64, march_7 + DAY as f64), &mut marks);
+    assert_eq!(marks.len(), 1);
+    assert_eq!(marks[0].value, march_7); // A Monday
+
+    // Zoomed out beyond any calendar, there are no grid lines rather than billions of them:
+    let spacer = time_grid_spacer();
+    for bounds in [(-1e20, 1e20), (1e20, 1e20 + 1e12), (0.0, 1e15)] {
+        let input = GridInput {
+            bounds,
+            base_step_size: (bounds.1 - bounds.0) / 100.0,
+            points_per_unit: 1000.0 / (bounds.1 - bounds.0),
+        };
+        assert!(spacer(input).len() as f64 <= 3.0 * MAX_MARKS);
+    }
+}
+
+#[test]
+fn test_format_duration() {
+    assert_eq!(format_duration(0.2), "0s");
+    assert_eq!(format_duration(45.0), "45s");
+    assert_eq!(format_duration(3.0 * HOUR as f64), "3h");
+    assert_eq!(format_duration(-(15.0 * MINUTE as f64 + 30.0)), "-15m 30s");
+    assert_eq!(format_duration((2 * DAY + 4 * HOUR + 59) as f64), "2d 4h");
+}
@@ -18,6 +18,10 @@ impl PlotBounds {
         max: [-f64::INFINITY; 2],
     };
 
+    pub fn from_min_max(min: [f64; 2], max: [f64; 2]) -> Self {
+        Self { min, max }
+    }
+
     pub fn min(&self) -> [f64; 2] {
         self.min
     }
@@ -148,6 +152,41 @@ impl PlotBounds {
         self.min[1]..=self.max[1]
     }
 
+    /// Cut the range along the axis to positive values, as [`AxisScale::Log10`] needs.
+    /// A range reaching zero keeps the three decades below its end.
+    pub(crate) fn make_positive(&mut self, axis: usize) {
+        if self.max[axis] <= 0.0 {
+            self.min[axis] = 1.0;
+            self.max[axis] = 10.0;
+        } else if self.min[axis] <= 0.0 {
+            self.min[axis] = self.max[axis] / 1000.0;
+        }
+    }
+
+    /// The bounds in the space where the axes are linear, e.g. the log10 of the values along a
+    /// [`AxisScale::Log10`] axis.
+    pub(crate) fn scaled(&self, scales: [AxisScale; 2]) -> Self {
+        let mut scaled = *self;
+        for axis in 0..2 {
+            if scales[axis] == AxisScale::Log10 {
+                scaled.make_positive(axis);
+                scaled.min[axis] = scaled.min[axis].log10();
+                scaled.max[axis] = scaled.max[axis].log10();
+            }
+        }
+        scaled
+    }
+
+    /// The inverse of [`Self::scaled`].
+    pub(crate) fn unscaled(&self, scales: [AxisScale; 2]) -> Self {
+        let mut bounds = *self;
+        for axis in 0..2 {
+            bounds.min[axis] = scales[axis].invert(self.min[axis]);
+            bounds.max[axis] = scales[axis].invert(self.max[axis]);
+        }
+        bounds
+    }
+
     pub(crate) fn make_x_symmetrical(&mut self) {
         let x_abs = self.min[0].abs().max(self.max[0].abs());
         self.min[0] = -x_abs;
@@ -161,6 +200,42 @@ impl PlotBounds {
     }
 }
 
+/// How the values along an axis are mapped to the screen, see [`super::Plot::x_axis_scale`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum AxisScale {
+    /// Equal distances on the screen are equal differences of value.
+    Linear,
+
+    /// Equal distances on the screen are equal ratios of value, so 1, 10, 100 and 1000 are evenly
+    /// spaced. Only positive values can be shown; zero and negative ones end up far below the axis.
+    Log10,
+}
+
+impl Default for AxisScale {
+    fn default() -> Self {
+        Self::Linear
+    }
+}
+
+impl AxisScale {
+    /// The value in the space where the axis is linear.
+    pub(crate) fn apply(self, value: f64) -> f64 {
+        match self {
+            Self::Linear => value,
+            Self::Log10 => value.max(f64::MIN_POSITIVE).log10(),
+        }
+    }
+
+    /// The inverse of [`Self::apply`].
+    pub(crate) fn invert(self, value: f64) -> f64 {
+        match self {
+            Self::Linear => value,
+            Self::Log10 => 10.0_f64.powf(value),
+        }
+    }
+}
+
 /// Contains the screen rectangle and the plot bounds and provides methods to transform them.
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 #[derive(Clone)]
@@ -176,10 +251,19 @@ pub(crate) struct ScreenTransform {
 
     /// Whether to always center the y-range of the bounds.
     y_centered: bool,
+
+    /// How the values along each axis are mapped to the screen.
+    scales: [AxisScale; 2],
 }
 
 impl ScreenTransform {
-    pub fn new(frame: Rect, mut bounds: PlotBounds, x_centered: bool, y_centered: bool) -> Self {
+    pub fn new(
+        frame: Rect,
+        mut bounds: PlotBounds,
+        x_centered: bool,
+        y_centered: bool,
+        scales: [AxisScale; 2],
+    ) -> Self {
         // Make sure they are not empty.
         if !bounds.is_valid() {
             bounds = PlotBounds::new_symmetrical(1.0);
@@ -193,11 +277,18 @@ impl ScreenTransform {
             bounds.make_y_symmetrical();
         };
 
+        for axis in 0..2 {
+            if scales[axis] == AxisScale::Log10 {
+                bounds.make_positive(axis);
+            }
+        }
+
         Self {
             frame,
             bounds,
             x_centered,
             y_centered,
+            scales,
         }
     }
 
@@ -213,6 +304,15 @@ impl ScreenTransform {
         self.bounds = bounds;
     }
 
+    pub fn scales(&self) -> [AxisScale; 2] {
+        self.scales
+    }
+
+    /// The bounds in the space where the axes are linear, see [`PlotBounds::scaled`].
+    pub fn scaled_bounds(&self) -> PlotBounds {
+        self.bounds.scaled(self.scales)
+    }
+
     pub fn translate_bounds(&mut self, mut delta_pos: Vec2) {
         if self.x_centered {
             delta_pos.x = 0.;
@@ -220,20 +320,28 @@ impl ScreenTransform {
         if self.y_centered {
             delta_pos.y = 0.;
         }
-        delta_pos.x *= self.dvalue_dpos()[0] as f32;
-        delta_pos.y *= self.dvalue_dpos()[1] as f32;
-        self.bounds.translate(delta_pos);
+        let dpos_dscaled = self.dpos_dscaled();
+        delta_pos.x /= dpos_dscaled[0] as f32;
+        delta_pos.y /= dpos_dscaled[1] as f32;
+        let mut scaled = self.scaled_bounds();
+        scaled.translate(delta_pos);
+        self.bounds = scaled.unscaled(self.scales);
     }
 
     /// Zoom by a relative factor with the given screen position as center.
     pub fn zoom(&mut self, zoom_factor: Vec2, center: Pos2) {
         let center = self.value_from_position(center);
+        let center = PlotPoint::new(
+            self.scales[0].apply(center.x),
+            self.scales[1].apply(center.y),
+        );
 
-        let mut new_bounds = self.bounds;
+        let mut new_bounds = self.scaled_bounds();
         new_bounds.min[0] = center.x + (new_bounds.min[0] - center.x) / (zoom_factor.x as f64);
         new_bounds.max[0] = center.x + (new_bounds.max[0] - center.x) / (zoom_factor.x as f64);
         new_bounds.min[1] = center.y + (new_bounds.min[1] - center.y) / (zoom_factor.y as f64);
         new_bounds.max[1] = center.y + (new_bounds.max[1] - center.y) / (zoom_factor.y as f64);
+        let new_bounds = new_bounds.unscaled(self.scales);
 
         if new_bounds.is_valid() {
             self.bounds = new_bounds;
@@ -241,31 +349,33 @@ impl ScreenTransform {
     }
 
     pub fn position_from_point(&self, value: &PlotPoint) -> Pos2 {
+        let bounds = self.scaled_bounds();
         let x = remap(
-            value.x,
-            self.bounds.min[0]..=self.bounds.max[0],
+            self.scales[0].apply(value.x),
+            bounds.min[0]..=bounds.max[0],
             (self.frame.left() as f64)..=(self.frame.right() as f64),
         );
         let y = remap(
-            value.y,
-            self.bounds.min[1]..=self.bounds.max[1],
+            self.scales[1].apply(value.y),
+            bounds.min[1]..=bounds.max[1],
             (self.frame.bottom() as f64)..=(self.frame.top() as f64), // negated y axis!
         );
         pos2(x as f32, y as f32)
     }
 
     pub fn value_from_position(&self, pos: Pos2) -> PlotPoint {
+        let bounds = self.scaled_bounds();
         let x = remap(
             pos.x as f64,
             (self.frame.left() as f64)..=(self.frame.right() as f64),
-            self.bounds.min[0]..=self.bounds.max[0],
+            bounds.min[0]..=bounds.max[0],
         );
         let y = remap(
             pos.y as f64,
             (self.frame.bottom() as f64)..=(self.frame.top() as f64), // negated y axis!
-            self.bounds.min[1]..=self.bounds.max[1],
+            bounds.min[1]..=bounds.max[1],
         );
-        PlotPoint::new(x, y)
+        PlotPoint::new(self.scales[0].invert(x), self.scales[1].invert(y))
     }
 
     /// Transform a rectangle of plot values to a screen-coordinate rectangle.
@@ -302,10 +412,20 @@ impl ScreenTransform {
         [1.0 / self.dpos_dvalue_x(), 1.0 / self.dpos_dvalue_y()]
     }
 
+    /// delta position / delta scaled value, i.e. per decade along a log axis
+    pub fn dpos_dscaled(&self) -> [f64; 2] {
+        let bounds = self.scaled_bounds();
+        [
+            self.frame.width() as f64 / bounds.width(),
+            -self.frame.height() as f64 / bounds.height(), // negated y axis!
+        ]
+    }
+
     fn aspect(&self) -> f64 {
         let rw = self.frame.width() as f64;
         let rh = self.frame.height() as f64;
-        (self.bounds.width() / rw) / (self.bounds.height() / rh)
+        let bounds = self.scaled_bounds();
+        (bounds.width() / rw) / (bounds.height() / rh)
     }
 
     /// Sets the aspect ratio by expanding the x- or y-axis.
@@ -320,13 +440,13 @@ impl ScreenTransform {
             return;
         }
 
+        let mut bounds = self.scaled_bounds();
         if current_aspect < aspect {
-            self.bounds
-                .expand_x((aspect / current_aspect - 1.0) * self.bounds.width() * 0.5);
+            bounds.expand_x((aspect / current_aspect - 1.0) * bounds.width() * 0.5);
         } else {
-            self.bounds
-                .expand_y((current_aspect / aspect - 1.0) * self.bounds.height() * 0.5);
+            bounds.expand_y((current_aspect / aspect - 1.0) * bounds.height() * 0.5);
         }
+        self.bounds = bounds.unscaled(self.scales);
     }
 
     /// Sets the aspect ratio by changing either the X or Y axis (callers choice).
@@ -339,12 +459,50 @@ impl ScreenTransform {
             return;
         }
 
+        let mut bounds = self.scaled_bounds();
         if change_x {
-            self.bounds
-                .expand_x((aspect / current_aspect - 1.0) * self.bounds.width() * 0.5);
+            bounds.expand_x((aspect / current_aspect - 1.0) * bounds.width() * 0.5);
         } else {
-            self.bounds
-                .expand_y((current_aspect / aspect - 1.0) * self.bounds.height() * 0.5);
+            bounds.expand_y((current_aspect / aspect - 1.0) * bounds.height() * 0.5);
         }
+        self.bounds = bounds.unscaled(self.scales);
     }
 }
+
+#[test]
+fn test_log_axis() {
+    let frame = Rect::from_min_max(pos2(0.0, 0.0), pos2(300.0, 100.0));
+    let bounds = PlotBounds {
+        min: [1.0, -5.0],
+        max: [1000.0, 5.0],
+    };
+    let mut transform = ScreenTransform::new(
+        frame,
+        bounds,
+        false,
+        false,
+        [AxisScale::Log10, AxisScale::Linear],
+    );
+    let x = |value: f64| transform.position_from_point(&PlotPoint::new(value, 0.0)).x;
+    assert_eq!(
+        (x(1.0), x(10.0), x(100.0), x(1000.0)),
+        (0.0, 100.0, 200.0, 300.0)
+    );
+    let value = transform.value_from_position(pos2(100.0, 50.0));
+    assert!((value.x - 10.0).abs() < 1e-9 && value.y.abs() < 1e-9);
+
+    // Panning by a decade multiplies the bounds by 10:
+    transform.translate_bounds(vec2(100.0, 0.0));
+    assert!((transform.bounds().min[0] - 10.0).abs() < 1e-9);
+    assert!((transform.bounds().max[0] - 10_000.0).abs() < 1e-6);
+
+    // Log axes can't reach zero:
+    let transform = ScreenTransform::new(
+        frame,
+        bounds,
+        false,
+        false,
+        [AxisScale::Linear, AxisScale::Log10],
+    );
+    assert_eq!(transform.bounds().min[1], 5.0 / 1000.0);
+}
@@ -7,13 +7,16 @@ enum ProgressBarText {
 
 /// A simple progress bar.
 ///
-/// See also: [`crate::Spinner`].
+/// Use [`Self::indeterminate`] when you don't know how far along you are.
+///
+/// See also: [`crate::Spinner`] and [`crate::ProgressButton`].
 #[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
 pub struct ProgressBar {
     progress: f32,
     desired_width: Option<f32>,
     text: Option<ProgressBarText>,
     animate: bool,
+    indeterminate: bool,
 }
 
 impl ProgressBar {
@@ -24,6 +27,18 @@ impl ProgressBar {
             desired_width: None,
             text: None,
             animate: false,
+            indeterminate: false,
+        }
+    }
+
+    /// A progress bar for when the progress is unknown,
+    /// showing a segment that sweeps across the bar.
+    ///
+    /// Note that this will cause the UI to be redrawn.
+    pub fn indeterminate() -> Self {
+        Self {
+            indeterminate: true,
+            ..Self::new(0.0)
         }
     }
 
@@ -40,6 +55,8 @@ impl ProgressBar {
     }
 
     /// Show the progress in percent on the progress bar.
+    ///
+    /// Ignored for [`Self::indeterminate`] progress bars.
     pub fn show_percentage(mut self) -> Self {
         self.text = Some(ProgressBarText::Percentage);
         self
@@ -61,9 +78,10 @@ impl Widget for ProgressBar {
             desired_width,
             text,
             animate,
+            indeterminate,
         } = self;
 
-        let animate = animate && progress < 1.0;
+        let animate = animate && progress < 1.0 && !indeterminate;
 
         let desired_width =
             desired_width.unwrap_or_else(|| ui.available_size_before_wrap().x.at_least(96.0));
@@ -72,7 +90,7 @@ impl Widget for ProgressBar {
             ui.allocate_exact_size(vec2(desired_width, height), Sense::hover());
 
         if ui.is_rect_visible(response.rect) {
-            if animate {
+            if animate || indeterminate {
                 ui.ctx().request_repaint();
             }
 
@@ -84,6 +102,28 @@ impl Widget for ProgressBar {
                 visuals.extreme_bg_color,
                 Stroke::none(),
             );
+
+            if indeterminate {
+                // Sweep a segment across the bar, once every 1.5 seconds:
+                let segment_width = (0.3 * outer_rect.width()).at_least(outer_rect.height());
+                let t = (ui.input().time / 1.5).fract() as f32;
+                let left = lerp((outer_rect.left() - segment_width)..=outer_rect.right(), t);
+                let segment = Rect::from_min_size(
+                    pos2(left, outer_rect.top()),
+                    vec2(segment_width, outer_rect.height()),
+                );
+                ui.painter().with_clip_rect(outer_rect).rect(
+                    segment,
+                    rounding,
+                    visuals.selection.bg_fill,
+                    Stroke::none(),
+                );
+
+                if let Some(ProgressBarText::Custom(text)) = text {
+                    paint_text(ui, outer_rect, text);
+                }
+                return response;
+            }
             let inner_rect = Rect::from_min_size(
                 outer_rect.min,
                 vec2(
@@ -133,20 +173,25 @@ impl Widget for ProgressBar {
                         format!("{}%", (progress * 100.0) as usize).into()
                     }
                 };
-                let galley = text.into_galley(ui, Some(false), f32::INFINITY, TextStyle::Button);
-                let text_pos = outer_rect.left_center() - Vec2::new(0.0, galley.size().y / 2.0)
-                    + vec2(ui.spacing().item_spacing.x, 0.0);
-                let text_color = visuals
-                    .override_text_color
-                    .unwrap_or(visuals.selection.stroke.color);
-                galley.paint_with_fallback_color(
-                    &ui.painter().with_clip_rect(outer_rect),
-                    text_pos,
-                    text_color,
-                );
+                paint_text(ui, outer_rect, text);
             }
         }
 
         response
     }
 }
+
+fn paint_text(ui: &Ui, outer_rect: Rect, text: WidgetText) {
+    let visuals = ui.visuals();
+    let galley = text.into_galley(ui, Some(false), f32::INFINITY, TextStyle::Button);
+    let text_pos = outer_rect.left_center() - Vec2::new(0.0, galley.size().y / 2.0)
+        + vec2(ui.spacing().item_spacing.x, 0.0);
+    let text_color = visuals
+        .override_text_color
+        .unwrap_or(visuals.selection.stroke.color);
+    galley.paint_with_fallback_color(
+        &ui.painter().with_clip_rect(outer_rect),
+        text_pos,
+        text_color,
+    );
+}
@@ -1,15 +1,58 @@
-use epaint::{emath::lerp, vec2, Pos2, Shape, Stroke};
+use std::sync::Arc;
 
-use crate::{Response, Sense, Ui, Widget};
+use epaint::{emath::lerp, vec2, Color32, Pos2, Rect, Shape, Stroke};
+
+use crate::{Painter, Response, Sense, Ui, Widget};
+
+/// What a [`Spinner`] looks like.
+#[derive(Clone)]
+pub enum SpinnerStyle {
+    /// An arc that rotates while growing and shrinking.
+    Ring,
+
+    /// A circle of dots that light up one after another.
+    Dots,
+
+    /// A row of bars that bounce up and down.
+    Bars,
+
+    /// Paint the spinner yourself.
+    ///
+    /// Called with the painter, the rectangle to paint in, the current time (in seconds) and the color.
+    Custom(Arc<dyn Fn(&Painter, Rect, f64, Color32) + Send + Sync>),
+}
+
+impl Default for SpinnerStyle {
+    fn default() -> Self {
+        Self::Ring
+    }
+}
+
+impl SpinnerStyle {
+    /// See [`Self::Custom`].
+    pub fn custom(paint: impl Fn(&Painter, Rect, f64, Color32) + Send + Sync + 'static) -> Self {
+        Self::Custom(Arc::new(paint))
+    }
+}
 
 /// A spinner widget used to indicate loading.
 ///
-/// See also: [`crate::ProgressBar`].
+/// See also: [`crate::ProgressBar`] and [`crate::ProgressButton`].
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// ui.add(egui::Spinner::new());
+/// ui.add(egui::Spinner::new().style(egui::SpinnerStyle::Dots).size(32.0));
+/// # });
+/// ```
 #[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
 #[derive(Default)]
 pub struct Spinner {
     /// Uses the style's `interact_size` if `None`.
     size: Option<f32>,
+    style: SpinnerStyle,
+    /// Uses the style's strong text color if `None`.
+    color: Option<Color32>,
 }
 
 impl Spinner {
@@ -24,6 +67,82 @@ impl Spinner {
         self.size = Some(size);
         self
     }
+
+    /// What the spinner looks like. Default: [`SpinnerStyle::Ring`].
+    pub fn style(mut self, style: SpinnerStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets the spinner's color. Uses the style's strong text color by default.
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Paint the spinner in the given rectangle, without allocating any space.
+    pub fn paint_at(&self, ui: &Ui, rect: Rect) {
+        if !ui.is_rect_visible(rect) {
+            return;
+        }
+
+        ui.ctx().request_repaint();
+
+        let painter = ui.painter();
+        let time = ui.input().time;
+        let color = self
+            .color
+            .unwrap_or_else(|| ui.visuals().strong_text_color());
+        let radius = (rect.height() / 2.0) - 2.0;
+
+        match &self.style {
+            SpinnerStyle::Ring => {
+                let n_points = 20;
+                let start_angle = time * std::f64::consts::TAU;
+                let end_angle = start_angle + 240f64.to_radians() * time.sin();
+                let points: Vec<Pos2> = (0..n_points)
+                    .map(|i| {
+                        let angle = lerp(start_angle..=end_angle, i as f64 / n_points as f64);
+                        let (sin, cos) = angle.sin_cos();
+                        rect.center() + radius * vec2(cos as f32, sin as f32)
+                    })
+                    .collect();
+                painter.add(Shape::line(points, Stroke::new(3.0, color)));
+            }
+            SpinnerStyle::Dots => {
+                let n_dots = 8;
+                let dot_radius = 0.15 * radius;
+                for i in 0..n_dots {
+                    let t = i as f64 / n_dots as f64;
+                    let angle = t * std::f64::consts::TAU;
+                    let (sin, cos) = angle.sin_cos();
+                    let center =
+                        rect.center() + (radius - dot_radius) * vec2(cos as f32, sin as f32);
+                    // The brightest dot goes around once per second:
+                    let brightness = 1.0 - (t - time).rem_euclid(1.0) as f32;
+                    painter.circle_filled(center, dot_radius, color.linear_multiply(brightness));
+                }
+            }
+            SpinnerStyle::Bars => {
+                let n_bars = 5;
+                let bars_rect = rect.shrink(2.0);
+                let bar_width = bars_rect.width() / (2 * n_bars - 1) as f32;
+                for i in 0..n_bars {
+                    let phase = time * std::f64::consts::TAU - 0.6 * i as f64;
+                    let height = bars_rect.height() * (0.3 + 0.35 * (1.0 + phase.sin() as f32));
+                    let x = bars_rect.left() + 2.0 * bar_width * i as f32;
+                    let bar = Rect::from_center_size(
+                        Pos2::new(x + 0.5 * bar_width, bars_rect.center().y),
+                        vec2(bar_width, height),
+                    );
+                    painter.rect_filled(bar, 0.5 * bar_width, color);
+                }
+            }
+            SpinnerStyle::Custom(paint) => {
+                paint(painter, rect, time, color);
+            }
+        }
+    }
 }
 
 impl Widget for Spinner {
@@ -32,27 +151,7 @@ impl Widget for Spinner {
             .size
             .unwrap_or_else(|| ui.style().spacing.interact_size.y);
         let (rect, response) = ui.allocate_exact_size(vec2(size, size), Sense::hover());
-
-        if ui.is_rect_visible(rect) {
-            ui.ctx().request_repaint();
-
-            let radius = (rect.height() / 2.0) - 2.0;
-            let n_points = 20;
-            let start_angle = ui.input().time * std::f64::consts::TAU;
-            let end_angle = start_angle + 240f64.to_radians() * ui.input().time.sin();
-            let points: Vec<Pos2> = (0..n_points)
-                .map(|i| {
-                    let angle = lerp(start_angle..=end_angle, i as f64 / n_points as f64);
-                    let (sin, cos) = angle.sin_cos();
-                    rect.center() + radius * vec2(cos as f32, sin as f32)
-                })
-                .collect();
-            ui.painter().add(Shape::line(
-                points,
-                Stroke::new(3.0, ui.visuals().strong_text_color()),
-            ));
-        }
-
+        self.paint_at(ui, rect);
         response
     }
 }
@@ -128,6 +128,13 @@ impl BackendPanel {
             ui.ctx().set_debug_on_hover(debug_on_hover);
         }
 
+        {
+            let mut profiler_visible = ui.ctx().profiler_visible();
+            ui.checkbox(&mut profiler_visible, "⏱ Frame profiler")
+                .on_hover_text("Show how long egui spends on each frame (Ctrl+Shift+P)");
+            ui.ctx().set_profiler_visible(profiler_visible);
+        }
+
         ui.separator();
 
         {
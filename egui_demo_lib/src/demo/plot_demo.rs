@@ -4,9 +4,9 @@ use std::ops::RangeInclusive;
 use egui::plot::{GridInput, GridMark};
 use egui::*;
 use plot::{
-    Arrows, Bar, BarChart, BoxElem, BoxPlot, BoxSpread, CoordinatesFormatter, Corner, HLine,
-    Legend, Line, LineStyle, MarkerShape, Plot, PlotImage, PlotPoint, PlotPoints, Points, Polygon,
-    Text, VLine,
+    Arrows, Bar, BarChart, BoxElem, BoxPlot, BoxSpread, CoordinatesFormatter, Corner, FilledRange,
+    HLine, Legend, Line, LineStyle, MarkerShape, Plot, PlotImage, PlotPoint, PlotPoints, Points,
+    PolarPlot, Polygon, Text, VLine,
 };
 
 // ----------------------------------------------------------------------------
@@ -310,6 +310,10 @@ impl LegendDemo {
                     .clamp_range(0.0..=1.0),
             );
             ui.end_row();
+
+            ui.label("Outside:");
+            ui.checkbox(&mut config.outside, "Beside the plot");
+            ui.end_row();
         });
 
         let legend_plot = Plot::new("legend_demo")
@@ -444,6 +448,8 @@ impl CustomAxisDemo {
             .x_axis_formatter(x_fmt)
             .y_axis_formatter(y_fmt)
             .x_grid_spacer(CustomAxisDemo::x_grid)
+            .x_axis_label("Time")
+            .y_axis_label("Percent")
             .label_formatter(label_fmt)
             .show(ui, |plot_ui| {
                 plot_ui.line(CustomAxisDemo::logistic_fn());
@@ -596,9 +602,10 @@ impl ItemsDemo {
             .show_y(false)
             .data_aspect(1.0);
         plot.show(ui, |plot_ui| {
-            plot_ui.hline(HLine::new(9.0).name("Lines horizontal"));
+            plot_ui.filled_range(FilledRange::x(-9.0..=-6.0).name("Filled range"));
+            plot_ui.hline(HLine::new(9.0).name("Lines horizontal").label("y = 9"));
             plot_ui.hline(HLine::new(-9.0).name("Lines horizontal"));
-            plot_ui.vline(VLine::new(9.0).name("Lines vertical"));
+            plot_ui.vline(VLine::new(9.0).name("Lines vertical").label("x = 9"));
             plot_ui.vline(VLine::new(-9.0).name("Lines vertical"));
             plot_ui.line(line.name("Line with fill"));
             plot_ui.polygon(polygon.name("Convex polygon"));
@@ -617,16 +624,22 @@ impl ItemsDemo {
 // ----------------------------------------------------------------------------
 
 #[derive(Default, PartialEq)]
-struct InteractionDemo {}
+struct InteractionDemo {
+    measure: bool,
+}
 
 impl InteractionDemo {
-    #[allow(clippy::unused_self)]
     fn ui(&mut self, ui: &mut Ui) -> Response {
-        let plot = Plot::new("interaction_demo").height(300.0);
+        ui.checkbox(&mut self.measure, "Measure by dragging");
+        let plot = Plot::new("interaction_demo")
+            .height(300.0)
+            .measure(self.measure);
 
-        let InnerResponse {
+        let plot::PlotResponse {
             response,
             inner: (screen_pos, pointer_coordinate, pointer_coordinate_drag_delta, bounds, hovered),
+            measurement,
+            ..
         } = plot.show(ui, |plot_ui| {
             (
                 plot_ui.screen_from_plot(PlotPoint::new(0.0, 0.0)),
@@ -661,6 +674,10 @@ impl InteractionDemo {
             "pointer coordinate drag delta: {}",
             coordinate_text
         ));
+        if let Some(measurement) = measurement {
+            let [dx, dy] = measurement.delta();
+            ui.label(format!("measured: Δx: {:.02}, Δy: {:.02}", dx, dy));
+        }
 
         response
     }
@@ -685,12 +702,14 @@ impl Default for Chart {
 struct ChartsDemo {
     chart: Chart,
     vertical: bool,
+    grouped: bool,
 }
 
 impl Default for ChartsDemo {
     fn default() -> Self {
         Self {
             vertical: true,
+            grouped: false,
             chart: Chart::default(),
         }
     }
@@ -709,6 +728,9 @@ impl ChartsDemo {
             ui.selectable_value(&mut self.vertical, true, "Vertical");
             ui.selectable_value(&mut self.vertical, false, "Horizontal");
         });
+        if self.chart == Chart::StackedBars {
+            ui.checkbox(&mut self.grouped, "Side by side");
+        }
         match self.chart {
             Chart::GaussBars => self.bar_gauss(ui),
             Chart::StackedBars => self.bar_stacked(ui),
@@ -745,64 +767,42 @@ impl ChartsDemo {
     }
 
     fn bar_stacked(&self, ui: &mut Ui) -> Response {
-        let mut chart1 = BarChart::new(vec![
-            Bar::new(0.5, 1.0).name("Day 1"),
-            Bar::new(1.5, 3.0).name("Day 2"),
-            Bar::new(2.5, 1.0).name("Day 3"),
-            Bar::new(3.5, 2.0).name("Day 4"),
-            Bar::new(4.5, 4.0).name("Day 5"),
-        ])
-        .width(0.7)
-        .name("Set 1");
-
-        let mut chart2 = BarChart::new(vec![
-            Bar::new(0.5, 1.0),
-            Bar::new(1.5, 1.5),
-            Bar::new(2.5, 0.1),
-            Bar::new(3.5, 0.7),
-            Bar::new(4.5, 0.8),
-        ])
-        .width(0.7)
-        .name("Set 2")
-        .stack_on(&[&chart1]);
-
-        let mut chart3 = BarChart::new(vec![
-            Bar::new(0.5, -0.5),
-            Bar::new(1.5, 1.0),
-            Bar::new(2.5, 0.5),
-            Bar::new(3.5, -1.0),
-            Bar::new(4.5, 0.3),
-        ])
-        .width(0.7)
-        .name("Set 3")
-        .stack_on(&[&chart1, &chart2]);
-
-        let mut chart4 = BarChart::new(vec![
-            Bar::new(0.5, 0.5),
-            Bar::new(1.5, 1.0),
-            Bar::new(2.5, 0.5),
-            Bar::new(3.5, -0.5),
-            Bar::new(4.5, -0.5),
-        ])
-        .width(0.7)
-        .name("Set 4")
-        .stack_on(&[&chart1, &chart2, &chart3]);
-
-        if !self.vertical {
-            chart1 = chart1.horizontal();
-            chart2 = chart2.horizontal();
-            chart3 = chart3.horizontal();
-            chart4 = chart4.horizontal();
-        }
+        let sets = [
+            [1.0, 3.0, 1.0, 2.0, 4.0],
+            [1.0, 1.5, 0.1, 0.7, 0.8],
+            [-0.5, 1.0, 0.5, -1.0, 0.3],
+            [0.5, 1.0, 0.5, -0.5, -0.5],
+        ];
+        let charts = sets.iter().enumerate().map(|(set, values)| {
+            let bars = values
+                .iter()
+                .enumerate()
+                .map(|(day, &value)| {
+                    Bar::new(day as f64 + 0.5, value).name(format!("Day {}", day + 1))
+                })
+                .collect();
+            let chart = BarChart::new(bars)
+                .width(0.7)
+                .name(format!("Set {}", set + 1));
+            if self.vertical {
+                chart
+            } else {
+                chart.horizontal()
+            }
+        });
+        let charts = if self.grouped {
+            BarChart::group(charts.collect(), 0.8)
+        } else {
+            BarChart::stack(charts.collect())
+        };
 
         Plot::new("Stacked Bar Chart Demo")
             .legend(Legend::default())
             .data_aspect(1.0)
             .show(ui, |plot_ui| {
-                plot_ui.bar_chart(chart1);
-                plot_ui.bar_chart(chart2);
-                plot_ui.bar_chart(chart3);
-                plot_ui.bar_chart(chart4);
+                for chart in charts {
+                    plot_ui.bar_chart(chart);
+                }
             })
             .response
     }
@@ -852,6 +852,65 @@ impl ChartsDemo {
 
 // ----------------------------------------------------------------------------
 
+#[derive(PartialEq)]
+struct PolarDemo {
+    radar: bool,
+    clockwise: bool,
+}
+
+impl Default for PolarDemo {
+    fn default() -> Self {
+        Self {
+            radar: true,
+            clockwise: false,
+        }
+    }
+}
+
+impl PolarDemo {
+    fn ui(&mut self, ui: &mut Ui) -> Response {
+        ui.horizontal(|ui| {
+            ui.checkbox(&mut self.radar, "Radar chart");
+            ui.checkbox(&mut self.clockwise, "Clockwise");
+        });
+        ui.label("Drag to rotate, and zoom to change the radius.");
+
+        let mut plot = PolarPlot::new("polar_demo")
+            .legend(Legend::default())
+            .clockwise(self.clockwise);
+        if self.radar {
+            plot = plot.spokes(["Speed", "Power", "Range", "Armor", "Luck", "Stealth"]);
+        }
+        let radar = self.radar;
+        plot.show(ui, |polar_ui| {
+            if radar {
+                let knight = [3.0, 4.5, 1.5, 5.0, 2.0, 1.0];
+                let rogue = [4.5, 2.0, 3.0, 1.5, 4.0, 5.0];
+                polar_ui.polygon(
+                    Polygon::new(PlotPoints::from_radii(&knight))
+                        .name("Knight")
+                        .fill_alpha(0.3),
+                );
+                polar_ui.polygon(
+                    Polygon::new(PlotPoints::from_radii(&rogue))
+                        .name("Rogue")
+                        .fill_alpha(0.3),
+                );
+            } else {
+                let cardioid = PlotPoints::from_explicit_callback(|t| 1.0 + t.cos(), .., 256);
+                polar_ui.line(Line::new(cardioid).name("Cardioid"));
+                let rose = PlotPoints::from_explicit_callback(|t| (3.0 * t).cos().abs(), .., 256);
+                polar_ui.line(Line::new(rose).name("Rose"));
+                let samples: PlotPoints = (0..12).map(|i| [i as f64 * TAU / 12.0, 1.5]).collect();
+                polar_ui.points(Points::new(samples).radius(3.0).name("Samples"));
+            }
+        })
+        .response
+    }
+}
+
+// ----------------------------------------------------------------------------
+
 #[derive(PartialEq, Eq)]
 enum Panel {
     Lines,
@@ -862,6 +921,7 @@ enum Panel {
     Interaction,
     CustomAxes,
     LinkedAxes,
+    Polar,
 }
 
 impl Default for Panel {
@@ -882,6 +942,7 @@ pub struct PlotDemo {
     interaction_demo: InteractionDemo,
     custom_axes_demo: CustomAxisDemo,
     linked_axes_demo: LinkedAxisDemo,
+    polar_demo: PolarDemo,
     open_panel: Panel,
 }
 
@@ -906,7 +967,9 @@ impl super::View for PlotDemo {
             egui::reset_button(ui, self);
             ui.collapsing("Instructions", |ui| {
                 ui.label("Pan by dragging, or scroll (+ shift = horizontal).");
-                ui.label("Box zooming: Right click to zoom in and zoom out using a selection.");
+                ui.label(
+                    "Box zooming: Right click, or shift + drag, to zoom in using a selection.",
+                );
                 if cfg!(target_arch = "wasm32") {
                     ui.label("Zoom with ctrl / ⌘ + pointer wheel, or with pinch gesture.");
                 } else if cfg!(target_os = "macos") {
@@ -914,7 +977,7 @@ impl super::View for PlotDemo {
                 } else {
                     ui.label("Zoom with ctrl + scroll.");
                 }
-                ui.label("Reset view with double-click.");
+                ui.label("Double-click or backspace to undo a box zoom, or to reset the view.");
                 ui.add(crate::egui_github_link_file!());
             });
         });
@@ -928,6 +991,7 @@ impl super::View for PlotDemo {
             ui.selectable_value(&mut self.open_panel, Panel::Interaction, "Interaction");
             ui.selectable_value(&mut self.open_panel, Panel::CustomAxes, "Custom Axes");
             ui.selectable_value(&mut self.open_panel, Panel::LinkedAxes, "Linked Axes");
+            ui.selectable_value(&mut self.open_panel, Panel::Polar, "Polar");
         });
         ui.separator();
 
@@ -956,6 +1020,9 @@ impl super::View for PlotDemo {
             Panel::LinkedAxes => {
                 self.linked_axes_demo.ui(ui);
             }
+            Panel::Polar => {
+                self.polar_demo.ui(ui);
+            }
         }
     }
 }
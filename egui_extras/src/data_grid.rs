@@ -0,0 +1,927 @@
+//! A spreadsheet-like grid of editable cells, with a frozen header row and (optionally) first column.
+
+use std::ops::RangeInclusive;
+
+use egui::{
+    pos2, vec2, Align, Align2, Event, Id, Key, Layout, Modifiers, Pos2, Rect, Response, ScrollArea,
+    Sense, TextEdit, TextStyle, Ui,
+};
+
+/// How the cells of a [`GridColumn`] can be edited.
+#[derive(Clone, Debug, PartialEq)]
+pub enum CellEditor {
+    /// The cells can be selected and copied, but not changed.
+    ReadOnly,
+
+    /// Edit the text of the cell.
+    Text,
+
+    /// Edit a number. Anything that isn't a number is rejected.
+    ///
+    /// The number is shown and parsed according to [`egui::Context::locale_format`],
+    /// but handed to [`GridData::set_cell_text`] the way Rust writes numbers, e.g. `1234.5`.
+    Numeric,
+
+    /// Pick one of these options.
+    Combo(Vec<String>),
+}
+
+/// A column of a [`DataGrid`].
+#[derive(Clone, Debug)]
+pub struct GridColumn {
+    title: String,
+    width: f32,
+    editor: CellEditor,
+}
+
+impl GridColumn {
+    /// A column of text cells.
+    pub fn new(title: impl Into<String>, width: f32) -> Self {
+        Self {
+            title: title.into(),
+            width,
+            editor: CellEditor::Text,
+        }
+    }
+
+    /// How the cells of this column can be edited. Default: [`CellEditor::Text`].
+    pub fn editor(mut self, editor: CellEditor) -> Self {
+        self.editor = editor;
+        self
+    }
+}
+
+/// The rows shown in a [`DataGrid`].
+///
+/// Implemented for `Vec<Vec<String>>`, i.e. a list of rows of cells.
+pub trait GridData {
+    /// How many rows there are, not counting the header.
+    fn num_rows(&self) -> usize;
+
+    /// The text shown in (and copied from) a cell.
+    fn cell_text(&self, row: usize, column: usize) -> String;
+
+    /// Change a cell after the user edited or pasted into it.
+    ///
+    /// Return `false` to reject the new text.
+    fn set_cell_text(&mut self, row: usize, column: usize, text: &str) -> bool;
+}
+
+impl GridData for Vec<Vec<String>> {
+    fn num_rows(&self) -> usize {
+        self.len()
+    }
+
+    fn cell_text(&self, row: usize, column: usize) -> String {
+        self[row].get(column).cloned().unwrap_or_default()
+    }
+
+    fn set_cell_text(&mut self, row: usize, column: usize, text: &str) -> bool {
+        let row = &mut self[row];
+        if row.len() <= column {
+            row.resize(column + 1, String::new());
+        }
+        row[column] = text.to_owned();
+        true
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Cell {
+    row: usize,
+    column: usize,
+}
+
+#[derive(Clone)]
+struct Editing {
+    cell: Cell,
+    text: String,
+    /// Give the editor keyboard focus when it is shown.
+    request_focus: bool,
+}
+
+#[derive(Clone, Default)]
+struct DataGridState {
+    /// The current cell.
+    cursor: Option<Cell>,
+    /// The other corner of the selected range.
+    anchor: Option<Cell>,
+    editing: Option<Editing>,
+    scroll_to_cursor: bool,
+    /// Give the keyboard focus back to the grid next frame, after an edit.
+    refocus: bool,
+}
+
+impl DataGridState {
+    fn selection(&self) -> Option<(RangeInclusive<usize>, RangeInclusive<usize>)> {
+        let cursor = self.cursor?;
+        let anchor = self.anchor.unwrap_or(cursor);
+        Some((
+            cursor.row.min(anchor.row)..=cursor.row.max(anchor.row),
+            cursor.column.min(anchor.column)..=cursor.column.max(anchor.column),
+        ))
+    }
+
+    fn is_selected(&self, cell: Cell) -> bool {
+        self.selection().map_or(false, |(rows, columns)| {
+            rows.contains(&cell.row) && columns.contains(&cell.column)
+        })
+    }
+
+    fn set_cursor(&mut self, cell: Cell, extend_selection: bool) {
+        if !extend_selection || self.cursor.is_none() {
+            self.anchor = Some(cell);
+        }
+        self.cursor = Some(cell);
+        self.scroll_to_cursor = true;
+    }
+
+    /// Forget cells that no longer exist.
+    fn clamp(&mut self, num_rows: usize, num_columns: usize) {
+        let exists = |cell: &Cell| cell.row < num_rows && cell.column < num_columns;
+        if !self.cursor.as_ref().map_or(true, exists) || !self.anchor.as_ref().map_or(true, exists)
+        {
+            self.cursor = None;
+            self.anchor = None;
+        }
+        if !self.editing.as_ref().map_or(true, |e| exists(&e.cell)) {
+            self.editing = None;
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// A spreadsheet-like grid of editable cells, e.g. for a watchlist or a table of parameters.
+///
+/// * Click a cell to select it, and shift-click or drag to select a range.
+/// * Move around with the arrow keys, tab, home/end and page up/down. Hold shift to select.
+/// * Edit a cell by double-clicking it, pressing enter or F2, or just typing.
+///   Enter and tab save the edit and move on, escape cancels it.
+/// * Copy, cut and paste ranges as tab-separated values, which spreadsheet applications understand.
+///   Delete clears the selected cells.
+///
+/// The header row always stays in view, and so can the first column, see [`Self::freeze_first_column`].
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_extras::{CellEditor, DataGrid, GridColumn};
+///
+/// let mut rows = vec![
+///     vec!["AAPL".to_owned(), "150.2".to_owned(), "Buy".to_owned()],
+///     vec!["MSFT".to_owned(), "280.0".to_owned(), "Hold".to_owned()],
+/// ];
+/// let actions = vec!["Buy".to_owned(), "Hold".to_owned(), "Sell".to_owned()];
+///
+/// let response = DataGrid::new("watchlist")
+///     .column(GridColumn::new("Symbol", 80.0))
+///     .column(GridColumn::new("Price", 80.0).editor(CellEditor::Numeric))
+///     .column(GridColumn::new("Action", 80.0).editor(CellEditor::Combo(actions)))
+///     .freeze_first_column(true)
+///     .show(ui, &mut rows);
+/// if response.changed() {
+///     // Save the rows.
+/// }
+/// # });
+/// ```
+pub struct DataGrid {
+    id_source: Id,
+    columns: Vec<GridColumn>,
+    row_height: Option<f32>,
+    freeze_first_column: bool,
+    striped: bool,
+}
+
+impl DataGrid {
+    pub fn new(id_source: impl std::hash::Hash) -> Self {
+        Self {
+            id_source: Id::new(id_source),
+            columns: Vec::new(),
+            row_height: None,
+            freeze_first_column: false,
+            striped: true,
+        }
+    }
+
+    /// Add a column.
+    pub fn column(mut self, column: GridColumn) -> Self {
+        self.columns.push(column);
+        self
+    }
+
+    /// Add several columns.
+    pub fn columns(mut self, columns: impl IntoIterator<Item = GridColumn>) -> Self {
+        self.columns.extend(columns);
+        self
+    }
+
+    /// The height of each row, including the header. Default: the height of a button.
+    pub fn row_height(mut self, row_height: f32) -> Self {
+        self.row_height = Some(row_height);
+        self
+    }
+
+    /// Keep the first column in view when scrolling horizontally. Default: `false`.
+    pub fn freeze_first_column(mut self, freeze: bool) -> Self {
+        self.freeze_first_column = freeze;
+        self
+    }
+
+    /// Alternate the background of the rows. Default: `true`.
+    pub fn striped(mut self, striped: bool) -> Self {
+        self.striped = striped;
+        self
+    }
+
+    /// Show the grid, using up the available space.
+    ///
+    /// The response is [`Response::changed`] when a cell was changed.
+    pub fn show(self, ui: &mut Ui, data: &mut dyn GridData) -> Response {
+        let id = ui.make_persistent_id(self.id_source);
+        let mut state = ui.data().get_temp::<DataGridState>(id).unwrap_or_default();
+
+        let num_rows = data.num_rows();
+        let num_columns = self.columns.len();
+        state.clamp(num_rows, num_columns);
+
+        let row_height = self
+            .row_height
+            .unwrap_or_else(|| ui.spacing().interact_size.y);
+        let column_x: Vec<f32> = self
+            .columns
+            .iter()
+            .scan(0.0, |x, column| {
+                let left = *x;
+                *x += column.width;
+                Some(left)
+            })
+            .collect();
+        let total_width = self.columns.iter().map(|column| column.width).sum::<f32>();
+        let frozen_width = match self.columns.first() {
+            Some(column) if self.freeze_first_column => column.width,
+            _ => 0.0,
+        };
+
+        let mut changed = false;
+
+        if std::mem::take(&mut state.refocus) {
+            ui.memory().request_focus(id);
+        }
+        if ui.memory().has_focus(id) && state.editing.is_none() {
+            // Keep tab from moving the focus out of the grid:
+            ui.memory().lock_focus(id, true);
+            let page_rows = ((ui.available_height() / row_height) as usize).saturating_sub(2);
+            changed |= self.keyboard_and_clipboard(ui, &mut state, data, page_rows);
+        }
+
+        let output = ScrollArea::both()
+            .id_source(id)
+            .auto_shrink([false; 2])
+            .show_viewport(ui, |ui, viewport| {
+                let origin = ui.min_rect().min;
+                ui.set_min_size(vec2(total_width, row_height * (num_rows + 1) as f32));
+
+                let visible = viewport.translate(origin.to_vec2());
+                let clip = ui.clip_rect();
+                let body_clip =
+                    Rect::from_min_max(visible.min + vec2(frozen_width, row_height), visible.max)
+                        .intersect(clip);
+
+                let cell_rect = |cell: Cell| {
+                    let mut x = origin.x + column_x[cell.column];
+                    if self.freeze_first_column && cell.column == 0 {
+                        x = visible.left();
+                    }
+                    let y = origin.y + row_height * (cell.row + 1) as f32;
+                    Rect::from_min_size(
+                        pos2(x, y),
+                        vec2(self.columns[cell.column].width, row_height),
+                    )
+                };
+                let cell_at = |pos: Pos2| {
+                    if pos.y < visible.top() + row_height {
+                        return None; // header
+                    }
+                    let row = ((pos.y - origin.y) / row_height).floor() as usize;
+                    let column = if pos.x < visible.left() + frozen_width {
+                        0
+                    } else {
+                        column_x.iter().rposition(|&x| origin.x + x <= pos.x)?
+                    };
+                    (1..=num_rows)
+                        .contains(&row)
+                        .then(|| Cell {
+                            row: row - 1,
+                            column,
+                        })
+                        .filter(|cell| pos.x < cell_rect(*cell).right())
+                };
+
+                if std::mem::take(&mut state.scroll_to_cursor) {
+                    if let Some(cursor) = state.cursor {
+                        // Make sure the frozen header and column don't cover it:
+                        let mut rect = cell_rect(cursor);
+                        rect.min.y -= row_height;
+                        if cursor.column > 0 {
+                            rect.min.x = origin.x + column_x[cursor.column] - frozen_width;
+                        }
+                        ui.scroll_to_rect(rect, None);
+                    }
+                }
+
+                // The editor must come before the grid itself, so that it gets the clicks:
+                if state.editing.is_some() {
+                    changed |= self.cell_editor(ui, id, &mut state, data, &cell_rect, body_clip);
+                }
+
+                let response = ui.interact(visible.intersect(clip), id, Sense::click_and_drag());
+                if let Some(cell) = response.interact_pointer_pos().and_then(cell_at) {
+                    if response.drag_started() {
+                        response.request_focus();
+                        let extend_selection = ui.input().modifiers.shift;
+                        state.set_cursor(cell, extend_selection);
+                    } else if response.dragged() {
+                        state.set_cursor(cell, true);
+                    }
+                    if response.double_clicked() {
+                        self.start_editing(ui, id, &mut state, data, cell, None);
+                    }
+                }
+
+                // --- Painting ---
+                let visuals = ui.visuals();
+                let font_id = TextStyle::Body.resolve(ui.style());
+                let padding = ui.spacing().button_padding.x;
+                let grid_stroke = visuals.widgets.noninteractive.bg_stroke;
+                let frozen_fill = visuals.widgets.noninteractive.bg_fill;
+                let selection_fill = visuals.selection.bg_fill.linear_multiply(0.4);
+                let locale_format = ui.ctx().locale_format();
+
+                let rows = ((viewport.min.y / row_height) as usize).saturating_sub(1)
+                    ..((viewport.max.y / row_height).ceil() as usize).min(num_rows);
+                let paint_cell = |cell: Cell, clip_rect: Rect, fill: Option<egui::Color32>| {
+                    let rect = cell_rect(cell);
+                    let painter = ui.painter().with_clip_rect(clip_rect.intersect(rect));
+                    if let Some(fill) = fill {
+                        painter.rect_filled(rect, 0.0, fill);
+                    }
+                    if self.striped && cell.row % 2 == 1 {
+                        painter.rect_filled(rect, 0.0, visuals.faint_bg_color);
+                    }
+                    if state.is_selected(cell) {
+                        painter.rect_filled(rect, 0.0, selection_fill);
+                    }
+                    painter.rect_stroke(rect, 0.0, grid_stroke);
+                    let editing = state.editing.as_ref().map_or(false, |e| e.cell == cell);
+                    if !editing {
+                        let text = data.cell_text(cell.row, cell.column);
+                        if self.columns[cell.column].editor == CellEditor::Numeric {
+                            painter.text(
+                                rect.right_center() - vec2(padding, 0.0),
+                                Align2::RIGHT_CENTER,
+                                locale_format.format_number(&text),
+                                font_id.clone(),
+                                visuals.text_color(),
+                            );
+                        } else {
+                            painter.text(
+                                rect.left_center() + vec2(padding, 0.0),
+                                Align2::LEFT_CENTER,
+                                text,
+                                font_id.clone(),
+                                visuals.text_color(),
+                            );
+                        }
+                    }
+                    if state.cursor == Some(cell) {
+                        painter.rect_stroke(rect.shrink(1.0), 0.0, visuals.selection.stroke);
+                    }
+                };
+
+                let first_body_column = if self.freeze_first_column { 1 } else { 0 };
+                for row in rows.clone() {
+                    for column in first_body_column..num_columns {
+                        let cell = Cell { row, column };
+                        let rect = cell_rect(cell);
+                        if rect.right() >= visible.left() && rect.left() <= visible.right() {
+                            paint_cell(cell, body_clip, None);
+                        }
+                    }
+                }
+                if self.freeze_first_column && num_columns > 0 {
+                    let column_clip = Rect::from_min_max(
+                        pos2(visible.left(), visible.top() + row_height),
+                        pos2(visible.left() + frozen_width, visible.bottom()),
+                    )
+                    .intersect(clip);
+                    for row in rows {
+                        paint_cell(Cell { row, column: 0 }, column_clip, Some(frozen_fill));
+                    }
+                }
+
+                // The header row:
+                let header_rect =
+                    Rect::from_min_size(visible.min, vec2(visible.width(), row_height));
+                let header_clip = header_rect.intersect(clip);
+                ui.painter()
+                    .with_clip_rect(header_clip)
+                    .rect_filled(header_rect, 0.0, frozen_fill);
+                let header_body_clip =
+                    Rect::from_min_max(header_clip.min + vec2(frozen_width, 0.0), header_clip.max);
+                for (column, grid_column) in self.columns.iter().enumerate() {
+                    let mut rect = cell_rect(Cell { row: 0, column });
+                    rect = rect.translate(vec2(0.0, header_rect.top() - rect.top()));
+                    let frozen = self.freeze_first_column && column == 0;
+                    let column_clip = if frozen {
+                        header_clip
+                    } else {
+                        header_body_clip
+                    };
+                    let painter = ui.painter().with_clip_rect(column_clip.intersect(rect));
+                    painter.rect_stroke(rect, 0.0, grid_stroke);
+                    let (pos, align) = if grid_column.editor == CellEditor::Numeric {
+                        (
+                            rect.right_center() - vec2(padding, 0.0),
+                            Align2::RIGHT_CENTER,
+                        )
+                    } else {
+                        (rect.left_center() + vec2(padding, 0.0), Align2::LEFT_CENTER)
+                    };
+                    painter.text(
+                        pos,
+                        align,
+                        &grid_column.title,
+                        font_id.clone(),
+                        visuals.strong_text_color(),
+                    );
+                }
+
+                response
+            });
+
+        let mut response = output.inner;
+        ui.data().insert_temp(id, state);
+        if changed {
+            response.mark_changed();
+        }
+        response
+    }
+
+    /// Returns `true` if a cell was changed.
+    fn keyboard_and_clipboard(
+        &self,
+        ui: &Ui,
+        state: &mut DataGridState,
+        data: &mut dyn GridData,
+        page_rows: usize,
+    ) -> bool {
+        let num_rows = data.num_rows();
+        let num_columns = self.columns.len();
+        if num_rows == 0 || num_columns == 0 {
+            return false;
+        }
+        let id = ui.make_persistent_id(self.id_source);
+        let cursor = state.cursor.unwrap_or(Cell { row: 0, column: 0 });
+
+        let mut changed = false;
+        let mut consumed = Vec::new();
+        let events = ui.input().events.clone();
+        for (i, event) in events.iter().enumerate() {
+            match event {
+                Event::Key {
+                    key,
+                    pressed: true,
+                    modifiers,
+                } => {
+                    let extend = modifiers.shift;
+                    let last_row = num_rows - 1;
+                    let last_column = num_columns - 1;
+                    let up = |rows: usize| Cell {
+                        row: cursor.row.saturating_sub(rows),
+                        ..cursor
+                    };
+                    let down = |rows: usize| Cell {
+                        row: (cursor.row + rows).min(last_row),
+                        ..cursor
+                    };
+                    let handled = match key {
+                        Key::ArrowUp => {
+                            state.set_cursor(up(1), extend);
+                            true
+                        }
+                        Key::ArrowDown => {
+                            state.set_cursor(down(1), extend);
+                            true
+                        }
+                        Key::ArrowLeft => {
+                            let column = cursor.column.saturating_sub(1);
+                            state.set_cursor(Cell { column, ..cursor }, extend);
+                            true
+                        }
+                        Key::ArrowRight => {
+                            let column = (cursor.column + 1).min(last_column);
+                            state.set_cursor(Cell { column, ..cursor }, extend);
+                            true
+                        }
+                        Key::PageUp => {
+                            state.set_cursor(up(page_rows.max(1)), extend);
+                            true
+                        }
+                        Key::PageDown => {
+                            state.set_cursor(down(page_rows.max(1)), extend);
+                            true
+                        }
+                        Key::Home => {
+                            let row = if modifiers.command { 0 } else { cursor.row };
+                            state.set_cursor(Cell { row, column: 0 }, extend);
+                            true
+                        }
+                        Key::End => {
+                            let row = if modifiers.command {
+                                last_row
+                            } else {
+                                cursor.row
+                            };
+                            let column = last_column;
+                            state.set_cursor(Cell { row, column }, extend);
+                            true
+                        }
+                        Key::Tab => {
+                            state.set_cursor(
+                                next_cell(cursor, modifiers.shift, self, num_rows),
+                                false,
+                            );
+                            true
+                        }
+                        Key::Enter | Key::F2 => {
+                            self.start_editing(ui, id, state, data, cursor, None);
+                            true
+                        }
+                        Key::Escape => {
+                            state.anchor = state.cursor;
+                            true
+                        }
+                        Key::A if modifiers.command => {
+                            state.anchor = Some(Cell { row: 0, column: 0 });
+                            state.cursor = Some(Cell {
+                                row: last_row,
+                                column: last_column,
+                            });
+                            true
+                        }
+                        Key::Delete | Key::Backspace => {
+                            changed |= self.clear_selection(ui, state, data);
+                            true
+                        }
+                        _ => false,
+                    };
+                    if handled {
+                        consumed.push(i);
+                    }
+                }
+                Event::Text(text) => {
+                    let editor = &self.columns[cursor.column].editor;
+                    if state.editing.is_none()
+                        && matches!(editor, CellEditor::Text | CellEditor::Numeric)
+                        && !text.trim().is_empty()
+                    {
+                        // Start with an empty editor that will get this event:
+                        self.start_editing(ui, id, state, data, cursor, Some(String::new()));
+                    }
+                }
+                Event::Copy => {
+                    ui.output().copied_text = self.selection_as_tsv(state, data);
+                }
+                Event::Cut => {
+                    ui.output().copied_text = self.selection_as_tsv(state, data);
+                    changed |= self.clear_selection(ui, state, data);
+                }
+                Event::Paste(text) => {
+                    changed |= self.paste_tsv(ui, state, data, text);
+                }
+                _ => {}
+            }
+        }
+
+        if !consumed.is_empty() {
+            let mut i = 0;
+            ui.input_mut().events.retain(|_| {
+                let keep = !consumed.contains(&i);
+                i += 1;
+                keep
+            });
+        }
+        changed
+    }
+
+    fn start_editing(
+        &self,
+        ui: &Ui,
+        id: Id,
+        state: &mut DataGridState,
+        data: &dyn GridData,
+        cell: Cell,
+        text: Option<String>,
+    ) {
+        let text = match &self.columns[cell.column].editor {
+            CellEditor::ReadOnly => return,
+            CellEditor::Text | CellEditor::Combo(_) => {
+                text.unwrap_or_else(|| data.cell_text(cell.row, cell.column))
+            }
+            CellEditor::Numeric => text.unwrap_or_else(|| {
+                let text = data.cell_text(cell.row, cell.column);
+                ui.ctx().locale_format().format_number(&text)
+            }),
+        };
+        if matches!(self.columns[cell.column].editor, CellEditor::Combo(_)) {
+            ui.memory().open_popup(combo_popup_id(id));
+        }
+        state.set_cursor(cell, false);
+        state.editing = Some(Editing {
+            cell,
+            text,
+            request_focus: true,
+        });
+    }
+
+    /// Show the editor of the cell being edited. Returns `true` if the cell was changed.
+    fn cell_editor(
+        &self,
+        ui: &mut Ui,
+        id: Id,
+        state: &mut DataGridState,
+        data: &mut dyn GridData,
+        cell_rect: impl Fn(Cell) -> Rect,
+        body_clip: Rect,
+    ) -> bool {
+        let mut editing = match state.editing.take() {
+            Some(editing) => editing,
+            None => return false,
+        };
+        let cell = editing.cell;
+        let rect = cell_rect(cell);
+        let mut cell_ui = ui.child_ui(rect, Layout::left_to_right(Align::Center));
+        if cell.column > 0 || !self.freeze_first_column {
+            cell_ui.set_clip_rect(body_clip.intersect(rect));
+        }
+
+        /// What ended the edit.
+        enum Finish {
+            Save { next: Option<Cell> },
+            Cancel,
+        }
+        let mut finish = None;
+        // Set when the edit ended with the keyboard, as opposed to by clicking somewhere else:
+        let mut refocus = false;
+
+        match &self.columns[cell.column].editor {
+            CellEditor::ReadOnly => finish = Some(Finish::Cancel),
+            CellEditor::Text | CellEditor::Numeric => {
+                let edit_id = id.with("editor");
+                if std::mem::take(&mut editing.request_focus) {
+                    ui.memory().request_focus(edit_id);
+                }
+                if ui.memory().has_focus(edit_id) {
+                    let num_rows = data.num_rows();
+                    let mut input = ui.input_mut();
+                    refocus = true;
+                    if input.consume_key(Modifiers::NONE, Key::Escape) {
+                        finish = Some(Finish::Cancel);
+                    } else if input.consume_key(Modifiers::NONE, Key::Enter) {
+                        let row = (cell.row + 1).min(num_rows.saturating_sub(1));
+                        let next = Some(Cell { row, ..cell });
+                        finish = Some(Finish::Save { next });
+                    } else if input.consume_key(Modifiers::NONE, Key::Tab) {
+                        let next = Some(next_cell(cell, false, self, num_rows));
+                        finish = Some(Finish::Save { next });
+                    } else if input.consume_key(Modifiers::SHIFT, Key::Tab) {
+                        let next = Some(next_cell(cell, true, self, num_rows));
+                        finish = Some(Finish::Save { next });
+                    } else {
+                        refocus = false;
+                    }
+                }
+                if finish.is_none() {
+                    let response = cell_ui.add(
+                        TextEdit::singleline(&mut editing.text)
+                            .id(edit_id)
+                            .desired_width(f32::INFINITY),
+                    );
+                    if response.lost_focus() {
+                        // Clicked somewhere else
+                        finish = Some(Finish::Save { next: None });
+                    }
+                }
+            }
+            CellEditor::Combo(options) => {
+                let popup_id = combo_popup_id(id);
+                let response = cell_ui.interact(rect, id.with("combo"), Sense::click());
+                if response.clicked() {
+                    ui.memory().toggle_popup(popup_id);
+                }
+                let visuals = cell_ui.style().interact_selectable(&response, true);
+                let painter = cell_ui.painter();
+                painter.rect_filled(rect, 0.0, visuals.bg_fill);
+                let font_id = TextStyle::Body.resolve(cell_ui.style());
+                let padding = cell_ui.spacing().button_padding.x;
+                painter.text(
+                    rect.left_center() + vec2(padding, 0.0),
+                    Align2::LEFT_CENTER,
+                    &editing.text,
+                    font_id.clone(),
+                    visuals.text_color(),
+                );
+                painter.text(
+                    rect.right_center() - vec2(padding, 0.0),
+                    Align2::RIGHT_CENTER,
+                    "⏷",
+                    font_id,
+                    visuals.text_color(),
+                );
+
+                let picked = egui::popup::popup_below_widget(&cell_ui, popup_id, &response, |ui| {
+                    let mut picked = false;
+                    for option in options {
+                        picked |= ui
+                            .selectable_value(&mut editing.text, option.clone(), option)
+                            .clicked();
+                    }
+                    picked
+                });
+                if picked == Some(true) {
+                    refocus = true;
+                    finish = Some(Finish::Save { next: None });
+                } else if !ui.memory().is_popup_open(popup_id) {
+                    finish = Some(Finish::Cancel);
+                }
+            }
+        }
+
+        match finish {
+            None => {
+                state.editing = Some(editing);
+                false
+            }
+            Some(finish) => {
+                // Give the keyboard back to the grid. Not this frame, or a tab key press would move it on.
+                state.refocus = refocus;
+                match finish {
+                    Finish::Cancel => false,
+                    Finish::Save { next } => {
+                        if let Some(next) = next {
+                            state.set_cursor(next, false);
+                        }
+                        self.set_cell(ui, data, cell, &editing.text)
+                    }
+                }
+            }
+        }
+    }
+
+    /// Set a cell from edited, pasted or cleared text, if its editor accepts it.
+    fn set_cell(&self, ui: &Ui, data: &mut dyn GridData, cell: Cell, text: &str) -> bool {
+        if data.cell_text(cell.row, cell.column) == text {
+            return false;
+        }
+        let accepted = match &self.columns[cell.column].editor {
+            CellEditor::ReadOnly => return false,
+            CellEditor::Text => text.to_owned(),
+            CellEditor::Numeric if text.trim().is_empty() => String::new(),
+            CellEditor::Numeric => match ui.ctx().locale_format().parse_number(text) {
+                Some(number) => number.to_string(),
+                None => return false,
+            },
+            CellEditor::Combo(options) => {
+                if !options.iter().any(|option| option == text) {
+                    return false;
+                }
+                text.to_owned()
+            }
+        };
+        data.set_cell_text(cell.row, cell.column, &accepted)
+    }
+
+    fn clear_selection(&self, ui: &Ui, state: &DataGridState, data: &mut dyn GridData) -> bool {
+        let mut changed = false;
+        if let Some((rows, columns)) = state.selection() {
+            for row in rows {
+                for column in columns.clone() {
+                    changed |= self.set_cell(ui, data, Cell { row, column }, "");
+                }
+            }
+        }
+        changed
+    }
+
+    fn selection_as_tsv(&self, state: &DataGridState, data: &dyn GridData) -> String {
+        let (rows, columns) = match state.selection() {
+            Some(selection) => selection,
+            None => return String::new(),
+        };
+        let mut tsv = String::new();
+        for row in rows {
+            let cells: Vec<String> = columns
+                .clone()
+                .map(|column| {
+                    data.cell_text(row, column)
+                        .replace(&['\t', '\n', '\r'][..], " ")
+                })
+                .collect();
+            tsv += &cells.join("\t");
+            tsv.push('\n');
+        }
+        tsv
+    }
+
+    /// Paste tab-separated values, starting at the top left of the selection.
+    ///
+    /// A single value is pasted into every selected cell.
+    fn paste_tsv(
+        &self,
+        ui: &Ui,
+        state: &DataGridState,
+        data: &mut dyn GridData,
+        tsv: &str,
+    ) -> bool {
+        let (rows, columns) = match state.selection() {
+            Some(selection) => selection,
+            None => return false,
+        };
+        let values = parse_tsv(tsv);
+        let mut changed = false;
+        if let [row_values] = values.as_slice() {
+            if let [value] = row_values.as_slice() {
+                for row in rows {
+                    for column in columns.clone() {
+                        changed |= self.set_cell(ui, data, Cell { row, column }, value);
+                    }
+                }
+                return changed;
+            }
+        }
+        for (row, row_values) in (*rows.start()..data.num_rows()).zip(&values) {
+            for (column, value) in (*columns.start()..self.columns.len()).zip(row_values) {
+                changed |= self.set_cell(ui, data, Cell { row, column }, value);
+            }
+        }
+        changed
+    }
+}
+
+/// The cell that tab (or shift-tab) moves to, wrapping around to the next (or previous) row.
+fn next_cell(cell: Cell, backwards: bool, grid: &DataGrid, num_rows: usize) -> Cell {
+    let num_columns = grid.columns.len();
+    let index = cell.row * num_columns + cell.column;
+    let index = if backwards {
+        index.saturating_sub(1)
+    } else {
+        (index + 1).min(num_rows * num_columns - 1)
+    };
+    Cell {
+        row: index / num_columns,
+        column: index % num_columns,
+    }
+}
+
+fn combo_popup_id(grid_id: Id) -> Id {
+    grid_id.with("combo_popup")
+}
+
+fn parse_tsv(tsv: &str) -> Vec<Vec<String>> {
+    tsv.lines()
+        .map(|line| line.split('\t').map(str::to_owned).collect())
+        .collect()
+}
+
+#[test]
+fn test_tsv() {
+    assert_eq!(
+        parse_tsv("a\tb\r\nc\td\n"),
+        vec![vec!["a", "b"], vec!["c", "d"]]
+    );
+
+    let mut rows = vec![
+        vec!["1".to_owned(), "2".to_owned()],
+        vec!["3".to_owned(), "4".to_owned()],
+    ];
+    let grid = DataGrid::new("grid")
+        .column(GridColumn::new("A", 10.0))
+        .column(GridColumn::new("B", 10.0).editor(CellEditor::ReadOnly));
+    let state = DataGridState {
+        cursor: Some(Cell { row: 1, column: 1 }),
+        anchor: Some(Cell { row: 0, column: 0 }),
+        ..Default::default()
+    };
+    assert_eq!(grid.selection_as_tsv(&state, &rows), "1\t2\n3\t4\n");
+
+    let ctx = egui::Context::default();
+    let _ = ctx.run(Default::default(), |ctx| {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            assert!(grid.paste_tsv(ui, &state, &mut rows, "x\ty\nz\tw"));
+        });
+    });
+    // The second column is read-only:
+    assert_eq!(rows, vec![vec!["x", "2"], vec!["z", "4"]]);
+}
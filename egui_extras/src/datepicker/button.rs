@@ -1,5 +1,5 @@
 use super::popup::DatePickerPopup;
-use chrono::{Date, Utc};
+use chrono::{Date, Datelike, Utc};
 use egui::{Area, Button, Frame, Key, Order, RichText, Ui, Widget};
 
 #[derive(Default, Clone)]
@@ -8,6 +8,11 @@ pub(crate) struct DatePickerButtonState {
     pub picker_visible: bool,
 }
 
+/// Shows a date, and will open a date picker popup when clicked.
+///
+/// The built-in strings of the popup can be translated, see [`egui::i18n`].
+/// Their keys are `egui_extras.date_picker.month1` to `month12`, `monday` to `sunday`,
+/// `week`, `cancel`, `save`, and `previous_`/`next_` + `day`, `month` and `year`.
 pub struct DatePickerButton<'a> {
     selection: &'a mut Date<Utc>,
     id_source: Option<&'a str>,
@@ -70,7 +75,12 @@ impl<'a> Widget for DatePickerButton<'a> {
             .get_persisted::<DatePickerButtonState>(id)
             .unwrap_or_default();
 
-        let mut text = RichText::new(format!("{} 📆", self.selection.format("%Y-%m-%d")));
+        let date = ui.ctx().locale_format().format_date(
+            self.selection.year(),
+            self.selection.month(),
+            self.selection.day(),
+        );
+        let mut text = RichText::new(format!("{} 📆", date));
         let visuals = ui.visuals().widgets.open;
         if button_state.picker_visible {
             text = text.color(visuals.text_color());
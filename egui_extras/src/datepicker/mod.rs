@@ -10,20 +10,38 @@ struct Week {
     days: Vec<Date<Utc>>,
 }
 
-fn month_data(year: i32, month: u32) -> Vec<Week> {
+fn to_chrono_weekday(weekday: egui::i18n::Weekday) -> Weekday {
+    match weekday {
+        egui::i18n::Weekday::Monday => Weekday::Mon,
+        egui::i18n::Weekday::Tuesday => Weekday::Tue,
+        egui::i18n::Weekday::Wednesday => Weekday::Wed,
+        egui::i18n::Weekday::Thursday => Weekday::Thu,
+        egui::i18n::Weekday::Friday => Weekday::Fri,
+        egui::i18n::Weekday::Saturday => Weekday::Sat,
+        egui::i18n::Weekday::Sunday => Weekday::Sun,
+    }
+}
+
+fn month_data(year: i32, month: u32, first_weekday: Weekday) -> Vec<Week> {
     let first = Date::from_utc(NaiveDate::from_ymd(year, month, 1), Utc);
     let mut start = first;
-    while start.weekday() != Weekday::Mon {
+    while start.weekday() != first_weekday {
         start = start.checked_sub_signed(Duration::days(1)).unwrap();
     }
     let mut weeks = vec![];
     let mut week = vec![];
-    while start < first || start.month() == first.month() || start.weekday() != Weekday::Mon {
+    while start < first || start.month() == first.month() || start.weekday() != first_weekday {
         week.push(start);
 
-        if start.weekday() == Weekday::Sun {
+        if start.weekday() == first_weekday.pred() {
+            // The ISO week number is the one of the Monday, even if the week starts on another day:
+            let monday = week
+                .iter()
+                .copied()
+                .find(|day| day.weekday() == Weekday::Mon)
+                .unwrap_or(start);
             weeks.push(Week {
-                number: start.iso_week().week() as u8,
+                number: monday.iso_week().week() as u8,
                 days: week.drain(..).collect(),
             });
         }
@@ -1,7 +1,7 @@
 use super::{button::DatePickerButtonState, month_data};
 use crate::{Size, StripBuilder, TableBuilder};
 use chrono::{Date, Datelike, NaiveDate, Utc, Weekday};
-use egui::{Align, Button, Color32, ComboBox, Direction, Id, Layout, RichText, Ui, Vec2};
+use egui::{Align, Button, Color32, ComboBox, Context, Direction, Id, Layout, RichText, Ui, Vec2};
 
 #[derive(Default, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
@@ -35,6 +35,7 @@ pub(crate) struct DatePickerPopup<'a> {
 impl<'a> DatePickerPopup<'a> {
     pub fn draw(&mut self, ui: &mut Ui) {
         let id = ui.make_persistent_id("date_picker");
+        let ctx = ui.ctx().clone();
         let today = chrono::offset::Utc::now().date();
         let mut popup_state = ui
             .memory()
@@ -49,7 +50,8 @@ impl<'a> DatePickerPopup<'a> {
             ui.memory().data.insert_persisted(id, popup_state.clone());
         }
 
-        let weeks = month_data(popup_state.year, popup_state.month);
+        let first_weekday = super::to_chrono_weekday(ctx.locale_format().first_weekday);
+        let weeks = month_data(popup_state.year, popup_state.month, first_weekday);
         let mut close = false;
         let height = 20.0;
         let spacing = 2.0;
@@ -98,14 +100,14 @@ impl<'a> DatePickerPopup<'a> {
                             });
                             strip.cell(|ui| {
                                 ComboBox::from_id_source("date_picker_month")
-                                    .selected_text(month_name(popup_state.month))
+                                    .selected_text(month_name(&ctx, popup_state.month))
                                     .show_ui(ui, |ui| {
                                         for month in 1..=12 {
                                             if ui
                                                 .selectable_value(
                                                     &mut popup_state.month,
                                                     month,
-                                                    month_name(month),
+                                                    month_name(&ctx, month),
                                                 )
                                                 .changed()
                                             {
@@ -150,7 +152,10 @@ impl<'a> DatePickerPopup<'a> {
                                 ui.with_layout(Layout::top_down_justified(Align::Center), |ui| {
                                     if ui
                                         .button("<<<")
-                                        .on_hover_text("substract one year")
+                                        .on_hover_text(ctx.translate(
+                                            "egui_extras.date_picker.previous_year",
+                                            "substract one year",
+                                        ))
                                         .clicked()
                                     {
                                         popup_state.year -= 1;
@@ -164,7 +169,10 @@ impl<'a> DatePickerPopup<'a> {
                                 ui.with_layout(Layout::top_down_justified(Align::Center), |ui| {
                                     if ui
                                         .button("<<")
-                                        .on_hover_text("substract one month")
+                                        .on_hover_text(ctx.translate(
+                                            "egui_extras.date_picker.previous_month",
+                                            "substract one month",
+                                        ))
                                         .clicked()
                                     {
                                         popup_state.month -= 1;
@@ -180,7 +188,14 @@ impl<'a> DatePickerPopup<'a> {
                             });
                             strip.cell(|ui| {
                                 ui.with_layout(Layout::top_down_justified(Align::Center), |ui| {
-                                    if ui.button("<").on_hover_text("substract one day").clicked() {
+                                    if ui
+                                        .button("<")
+                                        .on_hover_text(ctx.translate(
+                                            "egui_extras.date_picker.previous_day",
+                                            "substract one day",
+                                        ))
+                                        .clicked()
+                                    {
                                         popup_state.day -= 1;
                                         if popup_state.day == 0 {
                                             popup_state.month -= 1;
@@ -196,7 +211,14 @@ impl<'a> DatePickerPopup<'a> {
                             });
                             strip.cell(|ui| {
                                 ui.with_layout(Layout::top_down_justified(Align::Center), |ui| {
-                                    if ui.button(">").on_hover_text("add one day").clicked() {
+                                    if ui
+                                        .button(">")
+                                        .on_hover_text(ctx.translate(
+                                            "egui_extras.date_picker.next_day",
+                                            "add one day",
+                                        ))
+                                        .clicked()
+                                    {
                                         popup_state.day += 1;
                                         if popup_state.day > popup_state.last_day_of_month() {
                                             popup_state.day = 1;
@@ -212,7 +234,14 @@ impl<'a> DatePickerPopup<'a> {
                             });
                             strip.cell(|ui| {
                                 ui.with_layout(Layout::top_down_justified(Align::Center), |ui| {
-                                    if ui.button(">>").on_hover_text("add one month").clicked() {
+                                    if ui
+                                        .button(">>")
+                                        .on_hover_text(ctx.translate(
+                                            "egui_extras.date_picker.next_month",
+                                            "add one month",
+                                        ))
+                                        .clicked()
+                                    {
                                         popup_state.month += 1;
                                         if popup_state.month > 12 {
                                             popup_state.month = 1;
@@ -226,7 +255,14 @@ impl<'a> DatePickerPopup<'a> {
                             });
                             strip.cell(|ui| {
                                 ui.with_layout(Layout::top_down_justified(Align::Center), |ui| {
-                                    if ui.button(">>>").on_hover_text("add one year").clicked() {
+                                    if ui
+                                        .button(">>>")
+                                        .on_hover_text(ctx.translate(
+                                            "egui_extras.date_picker.next_year",
+                                            "add one year",
+                                        ))
+                                        .clicked()
+                                    {
                                         popup_state.year += 1;
                                         popup_state.day =
                                             popup_state.day.min(popup_state.last_day_of_month());
@@ -251,14 +287,28 @@ impl<'a> DatePickerPopup<'a> {
                                         ui.with_layout(
                                             Layout::centered_and_justified(Direction::TopDown),
                                             |ui| {
-                                                ui.label("Week");
+                                                ui.label(ctx.translate(
+                                                    "egui_extras.date_picker.week",
+                                                    "Week",
+                                                ));
                                             },
                                         );
                                     });
                                 }
 
-                                //TODO(elwerene): Locale
-                                for name in ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"] {
+                                let day_names = [
+                                    ("egui_extras.date_picker.monday", "Mo"),
+                                    ("egui_extras.date_picker.tuesday", "Tu"),
+                                    ("egui_extras.date_picker.wednesday", "We"),
+                                    ("egui_extras.date_picker.thursday", "Th"),
+                                    ("egui_extras.date_picker.friday", "Fr"),
+                                    ("egui_extras.date_picker.saturday", "Sa"),
+                                    ("egui_extras.date_picker.sunday", "Su"),
+                                ];
+                                let first = first_weekday.num_days_from_monday() as usize;
+                                for i in 0..7 {
+                                    let (key, english) = day_names[(first + i) % 7];
+                                    let name = ctx.translate(key, english);
                                     header.col(|ui| {
                                         ui.with_layout(
                                             Layout::centered_and_justified(Direction::TopDown),
@@ -359,14 +409,22 @@ impl<'a> DatePickerPopup<'a> {
                         strip.empty();
                         strip.cell(|ui| {
                             ui.with_layout(Layout::top_down_justified(Align::Center), |ui| {
-                                if ui.button("Cancel").clicked() {
+                                if ui
+                                    .button(
+                                        ctx.translate("egui_extras.date_picker.cancel", "Cancel"),
+                                    )
+                                    .clicked()
+                                {
                                     close = true;
                                 }
                             });
                         });
                         strip.cell(|ui| {
                             ui.with_layout(Layout::top_down_justified(Align::Center), |ui| {
-                                if ui.button("Save").clicked() {
+                                if ui
+                                    .button(ctx.translate("egui_extras.date_picker.save", "Save"))
+                                    .clicked()
+                                {
                                     *self.selection = Date::from_utc(
                                         NaiveDate::from_ymd(
                                             popup_state.year,
@@ -395,8 +453,8 @@ impl<'a> DatePickerPopup<'a> {
     }
 }
 
-fn month_name(i: u32) -> &'static str {
-    match i {
+fn month_name(ctx: &Context, i: u32) -> String {
+    let english = match i {
         1 => "January",
         2 => "February",
         3 => "March",
@@ -410,5 +468,7 @@ fn month_name(i: u32) -> &'static str {
         11 => "November",
         12 => "December",
         _ => panic!("Unknown month: {}", i),
-    }
+    };
+    ctx.translate(&format!("egui_extras.date_picker.month{}", i), english)
+        .into_owned()
 }
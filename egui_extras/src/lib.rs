@@ -9,6 +9,7 @@
 #![allow(clippy::float_cmp)]
 #![allow(clippy::manual_range_contains)]
 
+mod data_grid;
 #[cfg(feature = "chrono")]
 mod datepicker;
 
@@ -18,6 +19,7 @@ mod sizing;
 mod strip;
 mod table;
 
+pub use crate::data_grid::{CellEditor, DataGrid, GridColumn, GridData};
 #[cfg(feature = "chrono")]
 pub use crate::datepicker::DatePickerButton;
 
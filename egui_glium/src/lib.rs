@@ -99,5 +99,6 @@ impl EguiGlium {
             &clipped_primitives,
             &textures_delta,
         );
+        self.egui_ctx.recycle_primitives(clipped_primitives);
     }
 }
@@ -103,6 +103,11 @@ impl Painter {
                 Primitive::Mesh(mesh) => {
                     self.paint_mesh(target, display, pixels_per_point, clip_rect, mesh);
                 }
+                Primitive::Instanced(instanced) => {
+                    // No instancing support (yet), so expand it:
+                    let mesh = instanced.to_mesh();
+                    self.paint_mesh(target, display, pixels_per_point, clip_rect, &mesh);
+                }
                 Primitive::Callback(_) => {
                     panic!("Custom rendering callbacks are not implemented in egui_glium");
                 }
@@ -235,6 +240,10 @@ impl Painter {
                 );
                 image.pixels.iter().map(|color| color.to_tuple()).collect()
             }
+            egui::ImageData::Hdr(image) => {
+                // We only render standard dynamic range, so clamp the colors:
+                image.srgba_pixels().map(|color| color.to_tuple()).collect()
+            }
             egui::ImageData::Font(image) => {
                 let gamma = 1.0;
                 image
@@ -385,6 +385,10 @@ impl Painter {
                 Primitive::Mesh(mesh) => {
                     self.paint_mesh(mesh);
                 }
+                Primitive::Instanced(instanced) => {
+                    // No instancing support (yet), so expand it:
+                    self.paint_mesh(&instanced.to_mesh());
+                }
                 Primitive::Callback(callback) => {
                     if callback.rect.is_positive() {
                         crate::profile_scope!("callback");
@@ -514,6 +518,18 @@ impl Painter {
 
                 self.upload_texture_srgb(delta.pos, image.size, delta.filter, data);
             }
+            egui::ImageData::Hdr(image) => {
+                assert_eq!(
+                    image.width() * image.height(),
+                    image.pixels.len(),
+                    "Mismatch between texture size and texel count"
+                );
+
+                // We only render standard dynamic range, so clamp the colors:
+                let data: Vec<u8> = image.srgba_pixels().flat_map(|a| a.to_array()).collect();
+
+                self.upload_texture_srgb(delta.pos, image.size, delta.filter, &data);
+            }
             egui::ImageData::Font(image) => {
                 assert_eq!(
                     image.width() * image.height(),
@@ -81,6 +81,7 @@ impl EguiGlow {
             self.egui_ctx.pixels_per_point(),
             &clipped_primitives,
         );
+        self.egui_ctx.recycle_primitives(clipped_primitives);
 
         for id in textures_delta.free.drain(..) {
             self.painter.free_texture(id);
@@ -0,0 +1,92 @@
+//! Reusing allocations from one frame to the next, see [`BufferPool`].
+
+use crate::{ClippedPrimitive, ClippedShape, Mesh, Primitive};
+
+/// Memory that is reused from one frame to the next,
+/// so that painting a heavy UI doesn't allocate (and free) large buffers every frame.
+///
+/// [`crate::tessellate_shapes_pooled`] takes its buffers from here.
+/// Give them back with [`Self::recycle_shapes`] and [`Self::recycle_primitives`]
+/// once you are done with them.
+///
+/// The pool never holds on to more buffers than were recycled the last frame.
+#[derive(Default)]
+pub struct BufferPool {
+    shapes: Vec<ClippedShape>,
+    primitives: Vec<ClippedPrimitive>,
+    meshes: Vec<Mesh>,
+}
+
+impl BufferPool {
+    /// An empty list of shapes, with the capacity of the last one that was recycled.
+    pub fn take_shapes(&mut self) -> Vec<ClippedShape> {
+        std::mem::take(&mut self.shapes)
+    }
+
+    /// An empty list of primitives, with the capacity of the last one that was recycled.
+    pub fn take_primitives(&mut self) -> Vec<ClippedPrimitive> {
+        std::mem::take(&mut self.primitives)
+    }
+
+    /// An empty mesh, using the memory of a recycled one if there is any.
+    pub fn take_mesh(&mut self) -> Mesh {
+        self.meshes.pop().unwrap_or_default()
+    }
+
+    /// Keep the memory of this list for the next [`Self::take_shapes`].
+    pub fn recycle_shapes(&mut self, mut shapes: Vec<ClippedShape>) {
+        if shapes.capacity() > self.shapes.capacity() {
+            shapes.clear();
+            self.shapes = shapes;
+        }
+    }
+
+    /// Keep the memory of these primitives and their meshes,
+    /// e.g. after the backend has painted them.
+    pub fn recycle_primitives(&mut self, mut primitives: Vec<ClippedPrimitive>) {
+        // Meshes that weren't used since the last recycling are not needed:
+        self.meshes.clear();
+        for clipped_primitive in primitives.drain(..) {
+            if let Primitive::Mesh(mut mesh) = clipped_primitive.primitive {
+                mesh.clear();
+                self.meshes.push(mesh);
+            }
+        }
+        if primitives.capacity() > self.primitives.capacity() {
+            self.primitives = primitives;
+        }
+    }
+
+    /// Number of bytes held by the pool.
+    pub fn bytes_used(&self) -> usize {
+        self.shapes.capacity() * std::mem::size_of::<ClippedShape>()
+            + self.primitives.capacity() * std::mem::size_of::<ClippedPrimitive>()
+            + self
+                .meshes
+                .iter()
+                .map(|mesh| {
+                    mesh.indices.capacity() * std::mem::size_of::<u32>()
+                        + mesh.vertices.capacity() * std::mem::size_of::<crate::Vertex>()
+                })
+                .sum::<usize>()
+    }
+}
+
+#[test]
+fn test_buffer_pool() {
+    let mut pool = BufferPool::default();
+
+    let mut mesh = Mesh::default();
+    mesh.add_colored_rect(emath::Rect::EVERYTHING, crate::Color32::RED);
+    let primitives = vec![ClippedPrimitive {
+        clip_rect: emath::Rect::EVERYTHING,
+        primitive: Primitive::Mesh(mesh),
+    }];
+    pool.recycle_primitives(primitives);
+
+    assert_eq!(pool.take_primitives().capacity(), 1);
+    let mesh = pool.take_mesh();
+    assert!(mesh.is_empty());
+    assert!(mesh.vertices.capacity() >= 4);
+    assert_eq!(pool.take_mesh().vertices.capacity(), 0);
+}
@@ -231,6 +231,9 @@ fn test_from_rgba_hex() {
 // ----------------------------------------------------------------------------
 
 /// 0-1 linear space `RGBA` color with premultiplied alpha.
+///
+/// Values above 1 (brighter than white) and below 0 (outside the sRGB gamut) are allowed too,
+/// e.g. in a [`crate::HdrImage`]. They are clamped when converted to a [`Color32`].
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
@@ -422,6 +425,19 @@ impl Rgba {
             linear_u8_from_linear_f32(a.abs()),
         ]
     }
+
+    /// Is any color channel outside of what a [`Color32`] can hold?
+    pub fn is_extended_range(&self) -> bool {
+        let a = self.a().abs();
+        [self.r(), self.g(), self.b()]
+            .iter()
+            .any(|&c| c < 0.0 || c > a)
+    }
+
+    /// Premultiplied RGBA as the bits of half-precision floats, e.g. for a `RGBA16F` texture.
+    pub fn to_f16_array(&self) -> [u16; 4] {
+        self.0.map(f16_bits_from_f32)
+    }
 }
 
 impl std::ops::Add for Rgba {
@@ -579,6 +595,111 @@ pub fn gamma_from_linear(linear: f32) -> f32 {
     }
 }
 
+/// The bits of the half-precision float (IEEE 754 binary16) closest to `value`.
+///
+/// Values too large for a half-precision float become infinity.
+pub fn f16_bits_from_f32(value: f32) -> u16 {
+    let bits = value.to_bits();
+    let sign = ((bits >> 16) & 0x8000) as u16;
+    let exponent = ((bits >> 23) & 0xff) as i32;
+    let mantissa = bits & 0x007f_ffff;
+
+    if exponent == 0xff {
+        // Infinity or NaN
+        let nan = if mantissa == 0 { 0 } else { 0x0200 };
+        return sign | 0x7c00 | nan;
+    }
+
+    let exponent = exponent - 127 + 15;
+    if exponent >= 0x1f {
+        sign | 0x7c00
+    } else if exponent <= 0 {
+        if exponent < -10 {
+            return sign;
+        }
+        // Subnormal, where rounding up to 0x0400 correctly gives the smallest normal:
+        let mantissa = mantissa | 0x0080_0000;
+        sign | shift_right_round_even(mantissa, (14 - exponent) as u32) as u16
+    } else {
+        // A carry from the rounding correctly bumps the exponent, up to infinity:
+        let rounded = ((exponent as u32) << 10) + shift_right_round_even(mantissa, 13);
+        sign | rounded as u16
+    }
+}
+
+/// `value >> shift`, rounded to the nearest integer, and to the even one when halfway between two.
+fn shift_right_round_even(value: u32, shift: u32) -> u32 {
+    let truncated = value >> shift;
+    let remainder = value & ((1 << shift) - 1);
+    let half = 1 << (shift - 1);
+    if remainder > half || (remainder == half && truncated & 1 == 1) {
+        truncated + 1
+    } else {
+        truncated
+    }
+}
+
+#[test]
+fn test_f16_bits_from_f32() {
+    assert_eq!(f16_bits_from_f32(0.0), 0x0000);
+    assert_eq!(f16_bits_from_f32(1.0), 0x3c00);
+    assert_eq!(f16_bits_from_f32(0.5), 0x3800);
+    assert_eq!(f16_bits_from_f32(-2.0), 0xc000);
+    assert_eq!(f16_bits_from_f32(65504.0), 0x7bff);
+    assert_eq!(f16_bits_from_f32(1e6), 0x7c00);
+    assert_eq!(f16_bits_from_f32(f32::INFINITY), 0x7c00);
+    assert_eq!(f16_bits_from_f32(2.0_f32.powi(-24)), 0x0001);
+
+    // Halfway between two half-precision floats rounds to the even one:
+    assert_eq!(f16_bits_from_f32(1.0 + 2.0_f32.powi(-11)), 0x3c00);
+    assert_eq!(f16_bits_from_f32(1.0 + 3.0 * 2.0_f32.powi(-11)), 0x3c02);
+    assert_eq!(f16_bits_from_f32(2.0_f32.powi(-25)), 0x0000);
+    assert_eq!(f16_bits_from_f32(3.0 * 2.0_f32.powi(-25)), 0x0002);
+    assert_eq!(f16_bits_from_f32(65520.0), 0x7c00);
+}
+
+// ----------------------------------------------------------------------------
+
+/// How the bytes of an 8-bit color are encoded, e.g. the vertex colors that a backend expects,
+/// see [`crate::TessellationOptions::vertex_colors`].
+///
+/// Colors in egui are always sRGB ([`Color32`]). A backend that treats them as linear
+/// and then encodes them to sRGB a second time makes everything look washed out.
+/// Such a backend can ask for [`Self::Linear`] vertex colors instead of working around it with different colors.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum ColorEncoding {
+    /// Gamma-encoded sRGB with premultiplied alpha, like [`Color32`].
+    ///
+    /// This is what the egui backends expect: they decode the vertex colors to linear in their shaders.
+    Srgb,
+
+    /// Linear with premultiplied alpha, like [`Rgba`] but with 8 bits per channel.
+    ///
+    /// This loses precision in dark colors.
+    Linear,
+}
+
+impl Default for ColorEncoding {
+    fn default() -> Self {
+        Self::Srgb
+    }
+}
+
+impl ColorEncoding {
+    /// Encode a [`Color32`] (which is always sRGB) in this encoding.
+    pub fn encode(self, color: Color32) -> Color32 {
+        match self {
+            Self::Srgb => color,
+            Self::Linear => {
+                let [r, g, b, a] = color.to_array();
+                let linear = |c| linear_u8_from_linear_f32(linear_f32_from_gamma_u8(c));
+                Color32::from_rgba_premultiplied(linear(r), linear(g), linear(b), a)
+            }
+        }
+    }
+}
+
 // ----------------------------------------------------------------------------
 
 /// Hue, saturation, value, alpha. All in the range [0, 1].
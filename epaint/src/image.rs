@@ -1,4 +1,4 @@
-use crate::{textures::TextureFilter, Color32};
+use crate::{textures::TextureFilter, Color32, Rgba};
 
 /// An image stored in RAM.
 ///
@@ -6,13 +6,16 @@ use crate::{textures::TextureFilter, Color32};
 ///
 /// In order to paint the image on screen, you first need to convert it to
 ///
-/// See also: [`ColorImage`], [`FontImage`].
+/// See also: [`ColorImage`], [`HdrImage`], [`FontImage`].
 #[derive(Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub enum ImageData {
     /// RGBA image.
     Color(ColorImage),
 
+    /// Extended-range linear RGBA image, e.g. decoded from an HDR file.
+    Hdr(HdrImage),
+
     /// Used for the font texture.
     Font(FontImage),
 }
@@ -21,6 +24,7 @@ impl ImageData {
     pub fn size(&self) -> [usize; 2] {
         match self {
             Self::Color(image) => image.size,
+            Self::Hdr(image) => image.size,
             Self::Font(image) => image.size,
         }
     }
@@ -36,6 +40,7 @@ impl ImageData {
     pub fn bytes_per_pixel(&self) -> usize {
         match self {
             Self::Color(_) | Self::Font(_) => 4,
+            Self::Hdr(_) => 16,
         }
     }
 }
@@ -53,6 +58,15 @@ pub struct ColorImage {
     pub pixels: Vec<Color32>,
 }
 
+impl std::fmt::Debug for ColorImage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Don't print all the pixels:
+        f.debug_struct("ColorImage")
+            .field("size", &self.size)
+            .finish_non_exhaustive()
+    }
+}
+
 impl ColorImage {
     /// Create an image filled with the given color.
     pub fn new(size: [usize; 2], color: Color32) -> Self {
@@ -158,6 +172,98 @@ impl From<ColorImage> for ImageData {
 
 // ----------------------------------------------------------------------------
 
+/// A 2D image of linear colors in RAM, which unlike a [`ColorImage`] can go outside of 0-1.
+///
+/// The egui backends render standard dynamic range, so they show it clamped, as [`Self::srgba_pixels`].
+/// A backend that renders HDR can upload it as e.g. a `RGBA16F` texture (see [`Rgba::to_f16_array`]).
+#[derive(Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct HdrImage {
+    /// width, height.
+    pub size: [usize; 2],
+
+    /// The pixels (linear, premultiplied alpha), row by row, from top to bottom.
+    pub pixels: Vec<Rgba>,
+}
+
+impl std::fmt::Debug for HdrImage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Don't print all the pixels:
+        f.debug_struct("HdrImage")
+            .field("size", &self.size)
+            .finish_non_exhaustive()
+    }
+}
+
+impl HdrImage {
+    /// Create an image filled with the given color.
+    pub fn new(size: [usize; 2], color: Rgba) -> Self {
+        Self {
+            size,
+            pixels: vec![color; size[0] * size[1]],
+        }
+    }
+
+    /// Create a [`HdrImage`] from flat un-multiplied linear RGBA data,
+    /// e.g. from a decoded OpenEXR file.
+    ///
+    /// Panics if `size[0] * size[1] * 4 != rgba.len()`.
+    pub fn from_rgba_unmultiplied(size: [usize; 2], rgba: &[f32]) -> Self {
+        assert_eq!(size[0] * size[1] * 4, rgba.len());
+        let pixels = rgba
+            .chunks_exact(4)
+            .map(|p| Rgba::from_rgba_unmultiplied(p[0], p[1], p[2], p[3]))
+            .collect();
+        Self { size, pixels }
+    }
+
+    #[inline]
+    pub fn width(&self) -> usize {
+        self.size[0]
+    }
+
+    #[inline]
+    pub fn height(&self) -> usize {
+        self.size[1]
+    }
+
+    /// Returns the pixels clamped to `sRGBA` premultiplied, row by row, top to bottom.
+    ///
+    /// For backends that only render standard dynamic range.
+    pub fn srgba_pixels(&'_ self) -> impl ExactSizeIterator<Item = Color32> + '_ {
+        self.pixels.iter().map(|&pixel| Color32::from(pixel))
+    }
+}
+
+impl std::ops::Index<(usize, usize)> for HdrImage {
+    type Output = Rgba;
+
+    #[inline]
+    fn index(&self, (x, y): (usize, usize)) -> &Rgba {
+        let [w, h] = self.size;
+        assert!(x < w && y < h);
+        &self.pixels[y * w + x]
+    }
+}
+
+impl std::ops::IndexMut<(usize, usize)> for HdrImage {
+    #[inline]
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut Rgba {
+        let [w, h] = self.size;
+        assert!(x < w && y < h);
+        &mut self.pixels[y * w + x]
+    }
+}
+
+impl From<HdrImage> for ImageData {
+    #[inline(always)]
+    fn from(image: HdrImage) -> Self {
+        Self::Hdr(image)
+    }
+}
+
+// ----------------------------------------------------------------------------
+
 /// A single-channel image designed for the font texture.
 ///
 /// Each value represents "coverage", i.e. how much a texel is covered by a character.
@@ -13,6 +13,7 @@
 #![allow(clippy::manual_range_contains)]
 
 mod bezier;
+mod buffer_pool;
 pub mod color;
 pub mod image;
 mod mesh;
@@ -31,9 +32,10 @@ pub mod util;
 
 pub use {
     bezier::{CubicBezierShape, QuadraticBezierShape},
-    color::{Color32, Rgba},
-    image::{ColorImage, FontImage, ImageData, ImageDelta},
-    mesh::{Mesh, Mesh16, Vertex},
+    buffer_pool::BufferPool,
+    color::{Color32, ColorEncoding, Rgba},
+    image::{ColorImage, FontImage, HdrImage, ImageData, ImageDelta},
+    mesh::{Instance, InstancedMesh, Mesh, Mesh16, Vertex},
     shadow::Shadow,
     shape::{
         CircleShape, PaintCallback, PaintCallbackInfo, PathShape, RectShape, Rounding, Shape,
@@ -41,7 +43,7 @@ pub use {
     },
     stats::PaintStats,
     stroke::Stroke,
-    tessellator::{tessellate_shapes, TessellationOptions, Tessellator},
+    tessellator::{tessellate_shapes, tessellate_shapes_pooled, TessellationOptions, Tessellator},
     text::{FontFamily, FontId, Fonts, Galley},
     texture_atlas::TextureAtlas,
     texture_handle::TextureHandle,
@@ -89,6 +91,7 @@ impl Default for TextureId {
 ///
 /// Everything is using logical points.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct ClippedShape(
     /// Clip / scissor rectangle.
     /// Only show the part of the [`Shape`] that falls within this.
@@ -100,7 +103,11 @@ pub struct ClippedShape(
 /// A [`Mesh`] or [`PaintCallback`] within a clip rectangle.
 ///
 /// Everything is using logical points.
+///
+/// With the `serde` feature this can be serialized, e.g. to send it to a renderer
+/// in another process, as long as it isn't a [`Primitive::Callback`].
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct ClippedPrimitive {
     /// Clip / scissor rectangle.
     /// Only show the part of the [`Mesh`] that falls within this.
@@ -110,10 +117,17 @@ pub struct ClippedPrimitive {
     pub primitive: Primitive,
 }
 
-/// A rendering primitive - either a [`Mesh`] or a [`PaintCallback`].
+/// A rendering primitive - either a [`Mesh`], an [`InstancedMesh`] or a [`PaintCallback`].
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub enum Primitive {
     Mesh(Mesh),
+
+    /// Only produced with [`TessellationOptions::instancing`].
+    Instanced(InstancedMesh),
+
+    /// Can't be serialized, since it is only meaningful to the backend that created it.
+    #[cfg_attr(feature = "serde", serde(skip))]
     Callback(PaintCallback),
 }
 
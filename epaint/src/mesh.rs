@@ -53,7 +53,7 @@ impl Mesh {
     pub fn clear(&mut self) {
         self.indices.clear();
         self.vertices.clear();
-        self.vertices = Default::default();
+        self.texture_id = Default::default();
     }
 
     pub fn bytes_used(&self) -> usize {
@@ -267,6 +267,174 @@ impl Mesh {
 
 // ----------------------------------------------------------------------------
 
+/// Where and how to paint one copy of the template of an [`InstancedMesh`].
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+pub struct Instance {
+    /// Added to the template positions, after scaling.
+    pub offset: Vec2, // 64 bit
+
+    /// The template positions are multiplied by this.
+    pub scale: f32, // 32 bit
+
+    /// Multiplied with the template vertex colors.
+    /// Use [`Color32::WHITE`] to keep the template colors.
+    pub color: Color32, // 32 bit
+}
+
+impl Instance {
+    #[inline]
+    pub fn new(offset: Vec2, color: Color32) -> Self {
+        Self {
+            offset,
+            scale: 1.0,
+            color,
+        }
+    }
+
+    #[inline]
+    pub fn transform(&self, vertex: &Vertex) -> Vertex {
+        Vertex {
+            pos: (self.scale * vertex.pos.to_vec2() + self.offset).to_pos2(),
+            uv: vertex.uv,
+            color: multiply_colors(vertex.color, self.color),
+        }
+    }
+}
+
+/// Multiply two premultiplied colors channel by channel.
+#[inline]
+fn multiply_colors(a: Color32, b: Color32) -> Color32 {
+    if b == Color32::WHITE {
+        return a;
+    }
+    let mul = |a: u8, b: u8| ((a as u16 * b as u16 + 127) / 255) as u8;
+    Color32::from_rgba_premultiplied(
+        mul(a.r(), b.r()),
+        mul(a.g(), b.g()),
+        mul(a.b(), b.b()),
+        mul(a.a(), b.a()),
+    )
+}
+
+/// The same [`Mesh`] painted many times, e.g. the markers of a large scatter plot.
+///
+/// Backends that support it can paint this with GPU instancing,
+/// uploading the template once instead of a copy of it for each instance.
+/// See [`crate::TessellationOptions::instancing`].
+/// Other backends get it as a plain [`Mesh`], expanded by the [`crate::Tessellator`].
+///
+/// The template is usually centered on the origin,
+/// e.g. a circle tessellated at `(0, 0)`, and then moved in place by each [`Instance`].
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct InstancedMesh {
+    pub template: Mesh,
+    pub instances: Vec<Instance>,
+}
+
+impl InstancedMesh {
+    pub fn new(template: Mesh) -> Self {
+        Self {
+            template,
+            instances: Default::default(),
+        }
+    }
+
+    #[inline]
+    pub fn add(&mut self, instance: Instance) {
+        self.instances.push(instance);
+    }
+
+    #[inline]
+    pub fn texture_id(&self) -> TextureId {
+        self.template.texture_id
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.template.is_empty() || self.instances.is_empty()
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.template.is_valid()
+    }
+
+    pub fn bytes_used(&self) -> usize {
+        self.template.bytes_used() + self.instances.len() * std::mem::size_of::<Instance>()
+    }
+
+    /// Calculate a bounding rectangle of all instances.
+    pub fn calc_bounds(&self) -> Rect {
+        let mut bounds = Rect::NOTHING;
+        if self.template.vertices.is_empty() {
+            return bounds;
+        }
+        let template = self.template.calc_bounds();
+        for instance in &self.instances {
+            bounds = bounds.union(Rect::from_two_pos(
+                (instance.scale * template.min.to_vec2() + instance.offset).to_pos2(),
+                (instance.scale * template.max.to_vec2() + instance.offset).to_pos2(),
+            ));
+        }
+        bounds
+    }
+
+    /// Move all instances by this many points, in-place.
+    pub fn translate(&mut self, delta: Vec2) {
+        for instance in &mut self.instances {
+            instance.offset += delta;
+        }
+    }
+
+    /// A [`Mesh`] containing a transformed copy of the template for each instance.
+    pub fn to_mesh(&self) -> Mesh {
+        let mut mesh = Mesh::with_texture(self.template.texture_id);
+        self.append_to(&mut mesh);
+        mesh
+    }
+
+    /// Append a transformed copy of the template for each instance to `out`.
+    ///
+    /// `out` must be empty or use the same texture as the template.
+    pub fn append_to(&self, out: &mut Mesh) {
+        crate::epaint_assert!(self.is_valid());
+        if self.is_empty() {
+            return;
+        }
+        if out.is_empty() {
+            out.texture_id = self.template.texture_id;
+        } else {
+            assert_eq!(
+                out.texture_id, self.template.texture_id,
+                "Can't merge Mesh using different textures"
+            );
+        }
+
+        out.reserve_vertices(self.template.vertices.len() * self.instances.len());
+        out.indices
+            .reserve(self.template.indices.len() * self.instances.len());
+        for instance in &self.instances {
+            let index_offset = out.vertices.len() as u32;
+            out.indices.extend(
+                self.template
+                    .indices
+                    .iter()
+                    .map(|index| index + index_offset),
+            );
+            out.vertices.extend(
+                self.template
+                    .vertices
+                    .iter()
+                    .map(|vertex| instance.transform(vertex)),
+            );
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
 /// A version of [`Mesh`] that uses 16-bit indices.
 ///
 /// This is produced by [`Mesh::split_to_u16`] and is meant to be used for legacy render backends.
@@ -293,3 +461,30 @@ impl Mesh16 {
         }
     }
 }
+
+#[test]
+fn test_instanced_mesh() {
+    let mut template = Mesh::default();
+    template.add_colored_rect(
+        Rect::from_min_max(pos2(-1.0, -1.0), pos2(1.0, 1.0)),
+        Color32::WHITE,
+    );
+    let mut instanced = InstancedMesh::new(template);
+    instanced.add(Instance::new(vec2(10.0, 0.0), Color32::RED));
+    instanced.add(Instance {
+        offset: vec2(0.0, 10.0),
+        scale: 2.0,
+        color: Color32::WHITE,
+    });
+
+    let mesh = instanced.to_mesh();
+    assert!(mesh.is_valid());
+    assert_eq!(mesh.indices.len(), 12);
+    assert_eq!(mesh.vertices[0].color, Color32::RED);
+    assert_eq!(mesh.vertices[4].color, Color32::WHITE);
+    assert_eq!(mesh.calc_bounds(), instanced.calc_bounds());
+    assert_eq!(
+        instanced.calc_bounds(),
+        Rect::from_min_max(pos2(-2.0, -1.0), pos2(11.0, 12.0))
+    );
+}
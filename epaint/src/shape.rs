@@ -5,7 +5,7 @@ use std::{any::Any, sync::Arc};
 
 use crate::{
     text::{FontId, Fonts, Galley},
-    Color32, Mesh, Stroke, TextureId,
+    Color32, InstancedMesh, Mesh, Stroke, TextureId,
 };
 use emath::*;
 
@@ -18,8 +18,11 @@ pub use crate::{CubicBezierShape, QuadraticBezierShape};
 /// but storing them should also be fine with one exception:
 /// [`Shape::Text`] depends on the current `pixels_per_point` (dpi scale)
 /// and so must be recreated every time `pixels_per_point` changes.
+///
+/// With the `serde` feature, everything but [`Shape::Callback`] can be serialized.
 #[must_use = "Add a Shape to a Painter"]
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub enum Shape {
     /// Paint nothing. This can be useful as a placeholder.
     Noop,
@@ -51,6 +54,9 @@ pub enum Shape {
     /// Can be used to display images.
     Mesh(Mesh),
 
+    /// The same mesh painted many times, e.g. the markers of a scatter plot.
+    Instanced(InstancedMesh),
+
     /// A quadratic [Bézier Curve](https://en.wikipedia.org/wiki/B%C3%A9zier_curve).
     QuadraticBezier(QuadraticBezierShape),
 
@@ -58,6 +64,9 @@ pub enum Shape {
     CubicBezier(CubicBezierShape),
 
     /// Backend-specific painting.
+    ///
+    /// Can't be serialized, since it is only meaningful to the backend that created it.
+    #[cfg_attr(feature = "serde", serde(skip))]
     Callback(PaintCallback),
 }
 
@@ -81,6 +90,13 @@ impl From<Mesh> for Shape {
     }
 }
 
+impl From<InstancedMesh> for Shape {
+    #[inline(always)]
+    fn from(instanced: InstancedMesh) -> Self {
+        Self::Instanced(instanced)
+    }
+}
+
 /// ## Constructors
 impl Shape {
     /// A line between two points.
@@ -262,6 +278,7 @@ impl Shape {
             Self::Rect(rect_shape) => rect_shape.visual_bounding_rect(),
             Self::Text(text_shape) => text_shape.visual_bounding_rect(),
             Self::Mesh(mesh) => mesh.calc_bounds(),
+            Self::Instanced(instanced) => instanced.calc_bounds(),
             Self::QuadraticBezier(bezier) => bezier.visual_bounding_rect(),
             Self::CubicBezier(bezier) => bezier.visual_bounding_rect(),
             Self::Callback(custom) => custom.rect,
@@ -273,10 +290,10 @@ impl Shape {
 impl Shape {
     #[inline(always)]
     pub fn texture_id(&self) -> super::TextureId {
-        if let Shape::Mesh(mesh) = self {
-            mesh.texture_id
-        } else {
-            super::TextureId::default()
+        match self {
+            Shape::Mesh(mesh) => mesh.texture_id,
+            Shape::Instanced(instanced) => instanced.texture_id(),
+            _ => super::TextureId::default(),
         }
     }
 
@@ -311,6 +328,9 @@ impl Shape {
             Shape::Mesh(mesh) => {
                 mesh.translate(delta);
             }
+            Shape::Instanced(instanced) => {
+                instanced.translate(delta);
+            }
             Shape::QuadraticBezier(bezier_shape) => {
                 bezier_shape.points[0] += delta;
                 bezier_shape.points[1] += delta;
@@ -43,6 +43,12 @@ pub fn adjust_colors(shape: &mut Shape, adjust_color: &impl Fn(&mut Color32)) {
                 adjust_color(&mut v.color);
             }
         }
+        Shape::Instanced(instanced) => {
+            // The instance colors are multiplied with the template colors, so only adjust one of them:
+            for instance in &mut instanced.instances {
+                adjust_color(&mut instance.color);
+            }
+        }
         Shape::QuadraticBezier(quatratic) => {
             adjust_color(&mut quatratic.fill);
             adjust_color(&mut quatratic.stroke.color);
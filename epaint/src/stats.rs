@@ -219,6 +219,10 @@ impl PaintStats {
             Shape::Mesh(mesh) => {
                 self.shape_mesh += AllocInfo::from_mesh(mesh);
             }
+            Shape::Instanced(instanced) => {
+                self.shape_mesh += AllocInfo::from_mesh(&instanced.template);
+                self.shape_mesh += AllocInfo::from_slice(&instanced.instances);
+            }
             Shape::Callback(_) => {
                 self.num_callbacks += 1;
             }
@@ -231,9 +235,17 @@ impl PaintStats {
     ) -> Self {
         self.clipped_primitives += AllocInfo::from_slice(clipped_primitives);
         for clipped_primitive in clipped_primitives {
-            if let Primitive::Mesh(mesh) = &clipped_primitive.primitive {
-                self.vertices += AllocInfo::from_slice(&mesh.vertices);
-                self.indices += AllocInfo::from_slice(&mesh.indices);
+            match &clipped_primitive.primitive {
+                Primitive::Mesh(mesh) => {
+                    self.vertices += AllocInfo::from_slice(&mesh.vertices);
+                    self.indices += AllocInfo::from_slice(&mesh.indices);
+                }
+                Primitive::Instanced(instanced) => {
+                    self.vertices += AllocInfo::from_slice(&instanced.template.vertices);
+                    self.vertices += AllocInfo::from_slice(&instanced.instances);
+                    self.indices += AllocInfo::from_slice(&instanced.template.indices);
+                }
+                Primitive::Callback(_) => {}
             }
         }
         self
@@ -627,6 +627,25 @@ pub struct TessellationOptions {
     /// This makes the text sharper on most platforms.
     pub round_text_to_pixels: bool,
 
+    /// If `true` (default), axis-aligned lines and the strokes of sharp-cornered rectangles
+    /// are snapped to cover a whole number of physical pixels.
+    ///
+    /// At fractional scale factors (like 1.25 or 1.5) this keeps e.g. a 1 point separator
+    /// from being painted as one physical pixel in one place and as a blurry two in another.
+    pub round_strokes_to_pixels: bool,
+
+    /// If `true`, each [`Shape::Instanced`] is output as a [`Primitive::Instanced`],
+    /// for backends that paint them with GPU instancing.
+    ///
+    /// If `false` (default), they are expanded into plain meshes,
+    /// so only turn this on if your backend supports [`Primitive::Instanced`].
+    pub instancing: bool,
+
+    /// How the vertex colors of the output are encoded, for backends that expect linear ones.
+    ///
+    /// Default: [`ColorEncoding::Srgb`], which the egui backends expect.
+    pub vertex_colors: ColorEncoding,
+
     /// Output the clip rectangles to be painted.
     pub debug_paint_clip_rects: bool,
 
@@ -651,6 +670,9 @@ impl Default for TessellationOptions {
             coarse_tessellation_culling: true,
             prerasterized_discs: true,
             round_text_to_pixels: true,
+            round_strokes_to_pixels: true,
+            instancing: false,
+            vertex_colors: Default::default(),
             debug_paint_text_rects: false,
             debug_paint_clip_rects: false,
             debug_ignore_clip_rects: false,
@@ -982,6 +1004,8 @@ pub struct Tessellator {
     clip_rect: Rect,
     scratchpad_points: Vec<Pos2>,
     scratchpad_path: Path,
+    /// New meshes are taken from here, see [`tessellate_shapes_pooled`].
+    pool: BufferPool,
 }
 
 impl Tessellator {
@@ -1010,6 +1034,7 @@ impl Tessellator {
             clip_rect: Rect::EVERYTHING,
             scratchpad_points: Default::default(),
             scratchpad_path: Default::default(),
+            pool: Default::default(),
         }
     }
 
@@ -1027,6 +1052,25 @@ impl Tessellator {
         }
     }
 
+    /// Snap a stroke to cover a whole number of physical pixels (at least one).
+    ///
+    /// Returns the new stroke width, and a function for snapping the center coordinate of a
+    /// line with that stroke, in the direction across the line.
+    fn round_stroke_to_pixels(&self, stroke_width: f32) -> (f32, impl Fn(f32) -> f32) {
+        let pixels_per_point = self.pixels_per_point;
+        let width_in_pixels = (stroke_width * pixels_per_point).round().max(1.0);
+        // A line of odd width is centered on a pixel center, an even one on a pixel edge:
+        let offset = if width_in_pixels as i32 % 2 == 1 {
+            0.5
+        } else {
+            0.0
+        };
+        let snap = move |coord: f32| {
+            ((coord * pixels_per_point - offset).round() + offset) / pixels_per_point
+        };
+        (width_in_pixels / pixels_per_point, snap)
+    }
+
     /// Tessellate a clipped shape into a list of primitives.
     pub fn tessellate_clipped_shape(
         &mut self,
@@ -1054,6 +1098,20 @@ impl Tessellator {
             return;
         }
 
+        if self.options.instancing {
+            if let Shape::Instanced(instanced) = new_shape {
+                if !self.options.coarse_tessellation_culling
+                    || new_clip_rect.intersects(instanced.calc_bounds())
+                {
+                    out_primitives.push(ClippedPrimitive {
+                        clip_rect: new_clip_rect,
+                        primitive: Primitive::Instanced(instanced),
+                    });
+                }
+                return;
+            }
+        }
+
         let start_new_mesh = match out_primitives.last() {
             None => true,
             Some(output_clipped_primitive) => {
@@ -1062,7 +1120,7 @@ impl Tessellator {
                         Primitive::Mesh(output_mesh) => {
                             output_mesh.texture_id != new_shape.texture_id()
                         }
-                        Primitive::Callback(_) => true,
+                        Primitive::Instanced(_) | Primitive::Callback(_) => true,
                     }
             }
         };
@@ -1070,7 +1128,7 @@ impl Tessellator {
         if start_new_mesh {
             out_primitives.push(ClippedPrimitive {
                 clip_rect: new_clip_rect,
-                primitive: Primitive::Mesh(Mesh::default()),
+                primitive: Primitive::Mesh(self.pool.take_mesh()),
             });
         }
 
@@ -1114,6 +1172,19 @@ impl Tessellator {
                 }
                 out.append(mesh);
             }
+            Shape::Instanced(instanced) => {
+                if !instanced.is_valid() {
+                    crate::epaint_assert!(false, "Invalid Mesh in Shape::Instanced");
+                    return;
+                }
+
+                if self.options.coarse_tessellation_culling
+                    && !self.clip_rect.intersects(instanced.calc_bounds())
+                {
+                    return;
+                }
+                instanced.append_to(out);
+            }
             Shape::LineSegment { points, stroke } => self.tessellate_line(points, stroke, out),
             Shape::Path(path_shape) => {
                 self.tessellate_path(&path_shape, out);
@@ -1220,11 +1291,26 @@ impl Tessellator {
     ///
     /// * `shape`: the mesh to tessellate.
     /// * `out`: triangles are appended to this.
-    pub fn tessellate_line(&mut self, points: [Pos2; 2], stroke: Stroke, out: &mut Mesh) {
+    pub fn tessellate_line(&mut self, mut points: [Pos2; 2], mut stroke: Stroke, out: &mut Mesh) {
         if stroke.is_empty() {
             return;
         }
 
+        if self.options.round_strokes_to_pixels {
+            let [a, b] = &mut points;
+            if a.x == b.x {
+                let (width, snap) = self.round_stroke_to_pixels(stroke.width);
+                stroke.width = width;
+                a.x = snap(a.x);
+                b.x = a.x;
+            } else if a.y == b.y {
+                let (width, snap) = self.round_stroke_to_pixels(stroke.width);
+                stroke.width = width;
+                a.y = snap(a.y);
+                b.y = a.y;
+            }
+        }
+
         if self.options.coarse_tessellation_culling
             && !self
                 .clip_rect
@@ -1293,9 +1379,22 @@ impl Tessellator {
             mut rect,
             rounding,
             fill,
-            stroke,
+            mut stroke,
         } = *rect;
 
+        if self.options.round_strokes_to_pixels
+            && !stroke.is_empty()
+            && rounding == Rounding::none()
+            && rect.is_finite()
+        {
+            let (width, snap) = self.round_stroke_to_pixels(stroke.width);
+            stroke.width = width;
+            rect = Rect::from_min_max(
+                pos2(snap(rect.min.x), snap(rect.min.y)),
+                pos2(snap(rect.max.x), snap(rect.max.y)),
+            );
+        }
+
         if self.options.coarse_tessellation_culling
             && !rect.expand(stroke.width).intersects(self.clip_rect)
         {
@@ -1530,16 +1629,43 @@ pub fn tessellate_shapes(
     font_tex_size: [usize; 2],
     prepared_discs: Vec<PreparedDisc>,
     shapes: Vec<ClippedShape>,
+) -> Vec<ClippedPrimitive> {
+    tessellate_shapes_pooled(
+        pixels_per_point,
+        options,
+        font_tex_size,
+        prepared_discs,
+        shapes,
+        &mut BufferPool::default(),
+    )
+}
+
+/// Like [`tessellate_shapes`], but reuses the memory of the buffers in `pool`.
+///
+/// The emptied `shapes` are put into the pool. Put back the returned primitives with
+/// [`BufferPool::recycle_primitives`] once you have painted them,
+/// and the next call can reuse their memory.
+pub fn tessellate_shapes_pooled(
+    pixels_per_point: f32,
+    options: TessellationOptions,
+    font_tex_size: [usize; 2],
+    prepared_discs: Vec<PreparedDisc>,
+    mut shapes: Vec<ClippedShape>,
+    pool: &mut BufferPool,
 ) -> Vec<ClippedPrimitive> {
     let mut tessellator =
         Tessellator::new(pixels_per_point, options, font_tex_size, prepared_discs);
+    tessellator.pool = std::mem::take(pool);
 
-    let mut clipped_primitives: Vec<ClippedPrimitive> = Vec::default();
+    let mut clipped_primitives = tessellator.pool.take_primitives();
 
-    for clipped_shape in shapes {
+    for clipped_shape in shapes.drain(..) {
         tessellator.tessellate_clipped_shape(clipped_shape, &mut clipped_primitives);
     }
 
+    *pool = std::mem::take(&mut tessellator.pool);
+    pool.recycle_shapes(shapes);
+
     if options.debug_paint_clip_rects {
         clipped_primitives = add_clip_rects(&mut tessellator, clipped_primitives);
     }
@@ -1554,10 +1680,34 @@ pub fn tessellate_shapes(
         p.clip_rect.is_positive()
             && match &p.primitive {
                 Primitive::Mesh(mesh) => !mesh.is_empty(),
+                Primitive::Instanced(instanced) => !instanced.is_empty(),
                 Primitive::Callback(_) => true,
             }
     });
 
+    let vertex_colors = options.vertex_colors;
+    if vertex_colors != ColorEncoding::Srgb {
+        for clipped_primitive in &mut clipped_primitives {
+            match &mut clipped_primitive.primitive {
+                Primitive::Mesh(mesh) => {
+                    for vertex in &mut mesh.vertices {
+                        vertex.color = vertex_colors.encode(vertex.color);
+                    }
+                }
+                Primitive::Instanced(instanced) => {
+                    // Multiplying linear colors with each other is fine:
+                    for vertex in &mut instanced.template.vertices {
+                        vertex.color = vertex_colors.encode(vertex.color);
+                    }
+                    for instance in &mut instanced.instances {
+                        instance.color = vertex_colors.encode(instance.color);
+                    }
+                }
+                Primitive::Callback(_) => {}
+            }
+        }
+    }
+
     for clipped_primitive in &clipped_primitives {
         if let Primitive::Mesh(mesh) = &clipped_primitive.primitive {
             crate::epaint_assert!(mesh.is_valid(), "Tessellator generated invalid Mesh");
@@ -1626,3 +1776,47 @@ fn test_tessellator() {
     );
     assert_eq!(primitives.len(), 2);
 }
+
+#[test]
+fn test_round_stroke_to_pixels() {
+    let pixels_per_point = 1.25;
+    let tessellator = Tessellator::new(pixels_per_point, Default::default(), [1, 1], vec![]);
+    let in_pixels = |points: f32| (points * pixels_per_point * 1000.0).round() / 1000.0;
+
+    // 1.25 physical pixels becomes one, centered on a pixel center:
+    let (width, snap) = tessellator.round_stroke_to_pixels(1.0);
+    assert_eq!(in_pixels(width), 1.0);
+    assert_eq!(in_pixels(snap(10.3)), 12.5);
+
+    // 2.5 physical pixels becomes three, also centered on a pixel center:
+    let (width, snap) = tessellator.round_stroke_to_pixels(2.0);
+    assert_eq!(in_pixels(width), 3.0);
+    assert_eq!(in_pixels(snap(10.3)), 12.5);
+
+    // 2 physical pixels are centered on a pixel edge:
+    let (width, snap) = tessellator.round_stroke_to_pixels(1.6);
+    assert_eq!(in_pixels(width), 2.0);
+    assert_eq!(in_pixels(snap(10.3)), 13.0);
+}
+
+#[test]
+fn test_linear_vertex_colors() {
+    let rect = Rect::from_min_max(pos2(0.0, 0.0), pos2(1.0, 1.0));
+    let gray = Color32::from_gray(128);
+    let mut mesh = Mesh::default();
+    mesh.add_colored_rect(rect, gray);
+
+    let mut options = TessellationOptions::default();
+    options.vertex_colors = ColorEncoding::Linear;
+    let primitives = tessellate_shapes(
+        1.0,
+        options,
+        [1, 1],
+        vec![],
+        vec![ClippedShape(rect, Shape::mesh(mesh))],
+    );
+    match &primitives[0].primitive {
+        Primitive::Mesh(mesh) => assert_eq!(mesh.vertices[0].color, Color32::from_gray(55)),
+        _ => panic!("Expected a mesh"),
+    }
+}
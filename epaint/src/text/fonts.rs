@@ -454,6 +454,28 @@ impl Fonts {
         self.lock().layout_job(job)
     }
 
+    /// Like [`Self::layout_job`], but with the text given separately, so that it only
+    /// needs to be copied into the job when the [`Galley`] isn't already in the cache.
+    ///
+    /// [`LayoutJob::text`] is ignored, but the sections must refer to `text`.
+    pub fn layout_job_with_text(&self, text: &str, job: LayoutJob) -> Arc<Galley> {
+        let mut fonts_and_cache = self.lock();
+        let FontsAndCache {
+            fonts,
+            galley_cache,
+        } = &mut *fonts_and_cache;
+        galley_cache.layout_with_text(fonts, text, job)
+    }
+
+    /// Get a shared copy of this text, e.g. for a label that is shown every frame.
+    ///
+    /// Unlike passing a `&str` to a widget, passing the returned [`Arc<str>`]
+    /// doesn't allocate, so use this to avoid allocating for text that rarely changes.
+    /// Like the [`Galley`] cache, strings that are not interned during a frame are forgotten.
+    pub fn intern(&self, text: &str) -> Arc<str> {
+        self.lock().galley_cache.intern(text)
+    }
+
     pub fn num_galleys_in_cache(&self) -> usize {
         self.lock().galley_cache.num_galleys_in_cache()
     }
@@ -627,12 +649,42 @@ struct GalleyCache {
     /// Frame counter used to do garbage collection on the cache
     generation: u32,
     cache: nohash_hasher::IntMap<u64, CachedGalley>,
+
+    /// Interned strings, and the generation they were last used.
+    interned: ahash::AHashMap<Arc<str>, u32>,
 }
 
 impl GalleyCache {
     fn layout(&mut self, fonts: &mut FontsImpl, job: LayoutJob) -> Arc<Galley> {
         let hash = crate::util::hash(&job); // TODO(emilk): even faster hasher?
+        self.layout_with_hash(fonts, hash, || job)
+    }
 
+    /// Same cache entry as [`Self::layout`] with `job.text = text.to_owned()`.
+    fn layout_with_text(
+        &mut self,
+        fonts: &mut FontsImpl,
+        text: &str,
+        job: LayoutJob,
+    ) -> Arc<Galley> {
+        let hash = {
+            use std::hash::Hasher as _;
+            let mut hasher = ahash::AHasher::new_with_keys(123, 456);
+            job.hash_with_text(text, &mut hasher);
+            hasher.finish()
+        };
+        self.layout_with_hash(fonts, hash, || LayoutJob {
+            text: text.to_owned(),
+            ..job
+        })
+    }
+
+    fn layout_with_hash(
+        &mut self,
+        fonts: &mut FontsImpl,
+        hash: u64,
+        job: impl FnOnce() -> LayoutJob,
+    ) -> Arc<Galley> {
         match self.cache.entry(hash) {
             std::collections::hash_map::Entry::Occupied(entry) => {
                 let cached = entry.into_mut();
@@ -640,7 +692,7 @@ impl GalleyCache {
                 cached.galley.clone()
             }
             std::collections::hash_map::Entry::Vacant(entry) => {
-                let galley = super::layout(fonts, job.into());
+                let galley = super::layout(fonts, job().into());
                 let galley = Arc::new(galley);
                 entry.insert(CachedGalley {
                     last_used: self.generation,
@@ -651,6 +703,20 @@ impl GalleyCache {
         }
     }
 
+    fn intern(&mut self, text: &str) -> Arc<str> {
+        if let Some((interned, last_used)) = self.interned.get_key_value(text) {
+            let interned = interned.clone();
+            if *last_used != self.generation {
+                self.interned.insert(interned.clone(), self.generation);
+            }
+            interned
+        } else {
+            let interned: Arc<str> = text.into();
+            self.interned.insert(interned.clone(), self.generation);
+            interned
+        }
+    }
+
     pub fn num_galleys_in_cache(&self) -> usize {
         self.cache.len()
     }
@@ -661,6 +727,8 @@ impl GalleyCache {
         self.cache.retain(|_key, cached| {
             cached.last_used == current_generation // only keep those that were used this frame
         });
+        self.interned
+            .retain(|_text, last_used| *last_used == current_generation);
         self.generation = self.generation.wrapping_add(1);
     }
 }
@@ -737,3 +805,25 @@ impl FontImplCache {
             .clone()
     }
 }
+
+#[test]
+fn test_layout_job_with_text() {
+    let fonts = Fonts::new(1.0, 1024, FontDefinitions::default());
+    let job = LayoutJob::simple(
+        "Hello".to_owned(),
+        FontId::default(),
+        crate::Color32::WHITE,
+        f32::INFINITY,
+    );
+    let galley = fonts.layout_job(job.clone());
+    let job = LayoutJob {
+        text: String::new(),
+        ..job
+    };
+    assert!(Arc::ptr_eq(
+        &galley,
+        &fonts.layout_job_with_text("Hello", job)
+    ));
+
+    assert!(Arc::ptr_eq(&fonts.intern("Hello"), &fonts.intern("Hello")));
+}
@@ -166,8 +166,16 @@ impl LayoutJob {
 impl std::hash::Hash for LayoutJob {
     #[inline]
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.hash_with_text(&self.text, state);
+    }
+}
+
+impl LayoutJob {
+    /// Hash as if [`Self::text`] was `text`.
+    #[inline]
+    pub(crate) fn hash_with_text<H: std::hash::Hasher>(&self, text: &str, state: &mut H) {
         let Self {
-            text,
+            text: _,
             sections,
             wrap,
             first_row_min_height,
@@ -133,6 +133,11 @@ impl TextureAtlas {
         self.image.size
     }
 
+    /// The whole font texture, e.g. to paint text without a GPU.
+    pub fn image(&self) -> &FontImage {
+        &self.image
+    }
+
     /// Returns the locations and sizes of pre-rasterized discs (filled circles) in this atlas.
     pub fn prepared_discs(&self) -> Vec<PreparedDisc> {
         let size = self.size();